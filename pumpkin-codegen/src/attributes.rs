@@ -21,6 +21,9 @@ pub fn build() -> TokenStream {
     sorted_attributes.sort_by_key(|(_, raw)| raw.id);
 
     let mut constant_defs = Vec::new();
+    let mut from_name_arms = Vec::new();
+    let mut from_id_arms = Vec::new();
+    let mut name_arms = Vec::new();
 
     for (raw_name, raw_value) in sorted_attributes {
         let constant_ident = format_ident!("{}", raw_name.to_shouty_snake_case());
@@ -28,6 +31,10 @@ pub fn build() -> TokenStream {
         let id_lit = LitInt::new(&raw_value.id.to_string(), Span::call_site());
         let default_value_lit = raw_value.default_value;
 
+        from_name_arms.push(quote!(#raw_name => Some(&Self::#constant_ident),));
+        from_id_arms.push(quote!(#id_lit => Some(&Self::#constant_ident),));
+        name_arms.push(quote!(#id_lit => #raw_name,));
+
         constant_defs.push(quote!(
             pub const #constant_ident: Self = Self {
                 id: #id_lit,
@@ -57,6 +64,27 @@ pub fn build() -> TokenStream {
         }
         impl Attributes {
             #(#constant_defs)*
+
+            pub fn from_name(name: &str) -> Option<&'static Self> {
+                match name {
+                    #(#from_name_arms)*
+                    _ => None,
+                }
+            }
+
+            pub fn from_id(id: u8) -> Option<&'static Self> {
+                match id {
+                    #(#from_id_arms)*
+                    _ => None,
+                }
+            }
+
+            pub const fn name(&self) -> &'static str {
+                match self.id {
+                    #(#name_arms)*
+                    _ => "",
+                }
+            }
         }
     }
 }
@@ -69,6 +69,7 @@ fn generate_struct<T>(versions: &BTreeMap<MinecraftVersion, T>) -> TokenStream {
     }
 
     quote! {
+        #[derive(Clone, Copy)]
         pub struct TrackedId {
             #struct_fields
         }
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration for world backups.
+///
+/// Backups are full copies of the world folder. Files that did not change since the previous
+/// backup can be hard linked instead of copied, so every backup only takes up the space of what
+/// changed.
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct BackupConfig {
+    /// Whether backups are created on a schedule. `/backup now` works either way.
+    pub enabled: bool,
+    /// Time interval in seconds between scheduled backups.
+    pub interval: u64,
+    /// The folder backups are stored in.
+    pub folder: String,
+    /// How many backups are kept, deleting the oldest first. Specifying `0` keeps all of them.
+    pub max_backups: usize,
+    /// Whether unchanged files are hard linked to the previous backup instead of copied again.
+    pub hard_links: bool,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval: 3600,
+            folder: "backups".to_string(),
+            max_backups: 10,
+            hard_links: true,
+        }
+    }
+}
@@ -6,8 +6,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Deserialize, Serialize)]
 #[serde(default)]
 pub struct ChatConfig {
-    /// The custom chat format.
-    /// `Note`: it does not apply when secure chat is enabled.
+    /// The custom chat format, used unless a plugin replaces the chat renderer.
     pub format: String,
 }
 
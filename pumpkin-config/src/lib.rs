@@ -1,3 +1,4 @@
+use backup::BackupConfig;
 use fun::FunConfig;
 use logging::LoggingConfig;
 use pumpkin_util::world_seed::Seed;
@@ -8,6 +9,7 @@ use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::{fs, num::NonZeroU8, path::Path};
 use tracing::{debug, warn};
+pub mod backup;
 pub mod fun;
 pub mod logging;
 pub mod networking;
@@ -68,6 +70,8 @@ pub struct AdvancedConfiguration {
     pub server_links: ServerLinksConfig,
     /// Persistent player data handling and storage behaviour.
     pub player_data: PlayerDataConfig,
+    /// Scheduled world backups.
+    pub backup: BackupConfig,
     /// Optional fun and experimental features.
     pub fun: FunConfig,
 }
@@ -82,6 +86,9 @@ pub struct BasicConfiguration {
     pub java_edition: bool,
     /// The address and port to which the Java Edition server will bind.
     pub java_edition_address: SocketAddr,
+    /// Further addresses the Java Edition server binds to, e.g. `[::]:25565` to also accept IPv6.
+    /// Host names are resolved on startup and every address they resolve to is bound.
+    pub java_edition_additional_addresses: Vec<String>,
     /// Whether Bedrock Edition Clients are Accepted.
     pub bedrock_edition: bool,
     /// Whether Bedrock Edition Clients are Accepted.
@@ -104,6 +111,9 @@ pub struct BasicConfiguration {
     pub allow_end: bool,
     /// Whether the server is in hardcore mode.
     pub hardcore: bool,
+    /// Radius around the world spawn in which only operators can modify blocks. Has no effect
+    /// while there are no operators. Specifying `0` disables spawn protection.
+    pub spawn_protection: u32,
     /// Whether online mode is enabled. Requires valid Minecraft accounts.
     pub online_mode: bool,
     /// Whether packet encryption is enabled. Required when online mode is enabled.
@@ -130,6 +140,10 @@ pub struct BasicConfiguration {
     pub white_list: bool,
     /// Whether to enforce the whitelist.
     pub enforce_whitelist: bool,
+    /// Minutes of inactivity before a player is kicked. Specifying `0` disables the timeout.
+    pub player_idle_timeout: u32,
+    /// Minutes of inactivity before a player is considered AFK. Specifying `0` disables AFK detection.
+    pub player_afk_timeout: u32,
 }
 
 impl Default for BasicConfiguration {
@@ -137,6 +151,7 @@ impl Default for BasicConfiguration {
         Self {
             java_edition: true,
             java_edition_address: "0.0.0.0:25565".parse().unwrap(),
+            java_edition_additional_addresses: Vec::new(),
             bedrock_edition: true,
             bedrock_edition_address: "0.0.0.0:19132".parse().unwrap(),
             seed: Seed(random::get_seed()),
@@ -148,6 +163,7 @@ impl Default for BasicConfiguration {
             allow_nether: true,
             allow_end: true,
             hardcore: false,
+            spawn_protection: 16,
             online_mode: true,
             encryption: true,
             motd: "A blazingly fast Pumpkin server!".to_string(),
@@ -161,6 +177,8 @@ impl Default for BasicConfiguration {
             allow_chat_reports: false,
             white_list: false,
             enforce_whitelist: false,
+            player_idle_timeout: 0,
+            player_afk_timeout: 0,
         }
     }
 }
@@ -344,5 +362,11 @@ impl LoadConfiguration for BasicConfiguration {
                 "When allow_chat_reports is enabled, online_mode must be enabled"
             );
         }
+        if self.player_afk_timeout > 0 && self.player_idle_timeout > 0 {
+            assert!(
+                self.player_afk_timeout < self.player_idle_timeout,
+                "player_afk_timeout must be lower than player_idle_timeout, or players are kicked before they go AFK"
+            );
+        }
     }
 }
@@ -1,3 +1,5 @@
+use std::net::IpAddr;
+
 use serde::{Deserialize, Serialize};
 
 /// Configuration for proxy support.
@@ -12,6 +14,22 @@ pub struct ProxyConfig {
     pub velocity: VelocityConfig,
     /// Configuration for `BungeeCord` proxy integration.
     pub bungeecord: BungeeCordConfig,
+    /// Configuration for the `HAProxy` PROXY protocol. Works independently of `enabled`.
+    pub proxy_protocol: ProxyProtocolConfig,
+}
+
+/// Configuration for the `HAProxy` PROXY protocol.
+///
+/// TCP load balancers use it to pass on the real address of the client, which is then used for
+/// bans, the whitelist and plugins instead of the address of the load balancer.
+#[derive(Deserialize, Serialize, Default)]
+#[serde(default)]
+pub struct ProxyProtocolConfig {
+    /// Whether every Java Edition connection has to start with a PROXY protocol header.
+    pub enabled: bool,
+    /// Addresses of the load balancers allowed to send a header. Connections from other addresses
+    /// are refused, so leaving this empty refuses every connection.
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 /// Configuration for `BungeeCord` proxy integration.
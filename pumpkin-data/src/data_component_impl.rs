@@ -3,11 +3,13 @@
 use crate::attributes::Attributes;
 use crate::data_component::DataComponent;
 use crate::data_component::DataComponent::{
-    AttributeModifiers, BlocksAttacks, Consumable, CustomData, CustomName, Damage, DamageResistant,
-    DeathProtection, Enchantments, Equippable, FireworkExplosion, Fireworks, Food, ItemName,
-    JukeboxPlayable, MaxDamage, MaxStackSize, PotionContents, Tool, Unbreakable,
+    AttributeModifiers, BlockEntityData, BlocksAttacks, BucketEntityData, Consumable, Container,
+    CustomData, CustomModelData, CustomName, Damage, DamageResistant, DeathProtection,
+    Enchantments, Equippable, FireworkExplosion, Fireworks, Food, ItemName, JukeboxPlayable, Lore,
+    MaxDamage, MaxStackSize, PotionContents, Tool, Unbreakable,
 };
 use crate::entity_type::EntityType;
+use crate::item::Item;
 use crate::tag::{Tag, Taggable};
 use crate::{AttributeModifierSlot, Block, Enchantment};
 use crc_fast::CrcAlgorithm::Crc32Iscsi;
@@ -53,9 +55,86 @@ pub fn read_data(id: DataComponent, data: &NbtTag) -> Option<Box<dyn DataCompone
         PotionContents => Some(PotionContentsImpl::read_data(data)?.to_dyn()),
         Fireworks => Some(FireworksImpl::read_data(data)?.to_dyn()),
         FireworkExplosion => Some(FireworkExplosionImpl::read_data(data)?.to_dyn()),
+        MaxDamage => Some(MaxDamageImpl::read_data(data)?.to_dyn()),
+        CustomName => Some(CustomNameImpl::read_data(data)?.to_dyn()),
+        Lore => Some(LoreImpl::read_data(data)?.to_dyn()),
+        AttributeModifiers => Some(AttributeModifiersImpl::read_data(data)?.to_dyn()),
+        CustomModelData => Some(CustomModelDataImpl::read_data(data)?.to_dyn()),
+        Container => Some(ContainerImpl::read_data(data)?.to_dyn()),
+        BlockEntityData => Some(BlockEntityDataImpl::read_data(data)?.to_dyn()),
+        BucketEntityData => Some(BucketEntityDataImpl::read_data(data)?.to_dyn()),
         _ => None,
     }
 }
+
+/// Components an item stack adds to (`Some`) or removes from (`None`) its item's defaults
+pub type ComponentPatch = Vec<(DataComponent, Option<Box<dyn DataComponentImpl>>)>;
+
+/// Writes an item the way item stacks are stored, removed components get a `!` prefix
+pub fn write_item_nbt(
+    item: &Item,
+    count: u8,
+    patch: &[(DataComponent, Option<Box<dyn DataComponentImpl>>)],
+    compound: &mut NbtCompound,
+) {
+    compound.put_string("id", format!("minecraft:{}", item.registry_key));
+    compound.put_int("count", i32::from(count));
+
+    let mut components = NbtCompound::new();
+    for (id, data) in patch {
+        if let Some(data) = data {
+            components.put(id.to_name(), data.write_data());
+        } else {
+            let name = '!'.to_string() + id.to_name();
+            components.put(name.as_str(), NbtCompound::new());
+        }
+    }
+    compound.put_compound("components", components);
+}
+
+/// Reads an item written by [`write_item_nbt`]. Components that can't be read are skipped
+/// rather than dropping the whole item.
+#[must_use]
+pub fn read_item_nbt(compound: &NbtCompound) -> Option<(&'static Item, u8, ComponentPatch)> {
+    let full_id = compound.get_string("id")?;
+    let item = Item::from_registry_key(full_id.strip_prefix("minecraft:").unwrap_or(full_id))?;
+    let count = compound.get_int("count").unwrap_or(1).clamp(0, 255) as u8;
+
+    let mut patch = Vec::new();
+    if let Some(components) = compound.get_compound("components") {
+        for (name, data) in &components.child_tags {
+            if let Some(name) = name.strip_prefix('!') {
+                if let Some(id) = DataComponent::try_from_name(name) {
+                    patch.push((id, None));
+                }
+            } else if let Some(id) = DataComponent::try_from_name(name)
+                && let Some(data) = read_data(id, data)
+            {
+                patch.push((id, Some(data)));
+            }
+        }
+    }
+    Some((item, count, patch))
+}
+
+/// Compares two component patches regardless of their order
+#[must_use]
+pub fn patches_equal(
+    patch: &[(DataComponent, Option<Box<dyn DataComponentImpl>>)],
+    other: &[(DataComponent, Option<Box<dyn DataComponentImpl>>)],
+) -> bool {
+    patch.len() == other.len()
+        && patch.iter().all(|(id, data)| {
+            other
+                .iter()
+                .find(|(other_id, _)| other_id == id)
+                .is_some_and(|(_, other_data)| match (data, other_data) {
+                    (Some(data), Some(other_data)) => data.equal(other_data.as_ref()),
+                    (None, None) => true,
+                    _ => false,
+                })
+        })
+}
 // Also Pumpkin\pumpkin-protocol\src\codec\data_component.rs
 
 macro_rules! default_impl {
@@ -137,7 +216,18 @@ impl DataComponentImpl for MaxStackSizeImpl {
 pub struct MaxDamageImpl {
     pub max_damage: i32,
 }
+impl MaxDamageImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        data.extract_int().map(|max_damage| Self { max_damage })
+    }
+}
 impl DataComponentImpl for MaxDamageImpl {
+    fn write_data(&self) -> NbtTag {
+        NbtTag::Int(self.max_damage)
+    }
+    fn get_hash(&self) -> i32 {
+        get_i32_hash(self.max_damage) as i32
+    }
     default_impl!(MaxDamage);
 }
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -174,12 +264,22 @@ impl DataComponentImpl for UnbreakableImpl {
     }
     default_impl!(Unbreakable);
 }
-#[derive(Clone, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct CustomNameImpl {
-    // TODO make TextComponent const
-    pub name: &'static str,
+    pub name: TextComponent,
+}
+impl CustomNameImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        TextComponent::from_nbt(data).map(|name| Self { name })
+    }
 }
 impl DataComponentImpl for CustomNameImpl {
+    fn write_data(&self) -> NbtTag {
+        text_to_nbt(&self.name)
+    }
+    fn get_hash(&self) -> i32 {
+        get_nbt_hash(&text_to_nbt(&self.name)) as i32
+    }
     default_impl!(CustomName);
 }
 #[derive(Clone, Hash, PartialEq, Eq)]
@@ -192,8 +292,33 @@ impl DataComponentImpl for ItemNameImpl {
 }
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct ItemModelImpl;
-#[derive(Clone, Hash, PartialEq, Eq)]
-pub struct LoreImpl;
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LoreImpl {
+    pub lines: Vec<TextComponent>,
+}
+impl LoreImpl {
+    /// Vanilla refuses lore with more lines than this.
+    pub const MAX_LINES: usize = 256;
+
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let lines = data
+            .extract_list()?
+            .iter()
+            .take(Self::MAX_LINES)
+            .map(TextComponent::from_nbt)
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self { lines })
+    }
+}
+impl DataComponentImpl for LoreImpl {
+    fn write_data(&self) -> NbtTag {
+        NbtTag::List(self.lines.iter().map(text_to_nbt).collect())
+    }
+    fn get_hash(&self) -> i32 {
+        get_nbt_hash(&self.write_data()) as i32
+    }
+    default_impl!(Lore);
+}
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct RarityImpl;
 #[derive(Clone, Hash, PartialEq, Eq)]
@@ -216,10 +341,9 @@ impl EnchantmentsImpl {
 fn get_str_hash(val: &str) -> u32 {
     let mut digest = Digest::new(Crc32Iscsi);
     digest.update(&[12u8]);
-    digest.update(&(val.len() as u32).to_le_bytes());
-    let byte = val.as_bytes();
-    for i in byte {
-        digest.update(&[*i, 0u8]);
+    digest.update(&(val.encode_utf16().count() as u32).to_le_bytes());
+    for char in val.encode_utf16() {
+        digest.update(&char.to_le_bytes());
     }
     digest.finalize() as u32
 }
@@ -231,6 +355,108 @@ fn get_i32_hash(val: i32) -> u32 {
     digest.finalize() as u32
 }
 
+/// Hashes an NBT value the way the vanilla `HashOps` does, map entries are sorted by their hashes
+fn get_nbt_hash(tag: &NbtTag) -> u32 {
+    let mut digest = Digest::new(Crc32Iscsi);
+    match tag {
+        NbtTag::End => digest.update(&[1u8]),
+        NbtTag::Byte(value) => {
+            digest.update(&[6u8]);
+            digest.update(&value.to_le_bytes());
+        }
+        NbtTag::Short(value) => {
+            digest.update(&[7u8]);
+            digest.update(&value.to_le_bytes());
+        }
+        NbtTag::Int(value) => return get_i32_hash(*value),
+        NbtTag::Long(value) => {
+            digest.update(&[9u8]);
+            digest.update(&value.to_le_bytes());
+        }
+        NbtTag::Float(value) => {
+            digest.update(&[10u8]);
+            digest.update(&value.to_le_bytes());
+        }
+        NbtTag::Double(value) => {
+            digest.update(&[11u8]);
+            digest.update(&value.to_le_bytes());
+        }
+        NbtTag::String(value) => return get_str_hash(value),
+        NbtTag::ByteArray(values) => {
+            digest.update(&[14u8]);
+            digest.update(values);
+            digest.update(&[15u8]);
+        }
+        NbtTag::IntArray(values) => {
+            digest.update(&[16u8]);
+            for value in values {
+                digest.update(&value.to_le_bytes());
+            }
+            digest.update(&[17u8]);
+        }
+        NbtTag::LongArray(values) => {
+            digest.update(&[18u8]);
+            for value in values {
+                digest.update(&value.to_le_bytes());
+            }
+            digest.update(&[19u8]);
+        }
+        NbtTag::List(values) => return get_list_hash(values.iter().map(get_nbt_hash)),
+        NbtTag::Compound(compound) => {
+            return get_map_hash(
+                compound
+                    .child_tags
+                    .iter()
+                    .map(|(key, value)| (get_str_hash(key), get_nbt_hash(value))),
+            );
+        }
+    }
+    digest.finalize() as u32
+}
+
+/// NBT has no booleans and stores them as bytes, but vanilla hashes them as booleans
+fn get_bool_hash(val: bool) -> u32 {
+    let mut digest = Digest::new(Crc32Iscsi);
+    digest.update(&[13u8, u8::from(val)]);
+    digest.finalize() as u32
+}
+
+fn get_list_hash(hashes: impl Iterator<Item = u32>) -> u32 {
+    let mut digest = Digest::new(Crc32Iscsi);
+    digest.update(&[4u8]);
+    for hash in hashes {
+        digest.update(&hash.to_le_bytes());
+    }
+    digest.update(&[5u8]);
+    digest.finalize() as u32
+}
+
+/// Hashes map entries given as key and value hashes, sorted like vanilla does
+fn get_map_hash(entries: impl Iterator<Item = (u32, u32)>) -> u32 {
+    let mut entries: Vec<(u32, u32)> = entries.collect();
+    entries.sort_unstable();
+    let mut digest = Digest::new(Crc32Iscsi);
+    digest.update(&[2u8]);
+    for (key, value) in entries {
+        digest.update(&key.to_le_bytes());
+        digest.update(&value.to_le_bytes());
+    }
+    digest.update(&[3u8]);
+    digest.finalize() as u32
+}
+
+/// Text as vanilla stores it in components: plain text collapses to a bare string
+fn text_to_nbt(text: &TextComponent) -> NbtTag {
+    let tag = text.to_nbt();
+    if let NbtTag::Compound(compound) = &tag
+        && compound.child_tags.len() == 1
+        && let Some(text) = compound.get_string("text")
+    {
+        return NbtTag::String(text.to_string());
+    }
+    tag
+}
+
 #[test]
 fn hash() {
     assert_eq!(get_str_hash("minecraft:sharpness"), 2734053906u32);
@@ -243,6 +469,84 @@ fn hash() {
         -1580618251i32
     );
     assert_eq!(MaxStackSizeImpl { size: 99 }.get_hash(), -1632321551i32);
+    assert_eq!(
+        get_nbt_hash(&NbtTag::String("minecraft:sharpness".to_string())),
+        get_str_hash("minecraft:sharpness")
+    );
+    assert_eq!(
+        CustomNameImpl {
+            name: TextComponent::text("minecraft:sharpness"),
+        }
+        .get_hash(),
+        get_str_hash("minecraft:sharpness") as i32
+    );
+    let flags = [true, false];
+    assert_eq!(
+        CustomModelDataImpl {
+            floats: Vec::new(),
+            flags: flags.to_vec(),
+            strings: Vec::new(),
+            colors: Vec::new(),
+        }
+        .get_hash(),
+        get_map_hash(std::iter::once((
+            get_str_hash("flags"),
+            get_list_hash(flags.into_iter().map(get_bool_hash))
+        ))) as i32
+    );
+    assert_ne!(get_bool_hash(true), get_nbt_hash(&NbtTag::from(true)));
+}
+
+#[test]
+fn components_round_trip() {
+    let lore = LoreImpl {
+        lines: vec![
+            TextComponent::text("first"),
+            TextComponent::text("second").bold(),
+        ],
+    };
+    let tag = lore.write_data();
+    assert_eq!(LoreImpl::read_data(&tag), Some(lore));
+
+    let modifiers = AttributeModifiersImpl {
+        attribute_modifiers: Cow::Owned(vec![Modifier {
+            r#type: &Attributes::ARMOR,
+            id: Cow::Borrowed("minecraft:armor.chestplate"),
+            amount: 8.0,
+            operation: Operation::AddValue,
+            slot: AttributeModifierSlot::Chest,
+        }]),
+    };
+    let tag = modifiers.write_data();
+    assert_eq!(AttributeModifiersImpl::read_data(&tag), Some(modifiers));
+
+    let model_data = CustomModelDataImpl {
+        floats: vec![1.5],
+        flags: vec![true, false],
+        strings: vec!["model".to_string()],
+        colors: vec![0x00FF_00FF],
+    };
+    let tag = model_data.write_data();
+    assert_eq!(CustomModelDataImpl::read_data(&tag), Some(model_data));
+
+    let container = ContainerImpl {
+        items: vec![ContainerSlot {
+            slot: 3,
+            item: &crate::item::Item::DIAMOND_SWORD,
+            count: 1,
+            patch: vec![(
+                CustomName,
+                Some(
+                    CustomNameImpl {
+                        name: TextComponent::text("Excalibur"),
+                    }
+                    .to_dyn(),
+                ),
+            )],
+        }],
+    };
+    let tag = container.write_data();
+    assert!(ContainerImpl::read_data(&tag).is_some_and(|read| read == container));
 }
 
 impl DataComponentImpl for EnchantmentsImpl {
@@ -276,14 +580,130 @@ pub enum Operation {
     AddMultipliedBase,
     AddMultipliedTotal,
 }
+impl Operation {
+    const NAMES: [&str; 3] = ["add_value", "add_multiplied_base", "add_multiplied_total"];
+
+    #[must_use]
+    pub const fn to_id(self) -> i32 {
+        self as i32
+    }
+
+    #[must_use]
+    pub const fn from_id(id: i32) -> Option<Self> {
+        match id {
+            0 => Some(Self::AddValue),
+            1 => Some(Self::AddMultipliedBase),
+            2 => Some(Self::AddMultipliedTotal),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn to_name(self) -> &'static str {
+        Self::NAMES[self as usize]
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES
+            .iter()
+            .position(|operation| *operation == name)
+            .and_then(|id| Self::from_id(id as i32))
+    }
+}
+
+const SLOT_NAMES: [&str; 11] = [
+    "any", "mainhand", "offhand", "hand", "feet", "legs", "chest", "head", "armor", "body",
+    "saddle",
+];
+
+impl AttributeModifierSlot {
+    #[must_use]
+    pub const fn to_id(&self) -> i32 {
+        match self {
+            Self::Any => 0,
+            Self::MainHand => 1,
+            Self::OffHand => 2,
+            Self::Hand => 3,
+            Self::Feet => 4,
+            Self::Legs => 5,
+            Self::Chest => 6,
+            Self::Head => 7,
+            Self::Armor => 8,
+            Self::Body => 9,
+            Self::Saddle => 10,
+        }
+    }
+
+    #[must_use]
+    pub const fn from_id(id: i32) -> Option<Self> {
+        Some(match id {
+            0 => Self::Any,
+            1 => Self::MainHand,
+            2 => Self::OffHand,
+            3 => Self::Hand,
+            4 => Self::Feet,
+            5 => Self::Legs,
+            6 => Self::Chest,
+            7 => Self::Head,
+            8 => Self::Armor,
+            9 => Self::Body,
+            10 => Self::Saddle,
+            _ => return None,
+        })
+    }
+
+    #[must_use]
+    pub const fn to_name(&self) -> &'static str {
+        SLOT_NAMES[self.to_id() as usize]
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        SLOT_NAMES
+            .iter()
+            .position(|slot| *slot == name)
+            .and_then(|id| Self::from_id(id as i32))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Modifier {
     pub r#type: &'static Attributes,
-    pub id: &'static str,
+    pub id: Cow<'static, str>,
     pub amount: f64,
     pub operation: Operation,
     pub slot: AttributeModifierSlot,
 }
+impl Modifier {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let compound = data.extract_compound()?;
+        let name = compound.get_string("type")?;
+        Some(Self {
+            r#type: Attributes::from_name(name.strip_prefix("minecraft:").unwrap_or(name))?,
+            id: Cow::Owned(compound.get_string("id")?.to_string()),
+            amount: compound.get_double("amount")?,
+            operation: Operation::from_name(compound.get_string("operation")?)?,
+            slot: compound
+                .get_string("slot")
+                .map_or(Some(AttributeModifierSlot::Any), |slot| {
+                    AttributeModifierSlot::from_name(slot)
+                })?,
+        })
+    }
+
+    fn write_data(&self) -> NbtTag {
+        let mut compound = NbtCompound::new();
+        compound.put_string("type", format!("minecraft:{}", self.r#type.name()));
+        compound.put_string("id", self.id.to_string());
+        compound.put_double("amount", self.amount);
+        compound.put_string("operation", self.operation.to_name().to_string());
+        if self.slot != AttributeModifierSlot::Any {
+            compound.put_string("slot", self.slot.to_name().to_string());
+        }
+        NbtTag::Compound(compound)
+    }
+}
 impl Hash for Modifier {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.r#type.hash(state);
@@ -297,11 +717,119 @@ impl Hash for Modifier {
 pub struct AttributeModifiersImpl {
     pub attribute_modifiers: Cow<'static, [Modifier]>,
 }
+impl AttributeModifiersImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let modifiers = data
+            .extract_list()?
+            .iter()
+            .map(Modifier::read_data)
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            attribute_modifiers: Cow::Owned(modifiers),
+        })
+    }
+}
 impl DataComponentImpl for AttributeModifiersImpl {
+    fn write_data(&self) -> NbtTag {
+        NbtTag::List(
+            self.attribute_modifiers
+                .iter()
+                .map(Modifier::write_data)
+                .collect(),
+        )
+    }
+    fn get_hash(&self) -> i32 {
+        get_nbt_hash(&self.write_data()) as i32
+    }
     default_impl!(AttributeModifiers);
 }
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct CustomModelDataImpl;
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomModelDataImpl {
+    pub floats: Vec<f32>,
+    pub flags: Vec<bool>,
+    pub strings: Vec<String>,
+    pub colors: Vec<i32>,
+}
+impl CustomModelDataImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let compound = data.extract_compound()?;
+        let list = |name: &str| compound.get_list(name).unwrap_or_default();
+        Some(Self {
+            floats: list("floats")
+                .iter()
+                .map(NbtTag::extract_float)
+                .collect::<Option<_>>()?,
+            flags: list("flags")
+                .iter()
+                .map(NbtTag::extract_bool)
+                .collect::<Option<_>>()?,
+            strings: list("strings")
+                .iter()
+                .map(|tag| tag.extract_string().map(str::to_string))
+                .collect::<Option<_>>()?,
+            colors: list("colors")
+                .iter()
+                .map(NbtTag::extract_int)
+                .collect::<Option<_>>()?,
+        })
+    }
+}
+impl DataComponentImpl for CustomModelDataImpl {
+    fn write_data(&self) -> NbtTag {
+        let mut compound = NbtCompound::new();
+        if !self.floats.is_empty() {
+            compound.put_list(
+                "floats",
+                self.floats
+                    .iter()
+                    .map(|value| NbtTag::Float(*value))
+                    .collect(),
+            );
+        }
+        if !self.flags.is_empty() {
+            compound.put_list(
+                "flags",
+                self.flags
+                    .iter()
+                    .map(|value| NbtTag::from(*value))
+                    .collect(),
+            );
+        }
+        if !self.strings.is_empty() {
+            compound.put_list(
+                "strings",
+                self.strings
+                    .iter()
+                    .map(|value| NbtTag::String(value.clone()))
+                    .collect(),
+            );
+        }
+        if !self.colors.is_empty() {
+            compound.put_list(
+                "colors",
+                self.colors
+                    .iter()
+                    .map(|value| NbtTag::Int(*value))
+                    .collect(),
+            );
+        }
+        NbtTag::Compound(compound)
+    }
+    fn get_hash(&self) -> i32 {
+        let NbtTag::Compound(compound) = self.write_data() else {
+            return 0;
+        };
+        get_map_hash(compound.child_tags.iter().map(|(key, value)| {
+            let value = if key == "flags" {
+                get_list_hash(self.flags.iter().map(|flag| get_bool_hash(*flag)))
+            } else {
+                get_nbt_hash(value)
+            };
+            (get_str_hash(key), value)
+        })) as i32
+    }
+    default_impl!(CustomModelData);
+}
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct TooltipDisplayImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -1022,10 +1550,53 @@ pub struct TrimImpl;
 pub struct DebugStickStateImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct EntityDataImpl;
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct BucketEntityDataImpl;
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct BlockEntityDataImpl;
+/// Data of the mob caught in a bucket, applied to the mob when the bucket is emptied
+#[derive(Clone, Debug, PartialEq)]
+pub struct BucketEntityDataImpl {
+    pub data: NbtCompound,
+}
+impl BucketEntityDataImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        Some(Self {
+            data: data.extract_compound()?.clone(),
+        })
+    }
+}
+impl DataComponentImpl for BucketEntityDataImpl {
+    fn write_data(&self) -> NbtTag {
+        NbtTag::Compound(self.data.clone())
+    }
+    fn get_hash(&self) -> i32 {
+        get_nbt_hash(&self.write_data()) as i32
+    }
+    default_impl!(BucketEntityData);
+}
+/// Data a block entity is loaded with when the item is placed, `id` names its type
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockEntityDataImpl {
+    pub data: NbtCompound,
+}
+impl BlockEntityDataImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let data = data.extract_compound()?;
+        data.get_string("id")?;
+        Some(Self { data: data.clone() })
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &str {
+        self.data.get_string("id").unwrap_or_default()
+    }
+}
+impl DataComponentImpl for BlockEntityDataImpl {
+    fn write_data(&self) -> NbtTag {
+        NbtTag::Compound(self.data.clone())
+    }
+    fn get_hash(&self) -> i32 {
+        get_nbt_hash(&self.write_data()) as i32
+    }
+    default_impl!(BlockEntityData);
+}
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct InstrumentImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -1244,8 +1815,74 @@ pub struct BannerPatternsImpl;
 pub struct BaseColorImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct PotDecorationsImpl;
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct ContainerImpl;
+/// A non-empty stack stored in a container component, e.g. the contents of a shulker box item
+#[derive(Clone)]
+pub struct ContainerSlot {
+    pub slot: u8,
+    pub item: &'static Item,
+    pub count: u8,
+    pub patch: ComponentPatch,
+}
+impl PartialEq for ContainerSlot {
+    fn eq(&self, other: &Self) -> bool {
+        self.slot == other.slot
+            && self.item == other.item
+            && self.count == other.count
+            && patches_equal(&self.patch, &other.patch)
+    }
+}
+impl ContainerSlot {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let compound = data.extract_compound()?;
+        let slot = u8::try_from(compound.get_int("slot")?).ok()?;
+        let (item, count, patch) = read_item_nbt(compound.get_compound("item")?)?;
+        Some(Self {
+            slot,
+            item,
+            count,
+            patch,
+        })
+    }
+
+    fn write_data(&self) -> NbtTag {
+        let mut item = NbtCompound::new();
+        write_item_nbt(self.item, self.count, &self.patch, &mut item);
+        let mut compound = NbtCompound::new();
+        compound.put_int("slot", i32::from(self.slot));
+        compound.put_compound("item", item);
+        NbtTag::Compound(compound)
+    }
+}
+#[derive(Clone, PartialEq)]
+pub struct ContainerImpl {
+    /// Sorted by slot, empty slots are left out
+    pub items: Vec<ContainerSlot>,
+}
+impl ContainerImpl {
+    /// Vanilla containers hold at most this many slots.
+    pub const MAX_SLOTS: usize = 256;
+
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let mut items = data
+            .extract_list()?
+            .iter()
+            .take(Self::MAX_SLOTS)
+            .filter_map(ContainerSlot::read_data)
+            .collect::<Vec<_>>();
+        items.sort_by_key(|item| item.slot);
+        items.dedup_by_key(|item| item.slot);
+        Some(Self { items })
+    }
+}
+impl DataComponentImpl for ContainerImpl {
+    fn write_data(&self) -> NbtTag {
+        NbtTag::List(self.items.iter().map(ContainerSlot::write_data).collect())
+    }
+    fn get_hash(&self) -> i32 {
+        get_nbt_hash(&self.write_data()) as i32
+    }
+    default_impl!(Container);
+}
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct BlockStateImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -157,4 +157,124 @@ impl Attributes {
         id: 34,
         default_value: 0f64,
     };
+    pub fn from_name(name: &str) -> Option<&'static Self> {
+        match name {
+            "armor" => Some(&Self::ARMOR),
+            "armor_toughness" => Some(&Self::ARMOR_TOUGHNESS),
+            "attack_damage" => Some(&Self::ATTACK_DAMAGE),
+            "attack_knockback" => Some(&Self::ATTACK_KNOCKBACK),
+            "attack_speed" => Some(&Self::ATTACK_SPEED),
+            "block_break_speed" => Some(&Self::BLOCK_BREAK_SPEED),
+            "block_interaction_range" => Some(&Self::BLOCK_INTERACTION_RANGE),
+            "burning_time" => Some(&Self::BURNING_TIME),
+            "camera_distance" => Some(&Self::CAMERA_DISTANCE),
+            "explosion_knockback_resistance" => Some(&Self::EXPLOSION_KNOCKBACK_RESISTANCE),
+            "entity_interaction_range" => Some(&Self::ENTITY_INTERACTION_RANGE),
+            "fall_damage_multiplier" => Some(&Self::FALL_DAMAGE_MULTIPLIER),
+            "flying_speed" => Some(&Self::FLYING_SPEED),
+            "follow_range" => Some(&Self::FOLLOW_RANGE),
+            "gravity" => Some(&Self::GRAVITY),
+            "jump_strength" => Some(&Self::JUMP_STRENGTH),
+            "knockback_resistance" => Some(&Self::KNOCKBACK_RESISTANCE),
+            "luck" => Some(&Self::LUCK),
+            "max_absorption" => Some(&Self::MAX_ABSORPTION),
+            "max_health" => Some(&Self::MAX_HEALTH),
+            "mining_efficiency" => Some(&Self::MINING_EFFICIENCY),
+            "movement_efficiency" => Some(&Self::MOVEMENT_EFFICIENCY),
+            "movement_speed" => Some(&Self::MOVEMENT_SPEED),
+            "oxygen_bonus" => Some(&Self::OXYGEN_BONUS),
+            "safe_fall_distance" => Some(&Self::SAFE_FALL_DISTANCE),
+            "scale" => Some(&Self::SCALE),
+            "sneaking_speed" => Some(&Self::SNEAKING_SPEED),
+            "spawn_reinforcements" => Some(&Self::SPAWN_REINFORCEMENTS),
+            "step_height" => Some(&Self::STEP_HEIGHT),
+            "submerged_mining_speed" => Some(&Self::SUBMERGED_MINING_SPEED),
+            "sweeping_damage_ratio" => Some(&Self::SWEEPING_DAMAGE_RATIO),
+            "tempt_range" => Some(&Self::TEMPT_RANGE),
+            "water_movement_efficiency" => Some(&Self::WATER_MOVEMENT_EFFICIENCY),
+            "waypoint_transmit_range" => Some(&Self::WAYPOINT_TRANSMIT_RANGE),
+            "waypoint_receive_range" => Some(&Self::WAYPOINT_RECEIVE_RANGE),
+            _ => None,
+        }
+    }
+    pub fn from_id(id: u8) -> Option<&'static Self> {
+        match id {
+            0 => Some(&Self::ARMOR),
+            1 => Some(&Self::ARMOR_TOUGHNESS),
+            2 => Some(&Self::ATTACK_DAMAGE),
+            3 => Some(&Self::ATTACK_KNOCKBACK),
+            4 => Some(&Self::ATTACK_SPEED),
+            5 => Some(&Self::BLOCK_BREAK_SPEED),
+            6 => Some(&Self::BLOCK_INTERACTION_RANGE),
+            7 => Some(&Self::BURNING_TIME),
+            8 => Some(&Self::CAMERA_DISTANCE),
+            9 => Some(&Self::EXPLOSION_KNOCKBACK_RESISTANCE),
+            10 => Some(&Self::ENTITY_INTERACTION_RANGE),
+            11 => Some(&Self::FALL_DAMAGE_MULTIPLIER),
+            12 => Some(&Self::FLYING_SPEED),
+            13 => Some(&Self::FOLLOW_RANGE),
+            14 => Some(&Self::GRAVITY),
+            15 => Some(&Self::JUMP_STRENGTH),
+            16 => Some(&Self::KNOCKBACK_RESISTANCE),
+            17 => Some(&Self::LUCK),
+            18 => Some(&Self::MAX_ABSORPTION),
+            19 => Some(&Self::MAX_HEALTH),
+            20 => Some(&Self::MINING_EFFICIENCY),
+            21 => Some(&Self::MOVEMENT_EFFICIENCY),
+            22 => Some(&Self::MOVEMENT_SPEED),
+            23 => Some(&Self::OXYGEN_BONUS),
+            24 => Some(&Self::SAFE_FALL_DISTANCE),
+            25 => Some(&Self::SCALE),
+            26 => Some(&Self::SNEAKING_SPEED),
+            27 => Some(&Self::SPAWN_REINFORCEMENTS),
+            28 => Some(&Self::STEP_HEIGHT),
+            29 => Some(&Self::SUBMERGED_MINING_SPEED),
+            30 => Some(&Self::SWEEPING_DAMAGE_RATIO),
+            31 => Some(&Self::TEMPT_RANGE),
+            32 => Some(&Self::WATER_MOVEMENT_EFFICIENCY),
+            33 => Some(&Self::WAYPOINT_TRANSMIT_RANGE),
+            34 => Some(&Self::WAYPOINT_RECEIVE_RANGE),
+            _ => None,
+        }
+    }
+    pub const fn name(&self) -> &'static str {
+        match self.id {
+            0 => "armor",
+            1 => "armor_toughness",
+            2 => "attack_damage",
+            3 => "attack_knockback",
+            4 => "attack_speed",
+            5 => "block_break_speed",
+            6 => "block_interaction_range",
+            7 => "burning_time",
+            8 => "camera_distance",
+            9 => "explosion_knockback_resistance",
+            10 => "entity_interaction_range",
+            11 => "fall_damage_multiplier",
+            12 => "flying_speed",
+            13 => "follow_range",
+            14 => "gravity",
+            15 => "jump_strength",
+            16 => "knockback_resistance",
+            17 => "luck",
+            18 => "max_absorption",
+            19 => "max_health",
+            20 => "mining_efficiency",
+            21 => "movement_efficiency",
+            22 => "movement_speed",
+            23 => "oxygen_bonus",
+            24 => "safe_fall_distance",
+            25 => "scale",
+            26 => "sneaking_speed",
+            27 => "spawn_reinforcements",
+            28 => "step_height",
+            29 => "submerged_mining_speed",
+            30 => "sweeping_damage_ratio",
+            31 => "tempt_range",
+            32 => "water_movement_efficiency",
+            33 => "waypoint_transmit_range",
+            34 => "waypoint_receive_range",
+            _ => "",
+        }
+    }
 }
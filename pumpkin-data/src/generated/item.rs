@@ -4084,7 +4084,7 @@ impl Item {
                 &AttributeModifiersImpl {
                     attribute_modifiers: Cow::Borrowed(&[Modifier {
                         r#type: &Attributes::WAYPOINT_TRANSMIT_RANGE,
-                        id: "minecraft:waypoint_transmit_range_hide",
+                        id: Cow::Borrowed("minecraft:waypoint_transmit_range_hide"),
                         amount: -1f64,
                         operation: Operation::AddMultipliedTotal,
                         slot: AttributeModifierSlot::Head,
@@ -4204,14 +4204,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 1f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
@@ -4256,14 +4256,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
@@ -4308,14 +4308,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
@@ -4360,14 +4360,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 4f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
@@ -5999,14 +5999,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 8f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3.200000047683716f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -6093,14 +6093,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 1f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
@@ -6202,14 +6202,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 4f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
@@ -6330,14 +6330,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
@@ -6382,14 +6382,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -6436,14 +6436,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 4f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -6528,14 +6528,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
@@ -6578,14 +6578,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 4f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -6670,14 +6670,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.799999952316284f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -6726,14 +6726,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 2.5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -6782,14 +6782,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 1f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.8235294818878174f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -6818,14 +6818,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 4f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.4000000953674316f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -7142,7 +7142,7 @@ impl Item {
                 &AttributeModifiersImpl {
                     attribute_modifiers: Cow::Borrowed(&[Modifier {
                         r#type: &Attributes::WAYPOINT_TRANSMIT_RANGE,
-                        id: "minecraft:waypoint_transmit_range_hide",
+                        id: Cow::Borrowed("minecraft:waypoint_transmit_range_hide"),
                         amount: -1f64,
                         operation: Operation::AddMultipliedTotal,
                         slot: AttributeModifierSlot::Head,
@@ -9201,14 +9201,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 8f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -9276,14 +9276,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
@@ -9328,14 +9328,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 8f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
@@ -9380,14 +9380,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
@@ -9432,14 +9432,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -9486,14 +9486,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 11f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -9540,14 +9540,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 6f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
@@ -9590,14 +9590,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 11f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -9663,14 +9663,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 4f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.799999952316284f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -9719,14 +9719,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 4.5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -9775,14 +9775,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3.0476189851760864f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -9811,14 +9811,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 6f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.4000000953674316f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -10097,7 +10097,7 @@ impl Item {
                 &AttributeModifiersImpl {
                     attribute_modifiers: Cow::Borrowed(&[Modifier {
                         r#type: &Attributes::WAYPOINT_TRANSMIT_RANGE,
-                        id: "minecraft:waypoint_transmit_range_hide",
+                        id: Cow::Borrowed("minecraft:waypoint_transmit_range_hide"),
                         amount: -1f64,
                         operation: Operation::AddMultipliedTotal,
                         slot: AttributeModifierSlot::Head,
@@ -12157,14 +12157,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 6f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -12213,14 +12213,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 1f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
@@ -12298,14 +12298,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
@@ -12350,14 +12350,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
@@ -12402,14 +12402,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -12456,14 +12456,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 7f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -12510,14 +12510,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
@@ -12560,14 +12560,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 7f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -12614,14 +12614,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 1f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.799999952316284f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -12670,14 +12670,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 1.5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -12726,14 +12726,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.9473683834075928f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -12762,14 +12762,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.4000000953674316f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -14279,14 +14279,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 8f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3.0999999046325684f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -14373,14 +14373,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
@@ -14444,14 +14444,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 6f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
@@ -14534,14 +14534,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
@@ -14586,14 +14586,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -1f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -14640,14 +14640,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -14713,14 +14713,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
@@ -14763,14 +14763,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -14855,14 +14855,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.799999952316284f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -14911,14 +14911,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 3.5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -14967,14 +14967,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.9473683834075928f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -15003,14 +15003,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.4000000953674316f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -15748,14 +15748,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 1f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
@@ -15800,14 +15800,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
@@ -15852,14 +15852,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 1f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
@@ -15902,14 +15902,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -15956,14 +15956,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
@@ -17202,14 +17202,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3.4000000953674316f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -19459,14 +19459,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 9f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -19546,21 +19546,21 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
                         },
                         Modifier {
                             r#type: &Attributes::KNOCKBACK_RESISTANCE,
-                            id: "minecraft:armor.boots",
+                            id: Cow::Borrowed("minecraft:armor.boots"),
                             amount: 0.10000000149011612f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Feet,
@@ -19611,21 +19611,21 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 8f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
                         },
                         Modifier {
                             r#type: &Attributes::KNOCKBACK_RESISTANCE,
-                            id: "minecraft:armor.chestplate",
+                            id: Cow::Borrowed("minecraft:armor.chestplate"),
                             amount: 0.10000000149011612f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Chest,
@@ -19676,21 +19676,21 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
                         },
                         Modifier {
                             r#type: &Attributes::KNOCKBACK_RESISTANCE,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 0.10000000149011612f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
@@ -19741,14 +19741,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -19801,21 +19801,21 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 19f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::KNOCKBACK_RESISTANCE,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 0.10000000149011612f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -19893,21 +19893,21 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 6f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
                         },
                         Modifier {
                             r#type: &Attributes::KNOCKBACK_RESISTANCE,
-                            id: "minecraft:armor.leggings",
+                            id: Cow::Borrowed("minecraft:armor.leggings"),
                             amount: 0.10000000149011612f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Legs,
@@ -19956,21 +19956,21 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 19f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::KNOCKBACK_RESISTANCE,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 0.10000000149011612f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -20023,14 +20023,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.799999952316284f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -20110,14 +20110,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 5.5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -20172,14 +20172,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 4f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3.13043475151062f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -20214,14 +20214,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 7f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.4000000953674316f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -22145,7 +22145,7 @@ impl Item {
                 &AttributeModifiersImpl {
                     attribute_modifiers: Cow::Borrowed(&[Modifier {
                         r#type: &Attributes::WAYPOINT_TRANSMIT_RANGE,
-                        id: "minecraft:waypoint_transmit_range_hide",
+                        id: Cow::Borrowed("minecraft:waypoint_transmit_range_hide"),
                         amount: -1f64,
                         operation: Operation::AddMultipliedTotal,
                         slot: AttributeModifierSlot::Head,
@@ -22641,7 +22641,7 @@ impl Item {
                 &AttributeModifiersImpl {
                     attribute_modifiers: Cow::Borrowed(&[Modifier {
                         r#type: &Attributes::WAYPOINT_TRANSMIT_RANGE,
-                        id: "minecraft:waypoint_transmit_range_hide",
+                        id: Cow::Borrowed("minecraft:waypoint_transmit_range_hide"),
                         amount: -1f64,
                         operation: Operation::AddMultipliedTotal,
                         slot: AttributeModifierSlot::Head,
@@ -26355,7 +26355,7 @@ impl Item {
                 &AttributeModifiersImpl {
                     attribute_modifiers: Cow::Borrowed(&[Modifier {
                         r#type: &Attributes::WAYPOINT_TRANSMIT_RANGE,
-                        id: "minecraft:waypoint_transmit_range_hide",
+                        id: Cow::Borrowed("minecraft:waypoint_transmit_range_hide"),
                         amount: -1f64,
                         operation: Operation::AddMultipliedTotal,
                         slot: AttributeModifierSlot::Head,
@@ -27667,14 +27667,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 8f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3.200000047683716f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -27818,14 +27818,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -27874,14 +27874,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.799999952316284f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -27949,14 +27949,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 2.5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -28024,14 +28024,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 1f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.666666626930237f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -28079,14 +28079,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 4f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.4000000953674316f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -29252,14 +29252,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 8f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.9000000953674316f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -29623,14 +29623,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 2f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.helmet",
+                            id: Cow::Borrowed("minecraft:armor.helmet"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Head,
@@ -32257,7 +32257,7 @@ impl Item {
                 &AttributeModifiersImpl {
                     attribute_modifiers: Cow::Borrowed(&[Modifier {
                         r#type: &Attributes::WAYPOINT_TRANSMIT_RANGE,
-                        id: "minecraft:waypoint_transmit_range_hide",
+                        id: Cow::Borrowed("minecraft:waypoint_transmit_range_hide"),
                         amount: -1f64,
                         operation: Operation::AddMultipliedTotal,
                         slot: AttributeModifierSlot::Head,
@@ -32339,14 +32339,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ARMOR,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 11f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
                         },
                         Modifier {
                             r#type: &Attributes::ARMOR_TOUGHNESS,
-                            id: "minecraft:armor.body",
+                            id: Cow::Borrowed("minecraft:armor.body"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::Body,
@@ -32412,14 +32412,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 6f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3.200000047683716f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -32468,14 +32468,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -32524,14 +32524,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 1f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.799999952316284f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -32580,14 +32580,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 1.5f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -32636,14 +32636,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 0f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.4615384340286255f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -32672,14 +32672,14 @@ impl Item {
                     attribute_modifiers: Cow::Borrowed(&[
                         Modifier {
                             r#type: &Attributes::ATTACK_DAMAGE,
-                            id: "minecraft:base_attack_damage",
+                            id: Cow::Borrowed("minecraft:base_attack_damage"),
                             amount: 3f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
                         },
                         Modifier {
                             r#type: &Attributes::ATTACK_SPEED,
-                            id: "minecraft:base_attack_speed",
+                            id: Cow::Borrowed("minecraft:base_attack_speed"),
                             amount: -2.4000000953674316f64,
                             operation: Operation::AddValue,
                             slot: AttributeModifierSlot::MainHand,
@@ -33109,7 +33109,7 @@ impl Item {
                 &AttributeModifiersImpl {
                     attribute_modifiers: Cow::Borrowed(&[Modifier {
                         r#type: &Attributes::WAYPOINT_TRANSMIT_RANGE,
-                        id: "minecraft:waypoint_transmit_range_hide",
+                        id: Cow::Borrowed("minecraft:waypoint_transmit_range_hide"),
                         amount: -1f64,
                         operation: Operation::AddMultipliedTotal,
                         slot: AttributeModifierSlot::Head,
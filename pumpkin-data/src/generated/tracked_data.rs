@@ -1,5 +1,6 @@
 /* This file is generated. Do not edit manually. */
 use pumpkin_util::version::MinecraftVersion;
+#[derive(Clone, Copy)]
 pub struct TrackedId {
     pub v1_21: u8,
     pub v1_21_2: u8,
@@ -31,6 +31,8 @@ pub struct ResultSlot {
     pub id: AtomicU8,
     pub result: Arc<Mutex<ItemStack>>,
     recipe_cache: AtomicCell<Option<&'static CraftingRecipeTypes>>,
+    /// Items taken out by a swap click, which count as crafted once the take completes.
+    swap_crafted: AtomicU8,
 }
 
 fn is_symmetrical_horizontally(pattern: &'static [&'static str]) -> bool {
@@ -235,7 +237,10 @@ async fn recipe_matches<'a>(
 }
 
 impl ResultSlot {
-    //fn stat_crafted(&self, _crafted_amount: u8, _player: &dyn InventoryPlayer) {}
+    #[expect(clippy::unused_self)]
+    const fn stat_crafted(&self, _crafted_amount: u8, _player: &dyn InventoryPlayer) {
+        // TODO: Crafting statistics and recipe advancements
+    }
 
     pub fn new(inventory: Arc<dyn RecipeInputInventory>) -> Self {
         Self {
@@ -243,6 +248,7 @@ impl ResultSlot {
             id: AtomicU8::new(0),
             result: Arc::new(Mutex::new(ItemStack::EMPTY.clone())),
             recipe_cache: AtomicCell::new(None),
+            swap_crafted: AtomicU8::new(0),
         }
     }
 
@@ -346,10 +352,24 @@ impl Slot for ResultSlot {
         })
     }
 
+    fn can_take_item_for_pick_all(&self) -> bool {
+        false
+    }
+
+    fn can_drag_to(&self) -> bool {
+        false
+    }
+
+    fn on_swap_craft(&self, amount: u8) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            self.swap_crafted.fetch_add(amount, Ordering::Relaxed);
+        })
+    }
+
     fn on_take_item<'a>(
         &'a self,
-        _player: &'a dyn InventoryPlayer,
-        _stack: &'a ItemStack,
+        player: &'a dyn InventoryPlayer,
+        stack: &'a ItemStack,
     ) -> BoxFuture<'a, ()> {
         Box::pin(async move {
             for i in 0..self.inventory.size() {
@@ -360,8 +380,8 @@ impl Slot for ResultSlot {
                     stack.item_count -= 1;
                 }
             }
-            // TODO
-            //self.stat_crafted(stack.item_count, player);
+            let swap_crafted = self.swap_crafted.swap(0, Ordering::Relaxed);
+            self.stat_crafted(stack.item_count.max(swap_crafted), player);
             self.mark_dirty().await;
         })
     }
@@ -0,0 +1,294 @@
+use std::{
+    any::Any,
+    array::from_fn,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU8, Ordering},
+    },
+};
+
+use pumpkin_data::{
+    data_component_impl::{EntityTypeOrTag, EquipmentSlot, EquippableImpl},
+    entity::EntityType,
+};
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+use pumpkin_world::{
+    inventory::{Clearable, Inventory, InventoryFuture},
+    item::ItemStack,
+};
+use tokio::sync::Mutex;
+
+use crate::entity_equipment::EntityEquipment;
+
+/// The inventory of a horse-like mount.
+///
+/// Slot 0 is the saddle and slot 1 the body armor (or carpet for llamas), both backed by the
+/// equipment of the mount. The chest slots follow, three per column.
+pub struct HorseInventory {
+    pub entity_type: &'static EntityType,
+    pub equipment: Arc<Mutex<EntityEquipment>>,
+    pub chest: [Arc<Mutex<ItemStack>>; Self::MAX_CHEST_SIZE],
+    columns: AtomicU8,
+    dirty: AtomicBool,
+}
+
+impl HorseInventory {
+    pub const SADDLE_SLOT: usize = 0;
+    pub const BODY_SLOT: usize = 1;
+    pub const CHEST_START: usize = 2;
+    pub const MAX_COLUMNS: u8 = 5;
+    pub const MAX_CHEST_SIZE: usize = Self::MAX_COLUMNS as usize * 3;
+
+    #[must_use]
+    pub fn new(entity_type: &'static EntityType, equipment: Arc<Mutex<EntityEquipment>>) -> Self {
+        Self {
+            entity_type,
+            equipment,
+            chest: from_fn(|_| Arc::new(Mutex::new(ItemStack::EMPTY.clone()))),
+            columns: AtomicU8::new(0),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    /// The number of chest columns, zero while the mount carries no chest.
+    pub fn columns(&self) -> u8 {
+        self.columns.load(Ordering::Relaxed)
+    }
+
+    pub fn set_columns(&self, columns: u8) {
+        self.columns
+            .store(columns.min(Self::MAX_COLUMNS), Ordering::Relaxed);
+    }
+
+    pub fn chest_size(&self) -> usize {
+        usize::from(self.columns()) * 3
+    }
+
+    /// Returns whether the inventory changed since the last call.
+    pub fn take_dirty(&self) -> bool {
+        self.dirty.swap(false, Ordering::Relaxed)
+    }
+
+    /// Takes every item out of the chest, including slots beyond the current chest size.
+    pub async fn take_chest_items(&self) -> Vec<ItemStack> {
+        let mut items = Vec::new();
+        for slot in &self.chest {
+            let stack = std::mem::replace(&mut *slot.lock().await, ItemStack::EMPTY.clone());
+            if !stack.is_empty() {
+                items.push(stack);
+            }
+        }
+        items
+    }
+
+    /// Writes the chest contents as `Items`, numbered from the first chest slot.
+    pub async fn write_chest_nbt(&self, nbt: &mut NbtCompound) {
+        let mut items = Vec::new();
+        for (slot, stack) in self.chest.iter().enumerate() {
+            let stack = stack.lock().await;
+            if !stack.is_empty() {
+                let mut item_nbt = NbtCompound::new();
+                item_nbt.put_byte("Slot", slot as i8);
+                stack.write_item_stack(&mut item_nbt);
+                items.push(NbtTag::Compound(item_nbt));
+            }
+        }
+        nbt.put_list("Items", items);
+    }
+
+    /// Reads the chest contents written by [`Self::write_chest_nbt`].
+    pub fn read_chest_nbt(&self, nbt: &NbtCompound) {
+        self.read_data(nbt, &self.chest);
+    }
+
+    /// Whether `stack` can be worn by the mount in `slot`.
+    #[must_use]
+    pub fn can_equip(&self, slot: &EquipmentSlot, stack: &ItemStack) -> bool {
+        let Some(equippable) = stack.get_data_component::<EquippableImpl>() else {
+            return false;
+        };
+        if equippable.slot != slot {
+            return false;
+        }
+        equippable.allowed_entities.is_none_or(|allowed| {
+            allowed.iter().any(|entry| match entry {
+                EntityTypeOrTag::Tag(tag) => tag.1.contains(&self.entity_type.id),
+                EntityTypeOrTag::Single(entity_type) => entity_type.id == self.entity_type.id,
+            })
+        })
+    }
+
+    async fn get_equipment_stack(&self, slot: &EquipmentSlot) -> Arc<Mutex<ItemStack>> {
+        self.equipment.lock().await.get_or_insert(slot)
+    }
+
+    /// The stack in `slot`, or a detached empty stack for slots past the current chest size.
+    async fn slot_stack(&self, slot: usize) -> Arc<Mutex<ItemStack>> {
+        match slot {
+            Self::SADDLE_SLOT => self.get_equipment_stack(&EquipmentSlot::SADDLE).await,
+            Self::BODY_SLOT => self.get_equipment_stack(&EquipmentSlot::BODY).await,
+            _ if slot < self.size() => self.chest[slot - Self::CHEST_START].clone(),
+            _ => Arc::new(Mutex::new(ItemStack::EMPTY.clone())),
+        }
+    }
+}
+
+impl Inventory for HorseInventory {
+    fn size(&self) -> usize {
+        Self::CHEST_START + self.chest_size()
+    }
+
+    fn is_empty(&self) -> InventoryFuture<'_, bool> {
+        Box::pin(async move {
+            for slot in 0..self.size() {
+                if !self.slot_stack(slot).await.lock().await.is_empty() {
+                    return false;
+                }
+            }
+
+            true
+        })
+    }
+
+    fn get_stack(&self, slot: usize) -> InventoryFuture<'_, Arc<Mutex<ItemStack>>> {
+        Box::pin(async move { self.slot_stack(slot).await })
+    }
+
+    fn remove_stack(&self, slot: usize) -> InventoryFuture<'_, ItemStack> {
+        Box::pin(async move {
+            let stack = self.slot_stack(slot).await;
+            let removed = std::mem::replace(&mut *stack.lock().await, ItemStack::EMPTY.clone());
+            self.mark_dirty();
+            removed
+        })
+    }
+
+    fn remove_stack_specific(&self, slot: usize, amount: u8) -> InventoryFuture<'_, ItemStack> {
+        Box::pin(async move {
+            let removed = self.slot_stack(slot).await.lock().await.split(amount);
+            self.mark_dirty();
+            removed
+        })
+    }
+
+    fn set_stack(&self, slot: usize, stack: ItemStack) -> InventoryFuture<'_, ()> {
+        Box::pin(async move {
+            *self.slot_stack(slot).await.lock().await = stack;
+            self.mark_dirty();
+        })
+    }
+
+    fn mark_dirty(&self) {
+        self.dirty.store(true, Ordering::Relaxed);
+    }
+
+    fn is_valid_slot_for(&self, slot: usize, stack: &ItemStack) -> bool {
+        match slot {
+            Self::SADDLE_SLOT => self.can_equip(&EquipmentSlot::SADDLE, stack),
+            Self::BODY_SLOT => self.can_equip(&EquipmentSlot::BODY, stack),
+            _ => true,
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl Clearable for HorseInventory {
+    fn clear(&self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            for slot in 0..self.size() {
+                *self.slot_stack(slot).await.lock().await = ItemStack::EMPTY.clone();
+            }
+            self.mark_dirty();
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_data::item::Item;
+    use pumpkin_nbt::compound::NbtCompound;
+    use pumpkin_world::inventory::Inventory;
+    use pumpkin_world::item::ItemStack;
+    use tokio::sync::Mutex;
+
+    use super::HorseInventory;
+    use crate::entity_equipment::EntityEquipment;
+
+    fn inventory(entity_type: &'static EntityType) -> HorseInventory {
+        HorseInventory::new(entity_type, Arc::new(Mutex::new(EntityEquipment::new())))
+    }
+
+    #[test]
+    fn equipment_slots_only_take_wearables() {
+        let horse = inventory(&EntityType::HORSE);
+        let saddle = ItemStack::new(1, &Item::SADDLE);
+        let armor = ItemStack::new(1, &Item::DIAMOND_HORSE_ARMOR);
+        let stone = ItemStack::new(1, &Item::STONE);
+
+        assert!(horse.is_valid_slot_for(HorseInventory::SADDLE_SLOT, &saddle));
+        assert!(!horse.is_valid_slot_for(HorseInventory::SADDLE_SLOT, &armor));
+        assert!(!horse.is_valid_slot_for(HorseInventory::SADDLE_SLOT, &stone));
+        assert!(horse.is_valid_slot_for(HorseInventory::BODY_SLOT, &armor));
+        assert!(!horse.is_valid_slot_for(HorseInventory::BODY_SLOT, &saddle));
+        assert!(horse.is_valid_slot_for(HorseInventory::CHEST_START, &stone));
+
+        // Horse armor only fits horses
+        let llama = inventory(&EntityType::LLAMA);
+        assert!(!llama.is_valid_slot_for(HorseInventory::BODY_SLOT, &armor));
+    }
+
+    #[test]
+    fn size_follows_columns() {
+        let inventory = inventory(&EntityType::DONKEY);
+        assert_eq!(inventory.size(), 2);
+        inventory.set_columns(3);
+        assert_eq!(inventory.size(), 11);
+        inventory.set_columns(9);
+        assert_eq!(inventory.size(), 2 + HorseInventory::MAX_CHEST_SIZE);
+        inventory.set_columns(0);
+        assert_eq!(inventory.size(), 2);
+    }
+
+    #[tokio::test]
+    async fn slots_past_the_chest_are_empty() {
+        let inventory = inventory(&EntityType::DONKEY);
+        *inventory.chest[0].lock().await = ItemStack::new(1, &Item::STONE);
+        assert!(inventory.get_stack(2).await.lock().await.is_empty());
+        assert!(inventory.get_stack(100).await.lock().await.is_empty());
+
+        inventory.set_columns(1);
+        assert!(!inventory.get_stack(2).await.lock().await.is_empty());
+        assert!(inventory.get_stack(100).await.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn chest_round_trips_through_nbt() {
+        let inventory = inventory(&EntityType::DONKEY);
+        inventory.set_columns(5);
+        inventory
+            .set_stack(HorseInventory::CHEST_START, ItemStack::new(3, &Item::STONE))
+            .await;
+        inventory
+            .set_stack(
+                HorseInventory::CHEST_START + 14,
+                ItemStack::new(1, &Item::CHEST),
+            )
+            .await;
+        let mut nbt = NbtCompound::new();
+        inventory.write_chest_nbt(&mut nbt).await;
+
+        let read = self::inventory(&EntityType::DONKEY);
+        read.read_chest_nbt(&nbt);
+        let stone = read.chest[0].lock().await.clone();
+        assert_eq!((stone.item.id, stone.item_count), (Item::STONE.id, 3));
+        assert_eq!(read.chest[14].lock().await.item.id, Item::CHEST.id);
+        assert!(read.chest[1].lock().await.is_empty());
+    }
+}
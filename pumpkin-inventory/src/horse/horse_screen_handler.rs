@@ -0,0 +1,296 @@
+use std::{
+    any::Any,
+    sync::{
+        Arc,
+        atomic::{AtomicU8, Ordering},
+    },
+};
+
+use pumpkin_world::{inventory::Inventory, item::ItemStack};
+
+use crate::{
+    player::player_inventory::PlayerInventory,
+    screen_handler::{
+        InventoryPlayer, ItemStackFuture, ScreenHandler, ScreenHandlerBehaviour,
+        ScreenHandlerFuture,
+    },
+    slot::{BoxFuture, NormalSlot, Slot},
+};
+
+use super::horse_inventory::HorseInventory;
+
+/// Saddle or body armor slot of a mount, holding a single item the mount can wear there.
+pub struct MountEquipmentSlot {
+    pub inventory: Arc<dyn Inventory>,
+    pub index: usize,
+    pub id: AtomicU8,
+}
+
+impl MountEquipmentSlot {
+    pub fn new(inventory: Arc<dyn Inventory>, index: usize) -> Self {
+        Self {
+            inventory,
+            index,
+            id: AtomicU8::new(0),
+        }
+    }
+}
+
+impl Slot for MountEquipmentSlot {
+    fn get_inventory(&self) -> Arc<dyn Inventory> {
+        self.inventory.clone()
+    }
+
+    fn get_index(&self) -> usize {
+        self.index
+    }
+
+    fn set_id(&self, id: usize) {
+        self.id.store(id as u8, Ordering::Relaxed);
+    }
+
+    fn mark_dirty(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            self.inventory.mark_dirty();
+        })
+    }
+
+    fn get_max_item_count(&self) -> BoxFuture<'_, u8> {
+        Box::pin(async move { 1 })
+    }
+
+    fn can_insert<'a>(&'a self, stack: &'a ItemStack) -> BoxFuture<'a, bool> {
+        Box::pin(async move { self.inventory.is_valid_slot_for(self.index, stack) })
+    }
+}
+
+/// The screen of a horse, donkey, mule or llama: saddle and body armor slots, the chest slots
+/// if it carries one, then the player inventory.
+pub struct HorseScreenHandler {
+    pub inventory: Arc<HorseInventory>,
+    behaviour: ScreenHandlerBehaviour,
+}
+
+impl HorseScreenHandler {
+    pub async fn new(
+        sync_id: u8,
+        player_inventory: &Arc<PlayerInventory>,
+        inventory: Arc<HorseInventory>,
+    ) -> Self {
+        let mut handler = Self {
+            inventory: inventory.clone(),
+            // The client opens this screen from the mount screen packet, which has no window type
+            behaviour: ScreenHandlerBehaviour::new(sync_id, None),
+        };
+
+        inventory.on_open().await;
+
+        let mount_inventory: Arc<dyn Inventory> = inventory.clone();
+        handler.add_slot(Arc::new(MountEquipmentSlot::new(
+            mount_inventory.clone(),
+            HorseInventory::SADDLE_SLOT,
+        )));
+        handler.add_slot(Arc::new(MountEquipmentSlot::new(
+            mount_inventory.clone(),
+            HorseInventory::BODY_SLOT,
+        )));
+        for i in 0..inventory.chest_size() {
+            handler.add_slot(Arc::new(NormalSlot::new(
+                mount_inventory.clone(),
+                HorseInventory::CHEST_START + i,
+            )));
+        }
+        let player_inventory: Arc<dyn Inventory> = player_inventory.clone();
+        handler.add_player_slots(&player_inventory);
+
+        handler
+    }
+}
+
+impl ScreenHandler for HorseScreenHandler {
+    fn on_closed<'a>(&'a mut self, player: &'a dyn InventoryPlayer) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            self.default_on_closed(player).await;
+            self.inventory.on_close().await;
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_behaviour(&self) -> &ScreenHandlerBehaviour {
+        &self.behaviour
+    }
+
+    fn get_behaviour_mut(&mut self) -> &mut ScreenHandlerBehaviour {
+        &mut self.behaviour
+    }
+
+    fn quick_move<'a>(
+        &'a mut self,
+        _player: &'a dyn InventoryPlayer,
+        slot_index: i32,
+    ) -> ItemStackFuture<'a> {
+        Box::pin(async move {
+            const SADDLE: i32 = HorseInventory::SADDLE_SLOT as i32;
+            const BODY: i32 = HorseInventory::BODY_SLOT as i32;
+            const CHEST_START: i32 = HorseInventory::CHEST_START as i32;
+
+            let mount_end = CHEST_START + self.inventory.chest_size() as i32;
+            let slots_len = self.get_behaviour().slots.len() as i32;
+            let hotbar_start = slots_len - 9;
+
+            let slot = self.get_behaviour().slots[slot_index as usize].clone();
+            if !slot.has_stack().await {
+                return ItemStack::EMPTY.clone();
+            }
+
+            let slot_stack_lock = slot.get_stack().await;
+            let mut stack = slot_stack_lock.lock().await;
+            let stack_left = stack.clone();
+
+            let body_slot = self.get_behaviour().slots[BODY as usize].clone();
+            let saddle_slot = self.get_behaviour().slots[SADDLE as usize].clone();
+
+            let success = if slot_index < mount_end {
+                // From the mount to the player inventory
+                self.insert_item(&mut stack, mount_end, slots_len, true)
+                    .await
+            } else if body_slot.can_insert(&stack).await && !body_slot.has_stack().await {
+                self.insert_item(&mut stack, BODY, BODY + 1, false).await
+            } else if saddle_slot.can_insert(&stack).await && !saddle_slot.has_stack().await {
+                self.insert_item(&mut stack, SADDLE, SADDLE + 1, false)
+                    .await
+            } else if mount_end > CHEST_START
+                && self
+                    .insert_item(&mut stack, CHEST_START, mount_end, false)
+                    .await
+            {
+                true
+            } else if slot_index >= hotbar_start {
+                // From the hotbar to the main inventory
+                self.insert_item(&mut stack, mount_end, hotbar_start, false)
+                    .await
+            } else {
+                // From the main inventory to the hotbar
+                self.insert_item(&mut stack, hotbar_start, slots_len, false)
+                    .await
+            };
+
+            if !success {
+                return ItemStack::EMPTY.clone();
+            }
+
+            if stack.is_empty() {
+                drop(stack);
+                slot.set_stack(ItemStack::EMPTY.clone()).await;
+            } else {
+                drop(stack);
+                slot.mark_dirty().await;
+            }
+
+            stack_left
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_data::item::Item;
+    use pumpkin_protocol::java::server::play::SlotActionType;
+    use pumpkin_world::item::ItemStack;
+    use tokio::sync::Mutex;
+
+    use super::HorseScreenHandler;
+    use crate::entity_equipment::EntityEquipment;
+    use crate::horse::horse_inventory::HorseInventory;
+    use crate::screen_handler::ScreenHandler;
+    use crate::screen_handler::test::TestPlayer;
+
+    /// Slots 0 and 1 are the saddle and body, 2-4 the chest, 5-31 the main inventory and 32-40
+    /// the hotbar.
+    const HOTBAR_START: usize = 32;
+
+    async fn horse(player: &TestPlayer) -> HorseScreenHandler {
+        let inventory = Arc::new(HorseInventory::new(
+            &EntityType::HORSE,
+            Arc::new(Mutex::new(EntityEquipment::new())),
+        ));
+        inventory.set_columns(1);
+        HorseScreenHandler::new(1, &player.inventory, inventory).await
+    }
+
+    async fn slot_item(handler: &HorseScreenHandler, slot: usize) -> Option<u16> {
+        let stack = handler.get_behaviour().slots[slot].get_cloned_stack().await;
+        (!stack.is_empty()).then_some(stack.item.id)
+    }
+
+    async fn shift_click(
+        handler: &mut HorseScreenHandler,
+        player: &TestPlayer,
+        slot: usize,
+        stack: ItemStack,
+    ) {
+        handler.get_behaviour().slots[slot].set_stack(stack).await;
+        handler
+            .on_slot_click(slot as i32, 0, SlotActionType::QuickMove, player)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn quick_move_fills_equipment_then_chest() {
+        let player = TestPlayer::new(false);
+        let mut handler = horse(&player).await;
+
+        shift_click(
+            &mut handler,
+            &player,
+            HOTBAR_START,
+            ItemStack::new(1, &Item::SADDLE),
+        )
+        .await;
+        assert_eq!(slot_item(&handler, 0).await, Some(Item::SADDLE.id));
+
+        let armor = ItemStack::new(1, &Item::IRON_HORSE_ARMOR);
+        shift_click(&mut handler, &player, HOTBAR_START, armor).await;
+        assert_eq!(
+            slot_item(&handler, 1).await,
+            Some(Item::IRON_HORSE_ARMOR.id)
+        );
+
+        // The saddle slot is taken, so a second saddle goes into the chest
+        shift_click(
+            &mut handler,
+            &player,
+            HOTBAR_START,
+            ItemStack::new(1, &Item::SADDLE),
+        )
+        .await;
+        assert_eq!(slot_item(&handler, 2).await, Some(Item::SADDLE.id));
+        assert_eq!(slot_item(&handler, HOTBAR_START).await, None);
+    }
+
+    #[tokio::test]
+    async fn quick_move_takes_out_of_the_mount() {
+        let player = TestPlayer::new(false);
+        let mut handler = horse(&player).await;
+
+        shift_click(&mut handler, &player, 2, ItemStack::new(5, &Item::STONE)).await;
+        assert_eq!(slot_item(&handler, 2).await, None);
+        assert_eq!(
+            slot_item(&handler, HOTBAR_START + 8).await,
+            Some(Item::STONE.id)
+        );
+
+        shift_click(&mut handler, &player, 0, ItemStack::new(1, &Item::SADDLE)).await;
+        assert_eq!(slot_item(&handler, 0).await, None);
+        assert_eq!(
+            slot_item(&handler, HOTBAR_START + 7).await,
+            Some(Item::SADDLE.id)
+        );
+    }
+}
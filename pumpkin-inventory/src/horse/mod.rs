@@ -0,0 +1,2 @@
+pub mod horse_inventory;
+pub mod horse_screen_handler;
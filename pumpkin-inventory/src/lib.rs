@@ -6,6 +6,7 @@ pub mod entity_equipment;
 mod error;
 pub mod furnace_like;
 pub mod generic_container_screen_handler;
+pub mod horse;
 pub mod player;
 pub mod screen_handler;
 pub mod slot;
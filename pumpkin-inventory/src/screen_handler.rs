@@ -24,14 +24,22 @@ use pumpkin_world::{
     block::entities::PropertyDelegate,
     inventory::{ComparableInventory, Inventory},
 };
+use std::pin::Pin;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::{any::Any, collections::HashMap, sync::Arc};
-use std::{cmp::max, pin::Pin};
 use tokio::sync::Mutex;
 use tracing::warn;
 
 const SLOT_INDEX_OUTSIDE: i32 = -999;
 
+const DRAG_STAGE_START: i32 = 0;
+const DRAG_STAGE_ADD_SLOT: i32 = 1;
+const DRAG_STAGE_END: i32 = 2;
+
+const DRAG_BUTTON_LEFT: i32 = 0;
+const DRAG_BUTTON_RIGHT: i32 = 1;
+const DRAG_BUTTON_MIDDLE: i32 = 2;
+
 pub struct ScreenProperty {
     old_value: i32,
     index: u8,
@@ -486,7 +494,9 @@ pub trait ScreenHandler: Send + Sync {
 
     fn is_slot_valid(&self, slot: i32) -> ScreenHandlerFuture<'_, bool> {
         Box::pin(async move {
-            slot == -1 || slot == -999 || slot < self.get_behaviour().slots.len() as i32
+            slot == -1
+                || slot == SLOT_INDEX_OUTSIDE
+                || (0..self.get_behaviour().slots.len() as i32).contains(&slot)
         })
     }
 
@@ -637,7 +647,8 @@ pub trait ScreenHandler: Send + Sync {
         })
     }
 
-    #[expect(clippy::too_many_lines)]
+    /// Runs a container click. Every screen handler shares this, screen specific behaviour
+    /// belongs in `quick_move`, `handle_slot_click` and the slots themselves.
     fn internal_on_slot_click<'a>(
         &'a mut self,
         slot_index: i32,
@@ -646,377 +657,544 @@ pub trait ScreenHandler: Send + Sync {
         player: &'a dyn InventoryPlayer,
     ) -> ScreenHandlerFuture<'a, ()> {
         Box::pin(async move {
-            if action_type == SlotActionType::PickupAll && button == 0 {
-                let behavior = self.get_behaviour_mut();
-                let mut cursor_stack = behavior.cursor_stack.lock().await;
-                let mut to_pick_up = cursor_stack.get_max_stack_size() - cursor_stack.item_count;
+            if action_type == SlotActionType::QuickCraft {
+                self.on_drag_click(slot_index, button, player).await;
+                return;
+            }
+            // Any other click cancels a drag that is still in progress
+            if self.get_behaviour().drag_stage != DRAG_STAGE_START {
+                self.get_behaviour_mut().end_drag();
+                return;
+            }
 
-                for slot in &behavior.slots {
-                    if to_pick_up == 0 {
-                        break;
+            match action_type {
+                SlotActionType::Pickup | SlotActionType::QuickMove
+                    if button == 0 || button == 1 =>
+                {
+                    let click_type = if button == 0 {
+                        MouseClick::Left
+                    } else {
+                        MouseClick::Right
+                    };
+                    if slot_index == SLOT_INDEX_OUTSIDE {
+                        self.on_outside_click(click_type, player).await;
+                    } else if action_type == SlotActionType::QuickMove {
+                        self.on_quick_move_click(slot_index, player).await;
+                    } else {
+                        self.on_pickup_click(slot_index, click_type, player).await;
                     }
+                }
+                SlotActionType::Swap => self.on_swap_click(slot_index, button, player).await,
+                SlotActionType::Clone => self.on_clone_click(slot_index, player).await,
+                SlotActionType::Throw => self.on_throw_click(slot_index, button, player).await,
+                SlotActionType::PickupAll => {
+                    self.on_pickup_all_click(slot_index, button, player).await;
+                }
+                _ => {}
+            }
+        })
+    }
 
-                    let item_stack = slot.get_cloned_stack().await;
-                    if !item_stack.are_items_and_components_equal(&cursor_stack) {
-                        continue;
-                    }
+    /// Drops the cursor stack, or a single item of it on a right click.
+    fn on_outside_click<'a>(
+        &'a mut self,
+        click_type: MouseClick,
+        player: &'a dyn InventoryPlayer,
+    ) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            let mut cursor_stack = self.get_behaviour().cursor_stack.lock().await;
+            if cursor_stack.is_empty() {
+                return;
+            }
+            if click_type == MouseClick::Left {
+                player.drop_item(cursor_stack.clone(), true).await;
+                *cursor_stack = ItemStack::EMPTY.clone();
+            } else {
+                player.drop_item(cursor_stack.split(1), true).await;
+            }
+        })
+    }
 
-                    if !slot.allow_modification(player).await {
-                        continue;
+    /// Shift clicks a slot, moving its stack until nothing more fits.
+    fn on_quick_move_click<'a>(
+        &'a mut self,
+        slot_index: i32,
+        player: &'a dyn InventoryPlayer,
+    ) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            let Some(slot) = self.get_slot_checked(slot_index) else {
+                return;
+            };
+            if !slot.can_take_items(player).await {
+                return;
+            }
+
+            let mut moved_stack = self.quick_move(player, slot_index).await;
+            while !moved_stack.is_empty()
+                && ItemStack::are_items_and_components_equal(
+                    &slot.get_cloned_stack().await,
+                    &moved_stack,
+                )
+            {
+                moved_stack = self.quick_move(player, slot_index).await;
+            }
+        })
+    }
+
+    /// Picks up, places, merges or swaps the cursor stack with a slot.
+    #[expect(clippy::too_many_lines)]
+    fn on_pickup_click<'a>(
+        &'a mut self,
+        slot_index: i32,
+        click_type: MouseClick,
+        player: &'a dyn InventoryPlayer,
+    ) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            let Some(slot) = self.get_slot_checked(slot_index) else {
+                return;
+            };
+
+            if click_type == MouseClick::Left {
+                slot.on_click(player).await;
+            }
+
+            let slot_stack = slot.get_cloned_stack().await;
+            let mut cursor_stack = self.get_behaviour().cursor_stack.lock().await;
+
+            let equipment_slot = cursor_stack
+                .get_data_component::<EquippableImpl>()
+                .map_or(&EquipmentSlot::MAIN_HAND, |equippable| equippable.slot);
+
+            if self
+                .handle_slot_click(
+                    player,
+                    click_type.clone(),
+                    slot.clone(),
+                    slot_stack.clone(),
+                    cursor_stack.clone(),
+                )
+                .await
+            {
+                return;
+            }
+
+            if slot_stack.is_empty() {
+                if !cursor_stack.is_empty() {
+                    if equipment_slot.slot_type() == EquipmentType::HumanoidArmor
+                        && (5..9).contains(&slot_index)
+                    {
+                        player
+                            .enqueue_equipment_change(equipment_slot, &cursor_stack)
+                            .await;
                     }
 
-                    let taken_stack = slot
-                        .safe_take(
-                            item_stack.item_count.min(to_pick_up),
-                            cursor_stack.get_max_stack_size() - cursor_stack.item_count,
-                            player,
-                        )
+                    let transfer_count = if click_type == MouseClick::Left {
+                        cursor_stack.item_count
+                    } else {
+                        1
+                    };
+                    *cursor_stack = slot
+                        .insert_stack_count(cursor_stack.clone(), transfer_count)
                         .await;
-                    to_pick_up -= taken_stack.item_count;
-                    cursor_stack.increment(taken_stack.item_count);
                 }
-            } else if action_type == SlotActionType::QuickCraft {
-                let drag_type = button & 3;
-                let drag_button = (button >> 2) & 3;
-                let behaviour = self.get_behaviour_mut();
-                if drag_type == 0 {
-                    behaviour.drag_slots.clear();
-                } else if drag_type == 1 {
-                    if slot_index < 0 {
-                        warn!("Invalid slot index for drag action: {slot_index}. Must be >= 0");
-                        return;
+            } else if slot.can_take_items(player).await {
+                if cursor_stack.is_empty() {
+                    let take_count = if click_type == MouseClick::Left {
+                        slot_stack.item_count
+                    } else {
+                        slot_stack.item_count.div_ceil(2)
+                    };
+                    let taken = slot.try_take_stack_range(take_count, u8::MAX, player).await;
+                    if let Some(taken) = taken {
+                        // Reverse order of operations, shouldn't affect anything
+                        *cursor_stack = taken.clone();
+                        slot.on_take_item(player, &taken).await;
+
+                        if (5..9).contains(&slot_index) {
+                            let equipment_slot = cursor_stack
+                                .get_data_component::<EquippableImpl>()
+                                .map_or(&EquipmentSlot::MAIN_HAND, |equippable| equippable.slot);
+                            player
+                                .enqueue_equipment_change(equipment_slot, ItemStack::EMPTY)
+                                .await;
+                        }
+                    }
+                } else if slot.can_insert(&cursor_stack).await {
+                    if equipment_slot.slot_type() == EquipmentType::HumanoidArmor
+                        && (5..9).contains(&slot_index)
+                    {
+                        player
+                            .enqueue_equipment_change(equipment_slot, &cursor_stack)
+                            .await;
                     }
-                    let cursor_stack = behaviour.cursor_stack.lock().await;
 
-                    let slot = &behaviour.slots[slot_index as usize];
-                    let stack_lock = slot.get_stack().await;
-                    let stack = stack_lock.lock().await;
-                    if !cursor_stack.is_empty()
-                        && slot.can_insert(&cursor_stack).await
-                        && (stack.are_items_and_components_equal(&cursor_stack) || stack.is_empty())
-                        && slot.get_max_item_count_for_stack(&stack).await > stack.item_count
+                    if ItemStack::are_items_and_components_equal(&slot_stack, &cursor_stack) {
+                        let insert_count = if click_type == MouseClick::Left {
+                            cursor_stack.item_count
+                        } else {
+                            1
+                        };
+                        *cursor_stack = slot
+                            .insert_stack_count(cursor_stack.clone(), insert_count)
+                            .await;
+                    } else if cursor_stack.item_count
+                        <= slot.get_max_item_count_for_stack(&cursor_stack).await
                     {
-                        behaviour.drag_slots.push(slot_index as u32);
+                        let old_cursor_stack = cursor_stack.clone();
+                        *cursor_stack = slot_stack.clone();
+                        slot.set_stack(old_cursor_stack).await;
                     }
-                } else if drag_type == 2 && !behaviour.drag_slots.is_empty() {
-                    // process drag end
-                    if behaviour.drag_slots.len() == 1 {
-                        let slot = behaviour.drag_slots[0] as i32;
-                        behaviour.drag_slots.clear();
-                        let _ = behaviour;
-                        self.internal_on_slot_click(
-                            slot,
-                            drag_button,
-                            SlotActionType::Pickup,
+                } else if ItemStack::are_items_and_components_equal(&slot_stack, &cursor_stack) {
+                    let taken = slot
+                        .try_take_stack_range(
+                            slot_stack.item_count,
+                            cursor_stack
+                                .get_max_stack_size()
+                                .saturating_sub(cursor_stack.item_count),
                             player,
                         )
                         .await;
 
-                        return;
-                    }
-                    if drag_button == 2 && !player.has_infinite_materials() {
-                        return; // Only creative
+                    if let Some(taken) = taken {
+                        cursor_stack.increment(taken.item_count);
+                        slot.on_take_item(player, &taken).await;
                     }
+                }
+            }
 
-                    let mut cursor_stack = behaviour.cursor_stack.lock().await;
-                    let initial_count = cursor_stack.item_count;
-                    for slot_index in &behaviour.drag_slots {
-                        let slot = behaviour.slots[*slot_index as usize].clone();
-                        let stack_lock = slot.get_stack().await;
-                        let stack = stack_lock.lock().await;
-
-                        if (stack.are_items_and_components_equal(&cursor_stack) || stack.is_empty())
-                            && slot.can_insert(&cursor_stack).await
-                        {
-                            let mut inserting_count = if drag_button == 0 {
-                                initial_count / behaviour.drag_slots.len() as u8
-                            } else if drag_button == 1 {
-                                1
-                            } else if drag_button == 2 {
-                                cursor_stack.item_count = cursor_stack.get_max_stack_size();
-                                cursor_stack.item_count
-                            } else {
-                                panic!("Invalid drag button: {drag_button}");
-                            };
-                            inserting_count = inserting_count
-                                .min(max(
-                                    0,
-                                    slot.get_max_item_count_for_stack(&stack).await
-                                        - stack.item_count,
-                                ))
-                                .min(cursor_stack.item_count);
-                            if inserting_count > 0 {
-                                let mut stack_clone = stack.clone();
-                                drop(stack);
-                                if stack_clone.is_empty() {
-                                    stack_clone = cursor_stack.copy_with_count(0);
-                                }
-                                stack_clone.increment(inserting_count);
-                                slot.set_stack(stack_clone).await;
-                                if drag_button != 2 {
-                                    cursor_stack.decrement(inserting_count);
-                                }
-                                if cursor_stack.is_empty() {
-                                    *cursor_stack = ItemStack::EMPTY.clone();
-                                    break;
-                                }
-                            }
-                        }
-                    }
+            slot.mark_dirty().await;
+        })
+    }
 
-                    if drag_button == 2 {
-                        *cursor_stack = ItemStack::EMPTY.clone();
-                    }
-                    behaviour.drag_slots.clear();
-                }
-            } else if action_type == SlotActionType::Throw {
-                if slot_index >= 0 && self.get_behaviour().cursor_stack.lock().await.is_empty() {
-                    let slot = self.get_behaviour().slots[slot_index as usize].clone();
-                    let prev_stack = slot.get_cloned_stack().await;
-                    if !prev_stack.is_empty() {
-                        if button == 1 {
-                            // Throw all
-                            while slot
-                                .get_cloned_stack()
-                                .await
-                                .are_items_and_components_equal(&prev_stack)
-                            {
-                                let drop_stack =
-                                    slot.safe_take(prev_stack.item_count, u8::MAX, player).await;
-                                player.drop_item(drop_stack, true).await;
-                                // player.handleCreativeModeItemDrop(itemStack);
-                            }
-                        } else {
-                            let drop_stack = slot.safe_take(1, u8::MAX, player).await;
-                            if !drop_stack.is_empty() {
-                                slot.on_take_item(player, &drop_stack).await;
-                                player.drop_item(drop_stack, true).await;
-                            }
-                        }
-                    }
+    /// Swaps a slot with a hotbar slot (number keys) or the off hand (F).
+    fn on_swap_click<'a>(
+        &'a mut self,
+        slot_index: i32,
+        button: i32,
+        player: &'a dyn InventoryPlayer,
+    ) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            if !(0..9).contains(&button) && button != PlayerInventory::OFF_HAND_SLOT as i32 {
+                return;
+            }
+            let Some(source_slot) = self.get_slot_checked(slot_index) else {
+                return;
+            };
+            let inventory = player.get_inventory();
+            let button = button as usize;
+            let mut button_stack = inventory.get_stack(button).await.lock().await.clone();
+            let source_stack = source_slot.get_cloned_stack().await;
+
+            if button_stack.is_empty() {
+                if !source_stack.is_empty() && source_slot.can_take_items(player).await {
+                    inventory.set_stack(button, source_stack.clone()).await;
+                    source_slot.on_swap_craft(source_stack.item_count).await;
+                    source_slot.set_stack(ItemStack::EMPTY.clone()).await;
+                    source_slot.on_take_item(player, &source_stack).await;
                 }
-            } else if action_type == SlotActionType::Clone {
-                if player.has_infinite_materials() && slot_index >= 0 {
-                    let behaviour = self.get_behaviour_mut();
-                    let mut cursor_stack = behaviour.cursor_stack.lock().await;
-                    if !cursor_stack.is_empty() {
-                        return;
+            } else if source_stack.is_empty() {
+                if source_slot.can_insert(&button_stack).await {
+                    let max_count = source_slot
+                        .get_max_item_count_for_stack(&button_stack)
+                        .await;
+                    if button_stack.item_count > max_count {
+                        source_slot.set_stack(button_stack.split(max_count)).await;
+                        inventory.set_stack(button, button_stack).await;
+                    } else {
+                        inventory.set_stack(button, ItemStack::EMPTY.clone()).await;
+                        source_slot.set_stack(button_stack).await;
                     }
-                    let slot = behaviour.slots[slot_index as usize].clone();
-                    let stack_lock = slot.get_stack().await;
-                    let stack = stack_lock.lock().await;
-                    *cursor_stack = stack.copy_with_count(stack.get_max_stack_size());
                 }
-            } else if (action_type == SlotActionType::Pickup
-                || action_type == SlotActionType::QuickMove)
-                && (button == 0 || button == 1)
+            } else if source_slot.can_take_items(player).await
+                && source_slot.can_insert(&button_stack).await
             {
-                let click_type = if button == 0 {
-                    MouseClick::Left
+                let max_count = source_slot
+                    .get_max_item_count_for_stack(&button_stack)
+                    .await;
+                if button_stack.item_count > max_count {
+                    // Only part of the button stack fits, so the source stack has to go elsewhere
+                    source_slot.set_stack(button_stack.split(max_count)).await;
+                    inventory.set_stack(button, button_stack).await;
+                    source_slot.on_take_item(player, &source_stack).await;
+                    offer_or_drop_stack(player, source_stack).await;
                 } else {
-                    MouseClick::Right
-                };
-
-                // Drop item if outside inventory
-                if slot_index == SLOT_INDEX_OUTSIDE {
-                    let mut cursor_stack = self.get_behaviour().cursor_stack.lock().await;
-                    if !cursor_stack.is_empty() {
-                        if click_type == MouseClick::Left {
-                            player.drop_item(cursor_stack.clone(), true).await;
-                            *cursor_stack = ItemStack::EMPTY.clone();
-                        } else {
-                            player.drop_item(cursor_stack.split(1), true).await;
-                        }
-                    }
-                } else if action_type == SlotActionType::QuickMove {
-                    if slot_index < 0 {
-                        return;
-                    }
+                    inventory.set_stack(button, source_stack.clone()).await;
+                    source_slot.set_stack(button_stack).await;
+                    source_slot.on_take_item(player, &source_stack).await;
+                }
+            }
+        })
+    }
 
-                    let slot = self.get_behaviour().slots[slot_index as usize].clone();
+    /// Puts a full stack copy of a slot on the cursor, only for players with infinite materials.
+    fn on_clone_click<'a>(
+        &'a mut self,
+        slot_index: i32,
+        player: &'a dyn InventoryPlayer,
+    ) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            if !player.has_infinite_materials() {
+                return;
+            }
+            let Some(slot) = self.get_slot_checked(slot_index) else {
+                return;
+            };
+            let mut cursor_stack = self.get_behaviour().cursor_stack.lock().await;
+            if !cursor_stack.is_empty() {
+                return;
+            }
+            let stack = slot.get_cloned_stack().await;
+            if !stack.is_empty() {
+                *cursor_stack = stack.copy_with_count(stack.get_max_stack_size());
+            }
+        })
+    }
 
-                    if !slot.can_take_items(player).await {
-                        return;
-                    }
+    /// Throws a single item (Q), or every stack of the same item in the slot (Ctrl + Q).
+    fn on_throw_click<'a>(
+        &'a mut self,
+        slot_index: i32,
+        button: i32,
+        player: &'a dyn InventoryPlayer,
+    ) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            if !self.get_behaviour().cursor_stack.lock().await.is_empty() {
+                return;
+            }
+            let Some(slot) = self.get_slot_checked(slot_index) else {
+                return;
+            };
+            let amount = if button == 0 {
+                1
+            } else {
+                slot.get_cloned_stack().await.item_count
+            };
 
-                    let mut moved_stack = self.quick_move(player, slot_index).await;
+            let dropped = slot.safe_take(amount, u8::MAX, player).await;
+            if dropped.is_empty() {
+                return;
+            }
+            let item = dropped.item;
+            player.drop_item(dropped, true).await;
 
-                    while !moved_stack.is_empty()
-                        && ItemStack::are_items_and_components_equal(
-                            &slot.get_cloned_stack().await,
-                            &moved_stack,
-                        )
-                    {
-                        moved_stack = self.quick_move(player, slot_index).await;
+            if button == 1 {
+                // Result slots refill after every take, so keep throwing while the item stays
+                loop {
+                    let slot_stack = slot.get_cloned_stack().await;
+                    if slot_stack.is_empty() || slot_stack.item.id != item.id {
+                        break;
                     }
-                } else {
-                    // Pickup
-                    if slot_index < 0 {
-                        return;
+                    let dropped = slot.safe_take(amount, u8::MAX, player).await;
+                    if dropped.is_empty() {
+                        break;
                     }
+                    player.drop_item(dropped, true).await;
+                }
+            }
+        })
+    }
 
-                    let slot = self.get_behaviour().slots[slot_index as usize].clone();
-
-                    if click_type == MouseClick::Left {
-                        slot.on_click(player).await;
-                    }
+    /// Double click: collects matching items into the cursor stack. Partial stacks are taken
+    /// before full ones, left clicks search from the first slot and right clicks from the last.
+    fn on_pickup_all_click<'a>(
+        &'a mut self,
+        slot_index: i32,
+        button: i32,
+        player: &'a dyn InventoryPlayer,
+    ) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            let Some(clicked_slot) = self.get_slot_checked(slot_index) else {
+                return;
+            };
+            let behaviour = self.get_behaviour();
+            let mut cursor_stack = behaviour.cursor_stack.lock().await;
+            if cursor_stack.is_empty()
+                || (clicked_slot.has_stack().await && clicked_slot.can_take_items(player).await)
+            {
+                return;
+            }
 
-                    let slot_stack = slot.get_cloned_stack().await;
-                    let mut cursor_stack = self.get_behaviour().cursor_stack.lock().await;
+            let max_count = cursor_stack.get_max_stack_size();
+            let mut order: Vec<usize> = (0..behaviour.slots.len()).collect();
+            if button != 0 {
+                order.reverse();
+            }
 
-                    let equipment_slot = cursor_stack
-                        .get_data_component::<EquippableImpl>()
-                        .map_or(&EquipmentSlot::MAIN_HAND, |equippable| equippable.slot);
+            for take_full_stacks in [false, true] {
+                for &index in &order {
+                    if cursor_stack.item_count >= max_count {
+                        return;
+                    }
+                    let slot = &behaviour.slots[index];
+                    if !slot.can_take_item_for_pick_all() {
+                        continue;
+                    }
+                    let stack = slot.get_cloned_stack().await;
+                    if stack.is_empty()
+                        || !stack.are_items_and_components_equal(&cursor_stack)
+                        || (!take_full_stacks && stack.item_count >= stack.get_max_stack_size())
+                        || !slot.allow_modification(player).await
+                    {
+                        continue;
+                    }
 
-                    if self
-                        .handle_slot_click(
+                    let taken = slot
+                        .safe_take(
+                            stack.item_count,
+                            max_count - cursor_stack.item_count,
                             player,
-                            click_type.clone(),
-                            slot.clone(),
-                            slot_stack.clone(),
-                            cursor_stack.clone(),
                         )
-                        .await
+                        .await;
+                    cursor_stack.increment(taken.item_count);
+                }
+            }
+        })
+    }
+
+    /// Drag splitting. A drag starts (stage 0), then adds slots (stage 1) and finally places
+    /// the cursor stack into them (stage 2). Left drags split the stack evenly, right drags
+    /// place one item per slot and middle drags fill every slot, which only works in creative.
+    fn on_drag_click<'a>(
+        &'a mut self,
+        slot_index: i32,
+        button: i32,
+        player: &'a dyn InventoryPlayer,
+    ) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            let behaviour = self.get_behaviour_mut();
+            let previous_stage = behaviour.drag_stage;
+            let stage = button & 3;
+            behaviour.drag_stage = stage;
+
+            let expected = previous_stage == stage
+                || (previous_stage == DRAG_STAGE_ADD_SLOT && stage == DRAG_STAGE_END);
+            if !expected || behaviour.cursor_stack.lock().await.is_empty() {
+                behaviour.end_drag();
+                return;
+            }
+
+            match stage {
+                DRAG_STAGE_START => {
+                    let drag_button = (button >> 2) & 3;
+                    if drag_button == DRAG_BUTTON_LEFT
+                        || drag_button == DRAG_BUTTON_RIGHT
+                        || (drag_button == DRAG_BUTTON_MIDDLE && player.has_infinite_materials())
                     {
+                        behaviour.drag_button = drag_button;
+                        behaviour.drag_stage = DRAG_STAGE_ADD_SLOT;
+                        behaviour.drag_slots.clear();
+                    } else {
+                        behaviour.end_drag();
+                    }
+                }
+                DRAG_STAGE_ADD_SLOT => {
+                    let Some(slot) = self.get_slot_checked(slot_index) else {
                         return;
+                    };
+                    let behaviour = self.get_behaviour_mut();
+                    let cursor_stack = behaviour.cursor_stack.lock().await.clone();
+                    let slot_index = slot_index as u32;
+                    if !behaviour.drag_slots.contains(&slot_index)
+                        && slot.can_drag_to()
+                        && can_drag_into(&slot.get_cloned_stack().await, &cursor_stack)
+                        && slot.can_insert(&cursor_stack).await
+                        && (behaviour.drag_button == DRAG_BUTTON_MIDDLE
+                            || usize::from(cursor_stack.item_count) > behaviour.drag_slots.len())
+                    {
+                        behaviour.drag_slots.push(slot_index);
                     }
+                }
+                DRAG_STAGE_END => self.on_drag_end(player).await,
+                _ => behaviour.end_drag(),
+            }
+        })
+    }
 
-                    if slot_stack.is_empty() {
-                        if !cursor_stack.is_empty() {
-                            if equipment_slot.slot_type() == EquipmentType::HumanoidArmor
-                                && (5..9).contains(&slot_index)
-                            {
-                                player
-                                    .enqueue_equipment_change(equipment_slot, &cursor_stack)
-                                    .await;
-                            }
-
-                            let transfer_count = if click_type == MouseClick::Left {
-                                cursor_stack.item_count
-                            } else {
-                                1
-                            };
-                            *cursor_stack = slot
-                                .insert_stack_count(cursor_stack.clone(), transfer_count)
-                                .await;
-                        }
-                    } else if slot.can_take_items(player).await {
-                        if cursor_stack.is_empty() {
-                            let take_count = if click_type == MouseClick::Left {
-                                slot_stack.item_count
-                            } else {
-                                slot_stack.item_count.div_ceil(2)
-                            };
-                            let taken =
-                                slot.try_take_stack_range(take_count, u8::MAX, player).await;
-                            if let Some(taken) = taken {
-                                // Reverse order of operations, shouldn't affect anything
-                                *cursor_stack = taken.clone();
-                                slot.on_take_item(player, &taken).await;
-
-                                if (5..9).contains(&slot_index) {
-                                    let equipment_slot = cursor_stack
-                                        .get_data_component::<EquippableImpl>()
-                                        .map_or(&EquipmentSlot::MAIN_HAND, |equippable| {
-                                            equippable.slot
-                                        });
-                                    player
-                                        .enqueue_equipment_change(equipment_slot, ItemStack::EMPTY)
-                                        .await;
-                                }
-                            }
-                        } else if slot.can_insert(&cursor_stack).await {
-                            if equipment_slot.slot_type() == EquipmentType::HumanoidArmor
-                                && (5..9).contains(&slot_index)
-                            {
-                                player
-                                    .enqueue_equipment_change(equipment_slot, &cursor_stack)
-                                    .await;
-                            }
-
-                            if ItemStack::are_items_and_components_equal(&slot_stack, &cursor_stack)
-                            {
-                                let insert_count = if click_type == MouseClick::Left {
-                                    cursor_stack.item_count
-                                } else {
-                                    1
-                                };
-                                *cursor_stack = slot
-                                    .insert_stack_count(cursor_stack.clone(), insert_count)
-                                    .await;
-                            } else if cursor_stack.item_count
-                                <= slot.get_max_item_count_for_stack(&cursor_stack).await
-                            {
-                                let old_cursor_stack = cursor_stack.clone();
-                                *cursor_stack = slot_stack.clone();
-                                slot.set_stack(old_cursor_stack).await;
-                            }
-                        } else if ItemStack::are_items_and_components_equal(
-                            &slot_stack,
-                            &cursor_stack,
-                        ) {
-                            let taken = slot
-                                .try_take_stack_range(
-                                    slot_stack.item_count,
-                                    cursor_stack
-                                        .get_max_stack_size()
-                                        .saturating_sub(cursor_stack.item_count),
-                                    player,
-                                )
-                                .await;
+    /// Places the dragged cursor stack into the collected slots.
+    fn on_drag_end<'a>(
+        &'a mut self,
+        player: &'a dyn InventoryPlayer,
+    ) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            let behaviour = self.get_behaviour_mut();
+            let drag_slots = std::mem::take(&mut behaviour.drag_slots);
+            let drag_button = behaviour.drag_button;
+            behaviour.end_drag();
 
-                            if let Some(taken) = taken {
-                                cursor_stack.increment(taken.item_count);
-                                slot.on_take_item(player, &taken).await;
-                            }
-                        }
-                    }
+            if drag_slots.is_empty() {
+                return;
+            }
+            if drag_slots.len() == 1 {
+                // A drag over a single slot is just a click
+                self.internal_on_slot_click(
+                    drag_slots[0] as i32,
+                    drag_button,
+                    SlotActionType::Pickup,
+                    player,
+                )
+                .await;
+                return;
+            }
 
-                    slot.mark_dirty().await;
+            let behaviour = self.get_behaviour();
+            let mut cursor_stack = behaviour.cursor_stack.lock().await;
+            let carried = cursor_stack.clone();
+            let per_slot = match drag_button {
+                DRAG_BUTTON_LEFT => (usize::from(carried.item_count) / drag_slots.len()) as u8,
+                DRAG_BUTTON_RIGHT => 1,
+                _ => carried.get_max_stack_size(),
+            };
+            let slot_count = drag_slots.len();
+            let mut remaining = i32::from(carried.item_count);
+
+            for index in drag_slots {
+                let slot = &behaviour.slots[index as usize];
+                let slot_stack = slot.get_cloned_stack().await;
+                if !slot.can_drag_to()
+                    || !can_drag_into(&slot_stack, &carried)
+                    || !slot.can_insert(&carried).await
+                    || (drag_button != DRAG_BUTTON_MIDDLE
+                        && usize::from(carried.item_count) < slot_count)
+                {
+                    continue;
                 }
-            } else if action_type == SlotActionType::Swap && (0..9).contains(&button)
-                || button == 40
-            {
-                let mut button_stack = player
-                    .get_inventory()
-                    .get_stack(button as usize)
-                    .await
-                    .lock()
-                    .await
-                    .clone();
-                let source_slot = self.get_behaviour().slots[slot_index as usize].clone();
-                let source_stack = source_slot.get_cloned_stack().await;
-
-                if !button_stack.is_empty() || !source_stack.is_empty() {
-                    if button_stack.is_empty() {
-                        if source_slot.can_take_items(player).await {
-                            player
-                                .get_inventory()
-                                .set_stack(button as usize, source_stack.clone())
-                                .await;
-                            source_slot.set_stack(ItemStack::EMPTY.clone()).await;
-                            source_slot.on_take_item(player, &source_stack).await;
-                        }
-                    } else if source_stack.is_empty() && source_slot.can_insert(&button_stack).await
-                    {
-                        let max_count = source_slot
-                            .get_max_item_count_for_stack(&button_stack)
-                            .await;
-                        if button_stack.item_count > max_count {
-                            // button_stack might need to be a ref instead of a clone
-                            source_slot.set_stack(button_stack.split(max_count)).await;
-                        } else {
-                            player
-                                .get_inventory()
-                                .set_stack(button as usize, ItemStack::EMPTY.clone())
-                                .await;
-                            source_slot.set_stack(button_stack).await;
-                        }
-                    }
+                let existing = if slot_stack.is_empty() {
+                    0
+                } else {
+                    slot_stack.item_count
+                };
+                let max_count = carried
+                    .get_max_stack_size()
+                    .min(slot.get_max_item_count_for_stack(&carried).await);
+                let new_count = per_slot.saturating_add(existing).min(max_count);
+                remaining -= i32::from(new_count) - i32::from(existing);
+                slot.set_stack(carried.copy_with_count(new_count)).await;
+            }
+
+            // Middle drags copy the stack without using it up
+            if drag_button != DRAG_BUTTON_MIDDLE {
+                if remaining > 0 {
+                    cursor_stack.set_count(remaining.min(i32::from(u8::MAX)) as u8);
+                } else {
+                    *cursor_stack = ItemStack::EMPTY.clone();
                 }
             }
         })
     }
+
+    /// The slot at `slot_index`, or `None` for negative or out of range indices.
+    fn get_slot_checked(&self, slot_index: i32) -> Option<Arc<dyn Slot>> {
+        usize::try_from(slot_index)
+            .ok()
+            .and_then(|index| self.get_behaviour().slots.get(index).cloned())
+    }
+}
+
+/// Whether a dragged stack can go into a slot holding `slot_stack`.
+fn can_drag_into(slot_stack: &ItemStack, cursor_stack: &ItemStack) -> bool {
+    slot_stack.is_empty()
+        || (slot_stack.are_items_and_components_equal(cursor_stack)
+            && slot_stack.item_count <= cursor_stack.get_max_stack_size())
 }
 
 pub trait ScreenHandlerListener: Send + Sync {
@@ -1068,6 +1246,9 @@ pub struct ScreenHandlerBehaviour {
     pub tracked_property_values: Vec<i32>,
     pub window_type: Option<WindowType>,
     pub drag_slots: Vec<u32>,
+    /// The stage of the current drag, `DRAG_STAGE_START` while no drag is in progress.
+    pub drag_stage: i32,
+    pub drag_button: i32,
 }
 
 impl ScreenHandlerBehaviour {
@@ -1088,11 +1269,392 @@ impl ScreenHandlerBehaviour {
             tracked_property_values: Vec::new(),
             window_type,
             drag_slots: Vec::new(),
+            drag_stage: DRAG_STAGE_START,
+            drag_button: DRAG_BUTTON_LEFT,
         }
     }
 
+    pub fn end_drag(&mut self) {
+        self.drag_stage = DRAG_STAGE_START;
+        self.drag_slots.clear();
+    }
+
     pub fn next_revision(&self) -> u32 {
         self.revision.fetch_add(1, Ordering::Relaxed);
         self.revision.fetch_and(32767, Ordering::Relaxed) & 32767
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::sync::Arc;
+
+    use pumpkin_data::data_component_impl::EquipmentSlot;
+    use pumpkin_data::item::Item;
+    use pumpkin_protocol::java::client::play::{
+        CSetContainerContent, CSetContainerProperty, CSetContainerSlot, CSetCursorItem,
+        CSetPlayerInventory, CSetSelectedSlot,
+    };
+    use pumpkin_protocol::java::server::play::SlotActionType;
+    use pumpkin_world::inventory::Inventory;
+    use pumpkin_world::item::ItemStack;
+    use tokio::sync::Mutex;
+
+    use super::{InventoryPlayer, PlayerFuture, ScreenHandler};
+    use crate::build_equipment_slots;
+    use crate::crafting::crafting_screen_handler::CraftingTableScreenHandler;
+    use crate::entity_equipment::EntityEquipment;
+    use crate::generic_container_screen_handler::{
+        GenericContainerScreenHandler, create_generic_9x3,
+    };
+    use crate::player::ender_chest_inventory::EnderChestInventory;
+    use crate::player::player_inventory::PlayerInventory;
+
+    /// Slots 0-26 are the container, 27-53 the main inventory and 54-62 the hotbar.
+    const HOTBAR_START: usize = 54;
+
+    pub struct TestPlayer {
+        pub inventory: Arc<PlayerInventory>,
+        creative: bool,
+        dropped: std::sync::Mutex<Vec<ItemStack>>,
+    }
+
+    impl TestPlayer {
+        pub fn new(creative: bool) -> Self {
+            Self {
+                inventory: Arc::new(PlayerInventory::new(
+                    Arc::new(Mutex::new(EntityEquipment::new())),
+                    Arc::new(build_equipment_slots()),
+                )),
+                creative,
+                dropped: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+
+        fn dropped_count(&self) -> u8 {
+            self.dropped
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|stack| stack.item_count)
+                .sum()
+        }
+    }
+
+    impl InventoryPlayer for TestPlayer {
+        fn drop_item(&self, item: ItemStack, _retain_ownership: bool) -> PlayerFuture<'_, ()> {
+            self.dropped.lock().unwrap().push(item);
+            Box::pin(async {})
+        }
+        fn get_inventory(&self) -> Arc<PlayerInventory> {
+            self.inventory.clone()
+        }
+        fn has_infinite_materials(&self) -> bool {
+            self.creative
+        }
+        fn enqueue_inventory_packet<'a>(
+            &'a self,
+            _packet: &'a CSetContainerContent,
+        ) -> PlayerFuture<'a, ()> {
+            Box::pin(async {})
+        }
+        fn enqueue_slot_packet<'a>(
+            &'a self,
+            _packet: &'a CSetContainerSlot,
+        ) -> PlayerFuture<'a, ()> {
+            Box::pin(async {})
+        }
+        fn enqueue_cursor_packet<'a>(
+            &'a self,
+            _packet: &'a CSetCursorItem,
+        ) -> PlayerFuture<'a, ()> {
+            Box::pin(async {})
+        }
+        fn enqueue_property_packet<'a>(
+            &'a self,
+            _packet: &'a CSetContainerProperty,
+        ) -> PlayerFuture<'a, ()> {
+            Box::pin(async {})
+        }
+        fn enqueue_slot_set_packet<'a>(
+            &'a self,
+            _packet: &'a CSetPlayerInventory,
+        ) -> PlayerFuture<'a, ()> {
+            Box::pin(async {})
+        }
+        fn enqueue_set_held_item_packet<'a>(
+            &'a self,
+            _packet: &'a CSetSelectedSlot,
+        ) -> PlayerFuture<'a, ()> {
+            Box::pin(async {})
+        }
+        fn enqueue_equipment_change<'a>(
+            &'a self,
+            _slot: &'a EquipmentSlot,
+            _stack: &'a ItemStack,
+        ) -> PlayerFuture<'a, ()> {
+            Box::pin(async {})
+        }
+        fn award_experience(&self, _amount: i32) -> PlayerFuture<'_, ()> {
+            Box::pin(async {})
+        }
+    }
+
+    async fn chest(player: &TestPlayer) -> GenericContainerScreenHandler {
+        create_generic_9x3(1, &player.inventory, Arc::new(EnderChestInventory::new())).await
+    }
+
+    async fn set_slot(handler: &impl ScreenHandler, slot: usize, stack: ItemStack) {
+        handler.get_behaviour().slots[slot].set_stack(stack).await;
+    }
+
+    async fn slot_count(handler: &impl ScreenHandler, slot: usize) -> u8 {
+        let stack = handler.get_behaviour().slots[slot].get_cloned_stack().await;
+        if stack.is_empty() {
+            0
+        } else {
+            stack.item_count
+        }
+    }
+
+    async fn set_cursor(handler: &impl ScreenHandler, stack: ItemStack) {
+        *handler.get_behaviour().cursor_stack.lock().await = stack;
+    }
+
+    async fn cursor_count(handler: &impl ScreenHandler) -> u8 {
+        let cursor = handler.get_behaviour().cursor_stack.lock().await;
+        if cursor.is_empty() {
+            0
+        } else {
+            cursor.item_count
+        }
+    }
+
+    fn stone(count: u8) -> ItemStack {
+        ItemStack::new(count, &Item::STONE)
+    }
+
+    async fn drag(
+        handler: &mut impl ScreenHandler,
+        player: &TestPlayer,
+        drag_button: i32,
+        slots: &[i32],
+    ) {
+        let button = |stage: i32| (drag_button << 2) | stage;
+        handler
+            .on_slot_click(-999, button(0), SlotActionType::QuickCraft, player)
+            .await;
+        for slot in slots {
+            handler
+                .on_slot_click(*slot, button(1), SlotActionType::QuickCraft, player)
+                .await;
+        }
+        handler
+            .on_slot_click(-999, button(2), SlotActionType::QuickCraft, player)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn pickup_takes_and_places() {
+        let player = TestPlayer::new(false);
+        let mut handler = chest(&player).await;
+        set_slot(&handler, 0, stone(10)).await;
+
+        handler
+            .on_slot_click(0, 1, SlotActionType::Pickup, &player)
+            .await;
+        assert_eq!(cursor_count(&handler).await, 5);
+        assert_eq!(slot_count(&handler, 0).await, 5);
+
+        handler
+            .on_slot_click(1, 1, SlotActionType::Pickup, &player)
+            .await;
+        assert_eq!(slot_count(&handler, 1).await, 1);
+
+        handler
+            .on_slot_click(0, 0, SlotActionType::Pickup, &player)
+            .await;
+        assert_eq!(slot_count(&handler, 0).await, 9);
+        assert_eq!(cursor_count(&handler).await, 0);
+    }
+
+    #[tokio::test]
+    async fn quick_move_moves_to_player_inventory() {
+        let player = TestPlayer::new(false);
+        let mut handler = chest(&player).await;
+        set_slot(&handler, 0, stone(10)).await;
+
+        handler
+            .on_slot_click(0, 0, SlotActionType::QuickMove, &player)
+            .await;
+        assert_eq!(slot_count(&handler, 0).await, 0);
+        assert_eq!(slot_count(&handler, HOTBAR_START + 8).await, 10);
+    }
+
+    #[tokio::test]
+    async fn swap_exchanges_with_hotbar() {
+        let player = TestPlayer::new(false);
+        let mut handler = chest(&player).await;
+        set_slot(&handler, 0, stone(10)).await;
+
+        handler
+            .on_slot_click(0, 2, SlotActionType::Swap, &player)
+            .await;
+        assert_eq!(slot_count(&handler, 0).await, 0);
+        assert_eq!(slot_count(&handler, HOTBAR_START + 2).await, 10);
+
+        handler
+            .on_slot_click(0, 2, SlotActionType::Swap, &player)
+            .await;
+        assert_eq!(slot_count(&handler, 0).await, 10);
+        assert_eq!(slot_count(&handler, HOTBAR_START + 2).await, 0);
+    }
+
+    #[tokio::test]
+    async fn clone_needs_infinite_materials() {
+        let player = TestPlayer::new(false);
+        let mut handler = chest(&player).await;
+        set_slot(&handler, 0, stone(10)).await;
+        handler
+            .on_slot_click(0, 2, SlotActionType::Clone, &player)
+            .await;
+        assert_eq!(cursor_count(&handler).await, 0);
+
+        let player = TestPlayer::new(true);
+        let mut handler = chest(&player).await;
+        set_slot(&handler, 0, stone(10)).await;
+        handler
+            .on_slot_click(0, 2, SlotActionType::Clone, &player)
+            .await;
+        assert_eq!(cursor_count(&handler).await, 64);
+        assert_eq!(slot_count(&handler, 0).await, 10);
+    }
+
+    #[tokio::test]
+    async fn throw_drops_one_or_all() {
+        let player = TestPlayer::new(false);
+        let mut handler = chest(&player).await;
+        set_slot(&handler, 0, stone(10)).await;
+
+        handler
+            .on_slot_click(0, 0, SlotActionType::Throw, &player)
+            .await;
+        assert_eq!(slot_count(&handler, 0).await, 9);
+        assert_eq!(player.dropped_count(), 1);
+
+        handler
+            .on_slot_click(0, 1, SlotActionType::Throw, &player)
+            .await;
+        assert_eq!(slot_count(&handler, 0).await, 0);
+        assert_eq!(player.dropped_count(), 10);
+    }
+
+    #[tokio::test]
+    async fn outside_click_drops_cursor() {
+        let player = TestPlayer::new(false);
+        let mut handler = chest(&player).await;
+        set_cursor(&handler, stone(10)).await;
+
+        handler
+            .on_slot_click(-999, 1, SlotActionType::Pickup, &player)
+            .await;
+        assert_eq!(cursor_count(&handler).await, 9);
+        handler
+            .on_slot_click(-999, 0, SlotActionType::Pickup, &player)
+            .await;
+        assert_eq!(cursor_count(&handler).await, 0);
+        assert_eq!(player.dropped_count(), 10);
+    }
+
+    #[tokio::test]
+    async fn pickup_all_prefers_partial_stacks() {
+        let player = TestPlayer::new(false);
+        let mut handler = chest(&player).await;
+        set_slot(&handler, 0, stone(64)).await;
+        set_slot(&handler, 1, stone(5)).await;
+        set_cursor(&handler, stone(1)).await;
+
+        handler
+            .on_slot_click(2, 0, SlotActionType::PickupAll, &player)
+            .await;
+        assert_eq!(cursor_count(&handler).await, 64);
+        assert_eq!(slot_count(&handler, 1).await, 0);
+        assert_eq!(slot_count(&handler, 0).await, 6);
+    }
+
+    #[tokio::test]
+    async fn drag_splits_cursor_stack() {
+        let player = TestPlayer::new(false);
+        let mut handler = chest(&player).await;
+        set_cursor(&handler, stone(10)).await;
+
+        drag(&mut handler, &player, 0, &[0, 1, 2]).await;
+        assert_eq!(slot_count(&handler, 0).await, 3);
+        assert_eq!(slot_count(&handler, 2).await, 3);
+        assert_eq!(cursor_count(&handler).await, 1);
+
+        set_cursor(&handler, stone(10)).await;
+        drag(&mut handler, &player, 1, &[3, 4]).await;
+        assert_eq!(slot_count(&handler, 3).await, 1);
+        assert_eq!(slot_count(&handler, 4).await, 1);
+        assert_eq!(cursor_count(&handler).await, 8);
+    }
+
+    #[tokio::test]
+    async fn other_click_cancels_drag() {
+        let player = TestPlayer::new(false);
+        let mut handler = chest(&player).await;
+        set_cursor(&handler, stone(10)).await;
+
+        handler
+            .on_slot_click(-999, 0, SlotActionType::QuickCraft, &player)
+            .await;
+        handler
+            .on_slot_click(0, 1, SlotActionType::QuickCraft, &player)
+            .await;
+        handler
+            .on_slot_click(1, 0, SlotActionType::Pickup, &player)
+            .await;
+        assert_eq!(slot_count(&handler, 1).await, 0);
+        assert_eq!(handler.get_behaviour().drag_slots, Vec::<u32>::new());
+    }
+
+    #[tokio::test]
+    async fn result_slot_is_skipped_by_pickup_all_and_drag() {
+        let player = TestPlayer::new(false);
+        let mut handler = CraftingTableScreenHandler::new(1, &player.inventory).await;
+        set_slot(&handler, 1, ItemStack::new(1, &Item::OAK_LOG)).await;
+        // Refills the result slot from the grid
+        set_slot(&handler, 0, ItemStack::EMPTY.clone()).await;
+        assert_eq!(slot_count(&handler, 0).await, 4);
+
+        set_cursor(&handler, ItemStack::new(1, &Item::OAK_PLANKS)).await;
+        handler
+            .on_slot_click(10, 0, SlotActionType::PickupAll, &player)
+            .await;
+        assert_eq!(cursor_count(&handler).await, 1);
+        assert_eq!(slot_count(&handler, 1).await, 1);
+
+        let slots = &handler.get_behaviour().slots;
+        assert!(!slots[0].can_drag_to());
+        assert!(!slots[0].can_take_item_for_pick_all());
+        assert!(slots[1].can_drag_to());
+    }
+
+    #[tokio::test]
+    async fn swap_out_of_result_slot_crafts() {
+        let player = TestPlayer::new(false);
+        let mut handler = CraftingTableScreenHandler::new(1, &player.inventory).await;
+        set_slot(&handler, 1, ItemStack::new(1, &Item::OAK_LOG)).await;
+        set_slot(&handler, 0, ItemStack::EMPTY.clone()).await;
+
+        handler
+            .on_slot_click(0, 0, SlotActionType::Swap, &player)
+            .await;
+        assert_eq!(
+            player.inventory.get_stack(0).await.lock().await.item_count,
+            4
+        );
+        assert_eq!(slot_count(&handler, 1).await, 0);
+    }
+}
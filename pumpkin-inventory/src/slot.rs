@@ -150,6 +150,28 @@ pub trait Slot: Send + Sync {
         Box::pin(async move { true })
     }
 
+    /// Whether a double click may collect items from this slot.
+    ///
+    /// Mojang name: `canTakeItemForPickAll`, which is a menu method there
+    fn can_take_item_for_pick_all(&self) -> bool {
+        true
+    }
+
+    /// Whether a drag may spread items into this slot.
+    ///
+    /// Mojang name: `canDragTo`, which is a menu method there
+    fn can_drag_to(&self) -> bool {
+        true
+    }
+
+    /// Called with the number of items a swap click takes out of the slot, before the slot is
+    /// emptied.
+    ///
+    /// Mojang name: `onSwapCraft`
+    fn on_swap_craft(&self, _amount: u8) -> BoxFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
     /// Mojang name: `allowModification`
     fn allow_modification<'a>(&'a self, player: &'a dyn InventoryPlayer) -> BoxFuture<'a, bool> {
         // Default implementation logic:
@@ -228,9 +250,11 @@ pub trait Slot: Send + Sync {
             if !stack.is_empty() && self.can_insert(&stack).await {
                 let stack_mutex = self.get_stack().await;
                 let mut stack_self = stack_mutex.lock().await;
-                let min_count = count
-                    .min(stack.item_count)
-                    .min(self.get_max_item_count_for_stack(&stack).await - stack_self.item_count);
+                let min_count = count.min(stack.item_count).min(
+                    self.get_max_item_count_for_stack(&stack)
+                        .await
+                        .saturating_sub(stack_self.item_count),
+                );
 
                 if min_count != 0 {
                     if stack_self.is_empty() {
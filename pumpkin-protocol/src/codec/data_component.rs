@@ -1,15 +1,28 @@
 use crate::codec::var_int::VarInt;
-use pumpkin_data::Enchantment;
+use pumpkin_data::attributes::Attributes;
 use pumpkin_data::data_component::DataComponent;
 use pumpkin_data::data_component_impl::{
-    DamageImpl, DataComponentImpl, EnchantmentsImpl, FireworkExplosionImpl, FireworkExplosionShape,
-    FireworksImpl, MaxStackSizeImpl, PotionContentsImpl, StatusEffectInstance, UnbreakableImpl,
-    get,
+    AttributeModifiersImpl, BlockEntityDataImpl, BucketEntityDataImpl, ContainerImpl,
+    ContainerSlot, CustomModelDataImpl, CustomNameImpl, DamageImpl, DataComponentImpl,
+    EnchantmentsImpl, FireworkExplosionImpl, FireworkExplosionShape, FireworksImpl, LoreImpl,
+    MaxDamageImpl, MaxStackSizeImpl, Modifier, Operation, PotionContentsImpl, StatusEffectInstance,
+    UnbreakableImpl, get,
 };
+use pumpkin_data::item::Item;
+use pumpkin_data::{AttributeModifierSlot, Enchantment};
+use pumpkin_nbt::deserializer::NbtReadHelper;
+use pumpkin_nbt::serializer::to_bytes_unnamed;
+use pumpkin_nbt::tag::NbtTag;
+use pumpkin_nbt::{
+    BYTE_ARRAY_ID, BYTE_ID, COMPOUND_ID, DOUBLE_ID, END_ID, FLOAT_ID, INT_ARRAY_ID, INT_ID,
+    LIST_ID, LONG_ARRAY_ID, LONG_ID, SHORT_ID, STRING_ID,
+};
+use pumpkin_util::text::TextComponent;
 use serde::de;
 use serde::de::SeqAccess;
-use serde::ser::SerializeStruct;
+use serde::ser::{self, SerializeStruct};
 use std::borrow::Cow;
+use std::io::Cursor;
 
 trait DataComponentCodec<Impl: DataComponentImpl> {
     fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error>;
@@ -414,6 +427,483 @@ impl DataComponentCodec<Self> for FireworksImpl {
     }
 }
 
+impl DataComponentCodec<Self> for MaxDamageImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        seq.serialize_field::<VarInt>("", &VarInt::from(self.max_damage))
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        let max_damage = seq
+            .next_element::<VarInt>()?
+            .ok_or(de::Error::custom("No max_damage VarInt!"))?
+            .0;
+        Ok(Self { max_damage })
+    }
+}
+
+/// Nesting limit for network NBT, the same as vanilla's
+const MAX_NBT_DEPTH: usize = 512;
+/// Size limit for network NBT inside item components, the same as vanilla's
+const MAX_NBT_SIZE: usize = 2 * 1024 * 1024;
+
+fn next_byte<'a, A: SeqAccess<'a>>(seq: &mut A, bytes: &mut Vec<u8>) -> Result<u8, A::Error> {
+    if bytes.len() >= MAX_NBT_SIZE {
+        return Err(de::Error::custom("NBT is too large"));
+    }
+    let byte = seq
+        .next_element::<u8>()?
+        .ok_or(de::Error::custom("Unexpected end of NBT!"))?;
+    bytes.push(byte);
+    Ok(byte)
+}
+
+fn copy_nbt_bytes<'a, A: SeqAccess<'a>>(
+    seq: &mut A,
+    len: usize,
+    bytes: &mut Vec<u8>,
+) -> Result<(), A::Error> {
+    if len > MAX_NBT_SIZE - bytes.len() {
+        return Err(de::Error::custom("NBT is too large"));
+    }
+    for _ in 0..len {
+        next_byte(seq, bytes)?;
+    }
+    Ok(())
+}
+
+fn copy_nbt_len<'a, A: SeqAccess<'a>>(seq: &mut A, bytes: &mut Vec<u8>) -> Result<usize, A::Error> {
+    let mut len = [0u8; 4];
+    for byte in &mut len {
+        *byte = next_byte(seq, bytes)?;
+    }
+    usize::try_from(i32::from_be_bytes(len)).map_err(|_| de::Error::custom("Negative NBT length"))
+}
+
+fn copy_nbt_string<'a, A: SeqAccess<'a>>(seq: &mut A, bytes: &mut Vec<u8>) -> Result<(), A::Error> {
+    let len = u16::from_be_bytes([next_byte(seq, bytes)?, next_byte(seq, bytes)?]);
+    copy_nbt_bytes(seq, usize::from(len), bytes)
+}
+
+/// Copies the payload of a network NBT tag, since its size is only known by walking it
+fn copy_nbt_payload<'a, A: SeqAccess<'a>>(
+    seq: &mut A,
+    id: u8,
+    bytes: &mut Vec<u8>,
+    depth: usize,
+) -> Result<(), A::Error> {
+    if depth > MAX_NBT_DEPTH {
+        return Err(de::Error::custom("NBT is nested too deeply"));
+    }
+    match id {
+        END_ID => Ok(()),
+        BYTE_ID => copy_nbt_bytes(seq, 1, bytes),
+        SHORT_ID => copy_nbt_bytes(seq, 2, bytes),
+        INT_ID | FLOAT_ID => copy_nbt_bytes(seq, 4, bytes),
+        LONG_ID | DOUBLE_ID => copy_nbt_bytes(seq, 8, bytes),
+        STRING_ID => copy_nbt_string(seq, bytes),
+        BYTE_ARRAY_ID => {
+            let len = copy_nbt_len(seq, bytes)?;
+            copy_nbt_bytes(seq, len, bytes)
+        }
+        INT_ARRAY_ID => {
+            let len = copy_nbt_len(seq, bytes)?;
+            copy_nbt_bytes(seq, len.saturating_mul(4), bytes)
+        }
+        LONG_ARRAY_ID => {
+            let len = copy_nbt_len(seq, bytes)?;
+            copy_nbt_bytes(seq, len.saturating_mul(8), bytes)
+        }
+        LIST_ID => {
+            let element_id = next_byte(seq, bytes)?;
+            let len = copy_nbt_len(seq, bytes)?;
+            for _ in 0..len {
+                copy_nbt_payload(seq, element_id, bytes, depth + 1)?;
+            }
+            Ok(())
+        }
+        COMPOUND_ID => loop {
+            let child_id = next_byte(seq, bytes)?;
+            if child_id == END_ID {
+                return Ok(());
+            }
+            copy_nbt_string(seq, bytes)?;
+            copy_nbt_payload(seq, child_id, bytes, depth + 1)?;
+        },
+        _ => Err(de::Error::custom(format!("Unknown NBT tag id {id}"))),
+    }
+}
+
+/// Reads an anonymous network NBT tag
+fn read_nbt<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<NbtTag, A::Error> {
+    let mut bytes = Vec::new();
+    let id = next_byte(seq, &mut bytes)?;
+    copy_nbt_payload(seq, id, &mut bytes, 0)?;
+    NbtTag::deserialize(&mut NbtReadHelper::new(Cursor::new(bytes))).map_err(de::Error::custom)
+}
+
+fn read_text<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<TextComponent, A::Error> {
+    TextComponent::from_nbt(&read_nbt(seq)?).ok_or(de::Error::custom("Invalid text component!"))
+}
+
+impl DataComponentCodec<Self> for CustomNameImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        seq.serialize_field::<TextComponent>("", &self.name)
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        Ok(Self {
+            name: read_text(seq)?,
+        })
+    }
+}
+
+impl DataComponentCodec<Self> for LoreImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        seq.serialize_field::<VarInt>("", &VarInt::from(self.lines.len() as i32))?;
+        for line in &self.lines {
+            seq.serialize_field::<TextComponent>("", line)?;
+        }
+        Ok(())
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        let len = seq
+            .next_element::<VarInt>()?
+            .ok_or(de::Error::custom("No Lore len VarInt!"))?
+            .0 as usize;
+        if len > Self::MAX_LINES {
+            return Err(de::Error::custom("Too many lore lines"));
+        }
+        let mut lines = Vec::with_capacity(len);
+        for _ in 0..len {
+            lines.push(read_text(seq)?);
+        }
+        Ok(Self { lines })
+    }
+}
+
+impl DataComponentCodec<Self> for AttributeModifiersImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        seq.serialize_field::<VarInt>("", &VarInt::from(self.attribute_modifiers.len() as i32))?;
+        for modifier in self.attribute_modifiers.iter() {
+            seq.serialize_field::<VarInt>("", &VarInt::from(modifier.r#type.id))?;
+            seq.serialize_field::<&str>("", &modifier.id.as_ref())?;
+            seq.serialize_field::<f64>("", &modifier.amount)?;
+            seq.serialize_field::<VarInt>("", &VarInt::from(modifier.operation.to_id()))?;
+            seq.serialize_field::<VarInt>("", &VarInt::from(modifier.slot.to_id()))?;
+            // Default tooltip display
+            seq.serialize_field::<VarInt>("", &VarInt::from(0))?;
+        }
+        Ok(())
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        const MAX_MODIFIERS: usize = 256;
+
+        let len = seq
+            .next_element::<VarInt>()?
+            .ok_or(de::Error::custom("No AttributeModifiers len VarInt!"))?
+            .0 as usize;
+        if len > MAX_MODIFIERS {
+            return Err(de::Error::custom("Too many attribute modifiers"));
+        }
+        let mut modifiers = Vec::with_capacity(len);
+        for _ in 0..len {
+            let attribute = seq
+                .next_element::<VarInt>()?
+                .ok_or(de::Error::custom("No attribute VarInt!"))?
+                .0;
+            let id = seq
+                .next_element::<String>()?
+                .ok_or(de::Error::custom("No modifier id String!"))?;
+            let amount = seq
+                .next_element::<f64>()?
+                .ok_or(de::Error::custom("No modifier amount f64!"))?;
+            let operation = seq
+                .next_element::<VarInt>()?
+                .ok_or(de::Error::custom("No modifier operation VarInt!"))?
+                .0;
+            let slot = seq
+                .next_element::<VarInt>()?
+                .ok_or(de::Error::custom("No modifier slot VarInt!"))?
+                .0;
+            // Tooltip display: default, hidden or overridden with a text the server doesn't need
+            let display = seq
+                .next_element::<VarInt>()?
+                .ok_or(de::Error::custom("No modifier display VarInt!"))?
+                .0;
+            if display == 2 {
+                read_text(seq)?;
+            }
+
+            modifiers.push(Modifier {
+                r#type: u8::try_from(attribute)
+                    .ok()
+                    .and_then(Attributes::from_id)
+                    .ok_or(de::Error::custom("Invalid attribute id!"))?,
+                id: Cow::Owned(id),
+                amount,
+                operation: Operation::from_id(operation)
+                    .ok_or(de::Error::custom("Invalid modifier operation!"))?,
+                slot: AttributeModifierSlot::from_id(slot)
+                    .ok_or(de::Error::custom("Invalid modifier slot!"))?,
+            });
+        }
+        Ok(Self {
+            attribute_modifiers: Cow::Owned(modifiers),
+        })
+    }
+}
+
+impl DataComponentCodec<Self> for CustomModelDataImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        seq.serialize_field::<VarInt>("", &VarInt::from(self.floats.len() as i32))?;
+        for value in &self.floats {
+            seq.serialize_field::<f32>("", value)?;
+        }
+        seq.serialize_field::<VarInt>("", &VarInt::from(self.flags.len() as i32))?;
+        for value in &self.flags {
+            seq.serialize_field::<bool>("", value)?;
+        }
+        seq.serialize_field::<VarInt>("", &VarInt::from(self.strings.len() as i32))?;
+        for value in &self.strings {
+            seq.serialize_field::<&str>("", &value.as_str())?;
+        }
+        seq.serialize_field::<VarInt>("", &VarInt::from(self.colors.len() as i32))?;
+        for value in &self.colors {
+            seq.serialize_field::<i32>("", value)?;
+        }
+        Ok(())
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        fn list<'a, A: SeqAccess<'a>, V: serde::Deserialize<'a>>(
+            seq: &mut A,
+        ) -> Result<Vec<V>, A::Error> {
+            const MAX_VALUES: usize = 256;
+
+            let len = seq
+                .next_element::<VarInt>()?
+                .ok_or(de::Error::custom("No CustomModelData len VarInt!"))?
+                .0 as usize;
+            if len > MAX_VALUES {
+                return Err(de::Error::custom("Too many custom model data values"));
+            }
+            let mut values = Vec::with_capacity(len);
+            for _ in 0..len {
+                values.push(
+                    seq.next_element::<V>()?
+                        .ok_or(de::Error::custom("No CustomModelData value!"))?,
+                );
+            }
+            Ok(values)
+        }
+
+        Ok(Self {
+            floats: list(seq)?,
+            flags: list(seq)?,
+            strings: list(seq)?,
+            colors: list(seq)?,
+        })
+    }
+}
+
+impl DataComponentCodec<Self> for BlockEntityDataImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        let mut bytes = Vec::new();
+        to_bytes_unnamed(&self.data, &mut bytes).map_err(ser::Error::custom)?;
+        for byte in &bytes {
+            seq.serialize_field::<u8>("", byte)?;
+        }
+        Ok(())
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        let NbtTag::Compound(data) = read_nbt(seq)? else {
+            return Err(de::Error::custom("BlockEntityData is not a compound!"));
+        };
+        if data.get_string("id").is_none() {
+            return Err(de::Error::custom("BlockEntityData has no id!"));
+        }
+        Ok(Self { data })
+    }
+}
+
+impl DataComponentCodec<Self> for BucketEntityDataImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        let mut bytes = Vec::new();
+        to_bytes_unnamed(&self.data, &mut bytes).map_err(ser::Error::custom)?;
+        for byte in &bytes {
+            seq.serialize_field::<u8>("", byte)?;
+        }
+        Ok(())
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        let NbtTag::Compound(data) = read_nbt(seq)? else {
+            return Err(de::Error::custom("BucketEntityData is not a compound!"));
+        };
+        Ok(Self { data })
+    }
+}
+
+/// Writes a nested item stack, unlike top level stacks its components are not length prefixed
+fn serialize_container_slot<T: SerializeStruct>(
+    slot: &ContainerSlot,
+    seq: &mut T,
+) -> Result<(), T::Error> {
+    let to_add = slot.patch.iter().filter(|(_, data)| data.is_some()).count();
+    seq.serialize_field::<VarInt>("", &VarInt::from(slot.count))?;
+    seq.serialize_field::<VarInt>("", &VarInt::from(slot.item.id))?;
+    seq.serialize_field::<VarInt>("", &VarInt::from(to_add as i32))?;
+    seq.serialize_field::<VarInt>("", &VarInt::from((slot.patch.len() - to_add) as i32))?;
+    for (id, data) in &slot.patch {
+        if let Some(data) = data {
+            seq.serialize_field::<VarInt>("", &VarInt::from(id.to_id()))?;
+            serialize(*id, data.as_ref(), seq)?;
+        }
+    }
+    for (id, data) in &slot.patch {
+        if data.is_none() {
+            seq.serialize_field::<VarInt>("", &VarInt::from(id.to_id()))?;
+        }
+    }
+    Ok(())
+}
+
+fn deserialize_component_id<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<DataComponent, A::Error> {
+    let id = seq
+        .next_element::<VarInt>()?
+        .ok_or(de::Error::custom("No component id VarInt!"))?
+        .0;
+    u8::try_from(id)
+        .ok()
+        .and_then(DataComponent::try_from_id)
+        .ok_or(de::Error::custom(format!("Unknown component ID: {id}")))
+}
+
+/// Reads a nested item stack, or `None` for an empty slot
+fn deserialize_container_slot<'a, A: SeqAccess<'a>>(
+    seq: &mut A,
+    slot: u8,
+    depth: usize,
+) -> Result<Option<ContainerSlot>, A::Error> {
+    const MAX_COMPONENTS: usize = 256;
+
+    let count = seq
+        .next_element::<VarInt>()?
+        .ok_or(de::Error::custom("No container item count VarInt!"))?
+        .0;
+    if count <= 0 {
+        return Ok(None);
+    }
+    let item = seq
+        .next_element::<VarInt>()?
+        .ok_or(de::Error::custom("No container item id VarInt!"))?
+        .0;
+    let item = u16::try_from(item)
+        .ok()
+        .and_then(Item::from_id)
+        .ok_or(de::Error::custom("Invalid container item id!"))?;
+    let to_add = seq
+        .next_element::<VarInt>()?
+        .ok_or(de::Error::custom("No container item to_add VarInt!"))?
+        .0 as usize;
+    let to_remove = seq
+        .next_element::<VarInt>()?
+        .ok_or(de::Error::custom("No container item to_remove VarInt!"))?
+        .0 as usize;
+    if to_add.saturating_add(to_remove) > MAX_COMPONENTS {
+        return Err(de::Error::custom("Too many components in container item"));
+    }
+
+    let mut patch = Vec::with_capacity(to_add + to_remove);
+    for _ in 0..to_add {
+        let id = deserialize_component_id(seq)?;
+        let data = if id == DataComponent::Container {
+            deserialize_container(seq, depth + 1)?.to_dyn()
+        } else {
+            deserialize(id, seq)?
+        };
+        patch.push((id, Some(data)));
+    }
+    for _ in 0..to_remove {
+        patch.push((deserialize_component_id(seq)?, None));
+    }
+
+    Ok(Some(ContainerSlot {
+        slot,
+        item,
+        count: count.min(i32::from(u8::MAX)) as u8,
+        patch,
+    }))
+}
+
+/// Reads container contents, `depth` counts the containers this one is nested in
+fn deserialize_container<'a, A: SeqAccess<'a>>(
+    seq: &mut A,
+    depth: usize,
+) -> Result<ContainerImpl, A::Error> {
+    // Containers holding containers could otherwise recurse without bound
+    const MAX_DEPTH: usize = 16;
+
+    if depth > MAX_DEPTH {
+        return Err(de::Error::custom("Containers are nested too deeply"));
+    }
+    let len = seq
+        .next_element::<VarInt>()?
+        .ok_or(de::Error::custom("No Container len VarInt!"))?
+        .0 as usize;
+    if len > ContainerImpl::MAX_SLOTS {
+        return Err(de::Error::custom("Too many container slots"));
+    }
+    let mut items = Vec::new();
+    for slot in 0..len {
+        if let Some(item) = deserialize_container_slot(seq, slot as u8, depth)? {
+            items.push(item);
+        }
+    }
+    Ok(ContainerImpl { items })
+}
+
+impl DataComponentCodec<Self> for ContainerImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        let len = self
+            .items
+            .last()
+            .map_or(0, |item| usize::from(item.slot) + 1);
+        seq.serialize_field::<VarInt>("", &VarInt::from(len as i32))?;
+        let mut items = self.items.iter().peekable();
+        for slot in 0..len {
+            if let Some(item) = items.next_if(|item| usize::from(item.slot) == slot) {
+                serialize_container_slot(item, seq)?;
+            } else {
+                seq.serialize_field::<VarInt>("", &VarInt::from(0))?;
+            }
+        }
+        Ok(())
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        deserialize_container(seq, 0)
+    }
+}
+
+/// Whether [`deserialize`] can read the component, others have to be skipped by their length
+#[must_use]
+pub const fn can_deserialize(id: DataComponent) -> bool {
+    matches!(
+        id,
+        DataComponent::MaxStackSize
+            | DataComponent::Enchantments
+            | DataComponent::Damage
+            | DataComponent::Unbreakable
+            | DataComponent::PotionContents
+            | DataComponent::FireworkExplosion
+            | DataComponent::Fireworks
+            | DataComponent::MaxDamage
+            | DataComponent::CustomName
+            | DataComponent::Lore
+            | DataComponent::AttributeModifiers
+            | DataComponent::CustomModelData
+            | DataComponent::Container
+            | DataComponent::BlockEntityData
+            | DataComponent::BucketEntityData
+    )
+}
+
 pub fn deserialize<'a, A: SeqAccess<'a>>(
     id: DataComponent,
     seq: &mut A,
@@ -426,6 +916,14 @@ pub fn deserialize<'a, A: SeqAccess<'a>>(
         DataComponent::PotionContents => Ok(PotionContentsImpl::deserialize(seq)?.to_dyn()),
         DataComponent::FireworkExplosion => Ok(FireworkExplosionImpl::deserialize(seq)?.to_dyn()),
         DataComponent::Fireworks => Ok(FireworksImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::MaxDamage => Ok(MaxDamageImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::CustomName => Ok(CustomNameImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::Lore => Ok(LoreImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::AttributeModifiers => Ok(AttributeModifiersImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::CustomModelData => Ok(CustomModelDataImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::Container => Ok(ContainerImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::BlockEntityData => Ok(BlockEntityDataImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::BucketEntityData => Ok(BucketEntityDataImpl::deserialize(seq)?.to_dyn()),
         _ => Err(serde::de::Error::custom("TODO")),
     }
 }
@@ -442,6 +940,14 @@ pub fn serialize<T: SerializeStruct>(
         DataComponent::PotionContents => get::<PotionContentsImpl>(value).serialize(seq),
         DataComponent::FireworkExplosion => get::<FireworkExplosionImpl>(value).serialize(seq),
         DataComponent::Fireworks => get::<FireworksImpl>(value).serialize(seq),
+        DataComponent::MaxDamage => get::<MaxDamageImpl>(value).serialize(seq),
+        DataComponent::CustomName => get::<CustomNameImpl>(value).serialize(seq),
+        DataComponent::Lore => get::<LoreImpl>(value).serialize(seq),
+        DataComponent::AttributeModifiers => get::<AttributeModifiersImpl>(value).serialize(seq),
+        DataComponent::CustomModelData => get::<CustomModelDataImpl>(value).serialize(seq),
+        DataComponent::Container => get::<ContainerImpl>(value).serialize(seq),
+        DataComponent::BlockEntityData => get::<BlockEntityDataImpl>(value).serialize(seq),
+        DataComponent::BucketEntityData => get::<BucketEntityDataImpl>(value).serialize(seq),
         _ => todo!("{} not yet implemented", id.to_name()),
     }
 }
@@ -1,5 +1,5 @@
 use crate::VarInt;
-use crate::codec::data_component::{deserialize, serialize};
+use crate::codec::data_component::{can_deserialize, deserialize, serialize};
 use crate::ser::{WritingError, serializer};
 use pumpkin_data::data_component::DataComponent;
 use pumpkin_data::item::Item;
@@ -74,6 +74,7 @@ impl<'de> Deserialize<'de> for ItemStackSerializer<'static> {
 
             fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
                 const MAX_COMPONENTS: i32 = 256;
+                const MAX_SKIPPED_LEN: i32 = 2 * 1024 * 1024;
 
                 let item_count = seq
                     .next_element::<VarInt>()?
@@ -114,9 +115,22 @@ impl<'de> Deserialize<'de> for ItemStackSerializer<'static> {
                     })?;
 
                     // Minecraft protocol sends a byte length for the component data here
-                    let _byte_len = seq
+                    let byte_len = seq
                         .next_element::<VarInt>()?
-                        .ok_or_else(|| de::Error::custom("No data len VarInt!"))?;
+                        .ok_or_else(|| de::Error::custom("No data len VarInt!"))?
+                        .0;
+
+                    if !can_deserialize(id) {
+                        // Drop components we can't read yet instead of rejecting the whole stack
+                        if !(0..=MAX_SKIPPED_LEN).contains(&byte_len) {
+                            return Err(de::Error::custom("Invalid component data length"));
+                        }
+                        for _ in 0..byte_len {
+                            seq.next_element::<u8>()?
+                                .ok_or_else(|| de::Error::custom("Component data too short"))?;
+                        }
+                        continue;
+                    }
 
                     let component_impl = deserialize(id, &mut seq)?;
 
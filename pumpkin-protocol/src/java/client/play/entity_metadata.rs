@@ -1,8 +1,10 @@
 use std::io::{Cursor, Write};
 
 use pumpkin_data::{
-    block_state_remap::remap_block_state_for_version, meta_data_type::MetaDataType,
-    packet::clientbound::PLAY_SET_ENTITY_DATA, tracked_data::TrackedId,
+    block_state_remap::remap_block_state_for_version,
+    meta_data_type::MetaDataType,
+    packet::{CURRENT_MC_VERSION, clientbound::PLAY_SET_ENTITY_DATA},
+    tracked_data::TrackedId,
 };
 use pumpkin_macros::java_packet;
 use pumpkin_util::version::MinecraftVersion;
@@ -71,12 +73,55 @@ impl<T> Metadata<T> {
 
     pub fn write<W: std::io::Write>(
         &self,
-        mut writer: W,
+        writer: W,
         version: &pumpkin_util::version::MinecraftVersion,
     ) -> Result<(), WritingError>
     where
         T: Serialize,
     {
+        self.to_serialized()?.write(writer, version)
+    }
+
+    /// Serializes the value, which does not depend on the protocol version, so that the entry
+    /// can be written for any version later on.
+    pub fn to_serialized(&self) -> Result<SerializedMetadata, WritingError>
+    where
+        T: Serialize,
+    {
+        let mut value = Vec::new();
+        self.value
+            .serialize(&mut serializer::Serializer::new(&mut value))
+            .map_err(|e| WritingError::Serde(e.to_string()))?;
+        Ok(SerializedMetadata {
+            index: self.index,
+            r#type: self.r#type,
+            value: value.into_boxed_slice(),
+        })
+    }
+}
+
+/// A metadata entry whose value is already serialized, remembered so that it can be sent to
+/// clients of any protocol version.
+pub struct SerializedMetadata {
+    index: TrackedId,
+    r#type: MetaDataType,
+    value: Box<[u8]>,
+}
+
+impl SerializedMetadata {
+    /// The index of the entry in the current protocol version, which identifies it across
+    /// versions.
+    #[must_use]
+    pub fn key(&self) -> u8 {
+        self.index.get(&CURRENT_MC_VERSION)
+    }
+
+    /// Writes the entry for the given protocol version, or nothing if it does not exist there.
+    pub fn write<W: std::io::Write>(
+        &self,
+        mut writer: W,
+        version: &MinecraftVersion,
+    ) -> Result<(), WritingError> {
         let resolved_index = self.index.get(version);
 
         if resolved_index == 255 {
@@ -93,15 +138,7 @@ impl<T> Metadata<T> {
         writer.write_var_int(&VarInt(remapped_type_id))?;
 
         if self.r#type == MetaDataType::BLOCK_STATE {
-            let mut serialized_value = Vec::new();
-            {
-                let mut serializer = serializer::Serializer::new(&mut serialized_value);
-                self.value
-                    .serialize(&mut serializer)
-                    .map_err(|e| WritingError::Serde(e.to_string()))?;
-            };
-
-            let mut cursor = Cursor::new(serialized_value);
+            let mut cursor = Cursor::new(&self.value);
             let decoded_state = VarInt::decode(&mut cursor).map_err(|e| {
                 WritingError::Message(format!("Failed to decode block state metadata: {e}"))
             })?;
@@ -112,11 +149,6 @@ impl<T> Metadata<T> {
             return Ok(());
         }
 
-        let mut serializer = serializer::Serializer::new(&mut writer);
-        self.value
-            .serialize(&mut serializer)
-            .map_err(|e| WritingError::Serde(e.to_string()))?;
-
-        Ok(())
+        writer.write_all(&self.value).map_err(WritingError::IoError)
     }
 }
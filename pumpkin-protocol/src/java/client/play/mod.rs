@@ -37,6 +37,7 @@ mod keep_alive;
 mod level_event;
 mod light_update;
 mod login;
+mod mount_screen_open;
 mod multi_block_update;
 mod open_screen;
 mod open_sign_editor;
@@ -49,6 +50,8 @@ mod player_info_update;
 mod player_position;
 mod player_remove;
 mod player_spawn_position;
+mod recipe_book_add;
+mod recipe_book_remove;
 mod remove_entities;
 mod remove_mob_effect;
 mod reset_score;
@@ -63,6 +66,7 @@ mod set_container_content;
 mod set_container_property;
 mod set_container_slot;
 mod set_cursor_slot;
+mod set_entity_link;
 mod set_equipment;
 mod set_experience;
 mod set_health;
@@ -132,6 +136,7 @@ pub use keep_alive::*;
 pub use level_event::*;
 pub use light_update::*;
 pub use login::*;
+pub use mount_screen_open::*;
 pub use multi_block_update::*;
 pub use open_screen::*;
 pub use open_sign_editor::*;
@@ -144,6 +149,8 @@ pub use player_info_update::*;
 pub use player_position::*;
 pub use player_remove::*;
 pub use player_spawn_position::*;
+pub use recipe_book_add::*;
+pub use recipe_book_remove::*;
 pub use remove_entities::*;
 pub use remove_mob_effect::*;
 pub use reset_score::*;
@@ -158,6 +165,7 @@ pub use set_container_content::*;
 pub use set_container_property::*;
 pub use set_container_slot::*;
 pub use set_cursor_slot::*;
+pub use set_entity_link::*;
 pub use set_equipment::*;
 pub use set_experience::*;
 pub use set_health::*;
@@ -0,0 +1,40 @@
+use pumpkin_data::packet::clientbound::{PLAY_HORSE_SCREEN_OPEN, PLAY_MOUNT_SCREEN_OPEN};
+use pumpkin_util::version::MinecraftVersion;
+use serde::Serialize;
+
+use crate::VarInt;
+use crate::packet::MultiVersionJavaPacket;
+
+/// Opens the inventory of a mount, like a horse or a llama, for the player riding or
+/// interacting with it.
+///
+/// Unlike [`super::COpenScreen`], the client builds the screen from the entity itself, so only
+/// the number of chest columns is sent along.
+#[derive(Serialize)]
+pub struct CMountScreenOpen {
+    pub sync_id: VarInt,
+    /// The number of chest columns, each holding three slots. Zero for mounts without a chest.
+    pub columns: VarInt,
+    pub entity_id: i32,
+}
+
+impl CMountScreenOpen {
+    #[must_use]
+    pub const fn new(sync_id: VarInt, columns: VarInt, entity_id: i32) -> Self {
+        Self {
+            sync_id,
+            columns,
+            entity_id,
+        }
+    }
+}
+
+impl MultiVersionJavaPacket for CMountScreenOpen {
+    fn to_id(version: MinecraftVersion) -> i32 {
+        if version >= MinecraftVersion::V_1_21_11 {
+            PLAY_MOUNT_SCREEN_OPEN.to_id(version)
+        } else {
+            PLAY_HORSE_SCREEN_OPEN.to_id(version)
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use std::borrow::Cow;
+use std::io::Write;
+
+use pumpkin_data::item_id_remap::remap_item_id_for_version;
+use pumpkin_data::packet::clientbound::PLAY_RECIPE_BOOK_ADD;
+use pumpkin_macros::java_packet;
+use pumpkin_util::version::MinecraftVersion;
+use pumpkin_world::item::ItemStack;
+
+use crate::{
+    ClientPacket, VarInt,
+    codec::item_stack_seralizer::ItemStackSerializer,
+    ser::{NetworkWriteExt, WritingError},
+};
+
+/// Adds recipes to the client's recipe book, or replaces its whole content.
+///
+/// Since 1.21.2 the client no longer knows the recipes themselves, so every entry carries
+/// everything the recipe book needs to show and fill in the recipe.
+#[java_packet(PLAY_RECIPE_BOOK_ADD)]
+pub struct CRecipeBookAdd {
+    pub entries: Vec<RecipeBookEntry>,
+    /// Whether the entries replace every recipe the client currently knows.
+    pub replace: bool,
+}
+
+impl CRecipeBookAdd {
+    #[must_use]
+    pub const fn new(entries: Vec<RecipeBookEntry>, replace: bool) -> Self {
+        Self { entries, replace }
+    }
+}
+
+pub struct RecipeBookEntry {
+    pub display: RecipeDisplayEntry,
+    /// Shows the "new recipe unlocked" toast.
+    pub notification: bool,
+    /// Marks the recipe as new in the recipe book until the player looks at it.
+    pub highlight: bool,
+}
+
+impl RecipeBookEntry {
+    const FLAG_NOTIFICATION: u8 = 1;
+    const FLAG_HIGHLIGHT: u8 = 2;
+
+    const fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.notification {
+            flags |= Self::FLAG_NOTIFICATION;
+        }
+        if self.highlight {
+            flags |= Self::FLAG_HIGHLIGHT;
+        }
+        flags
+    }
+}
+
+pub struct RecipeDisplayEntry {
+    /// The server chosen id the client uses to refer to this recipe, e.g. when placing it.
+    pub id: VarInt,
+    pub display: RecipeDisplay,
+    /// Recipes sharing a group are stacked into one button in the recipe book.
+    pub group: Option<VarInt>,
+    pub category: RecipeBookCategory,
+    /// The ingredients used to tell whether the player has the items to craft the recipe.
+    pub crafting_requirements: Option<Vec<Ingredient>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum RecipeBookCategory {
+    CraftingBuildingBlocks,
+    CraftingRedstone,
+    CraftingEquipment,
+    CraftingMisc,
+    FurnaceFood,
+    FurnaceBlocks,
+    FurnaceMisc,
+    BlastFurnaceBlocks,
+    BlastFurnaceMisc,
+    SmokerFood,
+    Stonecutter,
+    Smithing,
+    Campfire,
+}
+
+pub enum RecipeDisplay {
+    CraftingShapeless {
+        ingredients: Vec<SlotDisplay>,
+        result: SlotDisplay,
+        crafting_station: SlotDisplay,
+    },
+    CraftingShaped {
+        width: i32,
+        height: i32,
+        ingredients: Vec<SlotDisplay>,
+        result: SlotDisplay,
+        crafting_station: SlotDisplay,
+    },
+    Furnace {
+        ingredient: SlotDisplay,
+        fuel: SlotDisplay,
+        result: SlotDisplay,
+        crafting_station: SlotDisplay,
+        duration: i32,
+        experience: f32,
+    },
+}
+
+impl RecipeDisplay {
+    const fn type_id(&self) -> i32 {
+        match self {
+            Self::CraftingShapeless { .. } => 0,
+            Self::CraftingShaped { .. } => 1,
+            Self::Furnace { .. } => 2,
+        }
+    }
+}
+
+/// What the client shows in a recipe book slot.
+pub enum SlotDisplay {
+    Empty,
+    AnyFuel,
+    /// An item, by its latest registry id.
+    Item(u16),
+    ItemStack(ItemStack),
+    /// Every item of an item tag, cycling through them.
+    Tag(String),
+    /// Cycles through the given displays.
+    Composite(Vec<Self>),
+}
+
+impl SlotDisplay {
+    const fn type_id(&self) -> i32 {
+        match self {
+            Self::Empty => 0,
+            Self::AnyFuel => 1,
+            Self::Item(_) => 2,
+            Self::ItemStack(_) => 3,
+            Self::Tag(_) => 4,
+            Self::Composite(_) => 7,
+        }
+    }
+}
+
+/// A set of items that fits a recipe slot.
+pub enum Ingredient {
+    /// Items by their latest registry id.
+    Items(Vec<u16>),
+    Tag(String),
+}
+
+fn write_slot_display(
+    write: &mut impl Write,
+    display: &SlotDisplay,
+    version: MinecraftVersion,
+) -> Result<(), WritingError> {
+    write.write_var_int(&VarInt(display.type_id()))?;
+    match display {
+        SlotDisplay::Empty | SlotDisplay::AnyFuel => Ok(()),
+        SlotDisplay::Item(item) => {
+            write.write_var_int(&VarInt::from(remap_item_id_for_version(*item, version)))
+        }
+        SlotDisplay::ItemStack(stack) => {
+            ItemStackSerializer(Cow::Borrowed(stack)).write_with_version(&mut *write, &version)
+        }
+        SlotDisplay::Tag(tag) => write.write_string(tag),
+        SlotDisplay::Composite(displays) => write_slot_displays(write, displays, version),
+    }
+}
+
+fn write_slot_displays(
+    write: &mut impl Write,
+    displays: &[SlotDisplay],
+    version: MinecraftVersion,
+) -> Result<(), WritingError> {
+    write.write_var_int(&VarInt(displays.len() as i32))?;
+    for display in displays {
+        write_slot_display(write, display, version)?;
+    }
+    Ok(())
+}
+
+fn write_ingredient(
+    write: &mut impl Write,
+    ingredient: &Ingredient,
+    version: MinecraftVersion,
+) -> Result<(), WritingError> {
+    match ingredient {
+        Ingredient::Tag(tag) => {
+            write.write_var_int(&VarInt(0))?;
+            write.write_string(tag)
+        }
+        // Direct holder sets are prefixed with their size plus one, zero meaning a tag
+        Ingredient::Items(items) => {
+            write.write_var_int(&VarInt(items.len() as i32 + 1))?;
+            for item in items {
+                write.write_var_int(&VarInt::from(remap_item_id_for_version(*item, version)))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn write_recipe_display(
+    write: &mut impl Write,
+    display: &RecipeDisplay,
+    version: MinecraftVersion,
+) -> Result<(), WritingError> {
+    write.write_var_int(&VarInt(display.type_id()))?;
+    match display {
+        RecipeDisplay::CraftingShapeless {
+            ingredients,
+            result,
+            crafting_station,
+        } => {
+            write_slot_displays(write, ingredients, version)?;
+            write_slot_display(write, result, version)?;
+            write_slot_display(write, crafting_station, version)
+        }
+        RecipeDisplay::CraftingShaped {
+            width,
+            height,
+            ingredients,
+            result,
+            crafting_station,
+        } => {
+            write.write_var_int(&VarInt(*width))?;
+            write.write_var_int(&VarInt(*height))?;
+            write_slot_displays(write, ingredients, version)?;
+            write_slot_display(write, result, version)?;
+            write_slot_display(write, crafting_station, version)
+        }
+        RecipeDisplay::Furnace {
+            ingredient,
+            fuel,
+            result,
+            crafting_station,
+            duration,
+            experience,
+        } => {
+            write_slot_display(write, ingredient, version)?;
+            write_slot_display(write, fuel, version)?;
+            write_slot_display(write, result, version)?;
+            write_slot_display(write, crafting_station, version)?;
+            write.write_var_int(&VarInt(*duration))?;
+            write.write_f32_be(*experience)
+        }
+    }
+}
+
+impl ClientPacket for CRecipeBookAdd {
+    fn write_packet_data(
+        &self,
+        write: impl Write,
+        version: &MinecraftVersion,
+    ) -> Result<(), WritingError> {
+        let mut write = write;
+
+        write.write_var_int(&VarInt(self.entries.len() as i32))?;
+        for entry in &self.entries {
+            let display = &entry.display;
+            write.write_var_int(&display.id)?;
+            write_recipe_display(&mut write, &display.display, *version)?;
+            write.write_option(&display.group, NetworkWriteExt::write_var_int)?;
+            write.write_var_int(&VarInt(display.category as i32))?;
+            write.write_option(&display.crafting_requirements, |write, requirements| {
+                write.write_var_int(&VarInt(requirements.len() as i32))?;
+                for ingredient in requirements {
+                    write_ingredient(write, ingredient, *version)?;
+                }
+                Ok(())
+            })?;
+            write.write_u8(entry.flags())?;
+        }
+        write.write_bool(self.replace)
+    }
+}
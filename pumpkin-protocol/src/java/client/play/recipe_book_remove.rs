@@ -0,0 +1,19 @@
+use pumpkin_data::packet::clientbound::PLAY_RECIPE_BOOK_REMOVE;
+use pumpkin_macros::java_packet;
+use serde::Serialize;
+
+use crate::VarInt;
+
+/// Removes recipes, by the display ids they were added with, from the client's recipe book.
+#[derive(Serialize)]
+#[java_packet(PLAY_RECIPE_BOOK_REMOVE)]
+pub struct CRecipeBookRemove<'a> {
+    pub recipes: &'a [VarInt],
+}
+
+impl<'a> CRecipeBookRemove<'a> {
+    #[must_use]
+    pub const fn new(recipes: &'a [VarInt]) -> Self {
+        Self { recipes }
+    }
+}
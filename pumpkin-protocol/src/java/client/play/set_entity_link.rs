@@ -0,0 +1,25 @@
+use pumpkin_data::packet::clientbound::PLAY_SET_ENTITY_LINK;
+use pumpkin_macros::java_packet;
+use serde::Serialize;
+
+/// Attaches an entity to another with a lead, or detaches it.
+///
+/// Despite the name this is only used for leads; riding uses [`super::CSetPassengers`].
+#[derive(Serialize)]
+#[java_packet(PLAY_SET_ENTITY_LINK)]
+pub struct CSetEntityLink {
+    /// The Entity ID of the leashed entity.
+    pub attached_entity_id: i32,
+    /// The Entity ID of the entity holding the lead, or 0 to detach it.
+    pub holding_entity_id: i32,
+}
+
+impl CSetEntityLink {
+    #[must_use]
+    pub const fn new(attached_entity_id: i32, holding_entity_id: i32) -> Self {
+        Self {
+            attached_entity_id,
+            holding_entity_id,
+        }
+    }
+}
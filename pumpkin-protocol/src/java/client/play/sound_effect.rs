@@ -13,7 +13,7 @@ pub struct CSoundEffect {
     pub position: Vector3<i32>,
     pub volume: f32,
     pub pitch: f32,
-    pub seed: f64,
+    pub seed: i64,
 }
 
 impl CSoundEffect {
@@ -24,7 +24,7 @@ impl CSoundEffect {
         position: &Vector3<f64>,
         volume: f32,
         pitch: f32,
-        seed: f64,
+        seed: i64,
     ) -> Self {
         Self {
             sound_event,
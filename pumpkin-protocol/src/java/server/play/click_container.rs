@@ -29,6 +29,8 @@ impl<'de> Deserialize<'de> for SClickSlot {
             }
 
             fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                const MAX_CHANGED_SLOTS: i32 = 128;
+
                 let sync_id = seq
                     .next_element::<VarInt>()?
                     .ok_or(de::Error::custom("Failed to decode u8"))?;
@@ -48,6 +50,9 @@ impl<'de> Deserialize<'de> for SClickSlot {
                 let length_of_array = seq
                     .next_element::<VarInt>()?
                     .ok_or(de::Error::custom("Failed to decode VarInt"))?;
+                if !(0..=MAX_CHANGED_SLOTS).contains(&length_of_array.0) {
+                    return Err(de::Error::custom("Too many changed slots"));
+                }
                 let mut array_of_changed_slots = vec![];
                 for _ in 0..length_of_array.0 {
                     let slot_number = seq
@@ -27,6 +27,7 @@ mod player_position;
 mod player_position_rotation;
 mod player_rotation;
 mod player_session;
+mod recipe_book_seen_recipe;
 mod set_command_block;
 mod set_creative_slot;
 mod set_held_item;
@@ -64,6 +65,7 @@ pub use player_position::*;
 pub use player_position_rotation::*;
 pub use player_rotation::*;
 pub use player_session::*;
+pub use recipe_book_seen_recipe::*;
 pub use set_command_block::*;
 pub use set_creative_slot::*;
 pub use set_held_item::*;
@@ -0,0 +1,12 @@
+use pumpkin_data::packet::serverbound::PLAY_RECIPE_BOOK_SEEN_RECIPE;
+use pumpkin_macros::java_packet;
+use serde::{Deserialize, Serialize};
+
+use crate::VarInt;
+
+/// Sent when the player looks at a highlighted recipe in the recipe book.
+#[derive(Deserialize, Serialize)]
+#[java_packet(PLAY_RECIPE_BOOK_SEEN_RECIPE)]
+pub struct SRecipeBookSeenRecipe {
+    pub recipe: VarInt,
+}
@@ -38,3 +38,94 @@ impl FromStr for Difficulty {
         }
     }
 }
+
+impl Difficulty {
+    /// Scales damage dealt to players by mobs (and other difficulty-scaled sources).
+    #[must_use]
+    pub fn scale_damage(self, amount: f32) -> f32 {
+        match self {
+            Self::Peaceful => 0.0,
+            Self::Easy => (amount / 2.0 + 1.0).min(amount),
+            Self::Normal => amount,
+            Self::Hard => amount * 1.5,
+        }
+    }
+}
+
+/// The effective difficulty at a position, which grows with world age, time players spent in
+/// the chunk and the moon phase.
+///
+/// Mojang name: `DifficultyInstance`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LocalDifficulty {
+    pub difficulty: Difficulty,
+    /// Ranges from `0.0` (peaceful) to `6.75` (hard, fully inhabited, full moon).
+    pub local_difficulty: f32,
+}
+
+impl LocalDifficulty {
+    #[must_use]
+    pub fn new(
+        difficulty: Difficulty,
+        time_of_day: i64,
+        inhabited_time: i64,
+        moon_brightness: f32,
+    ) -> Self {
+        Self {
+            difficulty,
+            local_difficulty: Self::calculate(
+                difficulty,
+                time_of_day,
+                inhabited_time,
+                moon_brightness,
+            ),
+        }
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    fn calculate(
+        difficulty: Difficulty,
+        time_of_day: i64,
+        inhabited_time: i64,
+        moon_brightness: f32,
+    ) -> f32 {
+        if difficulty == Difficulty::Peaceful {
+            return 0.0;
+        }
+        let hard = difficulty == Difficulty::Hard;
+        let global = ((time_of_day as f32 - 72_000.0) / 1_440_000.0).clamp(0.0, 1.0) * 0.25;
+        let mut local =
+            (inhabited_time as f32 / 3_600_000.0).clamp(0.0, 1.0) * if hard { 1.0 } else { 0.75 };
+        local += (moon_brightness * 0.25).clamp(0.0, global);
+        if difficulty == Difficulty::Easy {
+            local *= 0.5;
+        }
+        f32::from(difficulty as u8) * (0.75 + global + local)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Difficulty, LocalDifficulty};
+
+    #[test]
+    fn local_difficulty() {
+        let peaceful = LocalDifficulty::new(Difficulty::Peaceful, 10_000_000, 10_000_000, 1.0);
+        assert_eq!(peaceful.local_difficulty, 0.0);
+
+        let fresh = LocalDifficulty::new(Difficulty::Normal, 0, 0, 1.0);
+        assert!((fresh.local_difficulty - 1.5).abs() < f32::EPSILON);
+
+        let maxed = LocalDifficulty::new(Difficulty::Hard, 10_000_000, 10_000_000, 1.0);
+        assert!((maxed.local_difficulty - 6.75).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn scale_damage() {
+        assert_eq!(Difficulty::Peaceful.scale_damage(4.0), 0.0);
+        assert_eq!(Difficulty::Easy.scale_damage(4.0), 3.0);
+        assert_eq!(Difficulty::Easy.scale_damage(1.0), 1.0);
+        assert_eq!(Difficulty::Normal.scale_damage(4.0), 4.0);
+        assert_eq!(Difficulty::Hard.scale_damage(4.0), 6.0);
+    }
+}
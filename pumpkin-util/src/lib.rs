@@ -3,7 +3,7 @@ use std::ops::{Index, IndexMut};
 
 pub use serde_json;
 
-pub use difficulty::Difficulty;
+pub use difficulty::{Difficulty, LocalDifficulty};
 pub use gamemode::GameMode;
 pub use permission::PermissionLvl;
 
@@ -7,11 +7,14 @@ use color::Color;
 use colored::Colorize;
 use core::str;
 use hover::HoverEvent;
+use pumpkin_nbt::deserializer::NbtReadHelper;
 use pumpkin_nbt::serializer::Serializer;
+use pumpkin_nbt::tag::NbtTag;
 use serde::de::{Error, MapAccess, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::borrow::Cow;
 use std::fmt::Formatter;
+use std::io::Cursor;
 use style::Style;
 
 pub mod click;
@@ -562,6 +565,29 @@ impl TextComponent {
         buf.into_boxed_slice()
     }
 
+    /// Converts this component into the NBT tag it is encoded as, falling back to its plain
+    /// text if it can't be encoded.
+    #[must_use]
+    pub fn to_nbt(&self) -> NbtTag {
+        let mut buf = Vec::new();
+        let encoded = self
+            .0
+            .clone()
+            .to_translated()
+            .serialize(&mut Serializer::new(&mut buf, None))
+            .ok()
+            .and_then(|()| NbtTag::deserialize(&mut NbtReadHelper::new(Cursor::new(buf))).ok());
+        encoded.unwrap_or_else(|| NbtTag::String(self.clone().get_text()))
+    }
+
+    /// Reads a component from NBT, either a bare string, a list or a compound.
+    #[must_use]
+    pub fn from_nbt(tag: &NbtTag) -> Option<Self> {
+        serde_json::to_value(tag)
+            .and_then(serde_json::from_value)
+            .ok()
+    }
+
     /// Sets the text color.
     ///
     /// # Arguments
@@ -898,6 +924,7 @@ pub enum TextContent {
 #[cfg(test)]
 mod test {
     use pumpkin_nbt::serializer::to_bytes_unnamed;
+    use pumpkin_nbt::tag::NbtTag;
 
     use crate::text::{TextComponent, color::NamedColor};
 
@@ -923,4 +950,18 @@ mod test {
 
         assert_eq!(bytes, expected_bytes);
     }
+
+    #[test]
+    fn text_component_nbt_round_trip() {
+        let text = TextComponent::text("Sword")
+            .bold()
+            .color_named(NamedColor::Red)
+            .add_child(TextComponent::text(" of doom").italic());
+        assert_eq!(TextComponent::from_nbt(&text.to_nbt()), Some(text));
+
+        assert_eq!(
+            TextComponent::from_nbt(&NbtTag::String("plain".to_string())),
+            Some(TextComponent::text("plain"))
+        );
+    }
 }
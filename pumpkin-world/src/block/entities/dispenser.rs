@@ -0,0 +1,24 @@
+use std::sync::{Arc, atomic::AtomicBool};
+
+use pumpkin_util::math::position::BlockPos;
+use tokio::sync::Mutex;
+
+use crate::{
+    impl_block_entity_for_dispenser, impl_clearable_for_dispenser, impl_dispenser_helper_methods,
+    impl_inventory_for_dispenser, item::ItemStack,
+};
+
+pub struct DispenserBlockEntity {
+    pub position: BlockPos,
+    pub items: [Arc<Mutex<ItemStack>>; Self::INVENTORY_SIZE],
+    pub dirty: AtomicBool,
+}
+
+impl DispenserBlockEntity {
+    pub const ID: &'static str = "minecraft:dispenser";
+}
+
+impl_block_entity_for_dispenser!(DispenserBlockEntity);
+impl_inventory_for_dispenser!(DispenserBlockEntity);
+impl_clearable_for_dispenser!(DispenserBlockEntity);
+impl_dispenser_helper_methods!(DispenserBlockEntity);
@@ -0,0 +1,188 @@
+/// Implements the BlockEntity trait for dispenser-like block entities (dispensers and droppers).
+/// Parameters:
+/// - $struct_name: The type of the struct (e.g., DispenserBlockEntity)
+#[macro_export]
+macro_rules! impl_block_entity_for_dispenser {
+    ($struct_name:ty) => {
+        impl $crate::block::entities::BlockEntity for $struct_name {
+            fn write_nbt<'a>(
+                &'a self,
+                nbt: &'a mut pumpkin_nbt::compound::NbtCompound,
+            ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + 'a>> {
+                use $crate::inventory::Inventory;
+
+                self.write_inventory_nbt(nbt, true)
+            }
+
+            fn from_nbt(nbt: &pumpkin_nbt::compound::NbtCompound, position: BlockPos) -> Self
+            where
+                Self: Sized,
+            {
+                use $crate::inventory::Inventory;
+
+                let dispenser = Self::new(position);
+                dispenser.read_data(nbt, &dispenser.items);
+                dispenser
+            }
+
+            fn resource_location(&self) -> &'static str {
+                Self::ID
+            }
+
+            fn get_position(&self) -> BlockPos {
+                self.position
+            }
+
+            fn get_inventory(self: Arc<Self>) -> Option<Arc<dyn $crate::inventory::Inventory>> {
+                Some(self)
+            }
+
+            fn is_dirty(&self) -> bool {
+                self.dirty.load(std::sync::atomic::Ordering::Relaxed)
+            }
+
+            fn clear_dirty(&self) {
+                self.dirty
+                    .store(false, std::sync::atomic::Ordering::Relaxed);
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+    };
+}
+
+/// Implements the Inventory trait for dispenser-like block entities.
+#[macro_export]
+macro_rules! impl_inventory_for_dispenser {
+    ($struct_name:ty) => {
+        impl $crate::inventory::Inventory for $struct_name {
+            fn size(&self) -> usize {
+                self.items.len()
+            }
+
+            fn is_empty(&self) -> $crate::inventory::InventoryFuture<'_, bool> {
+                Box::pin(async move {
+                    for slot in &self.items {
+                        if !slot.lock().await.is_empty() {
+                            return false;
+                        }
+                    }
+
+                    true
+                })
+            }
+
+            fn get_stack(
+                &self,
+                slot: usize,
+            ) -> $crate::inventory::InventoryFuture<'_, Arc<Mutex<ItemStack>>> {
+                Box::pin(async move { self.items[slot].clone() })
+            }
+
+            fn remove_stack(
+                &self,
+                slot: usize,
+            ) -> $crate::inventory::InventoryFuture<'_, ItemStack> {
+                Box::pin(async move {
+                    let mut removed = ItemStack::EMPTY.clone();
+                    let mut guard = self.items[slot].lock().await;
+                    std::mem::swap(&mut removed, &mut *guard);
+                    self.mark_dirty();
+                    removed
+                })
+            }
+
+            fn remove_stack_specific(
+                &self,
+                slot: usize,
+                amount: u8,
+            ) -> $crate::inventory::InventoryFuture<'_, ItemStack> {
+                Box::pin(async move {
+                    let res = $crate::inventory::split_stack(&self.items, slot, amount).await;
+                    self.mark_dirty();
+                    res
+                })
+            }
+
+            fn set_stack(
+                &self,
+                slot: usize,
+                stack: ItemStack,
+            ) -> $crate::inventory::InventoryFuture<'_, ()> {
+                Box::pin(async move {
+                    *self.items[slot].lock().await = stack;
+                    self.mark_dirty();
+                })
+            }
+
+            fn mark_dirty(&self) {
+                self.dirty.store(true, std::sync::atomic::Ordering::Relaxed);
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+    };
+}
+
+/// Implements the Clearable trait for dispenser-like block entities.
+#[macro_export]
+macro_rules! impl_clearable_for_dispenser {
+    ($struct_name:ty) => {
+        impl $crate::inventory::Clearable for $struct_name {
+            fn clear(
+                &self,
+            ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + '_>> {
+                use $crate::inventory::Inventory;
+
+                Box::pin(async move {
+                    for slot in &self.items {
+                        *slot.lock().await = ItemStack::EMPTY.clone();
+                    }
+                    self.mark_dirty();
+                })
+            }
+        }
+    };
+}
+
+/// Implements `new()` and the random slot selection shared by dispensers and droppers.
+#[macro_export]
+macro_rules! impl_dispenser_helper_methods {
+    ($struct_name:ty) => {
+        impl $struct_name {
+            pub const INVENTORY_SIZE: usize = 9;
+
+            #[must_use]
+            pub fn new(position: BlockPos) -> Self {
+                Self {
+                    position,
+                    items: std::array::from_fn(|_| Arc::new(Mutex::new(ItemStack::EMPTY.clone()))),
+                    dirty: std::sync::atomic::AtomicBool::new(false),
+                }
+            }
+
+            /// Picks a random non-empty slot, every one being equally likely.
+            pub async fn get_random_slot(&self) -> Option<tokio::sync::MutexGuard<'_, ItemStack>> {
+                use rand::RngExt;
+
+                // this.unpackLootTable(null);
+                let mut ret = None;
+                let mut j = 1;
+                for slot in &self.items {
+                    let item = slot.lock().await;
+                    if !item.is_empty() {
+                        if rand::rng().random_range(0..j) == 0 {
+                            ret = Some(item);
+                        }
+                        j += 1;
+                    }
+                }
+                ret
+            }
+        }
+    };
+}
@@ -1,15 +1,12 @@
-use crate::block::entities::BlockEntity;
-use crate::inventory::{Clearable, Inventory, InventoryFuture, split_stack};
-use crate::item::ItemStack;
-use pumpkin_nbt::compound::NbtCompound;
+use std::sync::{Arc, atomic::AtomicBool};
+
 use pumpkin_util::math::position::BlockPos;
-use rand::{RngExt, rng};
-use std::any::Any;
-use std::array::from_fn;
-use std::pin::Pin;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
-use tokio::sync::{Mutex, MutexGuard};
+use tokio::sync::Mutex;
+
+use crate::{
+    impl_block_entity_for_dispenser, impl_clearable_for_dispenser, impl_dispenser_helper_methods,
+    impl_inventory_for_dispenser, item::ItemStack,
+};
 
 pub struct DropperBlockEntity {
     pub position: BlockPos,
@@ -17,145 +14,11 @@ pub struct DropperBlockEntity {
     pub dirty: AtomicBool,
 }
 
-impl BlockEntity for DropperBlockEntity {
-    fn write_nbt<'a>(
-        &'a self,
-        nbt: &'a mut NbtCompound,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
-        self.write_inventory_nbt(nbt, true)
-    }
-
-    fn from_nbt(nbt: &pumpkin_nbt::compound::NbtCompound, position: BlockPos) -> Self
-    where
-        Self: Sized,
-    {
-        let dropper = Self {
-            position,
-            items: from_fn(|_| Arc::new(Mutex::new(ItemStack::EMPTY.clone()))),
-            dirty: AtomicBool::new(false),
-        };
-
-        dropper.read_data(nbt, &dropper.items);
-
-        dropper
-    }
-
-    fn resource_location(&self) -> &'static str {
-        Self::ID
-    }
-
-    fn get_position(&self) -> BlockPos {
-        self.position
-    }
-
-    fn get_inventory(self: Arc<Self>) -> Option<Arc<dyn Inventory>> {
-        Some(self)
-    }
-
-    fn is_dirty(&self) -> bool {
-        self.dirty.load(Ordering::Relaxed)
-    }
-
-    fn clear_dirty(&self) {
-        self.dirty.store(false, Ordering::Relaxed);
-    }
-
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
-    }
-}
-
 impl DropperBlockEntity {
-    pub const INVENTORY_SIZE: usize = 9;
     pub const ID: &'static str = "minecraft:dropper";
-
-    #[must_use]
-    pub fn new(position: BlockPos) -> Self {
-        Self {
-            position,
-            items: from_fn(|_| Arc::new(Mutex::new(ItemStack::EMPTY.clone()))),
-            dirty: AtomicBool::new(false),
-        }
-    }
-    pub async fn get_random_slot(&self) -> Option<MutexGuard<'_, ItemStack>> {
-        // this.unpackLootTable(null);
-        let mut ret = None;
-        let mut j = 1;
-        for i in &self.items {
-            let item = i.lock().await;
-            if !item.is_empty() {
-                if rng().random_range(0..j) == 0 {
-                    ret = Some(item);
-                }
-                j += 1;
-            }
-        }
-        ret
-    }
-}
-
-impl Inventory for DropperBlockEntity {
-    fn size(&self) -> usize {
-        self.items.len()
-    }
-
-    fn is_empty(&self) -> InventoryFuture<'_, bool> {
-        Box::pin(async move {
-            for slot in &self.items {
-                if !slot.lock().await.is_empty() {
-                    return false;
-                }
-            }
-
-            true
-        })
-    }
-
-    fn get_stack(&self, slot: usize) -> InventoryFuture<'_, Arc<Mutex<ItemStack>>> {
-        Box::pin(async move { self.items[slot].clone() })
-    }
-
-    fn remove_stack(&self, slot: usize) -> InventoryFuture<'_, ItemStack> {
-        Box::pin(async move {
-            let mut removed = ItemStack::EMPTY.clone();
-            let mut guard = self.items[slot].lock().await;
-            std::mem::swap(&mut removed, &mut *guard);
-            self.mark_dirty();
-            removed
-        })
-    }
-
-    fn remove_stack_specific(&self, slot: usize, amount: u8) -> InventoryFuture<'_, ItemStack> {
-        Box::pin(async move {
-            let res = split_stack(&self.items, slot, amount).await;
-            self.mark_dirty();
-            res
-        })
-    }
-
-    fn set_stack(&self, slot: usize, stack: ItemStack) -> InventoryFuture<'_, ()> {
-        Box::pin(async move {
-            *self.items[slot].lock().await = stack;
-            self.mark_dirty();
-        })
-    }
-
-    fn mark_dirty(&self) {
-        self.dirty.store(true, Ordering::Relaxed);
-    }
-
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
 }
 
-impl Clearable for DropperBlockEntity {
-    fn clear(&self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
-        Box::pin(async move {
-            for slot in &self.items {
-                *slot.lock().await = ItemStack::EMPTY.clone();
-            }
-            self.mark_dirty();
-        })
-    }
-}
+impl_block_entity_for_dispenser!(DropperBlockEntity);
+impl_inventory_for_dispenser!(DropperBlockEntity);
+impl_clearable_for_dispenser!(DropperBlockEntity);
+impl_dispenser_helper_methods!(DropperBlockEntity);
@@ -24,9 +24,12 @@ use crate::block::entities::jukebox::JukeboxBlockEntity;
 use crate::block::entities::mob_spawner::MobSpawnerBlockEntity;
 use crate::block::entities::shulker_box::ShulkerBoxBlockEntity;
 use crate::block::entities::smoker::SmokerBlockEntity;
+use crate::block::entities::trial_spawner::TrialSpawnerBlockEntity;
+use crate::block::entities::vault::VaultBlockEntity;
 use crate::{
     BlockStateId, block::entities::chiseled_bookshelf::ChiseledBookshelfBlockEntity,
-    block::entities::dropper::DropperBlockEntity, inventory::Inventory, world::SimpleWorld,
+    block::entities::dispenser::DispenserBlockEntity, block::entities::dropper::DropperBlockEntity,
+    inventory::Inventory, world::SimpleWorld,
 };
 
 pub mod barrel;
@@ -38,6 +41,8 @@ pub mod chiseled_bookshelf;
 pub mod command_block;
 pub mod comparator;
 pub mod daylight_detector;
+pub mod dispenser;
+pub mod dispenser_like_block_entity;
 pub mod dropper;
 pub mod end_portal;
 pub mod ender_chest;
@@ -47,10 +52,14 @@ pub mod hopper;
 pub mod jukebox;
 pub mod mob_spawner;
 pub mod piston;
+pub mod player_data;
 pub mod shulker_box;
 pub mod sign;
 pub mod smoker;
 pub mod trapped_chest;
+pub mod trial_loot;
+pub mod trial_spawner;
+pub mod vault;
 
 //TODO: We need a mark_dirty for chests
 pub trait BlockEntity: Any + Send + Sync {
@@ -162,6 +171,9 @@ pub fn block_entity_from_nbt(nbt: &NbtCompound) -> Option<Arc<dyn BlockEntity>>
         MobSpawnerBlockEntity::ID => {
             Arc::new(block_entity_from_generic::<MobSpawnerBlockEntity>(nbt))
         }
+        DispenserBlockEntity::ID => {
+            Arc::new(block_entity_from_generic::<DispenserBlockEntity>(nbt))
+        }
         DropperBlockEntity::ID => Arc::new(block_entity_from_generic::<DropperBlockEntity>(nbt)),
         ShulkerBoxBlockEntity::ID => {
             Arc::new(block_entity_from_generic::<ShulkerBoxBlockEntity>(nbt))
@@ -182,10 +194,25 @@ pub fn block_entity_from_nbt(nbt: &NbtCompound) -> Option<Arc<dyn BlockEntity>>
         DaylightDetectorBlockEntity::ID => Arc::new(block_entity_from_generic::<
             DaylightDetectorBlockEntity,
         >(nbt)),
+        TrialSpawnerBlockEntity::ID => {
+            Arc::new(block_entity_from_generic::<TrialSpawnerBlockEntity>(nbt))
+        }
+        VaultBlockEntity::ID => Arc::new(block_entity_from_generic::<VaultBlockEntity>(nbt)),
         _ => return None,
     })
 }
 
+/// Block entities whose data only operators may set from an item, as they can run commands or
+/// spawn arbitrary entities.
+pub const OP_ONLY_ITEM_DATA: [&str; 6] = [
+    CommandBlockEntity::ID,
+    SignBlockEntity::ID,
+    "minecraft:hanging_sign",
+    "minecraft:lectern",
+    MobSpawnerBlockEntity::ID,
+    TrialSpawnerBlockEntity::ID,
+];
+
 #[must_use]
 pub fn has_block_block_entity(block: &Block) -> bool {
     BLOCK_ENTITY_TYPES.contains(&block.name)
@@ -0,0 +1,74 @@
+//! Per-player state for block entities that remember individual players, such as trial
+//! spawners and vaults.
+//!
+//! Players are stored by UUID using the same four int array encoding as entity NBT, so the
+//! data stays compatible with vanilla worlds.
+
+use std::collections::HashSet;
+
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+use uuid::Uuid;
+
+/// Encodes a UUID as a four element int array, most significant bits first.
+#[must_use]
+pub fn uuid_to_tag(uuid: Uuid) -> NbtTag {
+    let uuid = uuid.as_u128();
+    NbtTag::IntArray(vec![
+        (uuid >> 96) as i32,
+        (uuid >> 64) as i32,
+        (uuid >> 32) as i32,
+        uuid as i32,
+    ])
+}
+
+/// Decodes a UUID stored as a four element int array.
+#[must_use]
+pub fn uuid_from_tag(tag: &NbtTag) -> Option<Uuid> {
+    let [a, b, c, d] = tag.extract_int_array()? else {
+        return None;
+    };
+    Some(Uuid::from_u128(
+        u128::from(*a as u32) << 96
+            | u128::from(*b as u32) << 64
+            | u128::from(*c as u32) << 32
+            | u128::from(*d as u32),
+    ))
+}
+
+/// Writes a set of players as a list of UUIDs.
+pub fn write_players(nbt: &mut NbtCompound, key: &str, players: &HashSet<Uuid>) {
+    nbt.put_list(key, players.iter().map(|uuid| uuid_to_tag(*uuid)).collect());
+}
+
+/// Reads a set of players written by [`write_players`]. Missing or malformed entries are skipped.
+#[must_use]
+pub fn read_players(nbt: &NbtCompound, key: &str) -> HashSet<Uuid> {
+    nbt.get_list(key)
+        .map(|list| list.iter().filter_map(uuid_from_tag).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use pumpkin_nbt::compound::NbtCompound;
+    use uuid::Uuid;
+
+    use super::{read_players, uuid_from_tag, uuid_to_tag, write_players};
+
+    #[test]
+    fn uuid_round_trip() {
+        let uuid = Uuid::from_u128(0xFFFF_FFFF_8000_0000_0000_0001_7FFF_FFFF);
+        assert_eq!(uuid_from_tag(&uuid_to_tag(uuid)), Some(uuid));
+    }
+
+    #[test]
+    fn players_round_trip() {
+        let players: HashSet<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let mut nbt = NbtCompound::new();
+        write_players(&mut nbt, "players", &players);
+        assert_eq!(read_players(&nbt, "players"), players);
+        assert!(read_players(&nbt, "missing").is_empty());
+    }
+}
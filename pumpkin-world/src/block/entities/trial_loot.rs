@@ -0,0 +1,200 @@
+//! Reward tables ejected by trial spawners and vaults.
+//!
+//! Chest and spawner loot tables are not part of the generated data yet, so these are
+//! hand written approximations of the vanilla tables, looked up by their resource location.
+
+use pumpkin_data::item::Item;
+use rand::RngExt;
+
+use crate::item::ItemStack;
+
+pub const TRIAL_CHAMBER_KEY: &str = "minecraft:spawners/trial_chamber/key";
+pub const TRIAL_CHAMBER_CONSUMABLES: &str = "minecraft:spawners/trial_chamber/consumables";
+pub const OMINOUS_TRIAL_CHAMBER_KEY: &str = "minecraft:spawners/ominous/trial_chamber/key";
+pub const OMINOUS_TRIAL_CHAMBER_CONSUMABLES: &str =
+    "minecraft:spawners/ominous/trial_chamber/consumables";
+pub const TRIAL_CHAMBER_REWARD: &str = "minecraft:chests/trial_chambers/reward";
+pub const TRIAL_CHAMBER_REWARD_OMINOUS: &str = "minecraft:chests/trial_chambers/reward_ominous";
+
+pub struct RewardEntry {
+    pub item: &'static Item,
+    pub weight: u32,
+    pub min_count: u8,
+    pub max_count: u8,
+}
+
+pub struct RewardPool {
+    pub min_rolls: u8,
+    pub max_rolls: u8,
+    pub entries: &'static [RewardEntry],
+}
+
+pub struct RewardTable {
+    pub pools: &'static [RewardPool],
+}
+
+const fn entry(item: &'static Item, weight: u32, min_count: u8, max_count: u8) -> RewardEntry {
+    RewardEntry {
+        item,
+        weight,
+        min_count,
+        max_count,
+    }
+}
+
+static KEY: RewardTable = RewardTable {
+    pools: &[RewardPool {
+        min_rolls: 1,
+        max_rolls: 1,
+        entries: &[entry(&Item::TRIAL_KEY, 1, 1, 1)],
+    }],
+};
+
+static OMINOUS_KEY: RewardTable = RewardTable {
+    pools: &[RewardPool {
+        min_rolls: 1,
+        max_rolls: 1,
+        entries: &[entry(&Item::OMINOUS_TRIAL_KEY, 1, 1, 1)],
+    }],
+};
+
+static CONSUMABLES: RewardTable = RewardTable {
+    pools: &[RewardPool {
+        min_rolls: 1,
+        max_rolls: 1,
+        entries: &[
+            entry(&Item::COOKED_CHICKEN, 3, 1, 3),
+            entry(&Item::BREAD, 3, 1, 3),
+            entry(&Item::BAKED_POTATO, 2, 1, 3),
+        ],
+    }],
+};
+
+static OMINOUS_CONSUMABLES: RewardTable = RewardTable {
+    pools: &[RewardPool {
+        min_rolls: 1,
+        max_rolls: 1,
+        entries: &[
+            entry(&Item::COOKED_BEEF, 3, 2, 4),
+            entry(&Item::BAKED_POTATO, 2, 2, 4),
+            entry(&Item::GOLDEN_CARROT, 2, 1, 2),
+        ],
+    }],
+};
+
+static REWARD: RewardTable = RewardTable {
+    pools: &[
+        // Rare
+        RewardPool {
+            min_rolls: 1,
+            max_rolls: 1,
+            entries: &[
+                entry(&Item::EMERALD, 5, 2, 4),
+                entry(&Item::DIAMOND, 1, 1, 2),
+                entry(&Item::GOLDEN_APPLE, 2, 1, 1),
+            ],
+        },
+        // Common
+        RewardPool {
+            min_rolls: 1,
+            max_rolls: 3,
+            entries: &[
+                entry(&Item::ARROW, 4, 2, 8),
+                entry(&Item::EMERALD, 4, 2, 4),
+                entry(&Item::WIND_CHARGE, 3, 4, 12),
+                entry(&Item::HONEY_BOTTLE, 3, 1, 2),
+                entry(&Item::IRON_INGOT, 2, 1, 3),
+            ],
+        },
+        // Unique
+        RewardPool {
+            min_rolls: 0,
+            max_rolls: 1,
+            entries: &[
+                entry(&Item::GOLDEN_APPLE, 4, 1, 1),
+                entry(&Item::BOLT_ARMOR_TRIM_SMITHING_TEMPLATE, 3, 1, 1),
+                entry(&Item::MUSIC_DISC_PRECIPICE, 2, 1, 1),
+                entry(&Item::GUSTER_BANNER_PATTERN, 1, 1, 1),
+                entry(&Item::TRIDENT, 1, 1, 1),
+            ],
+        },
+    ],
+};
+
+static REWARD_OMINOUS: RewardTable = RewardTable {
+    pools: &[
+        // Rare
+        RewardPool {
+            min_rolls: 1,
+            max_rolls: 1,
+            entries: &[
+                entry(&Item::EMERALD_BLOCK, 5, 1, 1),
+                entry(&Item::IRON_BLOCK, 4, 1, 1),
+                entry(&Item::DIAMOND, 3, 2, 3),
+            ],
+        },
+        // Common
+        RewardPool {
+            min_rolls: 1,
+            max_rolls: 3,
+            entries: &[
+                entry(&Item::EMERALD, 5, 4, 10),
+                entry(&Item::WIND_CHARGE, 4, 8, 12),
+                entry(&Item::DIAMOND, 2, 1, 2),
+            ],
+        },
+        // Unique
+        RewardPool {
+            min_rolls: 0,
+            max_rolls: 1,
+            entries: &[
+                entry(&Item::ENCHANTED_GOLDEN_APPLE, 3, 1, 1),
+                entry(&Item::FLOW_ARMOR_TRIM_SMITHING_TEMPLATE, 3, 1, 1),
+                entry(&Item::FLOW_BANNER_PATTERN, 2, 1, 1),
+                entry(&Item::MUSIC_DISC_CREATOR, 1, 1, 1),
+                entry(&Item::HEAVY_CORE, 1, 1, 1),
+            ],
+        },
+    ],
+};
+
+impl RewardTable {
+    /// Looks up a reward table by its resource location.
+    #[must_use]
+    pub fn from_key(key: &str) -> Option<&'static Self> {
+        Some(match key {
+            TRIAL_CHAMBER_KEY => &KEY,
+            TRIAL_CHAMBER_CONSUMABLES => &CONSUMABLES,
+            OMINOUS_TRIAL_CHAMBER_KEY => &OMINOUS_KEY,
+            OMINOUS_TRIAL_CHAMBER_CONSUMABLES => &OMINOUS_CONSUMABLES,
+            TRIAL_CHAMBER_REWARD => &REWARD,
+            TRIAL_CHAMBER_REWARD_OMINOUS => &REWARD_OMINOUS,
+            _ => return None,
+        })
+    }
+
+    /// Rolls every pool of the table and returns the resulting stacks.
+    #[must_use]
+    pub fn roll(&self) -> Vec<ItemStack> {
+        let mut rng = rand::rng();
+        let mut stacks = Vec::new();
+        for pool in self.pools {
+            let total_weight: u32 = pool.entries.iter().map(|entry| entry.weight).sum();
+            if total_weight == 0 {
+                continue;
+            }
+            for _ in 0..rng.random_range(pool.min_rolls..=pool.max_rolls) {
+                let mut pick = rng.random_range(0..total_weight);
+                for entry in pool.entries {
+                    if pick < entry.weight {
+                        let count = rng.random_range(entry.min_count..=entry.max_count);
+                        stacks.push(ItemStack::new(count, entry.item));
+                        break;
+                    }
+                    pick -= entry.weight;
+                }
+            }
+        }
+        stacks
+    }
+}
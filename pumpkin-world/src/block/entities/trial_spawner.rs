@@ -0,0 +1,634 @@
+use std::{
+    collections::HashSet,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use crossbeam::atomic::AtomicCell;
+use pumpkin_data::{
+    Block,
+    block_properties::{BlockProperties, TrialSpawnerLikeProperties, TrialSpawnerState},
+    entity::EntityType,
+    sound::{Sound, SoundCategory},
+    world::WorldEvent,
+};
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_util::math::{
+    boundingbox::{BoundingBox, EntityDimensions},
+    position::BlockPos,
+    vector3::Vector3,
+};
+use rand::RngExt;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::{
+    block::entities::{
+        BlockEntity,
+        player_data::{read_players, write_players},
+        trial_loot::{
+            OMINOUS_TRIAL_CHAMBER_CONSUMABLES, OMINOUS_TRIAL_CHAMBER_KEY, RewardTable,
+            TRIAL_CHAMBER_CONSUMABLES, TRIAL_CHAMBER_KEY,
+        },
+    },
+    world::{BlockFlags, SimpleWorld},
+};
+
+/// Spawning behaviour of a trial spawner, separately configured for its normal and ominous
+/// variants.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrialSpawnerConfig {
+    pub spawn_range: i32,
+    pub total_mobs: f32,
+    pub simultaneous_mobs: f32,
+    pub total_mobs_added_per_player: f32,
+    pub simultaneous_mobs_added_per_player: f32,
+    pub ticks_between_spawn: i32,
+    /// Loot tables ejected as rewards, one of which is picked at random per spawner cycle.
+    pub loot_tables_to_eject: Vec<String>,
+}
+
+impl TrialSpawnerConfig {
+    #[must_use]
+    pub fn normal() -> Self {
+        Self {
+            spawn_range: 4,
+            total_mobs: 6.0,
+            simultaneous_mobs: 2.0,
+            total_mobs_added_per_player: 2.0,
+            simultaneous_mobs_added_per_player: 1.0,
+            ticks_between_spawn: 40,
+            loot_tables_to_eject: vec![
+                TRIAL_CHAMBER_CONSUMABLES.to_string(),
+                TRIAL_CHAMBER_KEY.to_string(),
+            ],
+        }
+    }
+
+    #[must_use]
+    pub fn ominous() -> Self {
+        Self {
+            loot_tables_to_eject: vec![
+                OMINOUS_TRIAL_CHAMBER_CONSUMABLES.to_string(),
+                OMINOUS_TRIAL_CHAMBER_KEY.to_string(),
+            ],
+            ..Self::normal()
+        }
+    }
+
+    /// The total number of mobs to spawn before the trial is complete.
+    #[must_use]
+    pub fn calculate_target_total_mobs(&self, additional_players: usize) -> i32 {
+        self.total_mobs_added_per_player
+            .mul_add(additional_players as f32, self.total_mobs)
+            .floor() as i32
+    }
+
+    /// The number of mobs that may be alive at the same time.
+    #[must_use]
+    pub fn calculate_target_simultaneous_mobs(&self, additional_players: usize) -> usize {
+        self.simultaneous_mobs_added_per_player
+            .mul_add(additional_players as f32, self.simultaneous_mobs)
+            .floor() as usize
+    }
+
+    fn from_nbt(nbt: &NbtCompound, default: Self) -> Self {
+        let loot_tables_to_eject = nbt
+            .get_list("loot_tables_to_eject")
+            .map(|list| {
+                list.iter()
+                    .filter_map(|entry| {
+                        entry
+                            .extract_compound()
+                            .and_then(|entry| entry.get_string("data"))
+                            .map(str::to_string)
+                    })
+                    .collect()
+            })
+            .unwrap_or(default.loot_tables_to_eject);
+        Self {
+            spawn_range: nbt.get_int("spawn_range").unwrap_or(default.spawn_range),
+            total_mobs: nbt.get_float("total_mobs").unwrap_or(default.total_mobs),
+            simultaneous_mobs: nbt
+                .get_float("simultaneous_mobs")
+                .unwrap_or(default.simultaneous_mobs),
+            total_mobs_added_per_player: nbt
+                .get_float("total_mobs_added_per_player")
+                .unwrap_or(default.total_mobs_added_per_player),
+            simultaneous_mobs_added_per_player: nbt
+                .get_float("simultaneous_mobs_added_per_player")
+                .unwrap_or(default.simultaneous_mobs_added_per_player),
+            ticks_between_spawn: nbt
+                .get_int("ticks_between_spawn")
+                .unwrap_or(default.ticks_between_spawn),
+            loot_tables_to_eject,
+        }
+    }
+
+    fn to_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+        nbt.put_int("spawn_range", self.spawn_range);
+        nbt.put_float("total_mobs", self.total_mobs);
+        nbt.put_float("simultaneous_mobs", self.simultaneous_mobs);
+        nbt.put_float(
+            "total_mobs_added_per_player",
+            self.total_mobs_added_per_player,
+        );
+        nbt.put_float(
+            "simultaneous_mobs_added_per_player",
+            self.simultaneous_mobs_added_per_player,
+        );
+        nbt.put_int("ticks_between_spawn", self.ticks_between_spawn);
+        nbt.put_list(
+            "loot_tables_to_eject",
+            self.loot_tables_to_eject
+                .iter()
+                .map(|table| {
+                    let mut entry = NbtCompound::new();
+                    entry.put_string("data", table.clone());
+                    entry.put_int("weight", 1);
+                    entry.into()
+                })
+                .collect(),
+        );
+        nbt
+    }
+}
+
+/// The state of the current trial, persisted between restarts.
+#[derive(Default)]
+pub struct TrialSpawnerData {
+    /// Players that took part in the current trial; each one is rewarded once it ends.
+    pub detected_players: HashSet<Uuid>,
+    /// Mobs spawned by this spawner that are still alive.
+    pub current_mobs: HashSet<Uuid>,
+    pub cooldown_ends_at: i64,
+    pub next_mob_spawns_at: i64,
+    pub total_mobs_spawned: i32,
+    pub ejecting_loot_table: Option<String>,
+}
+
+impl TrialSpawnerData {
+    fn additional_players(&self) -> usize {
+        self.detected_players.len().saturating_sub(1)
+    }
+
+    fn reset_statistics(&mut self) {
+        self.detected_players.clear();
+        self.current_mobs.clear();
+        self.total_mobs_spawned = 0;
+        self.next_mob_spawns_at = 0;
+    }
+}
+
+pub struct TrialSpawnerBlockEntity {
+    pub position: BlockPos,
+    pub required_player_range: i32,
+    pub target_cooldown_length: i32,
+    pub normal_config: TrialSpawnerConfig,
+    pub ominous_config: TrialSpawnerConfig,
+    pub entity_type: AtomicCell<Option<&'static EntityType>>,
+    pub data: Mutex<TrialSpawnerData>,
+    pub dirty: AtomicBool,
+}
+
+impl TrialSpawnerBlockEntity {
+    pub const ID: &'static str = "minecraft:trial_spawner";
+    pub const DEFAULT_REQUIRED_PLAYER_RANGE: i32 = 14;
+    pub const DEFAULT_TARGET_COOLDOWN_LENGTH: i32 = 36000;
+    /// Players are only looked for once per second.
+    const DETECTION_INTERVAL: i64 = 20;
+    const DELAY_BEFORE_EJECTING: i64 = 40;
+    const TIME_BETWEEN_EJECTIONS: i64 = 30;
+
+    #[must_use]
+    pub fn new(position: BlockPos) -> Self {
+        Self {
+            position,
+            required_player_range: Self::DEFAULT_REQUIRED_PLAYER_RANGE,
+            target_cooldown_length: Self::DEFAULT_TARGET_COOLDOWN_LENGTH,
+            normal_config: TrialSpawnerConfig::normal(),
+            ominous_config: TrialSpawnerConfig::ominous(),
+            entity_type: AtomicCell::new(None),
+            data: Mutex::new(TrialSpawnerData::default()),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    pub fn set_entity_type(&self, entity_type: &'static EntityType) {
+        self.entity_type.store(Some(entity_type));
+        self.dirty.store(true, Ordering::Relaxed);
+    }
+
+    const fn config(&self, ominous: bool) -> &TrialSpawnerConfig {
+        if ominous {
+            &self.ominous_config
+        } else {
+            &self.normal_config
+        }
+    }
+
+    fn center(&self) -> Vector3<f64> {
+        self.position.to_centered_f64()
+    }
+
+    /// Registers nearby players as participants. Returns whether a participant carries the
+    /// Trial Omen, which turns the spawner ominous.
+    async fn detect_players(
+        &self,
+        world: &Arc<dyn SimpleWorld>,
+        data: &mut TrialSpawnerData,
+        now: i64,
+    ) -> bool {
+        if now % Self::DETECTION_INTERVAL != 0 {
+            return false;
+        }
+        let players = world
+            .get_nearby_players(self.center(), f64::from(self.required_player_range))
+            .await;
+        let mut has_trial_omen = false;
+        for player in players {
+            has_trial_omen |= player.has_trial_omen;
+            if data.detected_players.insert(player.uuid) {
+                world
+                    .sync_world_event(WorldEvent::TrialSpawnerDetectsPlayer, self.position, 0)
+                    .await;
+                self.dirty.store(true, Ordering::Relaxed);
+            }
+        }
+        has_trial_omen
+    }
+
+    async fn try_spawn_mob(
+        &self,
+        world: &Arc<dyn SimpleWorld>,
+        entity_type: &'static EntityType,
+        config: &TrialSpawnerConfig,
+    ) -> Option<Uuid> {
+        let spawn_pos = {
+            let mut rng = rand::rng();
+            let pos = self.position.0;
+            let range = f64::from(config.spawn_range);
+            Vector3::new(
+                f64::from(pos.x) + (rng.random::<f64>() - rng.random::<f64>()) * range + 0.5,
+                f64::from(pos.y + rng.random_range(-1..=1)),
+                f64::from(pos.z) + (rng.random::<f64>() - rng.random::<f64>()) * range + 0.5,
+            )
+        };
+        let bounding_box = BoundingBox::new_from_pos(
+            spawn_pos.x,
+            spawn_pos.y,
+            spawn_pos.z,
+            &EntityDimensions {
+                width: entity_type.dimension[0],
+                height: entity_type.dimension[1],
+                eye_height: entity_type.eye_height,
+            },
+        );
+        if !world.is_space_empty(bounding_box).await {
+            return None;
+        }
+
+        let uuid = world.clone().spawn_from_type(entity_type, spawn_pos).await;
+        world
+            .sync_world_event(WorldEvent::TrialSpawnerSpawnsMob, self.position, 0)
+            .await;
+        world
+            .play_sound(
+                Sound::BlockTrialSpawnerSpawnMob,
+                SoundCategory::Blocks,
+                &self.center(),
+            )
+            .await;
+        Some(uuid)
+    }
+
+    async fn eject_reward(&self, world: &Arc<dyn SimpleWorld>, loot_table: &str) {
+        let Some(table) = RewardTable::from_key(loot_table) else {
+            return;
+        };
+        let position = self.position.up().to_centered_f64();
+        for stack in table.roll() {
+            let velocity = {
+                let mut rng = rand::rng();
+                Vector3::new(
+                    rng.random_range(-0.05..0.05),
+                    0.2,
+                    rng.random_range(-0.05..0.05),
+                )
+            };
+            world.clone().spawn_item(position, stack, velocity).await;
+        }
+        world
+            .sync_world_event(WorldEvent::TrialSpawnerEjectsItem, self.position, 0)
+            .await;
+        world
+            .play_sound(
+                Sound::BlockTrialSpawnerEjectItem,
+                SoundCategory::Blocks,
+                &self.center(),
+            )
+            .await;
+    }
+
+    /// Advances the trial state machine by one tick and returns the next state.
+    async fn tick_state(
+        &self,
+        world: &Arc<dyn SimpleWorld>,
+        props: &mut TrialSpawnerLikeProperties,
+        data: &mut TrialSpawnerData,
+        now: i64,
+    ) -> TrialSpawnerState {
+        let entity_type = self.entity_type.load();
+        let config = self.config(props.ominous);
+        match props.trial_spawner_state {
+            TrialSpawnerState::Inactive => {
+                if entity_type.is_some() {
+                    TrialSpawnerState::WaitingForPlayers
+                } else {
+                    TrialSpawnerState::Inactive
+                }
+            }
+            TrialSpawnerState::WaitingForPlayers => {
+                if entity_type.is_none() {
+                    return TrialSpawnerState::Inactive;
+                }
+                if self.detect_players(world, data, now).await && !props.ominous {
+                    self.become_ominous(world, props, data).await;
+                }
+                if data.detected_players.is_empty() {
+                    TrialSpawnerState::WaitingForPlayers
+                } else {
+                    TrialSpawnerState::Active
+                }
+            }
+            TrialSpawnerState::Active => {
+                let Some(entity_type) = entity_type else {
+                    return TrialSpawnerState::Inactive;
+                };
+                if self.detect_players(world, data, now).await && !props.ominous {
+                    self.become_ominous(world, props, data).await;
+                    return TrialSpawnerState::Active;
+                }
+                let additional_players = data.additional_players();
+
+                if data.total_mobs_spawned >= config.calculate_target_total_mobs(additional_players)
+                {
+                    if data.current_mobs.is_empty() {
+                        data.cooldown_ends_at = now + i64::from(self.target_cooldown_length);
+                        data.total_mobs_spawned = 0;
+                        data.next_mob_spawns_at = 0;
+                        return TrialSpawnerState::WaitingForRewardEjection;
+                    }
+                } else if now >= data.next_mob_spawns_at
+                    && data.current_mobs.len()
+                        < config.calculate_target_simultaneous_mobs(additional_players)
+                    && let Some(uuid) = self.try_spawn_mob(world, entity_type, config).await
+                {
+                    data.current_mobs.insert(uuid);
+                    data.total_mobs_spawned += 1;
+                    data.next_mob_spawns_at = now + i64::from(config.ticks_between_spawn);
+                }
+                TrialSpawnerState::Active
+            }
+            TrialSpawnerState::WaitingForRewardEjection => {
+                let trial_ended_at = data.cooldown_ends_at - i64::from(self.target_cooldown_length);
+                if now >= trial_ended_at + Self::DELAY_BEFORE_EJECTING {
+                    world
+                        .play_sound(
+                            Sound::BlockTrialSpawnerOpenShutter,
+                            SoundCategory::Blocks,
+                            &self.center(),
+                        )
+                        .await;
+                    TrialSpawnerState::EjectingReward
+                } else {
+                    TrialSpawnerState::WaitingForRewardEjection
+                }
+            }
+            TrialSpawnerState::EjectingReward => {
+                let trial_ended_at = data.cooldown_ends_at - i64::from(self.target_cooldown_length);
+                if (now - trial_ended_at) % Self::TIME_BETWEEN_EJECTIONS != 0 {
+                    return TrialSpawnerState::EjectingReward;
+                }
+                // Every participant gets one reward, ejected one after another
+                let Some(&player) = data.detected_players.iter().next() else {
+                    world
+                        .play_sound(
+                            Sound::BlockTrialSpawnerCloseShutter,
+                            SoundCategory::Blocks,
+                            &self.center(),
+                        )
+                        .await;
+                    data.ejecting_loot_table = None;
+                    return TrialSpawnerState::Cooldown;
+                };
+                if data.ejecting_loot_table.is_none() && !config.loot_tables_to_eject.is_empty() {
+                    let index = rand::random_range(0..config.loot_tables_to_eject.len());
+                    data.ejecting_loot_table = Some(config.loot_tables_to_eject[index].clone());
+                }
+                if let Some(loot_table) = &data.ejecting_loot_table {
+                    self.eject_reward(world, loot_table).await;
+                }
+                data.detected_players.remove(&player);
+                TrialSpawnerState::EjectingReward
+            }
+            TrialSpawnerState::Cooldown => {
+                if now >= data.cooldown_ends_at {
+                    props.ominous = false;
+                    data.cooldown_ends_at = 0;
+                    data.reset_statistics();
+                    TrialSpawnerState::WaitingForPlayers
+                } else {
+                    TrialSpawnerState::Cooldown
+                }
+            }
+        }
+    }
+
+    /// Switches to the ominous configuration and restarts the trial, as vanilla does when a
+    /// player with the Trial Omen is detected.
+    async fn become_ominous(
+        &self,
+        world: &Arc<dyn SimpleWorld>,
+        props: &mut TrialSpawnerLikeProperties,
+        data: &mut TrialSpawnerData,
+    ) {
+        props.ominous = true;
+        // The mobs of the normal trial vanish so that the ominous one starts from scratch
+        for uuid in data.current_mobs.drain() {
+            if let Some(pos) = world.discard_entity(uuid).await {
+                world
+                    .sync_world_event(WorldEvent::TrialSpawnerSpawnsMob, pos, 0)
+                    .await;
+            }
+        }
+        data.total_mobs_spawned = 0;
+        data.next_mob_spawns_at = 0;
+        world
+            .sync_world_event(WorldEvent::TrialSpawnerTurnsOminous, self.position, 0)
+            .await;
+        world
+            .play_sound(
+                Sound::BlockTrialSpawnerOminousActivate,
+                SoundCategory::Blocks,
+                &self.center(),
+            )
+            .await;
+    }
+}
+
+impl BlockEntity for TrialSpawnerBlockEntity {
+    fn resource_location(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn get_position(&self) -> BlockPos {
+        self.position
+    }
+
+    fn tick<'a>(
+        &'a self,
+        world: &'a Arc<dyn SimpleWorld>,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let state_id = world.get_block_state_id(&self.position).await;
+            if Block::from_state_id(state_id) != &Block::TRIAL_SPAWNER {
+                return;
+            }
+            let mut props =
+                TrialSpawnerLikeProperties::from_state_id(state_id, &Block::TRIAL_SPAWNER);
+            let now = world.get_world_age().await;
+            let mut data = self.data.lock().await;
+
+            // Forget mobs that died or despawned
+            let mut alive = HashSet::with_capacity(data.current_mobs.len());
+            for uuid in &data.current_mobs {
+                if world.is_entity_alive(*uuid).await {
+                    alive.insert(*uuid);
+                }
+            }
+            if alive.len() != data.current_mobs.len() {
+                data.current_mobs = alive;
+                self.dirty.store(true, Ordering::Relaxed);
+            }
+
+            let old_props = props;
+            let next = self.tick_state(world, &mut props, &mut data, now).await;
+            drop(data);
+            props.trial_spawner_state = next;
+            if props != old_props {
+                self.dirty.store(true, Ordering::Relaxed);
+                world
+                    .clone()
+                    .set_block_state(
+                        &self.position,
+                        props.to_state_id(&Block::TRIAL_SPAWNER),
+                        BlockFlags::NOTIFY_ALL,
+                    )
+                    .await;
+            }
+        })
+    }
+
+    fn from_nbt(nbt: &NbtCompound, position: BlockPos) -> Self
+    where
+        Self: Sized,
+    {
+        let entity_type = nbt
+            .get_compound("spawn_data")
+            .and_then(|spawn_data| spawn_data.get_compound("entity"))
+            .and_then(|entity| entity.get_string("id"))
+            .and_then(|id| EntityType::from_name(id.strip_prefix("minecraft:").unwrap_or(id)));
+        let data = TrialSpawnerData {
+            detected_players: read_players(nbt, "registered_players"),
+            current_mobs: read_players(nbt, "current_mobs"),
+            cooldown_ends_at: nbt.get_long("cooldown_ends_at").unwrap_or(0),
+            next_mob_spawns_at: nbt.get_long("next_mob_spawns_at").unwrap_or(0),
+            total_mobs_spawned: nbt.get_int("total_mobs_spawned").unwrap_or(0),
+            ejecting_loot_table: nbt.get_string("ejecting_loot_table").map(str::to_string),
+        };
+        Self {
+            position,
+            required_player_range: nbt
+                .get_int("required_player_range")
+                .unwrap_or(Self::DEFAULT_REQUIRED_PLAYER_RANGE),
+            target_cooldown_length: nbt
+                .get_int("target_cooldown_length")
+                .unwrap_or(Self::DEFAULT_TARGET_COOLDOWN_LENGTH),
+            normal_config: nbt
+                .get_compound("normal_config")
+                .map_or_else(TrialSpawnerConfig::normal, |config| {
+                    TrialSpawnerConfig::from_nbt(config, TrialSpawnerConfig::normal())
+                }),
+            ominous_config: nbt
+                .get_compound("ominous_config")
+                .map_or_else(TrialSpawnerConfig::ominous, |config| {
+                    TrialSpawnerConfig::from_nbt(config, TrialSpawnerConfig::ominous())
+                }),
+            entity_type: AtomicCell::new(entity_type),
+            data: Mutex::new(data),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    fn write_nbt<'a>(
+        &'a self,
+        nbt: &'a mut NbtCompound,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            nbt.put_int("required_player_range", self.required_player_range);
+            nbt.put_int("target_cooldown_length", self.target_cooldown_length);
+            nbt.put_compound("normal_config", self.normal_config.to_nbt());
+            nbt.put_compound("ominous_config", self.ominous_config.to_nbt());
+            if let Some(spawn_data) = self.spawn_data_nbt() {
+                nbt.put_compound("spawn_data", spawn_data);
+            }
+
+            let data = self.data.lock().await;
+            write_players(nbt, "registered_players", &data.detected_players);
+            write_players(nbt, "current_mobs", &data.current_mobs);
+            nbt.put_long("cooldown_ends_at", data.cooldown_ends_at);
+            nbt.put_long("next_mob_spawns_at", data.next_mob_spawns_at);
+            nbt.put_int("total_mobs_spawned", data.total_mobs_spawned);
+            if let Some(loot_table) = &data.ejecting_loot_table {
+                nbt.put_string("ejecting_loot_table", loot_table.clone());
+            }
+        })
+    }
+
+    fn chunk_data_nbt(&self) -> Option<NbtCompound> {
+        // The client only needs the mob to render inside the cage
+        let mut nbt = NbtCompound::new();
+        if let Some(spawn_data) = self.spawn_data_nbt() {
+            nbt.put_compound("spawn_data", spawn_data);
+        }
+        Some(nbt)
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty.load(Ordering::Relaxed)
+    }
+
+    fn clear_dirty(&self) {
+        self.dirty.store(false, Ordering::Relaxed);
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl TrialSpawnerBlockEntity {
+    fn spawn_data_nbt(&self) -> Option<NbtCompound> {
+        let entity_type = self.entity_type.load()?;
+        let mut entity = NbtCompound::new();
+        entity.put_string("id", format!("minecraft:{}", entity_type.resource_name));
+        let mut spawn_data = NbtCompound::new();
+        spawn_data.put_compound("entity", entity);
+        Some(spawn_data)
+    }
+}
@@ -0,0 +1,458 @@
+use std::{
+    collections::HashSet,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use pumpkin_data::{
+    Block,
+    block_properties::{BlockProperties, VaultLikeProperties, VaultState},
+    item::Item,
+    sound::{Sound, SoundCategory},
+    world::WorldEvent,
+};
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+use rand::RngExt;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::{
+    block::entities::{
+        BlockEntity,
+        player_data::{read_players, write_players},
+        trial_loot::{RewardTable, TRIAL_CHAMBER_REWARD, TRIAL_CHAMBER_REWARD_OMINOUS},
+    },
+    item::ItemStack,
+    world::{BlockFlags, SimpleWorld},
+};
+
+/// What a vault accepts and what it hands out.
+#[derive(Clone)]
+pub struct VaultConfig {
+    pub loot_table: String,
+    /// Players closer than this activate an inactive vault.
+    pub activation_range: f64,
+    /// Players further away than this no longer keep the vault active.
+    pub deactivation_range: f64,
+    pub key_item: &'static Item,
+}
+
+impl VaultConfig {
+    #[must_use]
+    pub fn normal() -> Self {
+        Self {
+            loot_table: TRIAL_CHAMBER_REWARD.to_string(),
+            activation_range: 4.0,
+            deactivation_range: 4.5,
+            key_item: &Item::TRIAL_KEY,
+        }
+    }
+
+    #[must_use]
+    pub fn ominous() -> Self {
+        Self {
+            loot_table: TRIAL_CHAMBER_REWARD_OMINOUS.to_string(),
+            key_item: &Item::OMINOUS_TRIAL_KEY,
+            ..Self::normal()
+        }
+    }
+
+    fn from_nbt(nbt: &NbtCompound) -> Self {
+        let default = Self::normal();
+        Self {
+            loot_table: nbt
+                .get_string("loot_table")
+                .map_or(default.loot_table, str::to_string),
+            activation_range: nbt
+                .get_double("activation_range")
+                .unwrap_or(default.activation_range),
+            deactivation_range: nbt
+                .get_double("deactivation_range")
+                .unwrap_or(default.deactivation_range),
+            key_item: nbt
+                .get_compound("key_item")
+                .and_then(ItemStack::read_item_stack)
+                .map_or(default.key_item, |stack| stack.item),
+        }
+    }
+
+    fn to_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+        nbt.put_string("loot_table", self.loot_table.clone());
+        nbt.put_double("activation_range", self.activation_range);
+        nbt.put_double("deactivation_range", self.deactivation_range);
+        let mut key_item = NbtCompound::new();
+        ItemStack::new(1, self.key_item).write_item_stack(&mut key_item);
+        nbt.put_compound("key_item", key_item);
+        nbt
+    }
+}
+
+/// The outcome of a player trying to unlock a vault.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VaultUnlockResult {
+    /// The vault is not active, so the key was not used at all.
+    NotActive,
+    /// The held item is not this vault's key.
+    InvalidKey,
+    /// The player has already been rewarded by this vault.
+    AlreadyRewarded,
+    /// The vault accepted the key and is ejecting its reward.
+    Unlocked,
+}
+
+/// Per-player and ejection state, persisted between restarts.
+#[derive(Default)]
+pub struct VaultServerData {
+    /// Players that already unlocked this vault. Each player can only be rewarded once.
+    pub rewarded_players: HashSet<Uuid>,
+    /// Players currently close enough to keep the vault active.
+    pub connected_players: HashSet<Uuid>,
+    pub state_updating_resumes_at: i64,
+    pub items_to_eject: Vec<ItemStack>,
+    pub total_ejections_needed: usize,
+}
+
+pub struct VaultBlockEntity {
+    pub position: BlockPos,
+    pub config: VaultConfig,
+    pub data: Mutex<VaultServerData>,
+    pub dirty: AtomicBool,
+}
+
+impl VaultBlockEntity {
+    pub const ID: &'static str = "minecraft:vault";
+    const UNLOCKING_DELAY: i64 = 14;
+    const TIME_BETWEEN_EJECTIONS: i64 = 20;
+
+    #[must_use]
+    pub fn new(position: BlockPos, ominous: bool) -> Self {
+        Self {
+            position,
+            config: if ominous {
+                VaultConfig::ominous()
+            } else {
+                VaultConfig::normal()
+            },
+            data: Mutex::new(VaultServerData::default()),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    fn center(&self) -> Vector3<f64> {
+        self.position.to_centered_f64()
+    }
+
+    /// Tries to unlock the vault with `key` for the given player.
+    ///
+    /// On success the vault starts ejecting its reward and the caller should consume the key.
+    /// Failures play the matching rejection sound.
+    pub async fn try_unlock(
+        &self,
+        world: &Arc<dyn SimpleWorld>,
+        player: Uuid,
+        key: &ItemStack,
+    ) -> VaultUnlockResult {
+        let state_id = world.get_block_state_id(&self.position).await;
+        let mut props = VaultLikeProperties::from_state_id(state_id, &Block::VAULT);
+        if props.vault_state != VaultState::Active {
+            return VaultUnlockResult::NotActive;
+        }
+
+        let mut data = self.data.lock().await;
+        let result = if key.item.id != self.config.key_item.id {
+            VaultUnlockResult::InvalidKey
+        } else if data.rewarded_players.contains(&player) {
+            VaultUnlockResult::AlreadyRewarded
+        } else {
+            VaultUnlockResult::Unlocked
+        };
+        let sound = match result {
+            VaultUnlockResult::InvalidKey => Sound::BlockVaultInsertItemFail,
+            VaultUnlockResult::AlreadyRewarded => Sound::BlockVaultRejectRewardedPlayer,
+            _ => Sound::BlockVaultInsertItem,
+        };
+        world
+            .play_sound(sound, SoundCategory::Blocks, &self.center())
+            .await;
+        if result != VaultUnlockResult::Unlocked {
+            return result;
+        }
+
+        let items = RewardTable::from_key(&self.config.loot_table)
+            .map(RewardTable::roll)
+            .unwrap_or_default();
+        data.total_ejections_needed = items.len();
+        data.items_to_eject = items;
+        data.rewarded_players.insert(player);
+        data.connected_players.remove(&player);
+        data.state_updating_resumes_at = world.get_world_age().await + Self::UNLOCKING_DELAY;
+        drop(data);
+        self.dirty.store(true, Ordering::Relaxed);
+
+        props.vault_state = VaultState::Unlocking;
+        world
+            .clone()
+            .set_block_state(
+                &self.position,
+                props.to_state_id(&Block::VAULT),
+                BlockFlags::NOTIFY_ALL,
+            )
+            .await;
+        result
+    }
+
+    /// Updates the players connected to the vault and returns whether any are left.
+    ///
+    /// Players that were already rewarded never count, so a vault only lights up for players
+    /// that can still unlock it.
+    async fn update_connected_players(
+        &self,
+        world: &Arc<dyn SimpleWorld>,
+        data: &mut VaultServerData,
+        range: f64,
+    ) -> bool {
+        let connected: HashSet<Uuid> = world
+            .get_nearby_players(self.center(), range)
+            .await
+            .into_iter()
+            .map(|player| player.uuid)
+            .filter(|uuid| !data.rewarded_players.contains(uuid))
+            .collect();
+        data.connected_players = connected;
+        !data.connected_players.is_empty()
+    }
+
+    async fn eject_item(
+        &self,
+        world: &Arc<dyn SimpleWorld>,
+        data: &VaultServerData,
+        stack: ItemStack,
+    ) {
+        let position = self.position.to_f64().add_raw(0.5, 1.2, 0.5);
+        let velocity = {
+            let mut rng = rand::rng();
+            Vector3::new(
+                rng.random_range(-0.03..0.03),
+                rng.random_range(0.2..0.3),
+                rng.random_range(-0.03..0.03),
+            )
+        };
+        world.clone().spawn_item(position, stack, velocity).await;
+        world
+            .sync_world_event(WorldEvent::VaultEjectsItem, self.position, 0)
+            .await;
+
+        let progress = if data.total_ejections_needed == 0 {
+            1.0
+        } else {
+            1.0 - data.items_to_eject.len() as f32 / data.total_ejections_needed as f32
+        };
+        world
+            .play_sound_fine(
+                Sound::BlockVaultEjectItem,
+                SoundCategory::Blocks,
+                &self.center(),
+                1.0,
+                0.4f32.mul_add(progress, 0.8),
+            )
+            .await;
+    }
+
+    async fn tick_state(
+        &self,
+        world: &Arc<dyn SimpleWorld>,
+        state: VaultState,
+        data: &mut VaultServerData,
+        now: i64,
+    ) -> VaultState {
+        match state {
+            VaultState::Inactive | VaultState::Active => {
+                let range = if state == VaultState::Inactive {
+                    self.config.activation_range
+                } else {
+                    self.config.deactivation_range
+                };
+                if self.update_connected_players(world, data, range).await {
+                    VaultState::Active
+                } else {
+                    VaultState::Inactive
+                }
+            }
+            VaultState::Unlocking => {
+                data.state_updating_resumes_at = now + Self::TIME_BETWEEN_EJECTIONS;
+                VaultState::Ejecting
+            }
+            VaultState::Ejecting => {
+                if data.items_to_eject.is_empty() {
+                    data.total_ejections_needed = 0;
+                    let range = self.config.activation_range;
+                    return if self.update_connected_players(world, data, range).await {
+                        VaultState::Active
+                    } else {
+                        VaultState::Inactive
+                    };
+                }
+                let stack = data.items_to_eject.remove(0);
+                self.eject_item(world, data, stack).await;
+                data.state_updating_resumes_at = now + Self::TIME_BETWEEN_EJECTIONS;
+                VaultState::Ejecting
+            }
+        }
+    }
+}
+
+impl BlockEntity for VaultBlockEntity {
+    fn resource_location(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn get_position(&self) -> BlockPos {
+        self.position
+    }
+
+    fn tick<'a>(
+        &'a self,
+        world: &'a Arc<dyn SimpleWorld>,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let state_id = world.get_block_state_id(&self.position).await;
+            if Block::from_state_id(state_id) != &Block::VAULT {
+                return;
+            }
+            let now = world.get_world_age().await;
+            let mut data = self.data.lock().await;
+            if now < data.state_updating_resumes_at {
+                return;
+            }
+
+            let mut props = VaultLikeProperties::from_state_id(state_id, &Block::VAULT);
+            let next = self
+                .tick_state(world, props.vault_state, &mut data, now)
+                .await;
+            drop(data);
+            if next == props.vault_state {
+                return;
+            }
+
+            let sound = match (props.vault_state, next) {
+                (VaultState::Inactive, VaultState::Active) => {
+                    Some((Sound::BlockVaultActivate, WorldEvent::VaultActivates))
+                }
+                (_, VaultState::Inactive) => {
+                    Some((Sound::BlockVaultDeactivate, WorldEvent::VaultDeactivates))
+                }
+                _ => None,
+            };
+            if let Some((sound, event)) = sound {
+                world
+                    .play_sound(sound, SoundCategory::Blocks, &self.center())
+                    .await;
+                world.sync_world_event(event, self.position, 0).await;
+            }
+
+            props.vault_state = next;
+            self.dirty.store(true, Ordering::Relaxed);
+            world
+                .clone()
+                .set_block_state(
+                    &self.position,
+                    props.to_state_id(&Block::VAULT),
+                    BlockFlags::NOTIFY_ALL,
+                )
+                .await;
+        })
+    }
+
+    fn from_nbt(nbt: &NbtCompound, position: BlockPos) -> Self
+    where
+        Self: Sized,
+    {
+        let config = nbt
+            .get_compound("config")
+            .map_or_else(VaultConfig::normal, VaultConfig::from_nbt);
+        let mut data = VaultServerData::default();
+        if let Some(server_data) = nbt.get_compound("server_data") {
+            data.rewarded_players = read_players(server_data, "rewarded_players");
+            data.state_updating_resumes_at = server_data
+                .get_long("state_updating_resumes_at")
+                .unwrap_or(0);
+            data.items_to_eject = server_data
+                .get_list("items_to_eject")
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(NbtTag::extract_compound)
+                        .filter_map(ItemStack::read_item_stack)
+                        .collect()
+                })
+                .unwrap_or_default();
+            data.total_ejections_needed = server_data
+                .get_int("total_ejections_needed")
+                .map_or(0, |total| total.max(0) as usize);
+        }
+        if let Some(shared_data) = nbt.get_compound("shared_data") {
+            data.connected_players = read_players(shared_data, "connected_players");
+        }
+        Self {
+            position,
+            config,
+            data: Mutex::new(data),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    fn write_nbt<'a>(
+        &'a self,
+        nbt: &'a mut NbtCompound,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            nbt.put_compound("config", self.config.to_nbt());
+
+            let data = self.data.lock().await;
+            let mut server_data = NbtCompound::new();
+            write_players(&mut server_data, "rewarded_players", &data.rewarded_players);
+            server_data.put_long("state_updating_resumes_at", data.state_updating_resumes_at);
+            server_data.put_list(
+                "items_to_eject",
+                data.items_to_eject
+                    .iter()
+                    .map(|stack| {
+                        let mut item = NbtCompound::new();
+                        stack.write_item_stack(&mut item);
+                        item.into()
+                    })
+                    .collect(),
+            );
+            server_data.put_int(
+                "total_ejections_needed",
+                i32::try_from(data.total_ejections_needed).unwrap_or(i32::MAX),
+            );
+            nbt.put_compound("server_data", server_data);
+
+            let mut shared_data = NbtCompound::new();
+            write_players(
+                &mut shared_data,
+                "connected_players",
+                &data.connected_players,
+            );
+            nbt.put_compound("shared_data", shared_data);
+        })
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty.load(Ordering::Relaxed)
+    }
+
+    fn clear_dirty(&self) {
+        self.dirty.store(false, Ordering::Relaxed);
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
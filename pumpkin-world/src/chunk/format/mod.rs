@@ -4,7 +4,7 @@ use std::{
     pin::Pin,
     sync::{
         RwLock,
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicI64, Ordering},
     },
 };
 
@@ -188,6 +188,7 @@ impl ChunkData {
             light_engine: std::sync::Mutex::new(light_engine),
             light_populated: AtomicBool::new(chunk_data.light_correct),
             status: chunk_data.status,
+            inhabited_time: AtomicI64::new(chunk_data.inhabited_time),
         })
     }
 
@@ -261,6 +262,7 @@ impl ChunkData {
             fluid_ticks: self.fluid_ticks.to_vec(),
             block_entities: block_entities_nbt,
             light_correct: is_light_correct,
+            inhabited_time: self.inhabited_time.load(Ordering::Relaxed),
         };
 
         let mut result = Vec::new();
@@ -514,6 +516,8 @@ struct ChunkNbt {
     block_entities: Vec<NbtCompound>,
     #[serde(rename = "isLightOn", default)]
     light_correct: bool,
+    #[serde(default)]
+    inhabited_time: i64,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -404,4 +404,26 @@ where
             // let _ = join_all(tasks);
         })
     }
+
+    fn flush(&self) -> BoxFuture<'_, Result<(), ChunkWritingError>> {
+        Box::pin(async move {
+            let serializers: Vec<_> = self
+                .file_locks
+                .read()
+                .await
+                .iter()
+                .filter_map(|(path, loader)| Some((path.clone(), loader.internal.get()?.clone())))
+                .collect();
+            for (path, serializer) in serializers {
+                debug!("Flushing file {}", path.display());
+                serializer
+                    .read()
+                    .await
+                    .write(&path)
+                    .await
+                    .map_err(|err| ChunkWritingError::IoError(err.kind()))?;
+            }
+            Ok(())
+        })
+    }
 }
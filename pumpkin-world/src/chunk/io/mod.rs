@@ -87,6 +87,10 @@ where
 
     /// Ensure that all ongoing operations are finished
     fn block_and_await_ongoing_tasks(&self) -> BoxFuture<'_, ()>;
+
+    /// Writes every cached file to disk, including the ones of watched chunks that
+    /// [`FileIO::save_chunks`] only updates in memory
+    fn flush(&self) -> BoxFuture<'_, Result<(), ChunkWritingError>>;
 }
 
 /// Trait to serialize and deserialize the chunk data to and from bytes.
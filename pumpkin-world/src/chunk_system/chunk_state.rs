@@ -5,7 +5,7 @@ use pumpkin_config::lighting::LightingEngineConfig;
 use pumpkin_data::dimension::Dimension;
 use rustc_hash::FxHashMap;
 use std::sync::Arc;
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, AtomicI64};
 
 use crate::ProtoChunk;
 use crate::level::SyncChunk;
@@ -198,6 +198,7 @@ impl Chunk {
                 block_entities: Default::default(),
                 light_engine: Mutex::new(ChunkLight::default()),
                 light_populated: AtomicBool::new(false),
+                inhabited_time: AtomicI64::new(0),
                 status: ChunkStatus::Empty,
                 dirty: AtomicBool::new(false),
             })),
@@ -287,6 +288,7 @@ impl Chunk {
             fluid_ticks: Default::default(),
             block_entities: Mutex::new(block_entities),
             status: proto_chunk.stage.into(),
+            inhabited_time: AtomicI64::new(0),
         };
 
         chunk.heightmap = Mutex::new(chunk.calculate_heightmap());
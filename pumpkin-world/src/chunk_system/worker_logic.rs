@@ -193,6 +193,7 @@ pub async fn io_write_work(recv: AsyncRx<Vec<(ChunkPos, Chunk)>>, level: Arc<Lev
             }
         }
         let pos = vec.iter().map(|(pos, _)| *pos).collect_vec();
+        let save_guard = level.save_gate.read().await;
         if let Err(e) = level
             .chunk_saver
             .save_chunks(&level.level_folder, vec)
@@ -200,6 +201,7 @@ pub async fn io_write_work(recv: AsyncRx<Vec<(ChunkPos, Chunk)>>, level: Arc<Lev
         {
             error!("Failed to save chunks: {:?}", e);
         }
+        drop(save_guard);
 
         for i in pos {
             let mut data = lock.0.lock().unwrap();
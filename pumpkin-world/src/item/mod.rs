@@ -1,8 +1,9 @@
 use pumpkin_data::data_component::DataComponent;
 use pumpkin_data::data_component::DataComponent::Enchantments;
 use pumpkin_data::data_component_impl::{
-    BlocksAttacksImpl, ConsumableImpl, DamageImpl, DataComponentImpl, EnchantmentsImpl, IDSet,
-    MaxDamageImpl, MaxStackSizeImpl, ToolImpl, UnbreakableImpl, get, get_mut, read_data,
+    BlocksAttacksImpl, ConsumableImpl, ContainerImpl, ContainerSlot, CustomNameImpl, DamageImpl,
+    DataComponentImpl, EnchantmentsImpl, IDSet, LoreImpl, MaxDamageImpl, MaxStackSizeImpl,
+    ToolImpl, UnbreakableImpl, get, get_mut, patches_equal, read_item_nbt, write_item_nbt,
 };
 use pumpkin_data::item::Item;
 use pumpkin_data::recipes::RecipeResultStruct;
@@ -10,6 +11,7 @@ use pumpkin_data::tag::Taggable;
 use pumpkin_data::{Block, Enchantment};
 use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_util::GameMode;
+use pumpkin_util::text::TextComponent;
 use std::borrow::Cow;
 use std::cmp::{max, min};
 
@@ -89,6 +91,23 @@ impl ItemStack {
         None
     }
 
+    /// Sets a component in the patch, replacing the previous value or removal.
+    pub fn set_data_component<T: DataComponentImpl + 'static>(&mut self, component: T) {
+        let to_set_id = T::get_enum();
+        let component = Some(component.to_dyn());
+        if let Some((_, data)) = self.patch.iter_mut().find(|(id, _)| *id == to_set_id) {
+            *data = component;
+        } else {
+            self.patch.push((to_set_id, component));
+        }
+    }
+
+    /// Drops a component from the patch so the item's default applies again.
+    pub fn reset_data_component<T: DataComponentImpl + 'static>(&mut self) {
+        let to_reset_id = T::get_enum();
+        self.patch.retain(|(id, _)| *id != to_reset_id);
+    }
+
     pub const EMPTY: &'static Self = &Self {
         item_count: 0,
         item: &Item::AIR,
@@ -131,6 +150,64 @@ impl ItemStack {
         self.get_data_component::<UnbreakableImpl>().is_some()
     }
 
+    #[must_use]
+    pub fn get_custom_name(&self) -> Option<&TextComponent> {
+        self.get_data_component::<CustomNameImpl>()
+            .map(|value| &value.name)
+    }
+
+    pub fn set_custom_name(&mut self, name: Option<TextComponent>) {
+        match name {
+            Some(name) => self.set_data_component(CustomNameImpl { name }),
+            None => self.reset_data_component::<CustomNameImpl>(),
+        }
+    }
+
+    #[must_use]
+    pub fn get_lore(&self) -> &[TextComponent] {
+        self.get_data_component::<LoreImpl>()
+            .map_or(&[], |value| value.lines.as_slice())
+    }
+
+    /// Returns the stored contents for container items like shulker boxes, indexed by slot.
+    #[must_use]
+    pub fn get_container_items(&self) -> Vec<Self> {
+        let Some(container) = self.get_data_component::<ContainerImpl>() else {
+            return Vec::new();
+        };
+        let len = container
+            .items
+            .last()
+            .map_or(0, |item| usize::from(item.slot) + 1);
+        let mut items = vec![Self::EMPTY.clone(); len];
+        for item in &container.items {
+            items[usize::from(item.slot)] =
+                Self::new_with_component(item.count, item.item, item.patch.clone());
+        }
+        items
+    }
+
+    /// Stores the contents of a container, or removes them when every stack is empty.
+    pub fn set_container_items(&mut self, items: &[Self]) {
+        let items: Vec<ContainerSlot> = items
+            .iter()
+            .take(ContainerImpl::MAX_SLOTS)
+            .enumerate()
+            .filter(|(_, stack)| !stack.is_empty())
+            .map(|(slot, stack)| ContainerSlot {
+                slot: slot as u8,
+                item: stack.item,
+                count: stack.item_count,
+                patch: stack.patch.clone(),
+            })
+            .collect();
+        if items.is_empty() {
+            self.reset_data_component::<ContainerImpl>();
+        } else {
+            self.set_data_component(ContainerImpl { items });
+        }
+    }
+
     pub fn set_damage(&mut self, damage: i32) {
         let damage = damage.max(0);
         if damage == 0 {
@@ -328,31 +405,7 @@ impl ItemStack {
 
     #[must_use]
     pub fn are_items_and_components_equal(&self, other: &Self) -> bool {
-        if self.item != other.item || self.patch.len() != other.patch.len() {
-            return false;
-        }
-        for (id, data) in &self.patch {
-            let mut not_found = true;
-            'out: for (other_id, other_data) in &other.patch {
-                if id == other_id {
-                    if let (Some(data), Some(other_data)) = (data, other_data) {
-                        if !data.equal(other_data.as_ref()) {
-                            return false;
-                        }
-                        not_found = false;
-                        break 'out;
-                    } else if data.is_none() && other_data.is_none() {
-                        not_found = false;
-                        break 'out;
-                    }
-                    return false;
-                }
-            }
-            if not_found {
-                return false;
-            }
-        }
-        true
+        self.item == other.item && patches_equal(&self.patch, &other.patch)
     }
 
     #[must_use]
@@ -419,57 +472,13 @@ impl ItemStack {
     }
 
     pub fn write_item_stack(&self, compound: &mut NbtCompound) {
-        // Minecraft 1.21.4 uses "id" as string with namespaced ID (minecraft:diamond_sword)
-        compound.put_string("id", format!("minecraft:{}", self.item.registry_key));
-        compound.put_int("count", self.item_count as i32);
-
-        // Create a tag compound for additional data
-        let mut tag = NbtCompound::new();
-
-        for (id, data) in &self.patch {
-            if let Some(data) = data {
-                tag.put(id.to_name(), data.write_data());
-            } else {
-                let name = '!'.to_string() + id.to_name();
-                tag.put(name.as_str(), NbtCompound::new());
-            }
-        }
-
-        // Store custom data like enchantments, display name, etc. would go here
-        compound.put_compound("components", tag);
+        write_item_nbt(self.item, self.item_count, &self.patch, compound);
     }
 
     #[must_use]
     pub fn read_item_stack(compound: &NbtCompound) -> Option<Self> {
-        // Get ID, which is a string like "minecraft:diamond_sword"
-        let full_id = compound.get_string("id")?;
-
-        // Remove the "minecraft:" prefix if present
-        let registry_key = full_id.strip_prefix("minecraft:").unwrap_or(full_id);
-
-        // Try to get item by registry key
-        let item = Item::from_registry_key(registry_key)?;
-
-        let count = compound.get_int("count")? as u8;
-
-        // Create the item stack
-        let mut item_stack = Self::new(count, item);
-
-        // Process any additional data in the components compound
-        if let Some(tag) = compound.get_compound("components") {
-            for (name, data) in &tag.child_tags {
-                if let Some(name) = name.strip_prefix("!") {
-                    item_stack
-                        .patch
-                        .push((DataComponent::try_from_name(name)?, None));
-                } else {
-                    let id = DataComponent::try_from_name(name)?;
-                    item_stack.patch.push((id, Some(read_data(id, data)?)));
-                }
-            }
-        }
-
-        Some(item_stack)
+        let (item, count, patch) = read_item_nbt(compound)?;
+        Some(Self::new_with_component(count, item, patch))
     }
 }
 
@@ -483,3 +492,61 @@ impl From<&RecipeResultStruct> for ItemStack {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_data::data_component_impl::BucketEntityDataImpl;
+    use pumpkin_data::item::Item;
+    use pumpkin_nbt::compound::NbtCompound;
+    use pumpkin_util::text::TextComponent;
+
+    use super::ItemStack;
+
+    #[test]
+    fn item_stack_nbt_round_trip() {
+        let mut sword = ItemStack::new(1, &Item::DIAMOND_SWORD);
+        sword.set_custom_name(Some(TextComponent::text("Excalibur").bold()));
+        sword.set_damage(12);
+
+        let mut shulker = ItemStack::new(1, &Item::SHULKER_BOX);
+        shulker.set_container_items(&[
+            ItemStack::EMPTY.clone(),
+            sword.clone(),
+            ItemStack::new(64, &Item::STONE),
+        ]);
+
+        let mut compound = NbtCompound::new();
+        shulker.write_item_stack(&mut compound);
+        let read = ItemStack::read_item_stack(&compound).expect("shulker box should read back");
+        assert!(read.are_equal(&shulker));
+
+        let contents = read.get_container_items();
+        assert_eq!(contents.len(), 3);
+        assert!(contents[0].is_empty());
+        assert!(contents[1].are_equal(&sword));
+        assert_eq!(
+            contents[1].get_custom_name(),
+            Some(&TextComponent::text("Excalibur").bold())
+        );
+        assert_eq!(contents[2].item_count, 64);
+    }
+
+    #[test]
+    fn bucket_entity_data_round_trip() {
+        let mut data = NbtCompound::new();
+        data.put_float("Health", 2.5);
+        data.put_int("Variant", 3);
+        let mut bucket = ItemStack::new(1, &Item::AXOLOTL_BUCKET);
+        bucket.set_data_component(BucketEntityDataImpl { data: data.clone() });
+
+        let mut compound = NbtCompound::new();
+        bucket.write_item_stack(&mut compound);
+        let read = ItemStack::read_item_stack(&compound).expect("bucket should read back");
+        assert!(read.are_equal(&bucket));
+        assert_eq!(
+            read.get_data_component::<BucketEntityDataImpl>()
+                .map(|bucket_data| &bucket_data.data),
+            Some(&data)
+        );
+    }
+}
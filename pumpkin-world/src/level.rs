@@ -39,6 +39,7 @@ use tracing::{debug, error, info, trace, warn};
 use tokio::{
     select,
     sync::{
+        RwLock, RwLockWriteGuard,
         mpsc::{self, UnboundedReceiver},
         oneshot,
     },
@@ -77,6 +78,8 @@ pub struct Level {
 
     pub chunk_saver: Arc<dyn FileIO<Data = SyncChunk>>,
     entity_saver: Arc<dyn FileIO<Data = SyncEntityChunk>>,
+    /// Held for reading while chunks are written to disk, see [`Self::pause_saving`].
+    pub(crate) save_gate: RwLock<()>,
 
     pub world_gen: Arc<VanillaGenerator>,
 
@@ -185,6 +188,7 @@ impl Level {
             light_engine: DynamicLightEngine::new(),
             chunk_saver,
             entity_saver,
+            save_gate: RwLock::new(()),
             schedule_tick_counts: AtomicU64::new(0),
             loaded_chunks: Arc::new(DashMap::new()),
             loaded_entity_chunks: Arc::new(DashMap::new()),
@@ -560,6 +564,75 @@ impl Level {
         chunk
     }
 
+    /// Generates every chunk in the square of `radius` chunks around `center`, loading the
+    /// ones that already exist instead.
+    ///
+    /// Chunks are requested in row-major batches of `batch_size` so the generation workers
+    /// always have a full queue. A batch is only released once the next one finished, keeping
+    /// the chunks along the seam loaded for their neighbours. Released chunks are written to
+    /// disk by the chunk system.
+    ///
+    /// `on_progress` receives the number of finished chunks after every chunk and returns
+    /// whether generation should go on. Returns the number of finished chunks.
+    pub async fn generate_area(
+        self: &Arc<Self>,
+        center: Vector2<i32>,
+        radius: i32,
+        batch_size: usize,
+        mut on_progress: impl FnMut(usize) -> bool + Send,
+    ) -> usize {
+        let mut positions = area_positions(center, radius);
+
+        let mut finished = 0;
+        let mut previous = Vec::new();
+        'batches: loop {
+            let batch: Vec<Vector2<i32>> = positions.by_ref().take(batch_size.max(1)).collect();
+            if batch.is_empty() {
+                break;
+            }
+            let receivers: Vec<_> = batch
+                .iter()
+                .map(|pos| self.chunk_listener.add_single_chunk_listener(*pos))
+                .collect();
+            {
+                let mut lock = self.chunk_loading.lock().unwrap();
+                for pos in &batch {
+                    lock.add_ticket(*pos, ChunkLoading::FULL_CHUNK_LEVEL);
+                }
+                lock.send_change();
+            }
+
+            for (pos, recv) in batch.iter().zip(receivers) {
+                if !self.loaded_chunks.contains_key(pos) {
+                    select! {
+                        _ = recv => {}
+                        () = self.cancel_token.cancelled() => break 'batches,
+                    }
+                }
+                finished += 1;
+                if !on_progress(finished) {
+                    break 'batches;
+                }
+            }
+
+            self.release_tickets(&previous, ChunkLoading::FULL_CHUNK_LEVEL);
+            previous = batch;
+        }
+        self.release_tickets(&previous, ChunkLoading::FULL_CHUNK_LEVEL);
+        finished
+    }
+
+    fn release_tickets(&self, positions: &[Vector2<i32>], level: i8) {
+        if positions.is_empty() {
+            return;
+        }
+        let mut lock = self.chunk_loading.lock().unwrap();
+        for pos in positions {
+            lock.remove_ticket(*pos, level);
+        }
+        lock.send_change();
+    }
+
     async fn load_single_entity_chunk(
         &self,
         pos: Vector2<i32>,
@@ -720,10 +793,44 @@ impl Level {
         replaced_block_state_id
     }
 
+    /// Waits for ongoing chunk writes to finish and holds back new ones until the guard is
+    /// dropped, so the files in the level folder can be copied in a consistent state.
+    pub async fn pause_saving(&self) -> RwLockWriteGuard<'_, ()> {
+        self.save_gate.write().await
+    }
+
+    /// Writes every dirty chunk and entity chunk to disk and waits until they are written, like
+    /// vanilla's `save-all flush`. Chunks players are near are written as well.
+    pub async fn flush(&self) {
+        let chunks = self
+            .loaded_chunks
+            .iter()
+            .filter(|chunk| chunk.is_dirty())
+            .map(|chunk| (*chunk.key(), chunk.value().clone()))
+            .collect();
+        self.write_chunks(chunks).await;
+        let entity_chunks = self
+            .loaded_entity_chunks
+            .iter()
+            .filter(|chunk| chunk.is_dirty())
+            .map(|chunk| (*chunk.key(), chunk.value().clone()))
+            .collect();
+        self.write_entity_chunks(entity_chunks).await;
+
+        let _save_guard = self.save_gate.read().await;
+        if let Err(error) = self.chunk_saver.flush().await {
+            error!("Failed flushing chunks to disk {error}");
+        }
+        if let Err(error) = self.entity_saver.flush().await {
+            error!("Failed flushing entity chunks to disk {error}");
+        }
+    }
+
     pub async fn write_chunks(&self, chunks_to_write: Vec<(Vector2<i32>, SyncChunk)>) {
         if chunks_to_write.is_empty() {
             return;
         }
+        let _save_guard = self.save_gate.read().await;
 
         let chunk_saver = self.chunk_saver.clone();
         let level_folder = self.level_folder.clone();
@@ -741,6 +848,7 @@ impl Level {
         if chunks_to_write.is_empty() {
             return;
         }
+        let _save_guard = self.save_gate.read().await;
 
         let chunk_saver = self.entity_saver.clone();
         let level_folder = self.level_folder.clone();
@@ -825,3 +933,43 @@ impl Level {
         chunk.fluid_ticks.is_scheduled(*block_pos, fluid)
     }
 }
+
+/// Every chunk position in the square of `radius` chunks around `center`, row by row.
+fn area_positions(center: Vector2<i32>, radius: i32) -> impl Iterator<Item = Vector2<i32>> {
+    (-radius..=radius).flat_map(move |z| {
+        (-radius..=radius).map(move |x| Vector2::new(center.x + x, center.y + z))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_util::math::vector2::Vector2;
+
+    use super::area_positions;
+
+    #[test]
+    fn area_covers_square_row_by_row() {
+        let positions: Vec<_> = area_positions(Vector2::new(10, -5), 1).collect();
+        assert_eq!(positions.len(), 9);
+        assert_eq!(positions[0], Vector2::new(9, -6));
+        assert_eq!(positions[1], Vector2::new(10, -6));
+        assert_eq!(positions[3], Vector2::new(9, -5));
+        assert_eq!(positions[8], Vector2::new(11, -4));
+    }
+
+    #[test]
+    fn area_of_radius_zero_is_center() {
+        let positions: Vec<_> = area_positions(Vector2::new(3, 4), 0).collect();
+        assert_eq!(positions, vec![Vector2::new(3, 4)]);
+    }
+
+    #[test]
+    fn area_is_lazy() {
+        // Would not fit in memory if the positions were collected up front
+        let mut positions = area_positions(Vector2::new(0, 0), i32::MAX / 2);
+        assert_eq!(
+            positions.nth(1),
+            Some(Vector2::new(-(i32::MAX / 2) + 1, -(i32::MAX / 2)))
+        );
+    }
+}
@@ -17,6 +17,7 @@ pub mod level;
 pub mod lighting;
 pub mod lock;
 pub mod poi;
+pub mod schematic;
 pub mod tick;
 pub mod world;
 pub mod world_info;
@@ -12,6 +12,8 @@ use serde::{Deserialize, Serialize};
 
 /// POI type identifier for nether portals
 pub const POI_TYPE_NETHER_PORTAL: &str = "minecraft:nether_portal";
+/// POI type identifier for lightning rods
+pub const POI_TYPE_LIGHTNING_ROD: &str = "minecraft:lightning_rod";
 
 /// MCA format constants
 const SECTOR_SIZE: usize = 4096;
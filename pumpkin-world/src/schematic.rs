@@ -0,0 +1,500 @@
+//! Sponge schematic (`.schem`, version 3) reading and writing.
+//!
+//! Schematics are the interchange format used by `WorldEdit` and most other building tools.
+//! A schematic stores a cuboid of block states as a palette plus a varint encoded index array
+//! in YZX order, an offset from the copy origin to the minimum corner, and the block entities
+//! inside the region.
+
+use std::io::{Cursor, Read};
+
+use pumpkin_data::{Block, BlockState};
+use pumpkin_nbt::{
+    compound::NbtCompound,
+    nbt_compress::{read_gzip_compound_tag, write_gzip_compound_tag_to_bytes},
+    tag::NbtTag,
+};
+use pumpkin_util::math::vector3::Vector3;
+use thiserror::Error;
+
+use crate::chunk::format::anvil::WORLD_DATA_VERSION;
+use crate::generation::structure::template::PaletteEntry;
+
+/// The Sponge schematic format version read and written by [`Schematic`].
+pub const SCHEMATIC_VERSION: i32 = 3;
+pub const SCHEMATIC_EXTENSION: &str = "schem";
+/// The most blocks a schematic may hold, so that a crafted file can't make us allocate an
+/// arbitrary amount of memory.
+pub const MAX_SCHEMATIC_VOLUME: usize = 1 << 24;
+
+#[derive(Debug, Error)]
+pub enum SchematicError {
+    #[error("Failed to read NBT: {0}")]
+    Nbt(#[from] pumpkin_nbt::Error),
+
+    #[error("Missing required field: {0}")]
+    MissingField(&'static str),
+
+    #[error("Invalid field: {0}")]
+    InvalidField(&'static str),
+
+    #[error("Unsupported schematic version {0}")]
+    UnsupportedVersion(i32),
+
+    #[error("Schematic of size {0}x{1}x{2} is too large")]
+    TooLarge(i32, i32, i32),
+}
+
+/// A block entity stored in a schematic, positioned relative to the minimum corner.
+#[derive(Debug, Clone)]
+pub struct SchematicBlockEntity {
+    pub pos: Vector3<i32>,
+    pub id: String,
+    /// The block entity data without its `id` and position.
+    pub data: NbtCompound,
+}
+
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    /// Width (x), height (y) and length (z) of the region.
+    pub size: Vector3<i32>,
+    /// Offset from the copy origin to the minimum corner of the region.
+    pub offset: Vector3<i32>,
+    pub palette: Vec<PaletteEntry>,
+    /// Palette index of every block in YZX order.
+    pub blocks: Vec<u32>,
+    pub block_entities: Vec<SchematicBlockEntity>,
+}
+
+impl Schematic {
+    /// Creates a schematic of the given size filled with air.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the size is negative, exceeds what the format can store or holds
+    /// more than [`MAX_SCHEMATIC_VOLUME`] blocks.
+    pub fn new(size: Vector3<i32>, offset: Vector3<i32>) -> Result<Self, SchematicError> {
+        let too_large = || SchematicError::TooLarge(size.x, size.y, size.z);
+        let dimension = |value: i32| {
+            u16::try_from(value)
+                .map(usize::from)
+                .map_err(|_| too_large())
+        };
+        let (width, height, length) = (dimension(size.x)?, dimension(size.y)?, dimension(size.z)?);
+        let volume = width
+            .checked_mul(height)
+            .and_then(|area| area.checked_mul(length))
+            .filter(|volume| *volume <= MAX_SCHEMATIC_VOLUME)
+            .ok_or_else(too_large)?;
+        Ok(Self {
+            size,
+            offset,
+            palette: vec![PaletteEntry::new("minecraft:air".to_string())],
+            blocks: vec![0; volume],
+            block_entities: Vec::new(),
+        })
+    }
+
+    /// Returns the index of a relative position into [`Self::blocks`].
+    #[must_use]
+    pub const fn index(&self, pos: Vector3<i32>) -> usize {
+        (pos.x + pos.z * self.size.x + pos.y * self.size.x * self.size.z) as usize
+    }
+
+    /// Returns the relative position of an index into [`Self::blocks`].
+    #[must_use]
+    pub const fn position(&self, index: usize) -> Vector3<i32> {
+        let index = index as i32;
+        let layer = self.size.x * self.size.z;
+        Vector3::new(
+            index % self.size.x,
+            index / layer,
+            (index % layer) / self.size.x,
+        )
+    }
+
+    /// Stores a block state at a relative position, adding it to the palette if needed.
+    pub fn set_block_state(&mut self, pos: Vector3<i32>, state: &BlockState) {
+        let entry = palette_entry_from_state(state);
+        let palette_index = self
+            .palette
+            .iter()
+            .position(|existing| {
+                existing.name == entry.name && existing.properties == entry.properties
+            })
+            .unwrap_or_else(|| {
+                self.palette.push(entry);
+                self.palette.len() - 1
+            });
+        let index = self.index(pos);
+        self.blocks[index] = palette_index as u32;
+    }
+
+    /// Returns the palette entry of the block at a relative position.
+    #[must_use]
+    pub fn block_at(&self, pos: Vector3<i32>) -> &PaletteEntry {
+        &self.palette[self.blocks[self.index(pos)] as usize]
+    }
+
+    /// Reads a schematic from gzipped NBT bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not a valid version 3 Sponge schematic.
+    pub fn from_nbt_bytes(bytes: &[u8]) -> Result<Self, SchematicError> {
+        let root = read_gzip_compound_tag(Cursor::new(bytes))?;
+        Self::from_nbt_compound(&root)
+    }
+
+    /// Reads a schematic from its root compound.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if required fields are missing or malformed.
+    pub fn from_nbt_compound(root: &NbtCompound) -> Result<Self, SchematicError> {
+        let compound = root
+            .get_compound("Schematic")
+            .ok_or(SchematicError::MissingField("Schematic"))?;
+
+        let version = compound
+            .get_int("Version")
+            .ok_or(SchematicError::MissingField("Version"))?;
+        if version != SCHEMATIC_VERSION {
+            return Err(SchematicError::UnsupportedVersion(version));
+        }
+
+        let size = Vector3::new(
+            read_dimension(compound, "Width")?,
+            read_dimension(compound, "Height")?,
+            read_dimension(compound, "Length")?,
+        );
+        let offset = match compound.get_int_array("Offset") {
+            Some([x, y, z]) => Vector3::new(*x, *y, *z),
+            Some(_) => return Err(SchematicError::InvalidField("Offset")),
+            None => Vector3::new(0, 0, 0),
+        };
+
+        let mut schematic = Self::new(size, offset)?;
+        let Some(blocks) = compound.get_compound("Blocks") else {
+            return Ok(schematic);
+        };
+
+        let palette_compound = blocks
+            .get_compound("Palette")
+            .ok_or(SchematicError::MissingField("Blocks.Palette"))?;
+        let mut palette = vec![None; palette_compound.child_tags.len()];
+        for (state, tag) in &palette_compound.child_tags {
+            let NbtTag::Int(index) = tag else {
+                return Err(SchematicError::InvalidField("Blocks.Palette"));
+            };
+            let slot = palette
+                .get_mut(*index as usize)
+                .ok_or(SchematicError::InvalidField("Blocks.Palette"))?;
+            *slot = Some(parse_block_state(state));
+        }
+        schematic.palette = palette
+            .into_iter()
+            .collect::<Option<_>>()
+            .ok_or(SchematicError::InvalidField("Blocks.Palette"))?;
+
+        let Some(NbtTag::ByteArray(data)) = blocks.get("Data") else {
+            return Err(SchematicError::MissingField("Blocks.Data"));
+        };
+        let mut reader = Cursor::new(data.as_ref());
+        for block in &mut schematic.blocks {
+            let index =
+                read_varint(&mut reader).ok_or(SchematicError::InvalidField("Blocks.Data"))?;
+            if index as usize >= schematic.palette.len() {
+                return Err(SchematicError::InvalidField("Blocks.Data"));
+            }
+            *block = index;
+        }
+
+        for tag in blocks.get_list("BlockEntities").unwrap_or_default() {
+            let NbtTag::Compound(entry) = tag else {
+                return Err(SchematicError::InvalidField("Blocks.BlockEntities"));
+            };
+            let Some([x, y, z]) = entry.get_int_array("Pos") else {
+                return Err(SchematicError::InvalidField("Blocks.BlockEntities.Pos"));
+            };
+            let id = entry
+                .get_string("Id")
+                .ok_or(SchematicError::MissingField("Blocks.BlockEntities.Id"))?;
+            schematic.block_entities.push(SchematicBlockEntity {
+                pos: Vector3::new(*x, *y, *z),
+                id: id.to_string(),
+                data: entry.get_compound("Data").cloned().unwrap_or_default(),
+            });
+        }
+
+        Ok(schematic)
+    }
+
+    /// Builds the root compound of the schematic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a dimension does not fit the format's unsigned shorts.
+    pub fn to_nbt_compound(&self) -> Result<NbtCompound, SchematicError> {
+        let dimension = |value: i32| {
+            u16::try_from(value)
+                .map_err(|_| SchematicError::TooLarge(self.size.x, self.size.y, self.size.z))
+        };
+        let (width, height, length) = (
+            dimension(self.size.x)?,
+            dimension(self.size.y)?,
+            dimension(self.size.z)?,
+        );
+
+        let mut palette = NbtCompound::new();
+        for (index, entry) in self.palette.iter().enumerate() {
+            palette.put_int(&block_state_string(entry), index as i32);
+        }
+
+        let mut data = Vec::with_capacity(self.blocks.len());
+        for block in &self.blocks {
+            write_varint(&mut data, *block);
+        }
+
+        let block_entities = self
+            .block_entities
+            .iter()
+            .map(|block_entity| {
+                let mut entry = NbtCompound::new();
+                entry.put(
+                    "Pos",
+                    NbtTag::IntArray(vec![
+                        block_entity.pos.x,
+                        block_entity.pos.y,
+                        block_entity.pos.z,
+                    ]),
+                );
+                entry.put_string("Id", block_entity.id.clone());
+                entry.put_compound("Data", block_entity.data.clone());
+                NbtTag::Compound(entry)
+            })
+            .collect();
+
+        let mut blocks = NbtCompound::new();
+        blocks.put_compound("Palette", palette);
+        blocks.put("Data", NbtTag::ByteArray(data.into_boxed_slice()));
+        blocks.put_list("BlockEntities", block_entities);
+
+        let mut schematic = NbtCompound::new();
+        schematic.put_int("Version", SCHEMATIC_VERSION);
+        schematic.put_int("DataVersion", WORLD_DATA_VERSION);
+        // Dimensions are unsigned shorts
+        schematic.put_short("Width", width as i16);
+        schematic.put_short("Height", height as i16);
+        schematic.put_short("Length", length as i16);
+        schematic.put(
+            "Offset",
+            NbtTag::IntArray(vec![self.offset.x, self.offset.y, self.offset.z]),
+        );
+        schematic.put_compound("Blocks", blocks);
+
+        let mut root = NbtCompound::new();
+        root.put_compound("Schematic", schematic);
+        Ok(root)
+    }
+
+    /// Serializes the schematic to gzipped NBT bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schematic is too large or the NBT could not be written.
+    pub fn to_nbt_bytes(&self) -> Result<Vec<u8>, SchematicError> {
+        Ok(write_gzip_compound_tag_to_bytes(self.to_nbt_compound()?)?)
+    }
+}
+
+fn read_dimension(compound: &NbtCompound, name: &'static str) -> Result<i32, SchematicError> {
+    compound
+        .get_short(name)
+        .map(|value| i32::from(value as u16))
+        .ok_or(SchematicError::MissingField(name))
+}
+
+/// Builds the palette entry describing a block state.
+#[must_use]
+pub fn palette_entry_from_state(state: &BlockState) -> PaletteEntry {
+    let block = Block::from_state_id(state.id);
+    let properties = block
+        .properties(state.id)
+        .map(|properties| {
+            properties
+                .to_props()
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    PaletteEntry::with_properties(format!("minecraft:{}", block.name), properties)
+}
+
+/// Parses a block state string such as `minecraft:oak_stairs[facing=north,half=bottom]`.
+#[must_use]
+pub fn parse_block_state(state: &str) -> PaletteEntry {
+    let Some((name, properties)) = state.split_once('[') else {
+        return PaletteEntry::new(state.to_string());
+    };
+    let properties = properties
+        .trim_end_matches(']')
+        .split(',')
+        .filter_map(|property| property.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    PaletteEntry::with_properties(name.to_string(), properties)
+}
+
+/// Formats a palette entry as a block state string.
+#[must_use]
+pub fn block_state_string(entry: &PaletteEntry) -> String {
+    if entry.properties.is_empty() {
+        return entry.name.clone();
+    }
+    let properties: Vec<String> = entry
+        .properties
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect();
+    format!("{}[{}]", entry.name, properties.join(","))
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u32) {
+    loop {
+        if value & !0x7F == 0 {
+            buf.push(value as u8);
+            return;
+        }
+        buf.push((value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+}
+
+fn read_varint(reader: &mut impl Read) -> Option<u32> {
+    let mut value = 0u32;
+    for shift in (0..35).step_by(7) {
+        let mut byte = [0];
+        reader.read_exact(&mut byte).ok()?;
+        value |= u32::from(byte[0] & 0x7F) << shift;
+        if byte[0] & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_round_trips_position() {
+        let schematic = Schematic::new(Vector3::new(3, 4, 5), Vector3::new(0, 0, 0)).unwrap();
+        for index in 0..schematic.blocks.len() {
+            assert_eq!(schematic.index(schematic.position(index)), index);
+        }
+        assert_eq!(schematic.index(Vector3::new(1, 0, 0)), 1);
+        assert_eq!(schematic.index(Vector3::new(0, 0, 1)), 3);
+        assert_eq!(schematic.index(Vector3::new(0, 1, 0)), 15);
+    }
+
+    #[test]
+    fn block_state_string_round_trips() {
+        let state = "minecraft:oak_stairs[facing=east,half=top,shape=straight,waterlogged=false]";
+        assert_eq!(block_state_string(&parse_block_state(state)), state);
+        assert_eq!(
+            block_state_string(&parse_block_state("minecraft:stone")),
+            "minecraft:stone"
+        );
+    }
+
+    #[test]
+    fn varint_round_trips() {
+        for value in [0, 1, 127, 128, 300, 16_384, u32::MAX] {
+            let mut buf = Vec::new();
+            write_varint(&mut buf, value);
+            assert_eq!(read_varint(&mut Cursor::new(buf)), Some(value));
+        }
+    }
+
+    #[test]
+    fn schematic_round_trips_through_nbt() {
+        let mut schematic = Schematic::new(Vector3::new(2, 3, 2), Vector3::new(-1, 0, 4)).unwrap();
+        schematic.set_block_state(Vector3::new(0, 0, 0), Block::STONE.default_state);
+        schematic.set_block_state(Vector3::new(1, 2, 1), Block::OAK_STAIRS.default_state);
+        let mut data = NbtCompound::new();
+        data.put_string("CustomName", "\"chest\"".to_string());
+        schematic.block_entities.push(SchematicBlockEntity {
+            pos: Vector3::new(1, 1, 0),
+            id: "minecraft:chest".to_string(),
+            data,
+        });
+
+        let bytes = schematic.to_nbt_bytes().unwrap();
+        let read = Schematic::from_nbt_bytes(&bytes).unwrap();
+
+        assert_eq!(read.size, schematic.size);
+        assert_eq!(read.offset, schematic.offset);
+        for index in 0..schematic.blocks.len() {
+            let pos = schematic.position(index);
+            assert_eq!(
+                block_state_string(read.block_at(pos)),
+                block_state_string(schematic.block_at(pos))
+            );
+        }
+        assert_eq!(
+            block_state_string(read.block_at(Vector3::new(0, 0, 0))),
+            "minecraft:stone"
+        );
+        assert_eq!(read.block_entities.len(), 1);
+        assert_eq!(read.block_entities[0].pos, Vector3::new(1, 1, 0));
+        assert_eq!(read.block_entities[0].id, "minecraft:chest");
+        assert_eq!(
+            read.block_entities[0].data.get_string("CustomName"),
+            Some("\"chest\"")
+        );
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut inner = NbtCompound::new();
+        inner.put_int("Version", 2);
+        let mut root = NbtCompound::new();
+        root.put_compound("Schematic", inner);
+        assert!(matches!(
+            Schematic::from_nbt_compound(&root),
+            Err(SchematicError::UnsupportedVersion(2))
+        ));
+    }
+
+    #[test]
+    fn rejects_oversized_schematics() {
+        let origin = Vector3::new(0, 0, 0);
+        assert!(matches!(
+            Schematic::new(
+                Vector3::new(u16::MAX.into(), u16::MAX.into(), u16::MAX.into()),
+                origin
+            ),
+            Err(SchematicError::TooLarge(..))
+        ));
+        assert!(matches!(
+            Schematic::new(Vector3::new(70_000, 1, 1), origin),
+            Err(SchematicError::TooLarge(..))
+        ));
+        assert!(matches!(
+            Schematic::new(Vector3::new(-1, 1, 1), origin),
+            Err(SchematicError::TooLarge(..))
+        ));
+    }
+
+    #[test]
+    fn refuses_to_save_dimensions_over_u16() {
+        let mut schematic = Schematic::new(Vector3::new(1, 1, 1), Vector3::new(0, 0, 0)).unwrap();
+        schematic.size.x = 70_000;
+        assert!(matches!(
+            schematic.to_nbt_compound(),
+            Err(SchematicError::TooLarge(..))
+        ));
+    }
+}
@@ -2,6 +2,7 @@ use std::pin::Pin;
 use std::sync::Arc;
 
 use crate::block::entities::BlockEntity;
+use crate::item::ItemStack;
 use crate::{BlockStateId, inventory::Inventory, level::Level};
 use bitflags::bitflags;
 use pumpkin_data::dimension::Dimension;
@@ -13,6 +14,7 @@ use pumpkin_util::math::boundingbox::BoundingBox;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use thiserror::Error;
+use uuid::Uuid;
 
 bitflags! {
     /// Flags used to control the side effects of a block state change.
@@ -61,6 +63,14 @@ impl std::fmt::Display for GetBlockError {
     }
 }
 
+/// A player near a block entity, as seen by block entities reacting to players.
+#[derive(Clone, Copy, Debug)]
+pub struct NearbyPlayer {
+    pub uuid: Uuid,
+    /// Whether the player has the Trial Omen effect, which makes trial spawners ominous.
+    pub has_trial_omen: bool,
+}
+
 pub type WorldFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 
 pub trait SimpleWorld: BlockAccessor + Send + Sync {
@@ -85,10 +95,33 @@ pub trait SimpleWorld: BlockAccessor + Send + Sync {
 
     fn is_space_empty(&self, bounding_box: BoundingBox) -> WorldFuture<'_, bool>;
 
+    /// Spawns an entity of the given type and returns its UUID.
     fn spawn_from_type(
         self: Arc<Self>,
         entity_type: &'static EntityType,
         position: Vector3<f64>,
+    ) -> WorldFuture<'static, Uuid>;
+
+    /// Returns whether the entity with the given UUID is still alive in this world.
+    fn is_entity_alive(&self, uuid: Uuid) -> WorldFuture<'_, bool>;
+
+    /// Removes the entity with the given UUID from this world without it dying, returning the
+    /// block position it was at, if it existed.
+    fn discard_entity(&self, uuid: Uuid) -> WorldFuture<'_, Option<BlockPos>>;
+
+    /// Returns the players within `range` blocks of `center`, excluding spectators.
+    fn get_nearby_players(
+        &self,
+        center: Vector3<f64>,
+        range: f64,
+    ) -> WorldFuture<'_, Vec<NearbyPlayer>>;
+
+    /// Spawns an item entity with the given velocity.
+    fn spawn_item(
+        self: Arc<Self>,
+        position: Vector3<f64>,
+        stack: ItemStack,
+        velocity: Vector3<f64>,
     ) -> WorldFuture<'static, ()>;
 
     fn add_synced_block_event(&self, pos: BlockPos, r#type: u8, data: u8) -> WorldFuture<'_, ()>;
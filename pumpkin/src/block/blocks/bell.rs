@@ -0,0 +1,96 @@
+use pumpkin_data::BlockDirection;
+use pumpkin_data::block_properties::{Attachment, BellLikeProperties, BlockProperties};
+use pumpkin_data::sound::{Sound, SoundCategory};
+use pumpkin_macros::pumpkin_block;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_world::world::BlockFlags;
+
+use crate::block::blocks::redstone::block_receives_redstone_power;
+use crate::block::registry::BlockActionResult;
+use crate::block::{
+    BlockBehaviour, BlockFuture, NormalUseArgs, OnNeighborUpdateArgs, OnSyncedBlockEventArgs,
+};
+use crate::world::World;
+
+/// Synced block event type used by clients to play the bell swing animation.
+const RING_EVENT: u8 = 1;
+
+#[pumpkin_block("minecraft:bell")]
+pub struct BellBlock;
+
+impl BellBlock {
+    /// Rings the bell, swinging it away from `direction`.
+    pub async fn ring(world: &World, pos: &BlockPos, direction: BlockDirection) {
+        world
+            .play_block_sound_fine(Sound::BlockBellUse, SoundCategory::Blocks, *pos, 2.0, 1.0)
+            .await;
+        world
+            .add_synced_block_event(*pos, RING_EVENT, direction.to_index())
+            .await;
+    }
+
+    /// Returns whether a hit on `side` at the given height actually touches the bell itself
+    /// rather than its frame.
+    fn is_point_on_bell(props: BellLikeProperties, side: BlockDirection, y: f32) -> bool {
+        if !side.is_horizontal() || y > 0.8124 {
+            return false;
+        }
+        let facing_axis = BlockDirection::from_cardinal_direction(props.facing).to_axis();
+        match props.attachment {
+            Attachment::Floor => facing_axis == side.to_axis(),
+            Attachment::SingleWall | Attachment::DoubleWall => facing_axis != side.to_axis(),
+            Attachment::Ceiling => true,
+        }
+    }
+}
+
+impl BlockBehaviour for BellBlock {
+    fn normal_use<'a>(&'a self, args: NormalUseArgs<'a>) -> BlockFuture<'a, BlockActionResult> {
+        Box::pin(async move {
+            let state_id = args.world.get_block_state_id(args.position).await;
+            let props = BellLikeProperties::from_state_id(state_id, args.block);
+            if !Self::is_point_on_bell(props, *args.hit.face, args.hit.cursor_pos.y) {
+                return BlockActionResult::Pass;
+            }
+
+            Self::ring(args.world, args.position, *args.hit.face).await;
+            BlockActionResult::Success
+        })
+    }
+
+    fn on_neighbor_update<'a>(&'a self, args: OnNeighborUpdateArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let state_id = args.world.get_block_state_id(args.position).await;
+            let mut props = BellLikeProperties::from_state_id(state_id, args.block);
+            let powered = block_receives_redstone_power(args.world, args.position).await;
+            if powered == props.powered {
+                return;
+            }
+
+            if powered {
+                Self::ring(
+                    args.world,
+                    args.position,
+                    BlockDirection::from_cardinal_direction(props.facing),
+                )
+                .await;
+            }
+            props.powered = powered;
+            args.world
+                .set_block_state(
+                    args.position,
+                    props.to_state_id(args.block),
+                    BlockFlags::NOTIFY_ALL,
+                )
+                .await;
+        })
+    }
+
+    fn on_synced_block_event<'a>(
+        &'a self,
+        args: OnSyncedBlockEventArgs<'a>,
+    ) -> BlockFuture<'a, bool> {
+        // The swing animation is purely client-side, so just forward the event.
+        Box::pin(async move { args.r#type == RING_EVENT })
+    }
+}
@@ -319,23 +319,12 @@ fn get_oxidation_level(block: &Block) -> Option<u8> {
 }
 
 /// Try to oxidize a copper chest to its next oxidation level.
-/// Uses vanilla's degradation algorithm with neighbor checking.
 async fn try_oxidize_copper_chest(
     world: &Arc<World>,
     position: &BlockPos,
     current_block: &Block,
     chest_props: ChestLikeProperties,
 ) {
-    use rand::RngExt;
-
-    // Base chance per random tick: ~5.69%
-    const BASE_DEGRADATION_CHANCE: f32 = 0.056_888_89;
-
-    // First roll: only ~5.69% chance to even attempt oxidation
-    if rand::rng().random::<f32>() >= BASE_DEGRADATION_CHANCE {
-        return;
-    }
-
     // Find the next oxidation level
     let (next_block, current_level) = match COPPER_CHEST_OXIDATION
         .iter()
@@ -345,9 +334,40 @@ async fn try_oxidize_copper_chest(
         None => return, // Already fully oxidized or waxed
     };
 
+    if !should_oxidize(world, position, current_level, get_oxidation_level).await {
+        return;
+    }
+
+    // Apply oxidation with same properties
+    let new_state_id = chest_props.to_state_id(next_block);
+    world
+        .set_block_state(position, new_state_id, BlockFlags::NOTIFY_LISTENERS)
+        .await;
+}
+
+/// Rolls whether a copper block at `current_level` oxidizes on this random tick.
+///
+/// Uses vanilla's degradation algorithm with neighbor checking, `oxidation_level` tells which
+/// neighbors count as the same kind of copper.
+pub async fn should_oxidize(
+    world: &Arc<World>,
+    position: &BlockPos,
+    current_level: u8,
+    oxidation_level: fn(&Block) -> Option<u8>,
+) -> bool {
+    use rand::RngExt;
+
+    // Base chance per random tick: ~5.69%
+    const BASE_DEGRADATION_CHANCE: f32 = 0.056_888_89;
+
+    // First roll: only ~5.69% chance to even attempt oxidation
+    if rand::rng().random::<f32>() >= BASE_DEGRADATION_CHANCE {
+        return false;
+    }
+
     // Scan neighbors in 4-block Manhattan distance to calculate oxidation chance
     let (same_level_count, higher_level_count) =
-        count_neighbor_oxidation_levels(world, position, current_level).await;
+        count_neighbor_oxidation_levels(world, position, current_level, oxidation_level).await;
 
     // If we found any neighbors at a LOWER level, oxidation is blocked
     // (This is handled in count_neighbor_oxidation_levels by returning early)
@@ -359,15 +379,7 @@ async fn try_oxidize_copper_chest(
     let multiplier = if current_level == 0 { 0.75 } else { 1.0 };
     let final_chance = ratio * ratio * multiplier;
 
-    if rand::rng().random::<f32>() >= final_chance {
-        return;
-    }
-
-    // Apply oxidation with same properties
-    let new_state_id = chest_props.to_state_id(next_block);
-    world
-        .set_block_state(position, new_state_id, BlockFlags::NOTIFY_LISTENERS)
-        .await;
+    rand::rng().random::<f32>() < final_chance
 }
 
 /// Count copper blocks at same and higher oxidation levels within 4-block Manhattan distance.
@@ -376,6 +388,7 @@ async fn count_neighbor_oxidation_levels(
     world: &Arc<World>,
     center: &BlockPos,
     current_level: u8,
+    oxidation_level: fn(&Block) -> Option<u8>,
 ) -> (i32, i32) {
     use std::cmp::Ordering;
 
@@ -399,7 +412,7 @@ async fn count_neighbor_oxidation_levels(
 
                 let (neighbor_block, _) = world.get_block_and_state_id(&neighbor_pos).await;
 
-                if let Some(neighbor_level) = get_oxidation_level(neighbor_block) {
+                if let Some(neighbor_level) = oxidation_level(neighbor_block) {
                     match neighbor_level.cmp(&current_level) {
                         Ordering::Less => {
                             // Found a neighbor at lower oxidation level - block oxidation entirely
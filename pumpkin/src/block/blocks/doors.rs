@@ -14,6 +14,7 @@ use pumpkin_util::math::position::BlockPos;
 use pumpkin_world::BlockStateId;
 use pumpkin_world::world::BlockAccessor;
 use pumpkin_world::world::BlockFlags;
+use rand::{RngExt, rng};
 use std::sync::Arc;
 
 use crate::block::BlockBehaviour;
@@ -26,6 +27,7 @@ use crate::block::OnNeighborUpdateArgs;
 use crate::block::OnPlaceArgs;
 use crate::block::OnStateReplacedArgs;
 use crate::block::PlacedArgs;
+use crate::block::WindTriggeredArgs;
 use crate::block::blocks::redstone::block_receives_redstone_power;
 use crate::block::registry::BlockActionResult;
 use crate::entity::player::Player;
@@ -36,7 +38,7 @@ use pumpkin_util::GameMode;
 
 type DoorProperties = pumpkin_data::block_properties::OakDoorLikeProperties;
 
-async fn toggle_door(player: &Player, world: &Arc<World>, block_pos: &BlockPos) {
+async fn toggle_door(player: Option<&Player>, world: &Arc<World>, block_pos: &BlockPos) {
     let (block, block_state) = world.get_block_and_state_id(block_pos).await;
     let mut door_props = DoorProperties::from_state_id(block_state, block);
     door_props.open = !door_props.open;
@@ -51,14 +53,18 @@ async fn toggle_door(player: &Player, world: &Arc<World>, block_pos: &BlockPos)
     let mut other_door_props = DoorProperties::from_state_id(other_state_id, other_block);
     other_door_props.open = door_props.open;
 
-    world
-        .play_block_sound_expect(
-            player,
-            get_sound(block, door_props.open),
-            SoundCategory::Blocks,
-            *block_pos,
-        )
-        .await;
+    let pitch = rng().random::<f32>().mul_add(0.1, 0.9);
+    let sound = get_sound(block, door_props.open);
+
+    if let Some(player) = player {
+        world
+            .play_block_sound_expect(player, sound, SoundCategory::Blocks, *block_pos, 1.0, pitch)
+            .await;
+    } else {
+        world
+            .play_block_sound_fine(sound, SoundCategory::Blocks, *block_pos, 1.0, pitch)
+            .await;
+    }
 
     world
         .set_block_state(
@@ -209,12 +215,25 @@ impl BlockBehaviour for DoorBlock {
                 return BlockActionResult::Pass;
             }
 
-            toggle_door(args.player, args.world, args.position).await;
+            toggle_door(Some(args.player), args.world, args.position).await;
 
             BlockActionResult::Success
         })
     }
 
+    fn on_wind_triggered<'a>(&'a self, args: WindTriggeredArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            // Both halves are caught by the burst, only the lower one toggles the door
+            let door_props = DoorProperties::from_state_id(args.state.id, args.block);
+            if door_props.half == DoubleBlockHalf::Lower
+                && can_open_door(args.block)
+                && !door_props.powered
+            {
+                toggle_door(None, args.world, args.position).await;
+            }
+        })
+    }
+
     fn broken<'a>(&'a self, args: BrokenArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
             let door_props = DoorProperties::from_state_id(args.state.id, args.block);
@@ -271,11 +290,15 @@ impl BlockBehaviour for DoorBlock {
                     door_props.open = door_props.powered;
                     other_door_props.open = other_door_props.powered;
 
+                    let pitch = rng().random::<f32>().mul_add(0.1, 0.9);
+
                     args.world
-                        .play_block_sound(
+                        .play_block_sound_fine(
                             get_sound(args.block, powered),
                             SoundCategory::Blocks,
                             *args.position,
+                            1.0,
+                            pitch,
                         )
                         .await;
                 }
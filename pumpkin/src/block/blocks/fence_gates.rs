@@ -17,6 +17,7 @@ use pumpkin_macros::pumpkin_block_from_tag;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_world::BlockStateId;
 use pumpkin_world::world::BlockFlags;
+use rand::{RngExt, rng};
 
 type FenceGateProperties = pumpkin_data::block_properties::OakFenceGateLikeProperties;
 
@@ -60,12 +61,16 @@ pub async fn toggle_fence_gate(
         fence_gate_props.open = true;
     }
 
+    let pitch = rng().random::<f32>().mul_add(0.1, 0.9);
+
     world
         .play_block_sound_expect(
             player,
             get_sound(block, fence_gate_props.open),
             SoundCategory::Blocks,
             *block_pos,
+            1.0,
+            pitch,
         )
         .await;
 
@@ -130,11 +135,15 @@ impl BlockBehaviour for FenceGateBlock {
             if powered != fence_gate_props.open {
                 fence_gate_props.open = powered;
 
+                let pitch = rng().random::<f32>().mul_add(0.1, 0.9);
+
                 args.world
-                    .play_block_sound(
+                    .play_block_sound_fine(
                         get_sound(args.block, powered),
                         SoundCategory::Blocks,
                         *args.position,
+                        1.0,
+                        pitch,
                     )
                     .await;
             }
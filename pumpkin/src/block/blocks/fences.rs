@@ -1,6 +1,9 @@
 use crate::block::BlockFuture;
 use crate::block::GetStateForNeighborUpdateArgs;
+use crate::block::NormalUseArgs;
 use crate::block::OnPlaceArgs;
+use crate::block::registry::BlockActionResult;
+use crate::entity::leash;
 use pumpkin_data::BlockDirection;
 use pumpkin_data::BlockState;
 use pumpkin_data::block_properties::BlockProperties;
@@ -23,6 +26,16 @@ impl BlockMetadata for FenceBlock {
 }
 
 impl BlockBehaviour for FenceBlock {
+    fn normal_use<'a>(&'a self, args: NormalUseArgs<'a>) -> BlockFuture<'a, BlockActionResult> {
+        Box::pin(async move {
+            if leash::bind_player_mobs(args.world, args.player, args.position).await {
+                BlockActionResult::Success
+            } else {
+                BlockActionResult::Pass
+            }
+        })
+    }
+
     fn on_place<'a>(&'a self, args: OnPlaceArgs<'a>) -> BlockFuture<'a, BlockStateId> {
         Box::pin(async move {
             let mut fence_props = FenceProperties::default(args.block);
@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use pumpkin_data::block_properties::{Axis, BlockProperties, LightningRodLikeProperties};
+use pumpkin_data::world::WorldEvent;
+use pumpkin_data::{Block, FacingExt};
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_world::BlockStateId;
+use pumpkin_world::poi::POI_TYPE_LIGHTNING_ROD;
+use pumpkin_world::tick::TickPriority;
+use pumpkin_world::world::BlockFlags;
+
+use crate::block::blocks::chests::should_oxidize;
+use crate::block::{
+    BlockBehaviour, BlockFuture, BlockMetadata, EmitsRedstonePowerArgs, GetRedstonePowerArgs,
+    OnPlaceArgs, OnScheduledTickArgs, OnStateReplacedArgs, PlacedArgs, RandomTickArgs,
+};
+use crate::world::World;
+
+/// How long a lightning rod stays powered after being struck.
+const POWERED_TICKS: u8 = 8;
+
+/// Lightning rods with the rod they oxidize into.
+const LIGHTNING_ROD_OXIDATION: [(&Block, &Block); 3] = [
+    (&Block::LIGHTNING_ROD, &Block::EXPOSED_LIGHTNING_ROD),
+    (
+        &Block::EXPOSED_LIGHTNING_ROD,
+        &Block::WEATHERED_LIGHTNING_ROD,
+    ),
+    (
+        &Block::WEATHERED_LIGHTNING_ROD,
+        &Block::OXIDIZED_LIGHTNING_ROD,
+    ),
+];
+
+pub struct LightningRodBlock;
+
+impl BlockMetadata for LightningRodBlock {
+    fn ids() -> Box<[u16]> {
+        [
+            Block::LIGHTNING_ROD.id,
+            Block::EXPOSED_LIGHTNING_ROD.id,
+            Block::WEATHERED_LIGHTNING_ROD.id,
+            Block::OXIDIZED_LIGHTNING_ROD.id,
+            Block::WAXED_LIGHTNING_ROD.id,
+            Block::WAXED_EXPOSED_LIGHTNING_ROD.id,
+            Block::WAXED_WEATHERED_LIGHTNING_ROD.id,
+            Block::WAXED_OXIDIZED_LIGHTNING_ROD.id,
+        ]
+        .into()
+    }
+}
+
+impl BlockBehaviour for LightningRodBlock {
+    fn on_place<'a>(&'a self, args: OnPlaceArgs<'a>) -> BlockFuture<'a, BlockStateId> {
+        Box::pin(async move {
+            let mut props = LightningRodLikeProperties::default(args.block);
+            props.waterlogged = args.replacing.water_source();
+
+            // Rods placed against the tip of another rod continue it
+            let (block, state) = args
+                .world
+                .get_block_and_state_id(&args.position.offset(args.direction.to_offset()))
+                .await;
+            props.facing = if Self::is_lightning_rod(block)
+                && LightningRodLikeProperties::from_state_id(state, block).facing
+                    == args.direction.to_facing().opposite()
+            {
+                args.direction.to_facing()
+            } else {
+                args.direction.to_facing().opposite()
+            };
+
+            props.to_state_id(args.block)
+        })
+    }
+
+    fn placed<'a>(&'a self, args: PlacedArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            args.world
+                .portal_poi
+                .lock()
+                .await
+                .add(*args.position, POI_TYPE_LIGHTNING_ROD);
+
+            // A rod that oxidized while powered lost the tick of the previous block
+            if LightningRodLikeProperties::from_state_id(args.state_id, args.block).powered {
+                args.world
+                    .schedule_block_tick(
+                        args.block,
+                        *args.position,
+                        POWERED_TICKS,
+                        TickPriority::Normal,
+                    )
+                    .await;
+            }
+        })
+    }
+
+    fn on_state_replaced<'a>(&'a self, args: OnStateReplacedArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            args.world.portal_poi.lock().await.remove(args.position);
+
+            let props = LightningRodLikeProperties::from_state_id(args.old_state_id, args.block);
+            if !args.moved && props.powered {
+                Self::update_neighbors(args.world, args.position, &props).await;
+            }
+        })
+    }
+
+    fn on_scheduled_tick<'a>(&'a self, args: OnScheduledTickArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let state = args.world.get_block_state_id(args.position).await;
+            let mut props = LightningRodLikeProperties::from_state_id(state, args.block);
+            if !props.powered {
+                return;
+            }
+            props.powered = false;
+            args.world
+                .set_block_state(
+                    args.position,
+                    props.to_state_id(args.block),
+                    BlockFlags::NOTIFY_ALL,
+                )
+                .await;
+            Self::update_neighbors(args.world, args.position, &props).await;
+        })
+    }
+
+    fn random_tick<'a>(&'a self, args: RandomTickArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let Some((next, level)) = LIGHTNING_ROD_OXIDATION
+                .iter()
+                .zip(0..)
+                .find_map(|((from, to), level)| (*from == args.block).then_some((*to, level)))
+            else {
+                return;
+            };
+            if !should_oxidize(args.world, args.position, level, Self::oxidation_level).await {
+                return;
+            }
+            let state = args.world.get_block_state_id(args.position).await;
+            let props = LightningRodLikeProperties::from_state_id(state, args.block);
+            args.world
+                .set_block_state(
+                    args.position,
+                    props.to_state_id(next),
+                    BlockFlags::NOTIFY_LISTENERS,
+                )
+                .await;
+        })
+    }
+
+    fn emits_redstone_power<'a>(
+        &'a self,
+        _args: EmitsRedstonePowerArgs<'a>,
+    ) -> BlockFuture<'a, bool> {
+        Box::pin(async move { true })
+    }
+
+    fn get_weak_redstone_power<'a>(
+        &'a self,
+        args: GetRedstonePowerArgs<'a>,
+    ) -> BlockFuture<'a, u8> {
+        Box::pin(async move {
+            let props = LightningRodLikeProperties::from_state_id(args.state.id, args.block);
+            if props.powered { 15 } else { 0 }
+        })
+    }
+
+    fn get_strong_redstone_power<'a>(
+        &'a self,
+        args: GetRedstonePowerArgs<'a>,
+    ) -> BlockFuture<'a, u8> {
+        Box::pin(async move {
+            let props = LightningRodLikeProperties::from_state_id(args.state.id, args.block);
+            if props.powered && props.facing.to_block_direction() == args.direction {
+                15
+            } else {
+                0
+            }
+        })
+    }
+}
+
+impl LightningRodBlock {
+    #[must_use]
+    pub fn is_lightning_rod(block: &Block) -> bool {
+        Self::ids().contains(&block.id)
+    }
+
+    /// The rod one oxidation level below `block`, if it is an unwaxed, oxidized rod.
+    #[must_use]
+    pub fn deoxidized(block: &Block) -> Option<&'static Block> {
+        LIGHTNING_ROD_OXIDATION
+            .iter()
+            .find_map(|(from, to)| (*to == block).then_some(*from))
+    }
+
+    fn oxidation_level(block: &Block) -> Option<u8> {
+        [
+            &Block::LIGHTNING_ROD,
+            &Block::EXPOSED_LIGHTNING_ROD,
+            &Block::WEATHERED_LIGHTNING_ROD,
+            &Block::OXIDIZED_LIGHTNING_ROD,
+        ]
+        .iter()
+        .position(|rod| *rod == block)
+        .map(|level| level as u8)
+    }
+
+    /// Powers the rod at `position` after lightning struck it. It unpowers itself after 8 ticks.
+    pub async fn set_powered(world: &Arc<World>, position: &BlockPos) {
+        let (block, state) = world.get_block_and_state_id(position).await;
+        if !Self::is_lightning_rod(block) {
+            return;
+        }
+        let mut props = LightningRodLikeProperties::from_state_id(state, block);
+        props.powered = true;
+        world
+            .set_block_state(position, props.to_state_id(block), BlockFlags::NOTIFY_ALL)
+            .await;
+        Self::update_neighbors(world, position, &props).await;
+        world
+            .schedule_block_tick(block, *position, POWERED_TICKS, TickPriority::Normal)
+            .await;
+
+        let axis = match props.facing.to_block_direction().to_axis() {
+            Axis::X => 0,
+            Axis::Y => 1,
+            Axis::Z => 2,
+        };
+        world
+            .sync_world_event(WorldEvent::ElectricitySparks, *position, axis)
+            .await;
+    }
+
+    // TODO: Summon lightning onto a rod hit by a channeling trident during a thunderstorm, once
+    // tridents can be thrown
+
+    async fn update_neighbors(
+        world: &Arc<World>,
+        position: &BlockPos,
+        props: &LightningRodLikeProperties,
+    ) {
+        let behind = props.facing.to_block_direction().opposite();
+        world
+            .update_neighbors(&position.offset(behind.to_offset()), None)
+            .await;
+    }
+}
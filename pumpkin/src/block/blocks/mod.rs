@@ -2,6 +2,7 @@ pub mod anvil;
 pub mod barrel;
 pub mod barrier;
 pub mod bed;
+pub mod bell;
 pub mod cake;
 pub mod campfire;
 pub mod candle_cakes;
@@ -36,6 +37,7 @@ pub mod iron_bars;
 pub mod jukebox;
 pub mod ladder;
 pub mod lanterns;
+pub mod lightning_rod;
 pub mod logs;
 pub mod magma;
 pub mod mangrove_roots;
@@ -58,6 +60,8 @@ pub mod stairs;
 pub mod tnt;
 pub mod torches;
 pub mod trapdoor;
+pub mod trial_spawner;
+pub mod vault;
 pub mod vine;
 pub mod walls;
 pub mod wither_skull;
@@ -133,8 +133,8 @@ impl BlockBehaviour for NoteBlock {
             };
             // check hasCustomSound
             args.world
-                .play_sound_raw(
-                    convert_instrument_to_sound(instrument) as u16,
+                .play_sound_fine(
+                    convert_instrument_to_sound(instrument),
                     SoundCategory::Records,
                     &args.position.to_f64(),
                     3.0,
@@ -21,6 +21,7 @@ use crate::block::GetStateForNeighborUpdateArgs;
 use crate::block::OnPlaceArgs;
 use crate::block::OnScheduledTickArgs;
 use crate::block::OnStateReplacedArgs;
+use crate::block::WindTriggeredArgs;
 use crate::block::blocks::abstract_wall_mounting::WallMountedBlock;
 use crate::block::blocks::redstone::lever::LeverLikePropertiesExt;
 use crate::block::registry::BlockActionResult;
@@ -64,6 +65,12 @@ impl BlockBehaviour for ButtonBlock {
         })
     }
 
+    fn on_wind_triggered<'a>(&'a self, args: WindTriggeredArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            click_button(args.world, args.position).await;
+        })
+    }
+
     fn on_scheduled_tick<'a>(&'a self, args: OnScheduledTickArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
             let state = args.world.get_block_state(args.position).await;
@@ -1,12 +1,68 @@
-use crate::block::{BlockBehaviour, BlockFuture, OnPlaceArgs};
+use crate::block::blocks::redstone::block_receives_redstone_power;
+use crate::block::registry::BlockActionResult;
+use crate::block::{
+    BlockBehaviour, BlockFuture, NormalUseArgs, OnNeighborUpdateArgs, OnPlaceArgs,
+    OnScheduledTickArgs, PlacedArgs,
+};
+use crate::item::dispense::{DispenseResult, DispenseSource, add_or_drop, to_data3d};
+
 use pumpkin_data::block_properties::{BlockProperties, DispenserLikeProperties};
+use pumpkin_data::translation;
+use pumpkin_data::world::WorldEvent;
+use pumpkin_inventory::generic_container_screen_handler::create_generic_3x3;
+use pumpkin_inventory::player::player_inventory::PlayerInventory;
+use pumpkin_inventory::screen_handler::{
+    BoxFuture, InventoryPlayer, ScreenHandlerFactory, SharedScreenHandler,
+};
 use pumpkin_macros::pumpkin_block;
+use pumpkin_util::text::TextComponent;
 use pumpkin_world::BlockStateId;
+use pumpkin_world::block::entities::dispenser::DispenserBlockEntity;
+use pumpkin_world::inventory::Inventory;
+use pumpkin_world::tick::TickPriority;
+use pumpkin_world::world::BlockFlags;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+struct DispenserScreenFactory(Arc<dyn Inventory>);
+
+impl ScreenHandlerFactory for DispenserScreenFactory {
+    fn create_screen_handler<'a>(
+        &'a self,
+        sync_id: u8,
+        player_inventory: &'a Arc<PlayerInventory>,
+        _player: &'a dyn InventoryPlayer,
+    ) -> BoxFuture<'a, Option<SharedScreenHandler>> {
+        Box::pin(async move {
+            let handler = create_generic_3x3(sync_id, player_inventory, self.0.clone()).await;
+            let screen_handler_arc = Arc::new(Mutex::new(handler));
+
+            Some(screen_handler_arc as SharedScreenHandler)
+        })
+    }
+
+    fn get_display_name(&self) -> TextComponent {
+        TextComponent::translate(translation::CONTAINER_DISPENSER, &[])
+    }
+}
 
 #[pumpkin_block("minecraft:dispenser")]
 pub struct DispenserBlock;
 
 impl BlockBehaviour for DispenserBlock {
+    fn normal_use<'a>(&'a self, args: NormalUseArgs<'a>) -> BlockFuture<'a, BlockActionResult> {
+        Box::pin(async move {
+            if let Some(block_entity) = args.world.get_block_entity(args.position).await
+                && let Some(inventory) = block_entity.get_inventory()
+            {
+                args.player
+                    .open_handled_screen(&DispenserScreenFactory(inventory), Some(*args.position))
+                    .await;
+            }
+            BlockActionResult::Success
+        })
+    }
+
     fn on_place<'a>(&'a self, args: OnPlaceArgs<'a>) -> BlockFuture<'a, BlockStateId> {
         Box::pin(async move {
             let mut props = DispenserLikeProperties::default(args.block);
@@ -14,4 +70,105 @@ impl BlockBehaviour for DispenserBlock {
             props.to_state_id(args.block)
         })
     }
+
+    fn placed<'a>(&'a self, args: PlacedArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let dispenser_block_entity = DispenserBlockEntity::new(*args.position);
+            args.world
+                .add_block_entity(Arc::new(dispenser_block_entity))
+                .await;
+        })
+    }
+
+    fn on_neighbor_update<'a>(&'a self, args: OnNeighborUpdateArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let powered = block_receives_redstone_power(args.world, args.position).await
+                || block_receives_redstone_power(args.world, &args.position.up()).await;
+            let mut props = DispenserLikeProperties::from_state_id(
+                args.world.get_block_state(args.position).await.id,
+                args.block,
+            );
+            if powered && !props.triggered {
+                args.world
+                    .schedule_block_tick(args.block, *args.position, 4, TickPriority::Normal)
+                    .await;
+                props.triggered = true;
+                args.world
+                    .set_block_state(
+                        args.position,
+                        props.to_state_id(args.block),
+                        BlockFlags::NOTIFY_LISTENERS,
+                    )
+                    .await;
+            } else if !powered && props.triggered {
+                props.triggered = false;
+                args.world
+                    .set_block_state(
+                        args.position,
+                        props.to_state_id(args.block),
+                        BlockFlags::NOTIFY_LISTENERS,
+                    )
+                    .await;
+            }
+        })
+    }
+
+    fn on_scheduled_tick<'a>(&'a self, args: OnScheduledTickArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let Some(block_entity) = args.world.get_block_entity(args.position).await else {
+                return;
+            };
+            let Some(server) = args.world.server.upgrade() else {
+                return;
+            };
+            let Some(dispenser) = block_entity.as_any().downcast_ref::<DispenserBlockEntity>()
+            else {
+                return;
+            };
+            let Some(mut stack) = dispenser.get_random_slot().await else {
+                args.world
+                    .sync_world_event(WorldEvent::DispenserFails, *args.position, 0)
+                    .await;
+                return;
+            };
+
+            let props = DispenserLikeProperties::from_state_id(
+                args.world.get_block_state(args.position).await.id,
+                args.block,
+            );
+            let source = DispenseSource {
+                world: args.world,
+                position: *args.position,
+                facing: props.facing,
+            };
+            let behaviour = server.dispense_registry.get(stack.item);
+            let DispenseResult::Success(remainder) = behaviour.dispense(&source, &mut stack).await
+            else {
+                args.world
+                    .sync_world_event(WorldEvent::DispenserFails, *args.position, 0)
+                    .await;
+                return;
+            };
+
+            if let Some(remainder) = remainder {
+                if stack.is_empty() {
+                    *stack = remainder;
+                } else {
+                    drop(stack);
+                    add_or_drop(&source, dispenser, remainder).await;
+                }
+            }
+            dispenser.mark_dirty();
+            args.world
+                .sync_world_event(WorldEvent::DispenserDispenses, *args.position, 0)
+                .await;
+            args.world
+                .sync_world_event(
+                    WorldEvent::DispenserActivated,
+                    *args.position,
+                    to_data3d(props.facing),
+                )
+                .await;
+        })
+    }
 }
@@ -4,11 +4,9 @@ use crate::block::{
     BlockBehaviour, BlockFuture, NormalUseArgs, OnNeighborUpdateArgs, OnPlaceArgs,
     OnScheduledTickArgs, PlacedArgs,
 };
-use crate::entity::Entity;
-use crate::entity::item::ItemEntity;
+use crate::item::dispense::{spawn_item, to_data3d};
 
-use pumpkin_data::block_properties::{BlockProperties, Facing};
-use pumpkin_data::entity::EntityType;
+use pumpkin_data::block_properties::BlockProperties;
 use pumpkin_data::world::WorldEvent;
 use pumpkin_data::{FacingExt, translation};
 use pumpkin_inventory::generic_container_screen_handler::create_generic_3x3;
@@ -17,7 +15,6 @@ use pumpkin_inventory::screen_handler::{
     BoxFuture, InventoryPlayer, ScreenHandlerFactory, SharedScreenHandler,
 };
 use pumpkin_macros::pumpkin_block;
-use pumpkin_util::math::vector3::Vector3;
 use pumpkin_util::text::TextComponent;
 use pumpkin_world::BlockStateId;
 use pumpkin_world::block::entities::dropper::DropperBlockEntity;
@@ -25,7 +22,6 @@ use pumpkin_world::block::entities::hopper::HopperBlockEntity;
 use pumpkin_world::inventory::Inventory;
 use pumpkin_world::tick::TickPriority;
 use pumpkin_world::world::BlockFlags;
-use rand::{Rng, RngExt, rng};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -56,32 +52,6 @@ pub struct DropperBlock;
 
 type DispenserLikeProperties = pumpkin_data::block_properties::DispenserLikeProperties;
 
-fn triangle<R: Rng>(rng: &mut R, min: f64, max: f64) -> f64 {
-    (rng.random::<f64>() - rng.random::<f64>()).mul_add(max, min)
-}
-
-const fn to_normal(facing: Facing) -> Vector3<f64> {
-    match facing {
-        Facing::North => Vector3::new(0., 0., -1.),
-        Facing::East => Vector3::new(1., 0., 0.),
-        Facing::South => Vector3::new(0., 0., 1.),
-        Facing::West => Vector3::new(-1., 0., 0.),
-        Facing::Up => Vector3::new(0., 1., 0.),
-        Facing::Down => Vector3::new(0., -1., 0.),
-    }
-}
-
-const fn to_data3d(facing: Facing) -> i32 {
-    match facing {
-        Facing::North => 2,
-        Facing::East => 5,
-        Facing::South => 3,
-        Facing::West => 4,
-        Facing::Up => 1,
-        Facing::Down => 0,
-    }
-}
-
 impl BlockBehaviour for DropperBlock {
     fn normal_use<'a>(&'a self, args: NormalUseArgs<'a>) -> BlockFuture<'a, BlockActionResult> {
         Box::pin(async move {
@@ -193,23 +163,7 @@ impl BlockBehaviour for DropperBlock {
                         return;
                     }
                     let drop_item = item.split(1);
-                    let facing = to_normal(props.facing);
-                    let mut position = args.position.to_centered_f64().add(&(facing * 0.7));
-                    position.y -= match props.facing {
-                        Facing::Up | Facing::Down => 0.125,
-                        _ => 0.15625,
-                    };
-                    let entity = Entity::new(args.world.clone(), position, &EntityType::ITEM);
-                    let rd = rng().random::<f64>().mul_add(0.1, 0.2);
-                    let velocity = Vector3::new(
-                        triangle(&mut rng(), facing.x * rd, 0.017_227_5 * 6.),
-                        triangle(&mut rng(), 0.2, 0.017_227_5 * 6.),
-                        triangle(&mut rng(), facing.z * rd, 0.017_227_5 * 6.),
-                    );
-                    let item_entity = Arc::new(
-                        ItemEntity::new_with_velocity(entity, drop_item, velocity, 40).await,
-                    );
-                    args.world.spawn_entity(item_entity).await;
+                    spawn_item(args.world, drop_item, 6.0, props.facing, args.position).await;
                     args.world
                         .sync_world_event(WorldEvent::DispenserDispenses, *args.position, 0)
                         .await;
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use crate::block::{
     BlockFuture, CanPlaceAtArgs, EmitsRedstonePowerArgs, GetRedstonePowerArgs,
-    GetStateForNeighborUpdateArgs, OnPlaceArgs, OnStateReplacedArgs,
+    GetStateForNeighborUpdateArgs, OnPlaceArgs, OnStateReplacedArgs, WindTriggeredArgs,
     blocks::abstract_wall_mounting::WallMountedBlock,
 };
 use pumpkin_data::{
@@ -49,6 +49,12 @@ impl BlockBehaviour for LeverBlock {
         })
     }
 
+    fn on_wind_triggered<'a>(&'a self, args: WindTriggeredArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            toggle_lever(args.world, args.position).await;
+        })
+    }
+
     fn emits_redstone_power<'a>(
         &'a self,
         _args: EmitsRedstonePowerArgs<'a>,
@@ -335,23 +335,23 @@ impl TripwireHookBlock {
         let pos = block_pos.to_f64();
         if on && !off {
             world
-                .play_sound_raw(Sound::BlockTripwireClickOn as u16, cat, &pos, 0.4, 0.6)
+                .play_sound_fine(Sound::BlockTripwireClickOn, cat, &pos, 0.4, 0.6)
                 .await;
             // TODO world.emitGameEvent((Entity)null, GameEvent.BLOCK_ACTIVATE, pos);
         } else if !on && off {
             world
-                .play_sound_raw(Sound::BlockTripwireClickOff as u16, cat, &pos, 0.4, 0.5)
+                .play_sound_fine(Sound::BlockTripwireClickOff, cat, &pos, 0.4, 0.5)
                 .await;
             // TODO world.emitGameEvent((Entity)null, GameEvent.BLOCK_DEACTIVATE, pos);
         } else if attached && !detached {
             world
-                .play_sound_raw(Sound::BlockTripwireAttach as u16, cat, &pos, 0.4, 0.7)
+                .play_sound_fine(Sound::BlockTripwireAttach, cat, &pos, 0.4, 0.7)
                 .await;
             // TODO world.emitGameEvent((Entity)null, GameEvent.BLOCK_ATTACH, pos);
         } else if !attached && detached {
             let pitch = 1.2 / rng().random::<f32>().mul_add(0.2, 0.9);
             world
-                .play_sound_raw(Sound::BlockTripwireDetach as u16, cat, &pos, 0.4, pitch)
+                .play_sound_fine(Sound::BlockTripwireDetach, cat, &pos, 0.4, pitch)
                 .await;
             // TODO world.emitGameEvent((Entity)null, GameEvent.BLOCK_DETACH, pos);
         }
@@ -1,6 +1,9 @@
 use crate::block::blocks::redstone::block_receives_redstone_power;
 use crate::block::registry::BlockActionResult;
-use crate::block::{BlockBehaviour, BlockFuture, NormalUseArgs, OnNeighborUpdateArgs, OnPlaceArgs};
+use crate::block::{
+    BlockBehaviour, BlockFuture, NormalUseArgs, OnNeighborUpdateArgs, OnPlaceArgs,
+    WindTriggeredArgs,
+};
 use crate::entity::player::Player;
 use crate::world::World;
 use pumpkin_data::BlockDirection;
@@ -12,23 +15,28 @@ use pumpkin_macros::pumpkin_block_from_tag;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_world::BlockStateId;
 use pumpkin_world::world::BlockFlags;
+use rand::{RngExt, rng};
 use std::sync::Arc;
 
 type TrapDoorProperties = pumpkin_data::block_properties::OakTrapdoorLikeProperties;
 
-async fn toggle_trapdoor(player: &Player, world: &Arc<World>, block_pos: &BlockPos) {
+async fn toggle_trapdoor(player: Option<&Player>, world: &Arc<World>, block_pos: &BlockPos) {
     let (block, block_state) = world.get_block_and_state_id(block_pos).await;
     let mut trapdoor_props = TrapDoorProperties::from_state_id(block_state, block);
     trapdoor_props.open = !trapdoor_props.open;
 
-    world
-        .play_block_sound_expect(
-            player,
-            get_sound(block, trapdoor_props.open),
-            SoundCategory::Blocks,
-            *block_pos,
-        )
-        .await;
+    let pitch = rng().random::<f32>().mul_add(0.1, 0.9);
+    let sound = get_sound(block, trapdoor_props.open);
+
+    if let Some(player) = player {
+        world
+            .play_block_sound_expect(player, sound, SoundCategory::Blocks, *block_pos, 1.0, pitch)
+            .await;
+    } else {
+        world
+            .play_block_sound_fine(sound, SoundCategory::Blocks, *block_pos, 1.0, pitch)
+            .await;
+    }
 
     world
         .set_block_state(
@@ -74,12 +82,21 @@ impl BlockBehaviour for TrapDoorBlock {
                 return BlockActionResult::Pass;
             }
 
-            toggle_trapdoor(args.player, args.world, args.position).await;
+            toggle_trapdoor(Some(args.player), args.world, args.position).await;
 
             BlockActionResult::Success
         })
     }
 
+    fn on_wind_triggered<'a>(&'a self, args: WindTriggeredArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let props = TrapDoorProperties::from_state_id(args.state.id, args.block);
+            if can_open_trapdoor(args.block) && !props.powered {
+                toggle_trapdoor(None, args.world, args.position).await;
+            }
+        })
+    }
+
     fn on_place<'a>(&'a self, args: OnPlaceArgs<'a>) -> BlockFuture<'a, BlockStateId> {
         Box::pin(async move {
             let mut trapdoor_props = TrapDoorProperties::default(args.block);
@@ -125,11 +142,15 @@ impl BlockBehaviour for TrapDoorBlock {
                 if powered != trapdoor_props.open {
                     trapdoor_props.open = trapdoor_props.powered;
 
+                    let pitch = rng().random::<f32>().mul_add(0.1, 0.9);
+
                     args.world
-                        .play_block_sound(
+                        .play_block_sound_fine(
                             get_sound(args.block, powered),
                             SoundCategory::Blocks,
                             *args.position,
+                            1.0,
+                            pitch,
                         )
                         .await;
                 }
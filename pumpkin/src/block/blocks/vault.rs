@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use pumpkin_data::block_properties::{BlockProperties, VaultLikeProperties};
+use pumpkin_macros::pumpkin_block;
+use pumpkin_world::block::entities::vault::{VaultBlockEntity, VaultUnlockResult};
+use pumpkin_world::world::SimpleWorld;
+
+use crate::block::registry::BlockActionResult;
+use crate::block::{BlockBehaviour, BlockFuture, PlacedArgs, UseWithItemArgs};
+
+#[pumpkin_block("minecraft:vault")]
+pub struct VaultBlock;
+
+impl BlockBehaviour for VaultBlock {
+    fn placed<'a>(&'a self, args: PlacedArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let ominous = VaultLikeProperties::from_state_id(args.state_id, args.block).ominous;
+            let vault = VaultBlockEntity::new(*args.position, ominous);
+            args.world.add_block_entity(Arc::new(vault)).await;
+        })
+    }
+
+    fn use_with_item<'a>(
+        &'a self,
+        args: UseWithItemArgs<'a>,
+    ) -> BlockFuture<'a, BlockActionResult> {
+        Box::pin(async move {
+            let Some(block_entity) = args.world.get_block_entity(args.position).await else {
+                return BlockActionResult::PassToDefaultBlockAction;
+            };
+            let Some(vault) = block_entity.as_any().downcast_ref::<VaultBlockEntity>() else {
+                return BlockActionResult::PassToDefaultBlockAction;
+            };
+
+            let mut stack = args.item_stack.lock().await;
+            if stack.is_empty() {
+                return BlockActionResult::PassToDefaultBlockAction;
+            }
+            let world: Arc<dyn SimpleWorld> = args.world.clone();
+            match vault
+                .try_unlock(&world, args.player.gameprofile.id, &stack)
+                .await
+            {
+                VaultUnlockResult::NotActive => BlockActionResult::PassToDefaultBlockAction,
+                VaultUnlockResult::Unlocked => {
+                    stack.decrement_unless_creative(args.player.gamemode.load(), 1);
+                    BlockActionResult::Success
+                }
+                VaultUnlockResult::InvalidKey | VaultUnlockResult::AlreadyRewarded => {
+                    BlockActionResult::Success
+                }
+            }
+        })
+    }
+}
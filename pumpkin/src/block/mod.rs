@@ -88,6 +88,12 @@ pub trait BlockBehaviour: Send + Sync {
         Box::pin(async {})
     }
 
+    /// Called when a wind burst reaches this block. The block itself is never destroyed,
+    /// but things like buttons, levers, doors and trapdoors get to react to the gust.
+    fn on_wind_triggered<'a>(&'a self, _args: WindTriggeredArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async {})
+    }
+
     /// Handles the block event, which is an event specific to a block with an integer ID and data.
     ///
     /// returns whether the event was handled successfully
@@ -260,6 +266,13 @@ pub struct ExplodeArgs<'a> {
     pub position: &'a BlockPos,
 }
 
+pub struct WindTriggeredArgs<'a> {
+    pub world: &'a Arc<World>,
+    pub block: &'a Block,
+    pub state: &'a BlockState,
+    pub position: &'a BlockPos,
+}
+
 pub struct OnSyncedBlockEventArgs<'a> {
     pub world: &'a Arc<World>,
     pub block: &'a Block,
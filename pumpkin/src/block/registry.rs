@@ -4,6 +4,7 @@ use crate::block::blocks::banners::BannerBlock;
 use crate::block::blocks::barrel::BarrelBlock;
 use crate::block::blocks::barrier::BarrierBlock;
 use crate::block::blocks::bed::BedBlock;
+use crate::block::blocks::bell::BellBlock;
 use crate::block::blocks::cake::CakeBlock;
 use crate::block::blocks::campfire::CampfireBlock;
 use crate::block::blocks::candle_cakes::CandleCakeBlock;
@@ -104,6 +105,8 @@ use crate::block::blocks::stairs::StairBlock;
 use crate::block::blocks::tnt::TNTBlock;
 use crate::block::blocks::torches::TorchBlock;
 use crate::block::blocks::trapdoor::TrapDoorBlock;
+use crate::block::blocks::trial_spawner::TrialSpawnerBlock;
+use crate::block::blocks::vault::VaultBlock;
 use crate::block::blocks::vine::VineBlock;
 use crate::block::blocks::walls::WallBlock;
 use crate::block::blocks::wither_skull::WitherSkeletonSkullBlock;
@@ -118,6 +121,7 @@ use crate::entity::EntityBase;
 use crate::entity::player::Player;
 use crate::server::Server;
 use crate::world::World;
+use crate::world::redstone_debug::UpdateKind;
 use pumpkin_data::fluid::Fluid;
 use pumpkin_data::item::Item;
 use pumpkin_data::{Block, BlockDirection, BlockState};
@@ -153,6 +157,7 @@ use crate::block::blocks::jukebox::JukeboxBlock;
 use crate::block::blocks::ladder::LadderBlock;
 use crate::block::blocks::lanterns::LanternBlock;
 use crate::block::blocks::lectern::LecternBlock;
+use crate::block::blocks::lightning_rod::LightningRodBlock;
 use crate::block::blocks::shulker_box::ShulkerBoxBlock;
 use crate::block::blocks::skull_block::SkullBlock;
 use crate::block::blocks::smoker::SmokerBlock;
@@ -165,6 +170,7 @@ pub fn default_registry() -> Arc<BlockRegistry> {
     // Blocks
     manager.register(AnvilBlock);
     manager.register(BedBlock);
+    manager.register(BellBlock);
     manager.register(SaplingBlock);
     manager.register(CactusBlock);
     manager.register(CarpetBlock);
@@ -239,6 +245,8 @@ pub fn default_registry() -> Arc<BlockRegistry> {
     manager.register(WeightedPressurePlateBlock);
     manager.register(EndPortalBlock);
     manager.register(SpawnerBlock);
+    manager.register(TrialSpawnerBlock);
+    manager.register(VaultBlock);
     manager.register(EndPortalFrameBlock);
     manager.register(CandleBlock);
     manager.register(SeaPickleBlock);
@@ -253,6 +261,7 @@ pub fn default_registry() -> Arc<BlockRegistry> {
     manager.register(ChainBlock);
     manager.register(LanternBlock);
     manager.register(EndRodBlock);
+    manager.register(LightningRodBlock);
     manager.register(BarrierBlock);
     manager.register(MangroveRootsBlock);
     manager.register(LayeredSnowBlock);
@@ -887,6 +896,10 @@ impl BlockRegistry {
         source_block: &Block,
         notify: bool,
     ) {
+        world
+            .redstone_debugger
+            .record_update(*position, UpdateKind::Neighbor)
+            .await;
         let pumpkin_block = self.get_pumpkin_block(block.id);
         if let Some(pumpkin_block) = pumpkin_block {
             pumpkin_block
@@ -3,3 +3,4 @@ pub mod effect;
 pub mod enchantment;
 pub mod item;
 pub mod particle;
+pub mod recipe;
@@ -0,0 +1,85 @@
+use pumpkin_protocol::java::client::play::{ArgumentType, CommandSuggestion, SuggestionProviders};
+
+use crate::command::{
+    CommandSender,
+    args::{
+        Arg, ArgumentConsumer, ConsumeResult, ConsumedArgs, DefaultNameArgConsumer, FindArg,
+        GetClientSideArgParser, SplitSingleWhitespaceIncludingEmptyParts, SuggestResult,
+    },
+    dispatcher::CommandError,
+    tree::RawArgs,
+};
+use crate::server::Server;
+
+/// A recipe id. Newer clients don't know the recipes, so they are suggested by the server.
+pub struct RecipeArgumentConsumer;
+
+impl GetClientSideArgParser for RecipeArgumentConsumer {
+    fn get_client_side_parser(&self) -> ArgumentType<'_> {
+        ArgumentType::ResourceLocation
+    }
+
+    fn get_client_side_suggestion_type_override(&self) -> Option<SuggestionProviders> {
+        Some(SuggestionProviders::AskServer)
+    }
+}
+
+impl ArgumentConsumer for RecipeArgumentConsumer {
+    fn consume<'a, 'b>(
+        &'a self,
+        _sender: &'a CommandSender,
+        server: &'a Server,
+        args: &'b mut RawArgs<'a>,
+    ) -> ConsumeResult<'a> {
+        let s_opt: Option<&'a str> = args.pop();
+
+        Box::pin(async move {
+            s_opt
+                .filter(|id| server.recipe_manager.get(id).is_some())
+                .map(Arg::ResourceLocation)
+        })
+    }
+
+    fn suggest<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        server: &'a Server,
+        input: &'a str,
+    ) -> SuggestResult<'a> {
+        Box::pin(async move {
+            let Some(input) = input.split_single_whitespace_including_empty_parts().last() else {
+                return Ok(None);
+            };
+            let suggestions = server
+                .recipe_manager
+                .iter()
+                .filter(|recipe| {
+                    recipe.id.starts_with(input)
+                        || recipe
+                            .id
+                            .strip_prefix("minecraft:")
+                            .is_some_and(|id| id.starts_with(input))
+                })
+                .map(|recipe| CommandSuggestion::new(recipe.id.clone(), None))
+                .collect();
+            Ok(Some(suggestions))
+        })
+    }
+}
+
+impl DefaultNameArgConsumer for RecipeArgumentConsumer {
+    fn default_name(&self) -> &'static str {
+        "recipe"
+    }
+}
+
+impl<'a> FindArg<'a> for RecipeArgumentConsumer {
+    type Data = &'a str;
+
+    fn find_arg(args: &'a ConsumedArgs, name: &str) -> Result<Self::Data, CommandError> {
+        match args.get(name) {
+            Some(Arg::ResourceLocation(data)) => Ok(data),
+            _ => Err(CommandError::InvalidConsumption(Some(name.to_string()))),
+        }
+    }
+}
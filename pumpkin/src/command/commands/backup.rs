@@ -0,0 +1,111 @@
+use pumpkin_util::text::TextComponent;
+
+use crate::command::args::simple::SimpleArgConsumer;
+use crate::command::args::{ConsumedArgs, FindArg};
+use crate::command::tree::CommandTree;
+use crate::command::tree::builder::{argument, literal};
+use crate::command::{CommandError, CommandExecutor, CommandResult, CommandSender};
+use crate::server::Server;
+
+const NAMES: [&str; 1] = ["backup"];
+const DESCRIPTION: &str = "Creates, lists and restores world backups.";
+
+const ARG_NAME: &str = "name";
+
+fn failed(message: String) -> CommandError {
+    CommandError::CommandFailed(TextComponent::text(message))
+}
+
+struct NowExecutor;
+
+impl CommandExecutor for NowExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a Server,
+        _args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            sender
+                .send_message(TextComponent::text("Creating backup..."))
+                .await;
+            let (name, stats) = server
+                .backup_manager
+                .create(server)
+                .await
+                .map_err(|err| failed(format!("Failed to create backup: {err}")))?;
+            sender
+                .send_message(TextComponent::text(format!(
+                    "Created backup {name} ({} files copied, {} unchanged)",
+                    stats.copied, stats.linked
+                )))
+                .await;
+            Ok(1)
+        })
+    }
+}
+
+struct ListExecutor;
+
+impl CommandExecutor for ListExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a Server,
+        _args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let backups = server
+                .backup_manager
+                .list()
+                .await
+                .map_err(|err| failed(format!("Failed to list backups: {err}")))?;
+            let message = if backups.is_empty() {
+                "There are no backups".to_string()
+            } else {
+                format!(
+                    "There are {} backups: {}",
+                    backups.len(),
+                    backups.join(", ")
+                )
+            };
+            sender.send_message(TextComponent::text(message)).await;
+            Ok(backups.len() as i32)
+        })
+    }
+}
+
+struct RestoreExecutor;
+
+impl CommandExecutor for RestoreExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let name = SimpleArgConsumer::find_arg(args, ARG_NAME)?;
+            server
+                .backup_manager
+                .schedule_restore(name)
+                .await
+                .map_err(|err| failed(format!("Failed to restore backup {name}: {err}")))?;
+            sender
+                .send_message(TextComponent::text(format!(
+                    "Backup {name} will be restored when the server is restarted"
+                )))
+                .await;
+            Ok(1)
+        })
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(literal("now").execute(NowExecutor))
+        .then(literal("list").execute(ListExecutor))
+        .then(
+            literal("restore").then(argument(ARG_NAME, SimpleArgConsumer).execute(RestoreExecutor)),
+        )
+}
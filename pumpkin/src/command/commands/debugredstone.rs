@@ -0,0 +1,108 @@
+use pumpkin_util::text::TextComponent;
+
+use crate::command::args::bounded_num::BoundedNumArgumentConsumer;
+use crate::command::args::position_block::BlockPosArgumentConsumer;
+use crate::command::args::{ConsumedArgs, FindArg};
+use crate::command::tree::CommandTree;
+use crate::command::tree::builder::{argument, literal};
+use crate::command::{CommandError, CommandExecutor, CommandResult, CommandSender};
+use crate::server::Server;
+use crate::world::redstone_debug::{MAX_VOLUME, volume};
+
+const NAMES: [&str; 1] = ["debugredstone"];
+const DESCRIPTION: &str =
+    "Shows redstone power, scheduled ticks and update order of a region for a while.";
+
+const ARG_FROM: &str = "from";
+const ARG_TO: &str = "to";
+const ARG_SECONDS: &str = "seconds";
+
+const DEFAULT_SECONDS: i32 = 10;
+const MAX_SECONDS: i32 = 300;
+
+struct StartExecutor;
+
+impl CommandExecutor for StartExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        _server: &'a Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let player = sender.as_player().ok_or(CommandError::InvalidRequirement)?;
+            let from = BlockPosArgumentConsumer::find_arg(args, ARG_FROM)?;
+            let to = BlockPosArgumentConsumer::find_arg(args, ARG_TO)?;
+            let seconds = BoundedNumArgumentConsumer::<i32>::find_arg(args, ARG_SECONDS)
+                .unwrap_or(Ok(DEFAULT_SECONDS))?;
+
+            let volume = volume(&from, &to);
+            if volume > MAX_VOLUME {
+                return Err(CommandError::CommandFailed(TextComponent::text(format!(
+                    "Too many blocks in the specified area (maximum {MAX_VOLUME}, specified {volume})"
+                ))));
+            }
+
+            player
+                .world()
+                .redstone_debugger
+                .start(player.gameprofile.id, from, to, seconds as u32 * 20)
+                .await;
+            sender
+                .send_message(TextComponent::text(format!(
+                    "Debugging redstone from {from} to {to} for {seconds} seconds"
+                )))
+                .await;
+            Ok(1)
+        })
+    }
+}
+
+struct StopExecutor;
+
+impl CommandExecutor for StopExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        _server: &'a Server,
+        _args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let player = sender.as_player().ok_or(CommandError::InvalidRequirement)?;
+            if !player
+                .world()
+                .redstone_debugger
+                .stop(player.gameprofile.id)
+                .await
+            {
+                return Err(CommandError::CommandFailed(TextComponent::text(
+                    "You are not debugging any redstone",
+                )));
+            }
+            sender
+                .send_message(TextComponent::text("Stopped debugging redstone"))
+                .await;
+            Ok(1)
+        })
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(literal("stop").execute(StopExecutor))
+        .then(
+            argument(ARG_FROM, BlockPosArgumentConsumer).then(
+                argument(ARG_TO, BlockPosArgumentConsumer)
+                    .execute(StartExecutor)
+                    .then(
+                        argument(
+                            ARG_SECONDS,
+                            BoundedNumArgumentConsumer::<i32>::new()
+                                .min(1)
+                                .max(MAX_SECONDS),
+                        )
+                        .execute(StartExecutor),
+                    ),
+            ),
+        )
+}
@@ -7,6 +7,7 @@ use tokio::sync::RwLock;
 
 use super::dispatcher::CommandDispatcher;
 
+mod backup;
 mod ban;
 mod banip;
 mod banlist;
@@ -14,6 +15,7 @@ mod bossbar;
 mod clear;
 mod damage;
 mod data;
+mod debugredstone;
 pub mod defaultgamemode;
 mod deop;
 mod difficulty;
@@ -38,8 +40,10 @@ mod playsound;
 mod plugin;
 mod plugins;
 mod pumpkin;
+mod recipe;
 mod rotate;
 mod say;
+mod schem;
 mod seed;
 mod setblock;
 mod setidletimeout;
@@ -94,6 +98,12 @@ pub async fn default_dispatcher(
     dispatcher.register(seed::init_command_tree(), "minecraft:command.seed");
     dispatcher.register(tps::init_command_tree(), "pumpkin:command.tps");
     dispatcher.register(fill::init_command_tree(), "minecraft:command.fill");
+    dispatcher.register(schem::init_command_tree(), "pumpkin:command.schem");
+    dispatcher.register(backup::init_command_tree(), "pumpkin:command.backup");
+    dispatcher.register(
+        debugredstone::init_command_tree(),
+        "pumpkin:command.debugredstone",
+    );
     dispatcher.register(
         playsound::init_command_tree(),
         "minecraft:command.playsound",
@@ -134,6 +144,7 @@ pub async fn default_dispatcher(
         "minecraft:command.spawnpoint",
     );
     dispatcher.register(data::init_command_tree(), "minecraft:command.data");
+    dispatcher.register(recipe::init_command_tree(), "minecraft:command.recipe");
     // Three
     dispatcher.register(op::init_command_tree(), "minecraft:command.op");
     dispatcher.register(deop::init_command_tree(), "minecraft:command.deop");
@@ -288,6 +299,27 @@ fn register_level_2_permissions(registry: &mut PermissionRegistry) {
             PermissionDefault::Op(PermissionLvl::Two),
         ))
         .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "pumpkin:command.schem",
+            "Saves, loads and pastes schematics",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "pumpkin:command.debugredstone",
+            "Shows redstone power, scheduled ticks and update order of a region",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "pumpkin:command.backup",
+            "Creates, lists and restores world backups",
+            PermissionDefault::Op(PermissionLvl::Four),
+        ))
+        .unwrap();
     registry
         .register_permission(Permission::new(
             "minecraft:command.playsound",
@@ -407,6 +439,13 @@ fn register_level_2_permissions(registry: &mut PermissionRegistry) {
             PermissionDefault::Op(PermissionLvl::Two),
         ))
         .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "minecraft:command.recipe",
+            "Gives or takes player recipes",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .unwrap();
     registry
         .register_permission(Permission::new(
             "minecraft:command.enchant",
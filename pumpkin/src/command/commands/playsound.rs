@@ -6,6 +6,7 @@ use pumpkin_util::text::TextComponent;
 use rand::{RngExt, rng};
 
 use crate::entity::EntityBase;
+use crate::world::sound_range_squared;
 use crate::{
     command::{
         CommandExecutor, CommandResult, CommandSender,
@@ -109,7 +110,7 @@ impl CommandExecutor for Executor {
                 _ => 1.0,                // Default pitch
             };
 
-            // Get optional minimum volume (currently unused in implementation)
+            // Get optional minimum volume for players outside of hearing range
             let min_volume = match BoundedNumArgumentConsumer::<f32>::find_arg(args, ARG_MIN_VOLUME)
             {
                 Ok(Ok(v)) => v,
@@ -117,26 +118,38 @@ impl CommandExecutor for Executor {
             };
 
             // Use same random seed for all targets to ensure sound synchronization
-            let seed = rng().random::<f64>();
+            let seed = rng().random::<i64>();
+            let range_squared = sound_range_squared(volume);
 
             // Track how many players actually received the sound
             let mut players_who_heard = 0;
 
             // Play sound for each target player
             for target in targets {
-                let pos = position.unwrap_or(target.living_entity.entity.pos.load());
+                let mut pos = position.unwrap_or(target.living_entity.entity.pos.load());
+                let mut target_volume = volume;
 
-                // Check if player can hear the sound based on volume and distance
+                // Players out of range only hear the sound if a minimum volume is set, in which
+                // case it is relayed from just in front of them in the sound's direction
                 let player_pos = target.living_entity.entity.pos.load();
-                let distance = player_pos.squared_distance_to_vec(&pos);
-                let max_distance: f64 = (16.0 * volume).into(); // 16 blocks is base distance at volume 1.0
-
-                if distance <= max_distance || min_volume > 0.0 {
-                    target
-                        .play_sound(sound as u16, source, &pos, volume, pitch, seed)
-                        .await;
-                    players_who_heard += 1;
+                let distance_squared = player_pos.squared_distance_to_vec(&pos);
+                if distance_squared > range_squared {
+                    if min_volume <= 0.0 {
+                        continue;
+                    }
+                    let distance = distance_squared.sqrt();
+                    pos = player_pos.add(&pos.sub(&player_pos).multiply(
+                        2.0 / distance,
+                        2.0 / distance,
+                        2.0 / distance,
+                    ));
+                    target_volume = min_volume;
                 }
+
+                target
+                    .play_sound(sound as u16, source, &pos, target_volume, pitch, seed)
+                    .await;
+                players_who_heard += 1;
             }
 
             // Send appropriate message based on results
@@ -0,0 +1,134 @@
+use pumpkin_data::translation;
+use pumpkin_util::text::TextComponent;
+
+use crate::command::args::players::PlayersArgumentConsumer;
+use crate::command::args::resource::recipe::RecipeArgumentConsumer;
+use crate::command::args::{Arg, ConsumedArgs, FindArg};
+use crate::command::dispatcher::CommandError::{self, InvalidConsumption};
+use crate::command::tree::CommandTree;
+use crate::command::tree::builder::{argument, literal};
+use crate::command::{CommandExecutor, CommandResult, CommandSender};
+use crate::entity::EntityBase;
+use crate::server::Server;
+use crate::server::recipe_manager::RecipeHolder;
+
+const NAMES: [&str; 1] = ["recipe"];
+
+const DESCRIPTION: &str = "Gives or takes player recipes.";
+
+const ARG_TARGETS: &str = "targets";
+const ARG_RECIPE: &str = "recipe";
+
+#[derive(Clone, Copy)]
+enum Mode {
+    Give,
+    Take,
+}
+
+/// Either a single recipe, or `*` for every recipe.
+struct RecipeExecutor(Mode, bool);
+
+impl CommandExecutor for RecipeExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let Some(Arg::Players(targets)) = args.get(ARG_TARGETS) else {
+                return Err(InvalidConsumption(Some(ARG_TARGETS.into())));
+            };
+            let recipes: Vec<&RecipeHolder> = if self.1 {
+                server.recipe_manager.iter().collect()
+            } else {
+                let id = RecipeArgumentConsumer::find_arg(args, ARG_RECIPE)?;
+                let recipe = server.recipe_manager.get(id).ok_or_else(|| {
+                    CommandError::CommandFailed(TextComponent::translate(
+                        translation::RECIPE_NOTFOUND,
+                        [TextComponent::text(id.to_string())],
+                    ))
+                })?;
+                vec![recipe]
+            };
+
+            let mut changed = 0;
+            for target in targets {
+                changed += match self.0 {
+                    Mode::Give => {
+                        target
+                            .recipe_book
+                            .award(target, recipes.iter().copied())
+                            .await
+                    }
+                    Mode::Take => {
+                        target
+                            .recipe_book
+                            .take(target, recipes.iter().copied())
+                            .await
+                    }
+                };
+            }
+
+            let (failed, single, multiple) = match self.0 {
+                Mode::Give => (
+                    translation::COMMANDS_RECIPE_GIVE_FAILED,
+                    translation::COMMANDS_RECIPE_GIVE_SUCCESS_SINGLE,
+                    translation::COMMANDS_RECIPE_GIVE_SUCCESS_MULTIPLE,
+                ),
+                Mode::Take => (
+                    translation::COMMANDS_RECIPE_TAKE_FAILED,
+                    translation::COMMANDS_RECIPE_TAKE_SUCCESS_SINGLE,
+                    translation::COMMANDS_RECIPE_TAKE_SUCCESS_MULTIPLE,
+                ),
+            };
+            if changed == 0 {
+                return Err(CommandError::CommandFailed(TextComponent::translate(
+                    failed,
+                    [],
+                )));
+            }
+            let recipe_count = TextComponent::text(recipes.len().to_string());
+            if targets.len() == 1 {
+                sender
+                    .send_message(TextComponent::translate(
+                        single,
+                        [recipe_count, targets[0].get_display_name().await],
+                    ))
+                    .await;
+            } else {
+                sender
+                    .send_message(TextComponent::translate(
+                        multiple,
+                        [recipe_count, TextComponent::text(targets.len().to_string())],
+                    ))
+                    .await;
+            }
+            Ok(changed as i32)
+        })
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(
+            literal("give").then(
+                argument(ARG_TARGETS, PlayersArgumentConsumer)
+                    .then(literal("*").execute(RecipeExecutor(Mode::Give, true)))
+                    .then(
+                        argument(ARG_RECIPE, RecipeArgumentConsumer)
+                            .execute(RecipeExecutor(Mode::Give, false)),
+                    ),
+            ),
+        )
+        .then(
+            literal("take").then(
+                argument(ARG_TARGETS, PlayersArgumentConsumer)
+                    .then(literal("*").execute(RecipeExecutor(Mode::Take, true)))
+                    .then(
+                        argument(ARG_RECIPE, RecipeArgumentConsumer)
+                            .execute(RecipeExecutor(Mode::Take, false)),
+                    ),
+            ),
+        )
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::text::TextComponent;
+use pumpkin_world::generation::structure::template::BlockRotation;
+
+use crate::command::args::position_block::BlockPosArgumentConsumer;
+use crate::command::args::simple::SimpleArgConsumer;
+use crate::command::args::{ConsumedArgs, FindArg};
+use crate::command::tree::CommandTree;
+use crate::command::tree::builder::{argument, literal};
+use crate::command::{CommandError, CommandExecutor, CommandResult, CommandSender};
+use crate::server::Server;
+use crate::server::schematic::SchematicManager;
+
+const NAMES: [&str; 2] = ["schem", "schematic"];
+
+const DESCRIPTION: &str = "Saves, loads and pastes Sponge schematics.";
+
+const ARG_NAME: &str = "name";
+const ARG_FROM: &str = "from";
+const ARG_TO: &str = "to";
+const ARG_POSITION: &str = "position";
+
+const ROTATIONS: [(&str, BlockRotation); 4] = [
+    ("none", BlockRotation::None),
+    ("clockwise_90", BlockRotation::Clockwise90),
+    ("180", BlockRotation::Rotate180),
+    ("counterclockwise_90", BlockRotation::CounterClockwise90),
+];
+
+fn failed(message: String) -> CommandError {
+    CommandError::CommandFailed(TextComponent::text(message))
+}
+
+fn max_block_modifications(server: &Server) -> i64 {
+    server.level_info.load().game_rules.max_block_modifications
+}
+
+/// The block the sender stands in, falling back to `fallback` for senders without a position.
+fn sender_block_pos(sender: &CommandSender, fallback: BlockPos) -> BlockPos {
+    sender.position().map_or(fallback, BlockPos::floored_v)
+}
+
+struct SaveExecutor;
+
+impl CommandExecutor for SaveExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let name = SimpleArgConsumer::find_arg(args, ARG_NAME)?;
+            let from = BlockPosArgumentConsumer::find_arg(args, ARG_FROM)?;
+            let to = BlockPosArgumentConsumer::find_arg(args, ARG_TO)?;
+            let world = sender.world().ok_or(CommandError::InvalidRequirement)?;
+
+            if !world.is_in_build_limit(from) || !world.is_in_build_limit(to) {
+                return Err(CommandError::CommandFailed(TextComponent::translate(
+                    "argument.pos.outofbounds",
+                    [],
+                )));
+            }
+
+            let max_block_modifications = max_block_modifications(server);
+            let total_blocks = i64::from((from.0.x - to.0.x).abs() + 1)
+                * i64::from((from.0.y - to.0.y).abs() + 1)
+                * i64::from((from.0.z - to.0.z).abs() + 1);
+            if total_blocks > max_block_modifications {
+                return Err(failed(format!(
+                    "Too many blocks in the specified area (maximum {max_block_modifications}, specified {total_blocks})"
+                )));
+            }
+
+            let origin = sender_block_pos(sender, from);
+            let schematic = SchematicManager::copy(&world, from, to, origin)
+                .await
+                .map_err(|err| failed(format!("Failed to copy schematic {name}: {err}")))?;
+            server
+                .schematic_manager
+                .save(name, &schematic)
+                .await
+                .map_err(|err| failed(format!("Failed to save schematic {name}: {err}")))?;
+            server
+                .schematic_manager
+                .set_clipboard(sender.to_string(), Arc::new(schematic))
+                .await;
+
+            sender
+                .send_message(TextComponent::text(format!(
+                    "Saved {total_blocks} blocks to schematic {name}"
+                )))
+                .await;
+            Ok(total_blocks as i32)
+        })
+    }
+}
+
+struct LoadExecutor;
+
+impl CommandExecutor for LoadExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let name = SimpleArgConsumer::find_arg(args, ARG_NAME)?;
+            let schematic = server
+                .schematic_manager
+                .load(name)
+                .await
+                .map_err(|err| failed(format!("Failed to load schematic {name}: {err}")))?;
+            let size = schematic.size;
+            server
+                .schematic_manager
+                .set_clipboard(sender.to_string(), Arc::new(schematic))
+                .await;
+
+            sender
+                .send_message(TextComponent::text(format!(
+                    "Loaded schematic {name} ({}x{}x{}) into your clipboard",
+                    size.x, size.y, size.z
+                )))
+                .await;
+            Ok(1)
+        })
+    }
+}
+
+struct PasteExecutor(BlockRotation);
+
+impl CommandExecutor for PasteExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let world = sender.world().ok_or(CommandError::InvalidRequirement)?;
+            let origin = match BlockPosArgumentConsumer::find_arg(args, ARG_POSITION) {
+                Ok(position) => position,
+                Err(_) => {
+                    BlockPos::floored_v(sender.position().ok_or(CommandError::InvalidRequirement)?)
+                }
+            };
+            let schematic = server
+                .schematic_manager
+                .clipboard(&sender.to_string())
+                .await
+                .ok_or_else(|| {
+                    failed("Your clipboard is empty, load a schematic first".to_string())
+                })?;
+
+            let max_block_modifications = max_block_modifications(server);
+            let total_blocks = schematic.blocks.len() as i64;
+            if total_blocks > max_block_modifications {
+                return Err(failed(format!(
+                    "Too many blocks in the schematic (maximum {max_block_modifications}, specified {total_blocks})"
+                )));
+            }
+
+            let placed = SchematicManager::paste(&world, &schematic, origin, self.0, false).await;
+            sender
+                .send_message(TextComponent::text(format!("Pasted {placed} blocks")))
+                .await;
+            Ok(placed as i32)
+        })
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    let mut paste_at = argument(ARG_POSITION, BlockPosArgumentConsumer)
+        .execute(PasteExecutor(BlockRotation::None));
+    for (name, rotation) in ROTATIONS {
+        paste_at = paste_at.then(literal(name).execute(PasteExecutor(rotation)));
+    }
+
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(
+            literal("save").then(
+                argument(ARG_NAME, SimpleArgConsumer).then(
+                    argument(ARG_FROM, BlockPosArgumentConsumer)
+                        .then(argument(ARG_TO, BlockPosArgumentConsumer).execute(SaveExecutor)),
+                ),
+            ),
+        )
+        .then(literal("load").then(argument(ARG_NAME, SimpleArgConsumer).execute(LoadExecutor)))
+        .then(
+            literal("paste")
+                .then(paste_at)
+                .execute(PasteExecutor(BlockRotation::None)),
+        )
+}
@@ -15,6 +15,7 @@ use crate::plugin::world::spawn_change::SpawnChangeEvent;
 use crate::server::Server;
 use pumpkin_data::dimension::Dimension;
 use pumpkin_data::translation;
+use pumpkin_protocol::java::client::play::CPlayerSpawnPosition;
 use pumpkin_util::{math::position::BlockPos, text::TextComponent};
 
 const NAMES: [&str; 1] = ["setworldspawn"];
@@ -148,6 +149,16 @@ async fn setworldspawn(
     new_info.spawn_pitch = new_pitch;
 
     server.level_info.store(Arc::new(new_info));
+    // Spawn protection and respawn scattering read the spawn from level info, so they follow
+    // along; only the clients' compasses need to be told
+    world
+        .broadcast_packet_all(&CPlayerSpawnPosition::new(
+            new_position,
+            new_yaw,
+            new_pitch,
+            world.dimension.minecraft_name.to_owned(),
+        ))
+        .await;
 
     sender
         .send_message(TextComponent::translate(
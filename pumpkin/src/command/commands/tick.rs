@@ -235,11 +235,16 @@ impl CommandExecutor for TickExecutor {
                     Self::handle_set_tick_rate(sender, server, manager, rate).await
                 }
                 SubCommand::Freeze(freeze) => {
+                    if freeze {
+                        // Freezing ends a sprint or step that is still running
+                        manager.stop_sprinting(server).await;
+                        manager.stop_stepping(server).await;
+                    }
                     manager.set_frozen(server, freeze).await;
                     let message_key = if freeze {
-                        "commands.tick.status.frozen"
+                        translation::COMMANDS_TICK_STATUS_FROZEN
                     } else {
-                        "commands.tick.status.running"
+                        translation::COMMANDS_TICK_STATUS_RUNNING
                     };
                     sender
                         .send_message(TextComponent::translate(message_key, []))
@@ -263,7 +268,7 @@ impl CommandExecutor for TickExecutor {
                     if manager.stop_stepping(server).await {
                         sender
                             .send_message(TextComponent::translate(
-                                translation::COMMANDS_TICK_SPRINT_STOP_SUCCESS,
+                                translation::COMMANDS_TICK_STEP_STOP_SUCCESS,
                                 [],
                             ))
                             .await;
@@ -272,7 +277,7 @@ impl CommandExecutor for TickExecutor {
                         // TODO: send feedback as error without Err
                         sender
                             .send_message(TextComponent::translate(
-                                translation::COMMANDS_TICK_SPRINT_STOP_FAIL,
+                                translation::COMMANDS_TICK_STEP_STOP_FAIL,
                                 [],
                             ))
                             .await;
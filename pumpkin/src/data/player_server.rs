@@ -11,6 +11,7 @@ use std::{
     path::PathBuf,
     time::{Duration, Instant},
 };
+use tokio::sync::{RwLock, RwLockWriteGuard};
 use tracing::{debug, error};
 
 /// Helper for managing player data in the server context.
@@ -21,6 +22,8 @@ pub struct ServerPlayerData {
     storage: Arc<PlayerDataStorage>,
     save_interval: Duration,
     last_save: AtomicCell<Instant>,
+    /// Held for reading while player data is written to disk, see [`Self::pause_saving`].
+    save_gate: RwLock<()>,
 }
 
 impl ServerPlayerData {
@@ -30,6 +33,7 @@ impl ServerPlayerData {
             storage: Arc::new(PlayerDataStorage::new(data_path, enabled)),
             save_interval,
             last_save: AtomicCell::new(Instant::now()),
+            save_gate: RwLock::new(()),
         }
     }
 
@@ -57,6 +61,7 @@ impl ServerPlayerData {
         player.write_nbt(&mut nbt).await;
 
         // Save to disk
+        let _save_guard = self.save_gate.read().await;
         self.storage.save_player_data(&player.gameprofile.id, nbt)?;
 
         Ok(())
@@ -82,6 +87,7 @@ impl ServerPlayerData {
                     player.write_nbt(&mut nbt).await;
 
                     // Save to disk periodically to prevent data loss on server crash
+                    let _save_guard = self.save_gate.read().await;
                     if let Err(e) = self.storage.save_player_data(&player.gameprofile.id, nbt) {
                         error!(
                             "Failed to save player data for {}: {e}",
@@ -173,8 +179,15 @@ impl ServerPlayerData {
         let uuid = &player.gameprofile.id;
         let mut nbt = NbtCompound::new();
         player.write_nbt(&mut nbt).await;
+        let _save_guard = self.save_gate.read().await;
         self.storage.save_player_data(uuid, nbt)
     }
+
+    /// Waits for ongoing player data writes to finish and holds back new ones until the guard is
+    /// dropped.
+    pub async fn pause_saving(&self) -> RwLockWriteGuard<'_, ()> {
+        self.save_gate.write().await
+    }
 }
 
 #[cfg(test)]
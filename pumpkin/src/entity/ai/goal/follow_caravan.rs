@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use super::{Controls, Goal, GoalFuture, to_goal_ticks};
+use crate::entity::{
+    EntityBase, ai::pathfinder::NavigatorGoal, leash::Leash, mob::Mob,
+    passive::horse::llama::Caravan,
+};
+use crate::world::World;
+
+const SEARCH_RADIUS: f64 = 9.0;
+const MAX_CARAVAN_LENGTH: usize = 8;
+const MIN_DISTANCE_SQ: f64 = 4.0;
+const MAX_DISTANCE_SQ: f64 = 676.0;
+/// How far behind the llama ahead a caravan member keeps.
+const FOLLOW_DISTANCE: f64 = 2.0;
+
+/// Makes a llama join the caravan of a nearby leashed llama and follow the llama ahead of it.
+pub struct FollowCaravanGoal {
+    speed: f64,
+    head: Option<Arc<dyn EntityBase>>,
+    delay: i32,
+}
+
+impl FollowCaravanGoal {
+    #[must_use]
+    pub const fn new(speed: f64) -> Self {
+        Self {
+            speed,
+            head: None,
+            delay: 0,
+        }
+    }
+
+    /// Whether the caravan `entity` is part of is led by a leashed llama.
+    fn is_led(world: &World, entity: &dyn EntityBase) -> bool {
+        let mut current = entity.get_caravan().and_then(Caravan::head);
+        for _ in 0..MAX_CARAVAN_LENGTH {
+            let Some(head) = current.and_then(|id| world.get_entity_by_id(id)) else {
+                return false;
+            };
+            if head.get_leash().is_some_and(Leash::is_leashed) {
+                return true;
+            }
+            current = head.get_caravan().and_then(Caravan::head);
+        }
+        false
+    }
+
+    /// The closest llama without a follower, preferring caravan members over leashed llamas.
+    fn find_head(mob: &dyn Mob) -> Option<(f64, Arc<dyn EntityBase>)> {
+        let entity = &mob.get_mob_entity().living_entity.entity;
+        let pos = entity.pos.load();
+        let world = entity.world.load();
+
+        let mut in_caravan: Option<(f64, Arc<dyn EntityBase>)> = None;
+        let mut leashed: Option<(f64, Arc<dyn EntityBase>)> = None;
+        for candidate in world.get_nearby_entities(pos, SEARCH_RADIUS).into_values() {
+            let c_entity = candidate.get_entity();
+            if c_entity.entity_id == entity.entity_id || c_entity.entity_type != entity.entity_type
+            {
+                continue;
+            }
+            let Some(caravan) = candidate.get_caravan() else {
+                continue;
+            };
+            if caravan.has_tail() {
+                continue;
+            }
+            let dist_sq = pos.squared_distance_to_vec(&c_entity.pos.load());
+            let slot = if caravan.is_in_caravan() {
+                &mut in_caravan
+            } else if candidate.get_leash().is_some_and(Leash::is_leashed) {
+                &mut leashed
+            } else {
+                continue;
+            };
+            if slot.as_ref().is_none_or(|(d, _)| dist_sq < *d) {
+                *slot = Some((dist_sq, candidate));
+            }
+        }
+        in_caravan.or(leashed)
+    }
+}
+
+impl Goal for FollowCaravanGoal {
+    fn can_start<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async move {
+            let Some(caravan) = mob.get_caravan() else {
+                return false;
+            };
+            if mob.get_entity().leash.is_leashed() || caravan.is_in_caravan() {
+                return false;
+            }
+            let Some((dist_sq, head)) = Self::find_head(mob) else {
+                return false;
+            };
+            let world = head.get_entity().world.load();
+            let head_leashed = head.get_leash().is_some_and(Leash::is_leashed);
+            if dist_sq < MIN_DISTANCE_SQ || !(head_leashed || Self::is_led(&world, head.as_ref())) {
+                return false;
+            }
+            let Some(head_caravan) = head.get_caravan() else {
+                return false;
+            };
+            caravan.join(
+                head_caravan,
+                mob.get_mob_entity().living_entity.entity.entity_id,
+                head.get_entity().entity_id,
+            );
+            self.head = Some(head);
+            true
+        })
+    }
+
+    fn should_continue<'a>(&'a self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async move {
+            let Some(head) = &self.head else {
+                return false;
+            };
+            let entity = &mob.get_mob_entity().living_entity.entity;
+            if !head.get_entity().is_alive() || !Self::is_led(&entity.world.load(), mob) {
+                return false;
+            }
+            let dist_sq = entity
+                .pos
+                .load()
+                .squared_distance_to_vec(&head.get_entity().pos.load());
+            dist_sq <= MAX_DISTANCE_SQ
+        })
+    }
+
+    fn start<'a>(&'a mut self, _mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async move {
+            self.delay = 0;
+        })
+    }
+
+    fn tick<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async move {
+            self.delay -= 1;
+            if self.delay > 0 || mob.get_entity().leash.is_leashed() {
+                return;
+            }
+            self.delay = to_goal_ticks(10);
+            let Some(head) = &self.head else {
+                return;
+            };
+            let pos = mob.get_mob_entity().living_entity.entity.pos.load();
+            let delta = head.get_entity().pos.load().sub(&pos);
+            let distance = delta.length();
+            if distance <= FOLLOW_DISTANCE {
+                return;
+            }
+            let scale = (distance - FOLLOW_DISTANCE) / distance;
+            let target = pos.add(&delta.multiply(scale, scale, scale));
+            let mut navigator = mob.get_mob_entity().navigator.lock().await;
+            navigator.set_progress(NavigatorGoal::new(pos, target, self.speed));
+        })
+    }
+
+    fn stop<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async move {
+            if let Some(caravan) = mob.get_caravan() {
+                caravan.leave(self.head.as_deref());
+            }
+            self.head = None;
+        })
+    }
+
+    fn controls(&self) -> Controls {
+        Controls::MOVE
+    }
+}
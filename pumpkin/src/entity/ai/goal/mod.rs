@@ -11,6 +11,7 @@ pub mod creeper_ignite;
 pub mod destroy_egg;
 pub mod eat_grass;
 pub mod escape_danger;
+pub mod follow_caravan;
 pub mod follow_owner;
 pub mod follow_parent;
 pub mod goal_selector;
@@ -23,6 +24,7 @@ pub mod owner_hurt_target;
 pub mod pick_up_block;
 pub mod place_block;
 pub mod revenge;
+pub mod run_around_like_crazy;
 pub mod step_and_destroy_block;
 pub mod swim;
 pub mod teleport_towards_player;
@@ -0,0 +1,78 @@
+use super::{Controls, Goal, GoalFuture};
+use crate::entity::{ai::pathfinder::NavigatorGoal, mob::Mob};
+use pumpkin_util::math::vector3::Vector3;
+use rand::RngExt;
+
+const HORIZONTAL_RANGE: f64 = 5.0;
+const VERTICAL_RANGE: f64 = 4.0;
+
+/// Makes an untamed mount bolt around while someone is trying to ride it.
+pub struct RunAroundLikeCrazyGoal {
+    speed: f64,
+    target: Option<Vector3<f64>>,
+}
+
+impl RunAroundLikeCrazyGoal {
+    #[must_use]
+    pub const fn new(speed: f64) -> Self {
+        Self {
+            speed,
+            target: None,
+        }
+    }
+
+    async fn is_being_tamed(mob: &dyn Mob) -> bool {
+        mob.get_owner_uuid().is_none()
+            && mob
+                .get_mob_entity()
+                .living_entity
+                .entity
+                .has_passengers()
+                .await
+    }
+}
+
+impl Goal for RunAroundLikeCrazyGoal {
+    fn can_start<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async move {
+            if !Self::is_being_tamed(mob).await {
+                return false;
+            }
+            let pos = mob.get_mob_entity().living_entity.entity.pos.load();
+            let mut rng = mob.get_random();
+            self.target = Some(Vector3::new(
+                pos.x + rng.random_range(-HORIZONTAL_RANGE..=HORIZONTAL_RANGE),
+                pos.y + rng.random_range(-VERTICAL_RANGE..=VERTICAL_RANGE),
+                pos.z + rng.random_range(-HORIZONTAL_RANGE..=HORIZONTAL_RANGE),
+            ));
+            true
+        })
+    }
+
+    fn should_continue<'a>(&'a self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async move {
+            Self::is_being_tamed(mob).await
+                && !mob.get_mob_entity().navigator.lock().await.is_idle()
+        })
+    }
+
+    fn start<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async move {
+            if let Some(target) = self.target {
+                let pos = mob.get_mob_entity().living_entity.entity.pos.load();
+                let mut navigator = mob.get_mob_entity().navigator.lock().await;
+                navigator.set_progress(NavigatorGoal::new(pos, target, self.speed));
+            }
+        })
+    }
+
+    fn stop<'a>(&'a mut self, _mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async move {
+            self.target = None;
+        })
+    }
+
+    fn controls(&self) -> Controls {
+        Controls::MOVE
+    }
+}
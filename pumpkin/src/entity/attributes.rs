@@ -276,6 +276,11 @@ pub fn init_all_attributes() {
         crate::entity::mob::creeper::CreeperEntity::create_attributes(),
     );
 
+    reg.register_builder(
+        &EntityType::BREEZE,
+        crate::entity::mob::breeze::BreezeEntity::create_attributes(),
+    );
+
     reg.register_builder(
         &EntityType::ENDERMAN,
         crate::entity::mob::enderman::EndermanEntity::create_attributes(),
@@ -296,6 +301,32 @@ pub fn init_all_attributes() {
         crate::entity::passive::snow_golem::SnowGolemEntity::create_attributes(),
     );
 
+    reg.register_builder(
+        &EntityType::STRIDER,
+        crate::entity::passive::strider::StriderEntity::create_attributes(),
+    );
+
+    reg.register_builder(
+        &EntityType::HORSE,
+        crate::entity::passive::horse::horse::HorseEntity::create_attributes(),
+    );
+
+    // Mules share the donkey attributes
+    reg.register_builder(
+        &EntityType::DONKEY,
+        crate::entity::passive::horse::donkey::DonkeyEntity::create_attributes(),
+    );
+
+    reg.register_builder(
+        &EntityType::MULE,
+        crate::entity::passive::horse::donkey::DonkeyEntity::create_attributes(),
+    );
+
+    reg.register_builder(
+        &EntityType::LLAMA,
+        crate::entity::passive::horse::llama::LlamaEntity::create_attributes(),
+    );
+
     reg.register_builder(
         &EntityType::SKELETON,
         crate::entity::mob::skeleton::SkeletonEntityBase::create_attributes(),
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+use crate::entity::{
+    Entity, EntityBase, EntityBaseFuture, NBTStorage, leash, living::LivingEntity, player::Player,
+};
+use crate::server::Server;
+use crate::world::World;
+use pumpkin_data::damage::DamageType;
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::tag::{self, Taggable};
+use pumpkin_util::math::boundingbox::BoundingBox;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_world::item::ItemStack;
+
+/// How often the knot checks that its fence is still there.
+const SURVIVAL_CHECK_INTERVAL: i32 = 100;
+
+/// A knot tying leads to a fence post.
+pub struct LeashKnotEntity {
+    entity: Entity,
+}
+
+impl LeashKnotEntity {
+    pub const fn new(entity: Entity) -> Self {
+        Self { entity }
+    }
+
+    /// Returns the knot on the fence at `pos`, placing a new one if there is none yet.
+    pub async fn get_or_create(world: &Arc<World>, pos: &BlockPos) -> Arc<dyn EntityBase> {
+        let existing = world
+            .get_entities_at_box(&BoundingBox::from_block(pos))
+            .into_iter()
+            .find(|entity| {
+                let entity = entity.get_entity();
+                entity.entity_type == &EntityType::LEASH_KNOT && entity.block_pos.load() == *pos
+            });
+        if let Some(knot) = existing {
+            return knot;
+        }
+
+        let position = Vector3::new(
+            f64::from(pos.0.x) + 0.5,
+            f64::from(pos.0.y) + 0.375,
+            f64::from(pos.0.z) + 0.5,
+        );
+        let knot: Arc<dyn EntityBase> = Arc::new(Self::new(Entity::new(
+            world.clone(),
+            position,
+            &EntityType::LEASH_KNOT,
+        )));
+        world.spawn_entity(knot.clone()).await;
+        knot
+    }
+}
+
+impl NBTStorage for LeashKnotEntity {}
+
+impl EntityBase for LeashKnotEntity {
+    fn tick<'a>(
+        &'a self,
+        _caller: Arc<dyn EntityBase>,
+        _server: &'a Server,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            let age = self.entity.age.load(Ordering::Relaxed);
+            if age % SURVIVAL_CHECK_INTERVAL != 0 {
+                return;
+            }
+            let world = self.entity.world.load();
+            let block = world.get_block(&self.entity.block_pos.load()).await;
+            if !block.has_tag(&tag::Block::MINECRAFT_FENCES) {
+                // The leads tied to the knot snap on their next tick
+                self.entity.remove().await;
+            }
+        })
+    }
+
+    fn interact<'a>(
+        &'a self,
+        player: &'a Player,
+        _item_stack: &'a mut ItemStack,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            let world = self.entity.world.load_full();
+            let pos = self.entity.block_pos.load();
+            if !leash::bind_player_mobs(&world, player, &pos).await {
+                // Nothing to tie, so the player unties the knot instead
+                self.entity.remove().await;
+            }
+            true
+        })
+    }
+
+    fn damage_with_context<'a>(
+        &'a self,
+        _caller: &'a dyn EntityBase,
+        _amount: f32,
+        _damage_type: DamageType,
+        _position: Option<Vector3<f64>>,
+        _source: Option<&'a dyn EntityBase>,
+        _cause: Option<&'a dyn EntityBase>,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async {
+            self.entity.remove().await;
+            true
+        })
+    }
+
+    fn get_entity(&self) -> &Entity {
+        &self.entity
+    }
+
+    fn get_living_entity(&self) -> Option<&LivingEntity> {
+        None
+    }
+
+    fn as_nbt_storage(&self) -> &dyn NBTStorage {
+        self
+    }
+}
@@ -1,3 +1,4 @@
 pub mod armor_stand;
 pub mod end_crystal;
+pub mod leash_knot;
 pub mod painting;
@@ -81,8 +81,6 @@ impl EntityBase for FallingEntity {
 
             entity.velocity.store(velo.multiply(0.98, 0.98, 0.98));
 
-            entity.send_pos_rot().await;
-
             entity.send_velocity().await;
         })
     }
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
 
 use super::{EntityBase, NBTStorage, NBTStorageInit, player::Player};
 use crate::entity::NbtFuture;
@@ -94,6 +95,18 @@ impl HungerManager {
             timer = 0;
         }
 
+        if difficulty == Difficulty::Peaceful && natural_regen {
+            // Players slowly regain health and food on their own in peaceful
+            let age = player.living_entity.entity.age.load(Ordering::Relaxed);
+            if can_heal && age % 20 == 0 {
+                heal_amount += 1.0;
+            }
+            if level < MAX_FOOD && age % 10 == 0 {
+                level += 1;
+                needs_sync = true;
+            }
+        }
+
         if needs_sync || timer != self.tick_timer.load() {
             self.level.store(level);
             self.saturation.store(saturation);
@@ -347,7 +347,6 @@ impl ItemEntity {
             || entity.velocity.load().sub(&original_velo).length_squared() > 0.1;
 
         if velocity_dirty {
-            entity.send_pos_rot().await;
             entity.send_velocity().await;
         }
     }
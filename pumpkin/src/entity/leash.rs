@@ -0,0 +1,311 @@
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+use crossbeam::atomic::AtomicCell;
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::item::Item;
+use pumpkin_data::sound::{Sound, SoundCategory};
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+use pumpkin_protocol::java::client::play::CSetEntityLink;
+use pumpkin_util::math::boundingbox::BoundingBox;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_world::block::entities::player_data::{uuid_from_tag, uuid_to_tag};
+use pumpkin_world::item::ItemStack;
+use uuid::Uuid;
+
+use super::decoration::leash_knot::LeashKnotEntity;
+use super::{Entity, EntityBase, player::Player};
+use crate::world::World;
+
+/// Distance at which a lead starts pulling the leashed entity toward its holder.
+const ELASTIC_DISTANCE: f64 = 6.0;
+/// Distance at which a lead snaps and drops as an item.
+const SNAP_DISTANCE: f64 = 12.0;
+/// How far around a fence the mobs a player is leading get tied to it.
+const BIND_RANGE: f64 = 7.0;
+/// How long a lead read from NBT waits for its holder to load before it drops as an item.
+const RESOLVE_TICKS: i32 = 100;
+
+/// A lead holder as it is saved, before it is found in the world.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SavedHolder {
+    Entity(Uuid),
+    Knot(BlockPos),
+}
+
+/// The lead attached to an entity, if any.
+///
+/// Only the holder's entity id is kept; it is looked up in the world every tick so the lead
+/// breaks once the holder is removed, dies or changes worlds.
+#[derive(Default)]
+pub struct Leash {
+    holder_id: AtomicCell<Option<i32>>,
+    /// The holder read from NBT, kept until it shows up in the world.
+    saved_holder: AtomicCell<Option<SavedHolder>>,
+}
+
+impl Leash {
+    #[must_use]
+    pub fn holder_id(&self) -> Option<i32> {
+        self.holder_id.load()
+    }
+
+    #[must_use]
+    pub fn is_leashed(&self) -> bool {
+        self.holder_id.load().is_some()
+    }
+
+    #[must_use]
+    pub fn is_held_by(&self, holder: &Entity) -> bool {
+        self.holder_id.load() == Some(holder.entity_id)
+    }
+
+    /// Ties `entity` to `holder`, replacing any previous holder.
+    pub async fn attach(&self, entity: &Entity, holder: &Entity) {
+        self.saved_holder.store(None);
+        self.holder_id.store(Some(holder.entity_id));
+        entity
+            .world
+            .load()
+            .broadcast_packet_all(&CSetEntityLink::new(entity.entity_id, holder.entity_id))
+            .await;
+    }
+
+    /// Unties `entity`, dropping a lead item at its position if `drop_item` is set.
+    pub async fn detach(&self, entity: &Entity, drop_item: bool) {
+        if self.holder_id.take().is_none() {
+            return;
+        }
+        let world = entity.world.load_full();
+        world
+            .broadcast_packet_all(&CSetEntityLink::new(entity.entity_id, 0))
+            .await;
+        if drop_item {
+            world
+                .drop_stack(&entity.block_pos.load(), ItemStack::new(1, &Item::LEAD))
+                .await;
+        }
+    }
+
+    /// Pulls `entity` toward its holder once the lead is stretched, and snaps it when the holder
+    /// is gone or too far away. Runs before the entity moves so the pull applies the same tick.
+    pub async fn tick(&self, entity: &Entity, alive: bool) {
+        if let Some(saved) = self.saved_holder.load() {
+            self.resolve(entity, saved).await;
+        }
+        let Some(holder_id) = self.holder_id.load() else {
+            return;
+        };
+        let holder = entity
+            .world
+            .load()
+            .get_entity_by_id(holder_id)
+            .filter(|holder| {
+                holder
+                    .get_living_entity()
+                    .is_none_or(|living| !living.dead.load(Ordering::Relaxed))
+            });
+        let Some(holder) = holder.filter(|_| alive) else {
+            self.detach(entity, true).await;
+            return;
+        };
+
+        let offset = holder.get_entity().pos.load().sub(&entity.pos.load());
+        let distance = offset.length();
+        if distance > SNAP_DISTANCE {
+            self.detach(entity, true).await;
+            return;
+        }
+        if distance > ELASTIC_DISTANCE {
+            let direction = offset * (1.0 / distance);
+            let pull = Vector3::new(
+                (direction.x * direction.x * 0.4).copysign(direction.x),
+                (direction.y * direction.y * 0.4).copysign(direction.y),
+                (direction.z * direction.z * 0.4).copysign(direction.z),
+            );
+            entity.velocity.store(entity.velocity.load().add(&pull));
+        }
+    }
+
+    /// Attaches the lead to the holder it was saved with once that holder is loaded. Knots are
+    /// placed again on their fence, while leads whose holder never shows up drop as an item.
+    async fn resolve(&self, entity: &Entity, saved: SavedHolder) {
+        let world = entity.world.load_full();
+        let holder = match saved {
+            SavedHolder::Entity(uuid) => world.get_entity_by_uuid(uuid),
+            SavedHolder::Knot(pos) => Some(LeashKnotEntity::get_or_create(&world, &pos).await),
+        };
+        if let Some(holder) = holder {
+            self.attach(entity, holder.get_entity()).await;
+        } else if entity.age.load(Ordering::Relaxed) > RESOLVE_TICKS {
+            self.saved_holder.store(None);
+            world
+                .drop_stack(&entity.block_pos.load(), ItemStack::new(1, &Item::LEAD))
+                .await;
+        }
+    }
+
+    /// Writes the holder the vanilla way: a knot as the int array position of its fence, any
+    /// other holder as a compound with its UUID.
+    pub fn write_nbt(&self, entity: &Entity, nbt: &mut NbtCompound) {
+        let holder = self.holder_id.load().map_or_else(
+            || self.saved_holder.load(),
+            |holder_id| {
+                let holder = entity.world.load().get_entity_by_id(holder_id)?;
+                let holder = holder.get_entity();
+                Some(if holder.entity_type == &EntityType::LEASH_KNOT {
+                    SavedHolder::Knot(holder.block_pos.load())
+                } else {
+                    SavedHolder::Entity(holder.entity_uuid)
+                })
+            },
+        );
+        match holder {
+            Some(SavedHolder::Entity(uuid)) => {
+                let mut leash = NbtCompound::new();
+                leash.put("UUID", uuid_to_tag(uuid));
+                nbt.put("leash", NbtTag::Compound(leash));
+            }
+            Some(SavedHolder::Knot(pos)) => {
+                nbt.put("leash", NbtTag::IntArray(vec![pos.0.x, pos.0.y, pos.0.z]));
+            }
+            None => {}
+        }
+    }
+
+    /// Reads the holder written by [`Self::write_nbt`]. It is attached on a later tick, once it
+    /// is loaded.
+    pub fn read_nbt(&self, nbt: &NbtCompound) {
+        let saved = match nbt.get("leash") {
+            Some(NbtTag::Compound(leash)) => leash
+                .get("UUID")
+                .and_then(uuid_from_tag)
+                .map(SavedHolder::Entity),
+            Some(NbtTag::IntArray(pos)) => match pos[..] {
+                [x, y, z] => Some(SavedHolder::Knot(BlockPos::new(x, y, z))),
+                _ => None,
+            },
+            _ => None,
+        };
+        self.saved_holder.store(saved);
+    }
+
+    /// Handles a player right-clicking a leashable entity: unties it from that player, or ties it
+    /// to them when they hold a lead. Returns whether the click was consumed.
+    pub async fn interact(
+        &self,
+        entity: &Entity,
+        can_be_leashed: bool,
+        player: &Player,
+        item_stack: &mut ItemStack,
+    ) -> bool {
+        let world = entity.world.load_full();
+        let player_entity = player.get_entity();
+        if self.is_held_by(player_entity) {
+            self.detach(entity, true).await;
+            world
+                .play_sound(
+                    Sound::ItemLeadUntied,
+                    SoundCategory::Neutral,
+                    &entity.pos.load(),
+                )
+                .await;
+            return true;
+        }
+        if item_stack.item != &Item::LEAD || !can_be_leashed || self.is_leashed() {
+            return false;
+        }
+        self.attach(entity, player_entity).await;
+        item_stack.decrement_unless_creative(player.gamemode.load(), 1);
+        world
+            .play_sound(
+                Sound::ItemLeadTied,
+                SoundCategory::Neutral,
+                &entity.pos.load(),
+            )
+            .await;
+        true
+    }
+}
+
+/// Moves every lead `player` holds within range of the fence at `pos` onto a knot on that fence.
+/// Returns whether any lead was tied.
+pub async fn bind_player_mobs(world: &Arc<World>, player: &Player, pos: &BlockPos) -> bool {
+    let area = BoundingBox::from_block(pos).expand(BIND_RANGE, BIND_RANGE, BIND_RANGE);
+    let player_entity = player.get_entity();
+    let leashed: Vec<_> = world
+        .get_entities_at_box(&area)
+        .into_iter()
+        .filter(|entity| {
+            entity
+                .get_leash()
+                .is_some_and(|leash| leash.is_held_by(player_entity))
+        })
+        .collect();
+    if leashed.is_empty() {
+        return false;
+    }
+
+    let knot = LeashKnotEntity::get_or_create(world, pos).await;
+    for entity in &leashed {
+        if let Some(leash) = entity.get_leash() {
+            leash.attach(entity.get_entity(), knot.get_entity()).await;
+        }
+    }
+    world
+        .play_sound(
+            Sound::ItemLeadTied,
+            SoundCategory::Blocks,
+            &knot.get_entity().pos.load(),
+        )
+        .await;
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+    use pumpkin_util::math::position::BlockPos;
+    use pumpkin_world::block::entities::player_data::uuid_to_tag;
+    use uuid::Uuid;
+
+    use super::{Leash, SavedHolder};
+
+    #[test]
+    fn reads_entity_holder() {
+        let uuid = Uuid::from_u128(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+        let mut holder = NbtCompound::new();
+        holder.put("UUID", uuid_to_tag(uuid));
+        let mut nbt = NbtCompound::new();
+        nbt.put("leash", NbtTag::Compound(holder));
+
+        let leash = Leash::default();
+        leash.read_nbt(&nbt);
+        assert_eq!(leash.saved_holder.load(), Some(SavedHolder::Entity(uuid)));
+        assert!(!leash.is_leashed());
+    }
+
+    #[test]
+    fn reads_knot_holder() {
+        let mut nbt = NbtCompound::new();
+        nbt.put("leash", NbtTag::IntArray(vec![4, -60, 12]));
+
+        let leash = Leash::default();
+        leash.read_nbt(&nbt);
+        assert_eq!(
+            leash.saved_holder.load(),
+            Some(SavedHolder::Knot(BlockPos::new(4, -60, 12)))
+        );
+    }
+
+    #[test]
+    fn ignores_malformed_holder() {
+        let mut nbt = NbtCompound::new();
+        nbt.put("leash", NbtTag::IntArray(vec![4, -60]));
+
+        let leash = Leash::default();
+        leash.read_nbt(&nbt);
+        assert_eq!(leash.saved_holder.load(), None);
+    }
+}
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use pumpkin_data::block_properties::{BlockProperties, LightningRodLikeProperties};
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::world::WorldEvent;
+use pumpkin_data::{Block, FacingExt};
+use pumpkin_util::Difficulty;
+use pumpkin_util::math::boundingbox::BoundingBox;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_world::BlockStateId;
+use pumpkin_world::world::BlockFlags;
+use rand::{RngExt, rng};
+
+use super::{Entity, EntityBase, EntityBaseFuture, NBTStorage, living::LivingEntity};
+use crate::block::blocks::fire::FireBlockBase;
+use crate::block::blocks::fire::fire::FireBlock;
+use crate::block::blocks::lightning_rod::LightningRodBlock;
+use crate::item::items::axe::get_deoxidized_equivalent;
+use crate::server::Server;
+use crate::world::World;
+
+/// Copper blocks that can oxidize but are not oxidized yet.
+const UNAFFECTED_COPPER: [&Block; 11] = [
+    &Block::COPPER_BLOCK,
+    &Block::CHISELED_COPPER,
+    &Block::COPPER_GRATE,
+    &Block::CUT_COPPER,
+    &Block::CUT_COPPER_STAIRS,
+    &Block::CUT_COPPER_SLAB,
+    &Block::COPPER_BULB,
+    &Block::COPPER_DOOR,
+    &Block::COPPER_TRAPDOOR,
+    &Block::COPPER_CHEST,
+    &Block::LIGHTNING_ROD,
+];
+
+pub struct LightningEntity {
+    entity: Entity,
+    /// Ticks left of the current flash, negative while waiting for the next one.
+    ambient_tick: AtomicI32,
+    /// How many more times the bolt flashes.
+    remaining_actions: AtomicI32,
+}
+
+impl LightningEntity {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            ambient_tick: AtomicI32::new(2),
+            remaining_actions: AtomicI32::new(rng().random_range(1..=3)),
+        }
+    }
+
+    /// Strikes lightning at `position`.
+    pub async fn strike(world: &Arc<World>, position: Vector3<f64>) {
+        let entity = Entity::new(world.clone(), position, &EntityType::LIGHTNING_BOLT);
+        world.spawn_entity(Arc::new(Self::new(entity))).await;
+    }
+
+    /// The block the bolt hit, which is the one below it.
+    fn affected_block_pos(&self) -> BlockPos {
+        let pos = self.entity.pos.load();
+        BlockPos::floored(pos.x, pos.y - 1.0E-6, pos.z)
+    }
+
+    async fn spawn_fire(&self, world: &Arc<World>, spread_attempts: u32) {
+        if world
+            .level_info
+            .load()
+            .game_rules
+            .fire_spread_radius_around_player
+            == 0
+        {
+            return;
+        }
+        let origin = self.entity.block_pos.load();
+        Self::place_fire(world, origin).await;
+        for _ in 0..spread_attempts {
+            let offset = Vector3::new(
+                rng().random_range(-1..=1),
+                rng().random_range(-1..=1),
+                rng().random_range(-1..=1),
+            );
+            Self::place_fire(world, origin.offset(offset)).await;
+        }
+    }
+
+    async fn place_fire(world: &Arc<World>, pos: BlockPos) {
+        if !FireBlockBase::can_place_at(world, &pos).await {
+            return;
+        }
+        let fire = FireBlockBase::get_fire_type(world, &pos).await;
+        let state = FireBlock.get_state_for_position(world, &fire, &pos).await;
+        world
+            .set_block_state(&pos, state, BlockFlags::NOTIFY_ALL)
+            .await;
+    }
+
+    async fn strike_entities(&self, world: &Arc<World>) {
+        let pos = self.entity.pos.load();
+        let area = BoundingBox::new(
+            Vector3::new(pos.x - 3.0, pos.y - 3.0, pos.z - 3.0),
+            Vector3::new(pos.x + 3.0, pos.y + 9.0, pos.z + 3.0),
+        );
+        for entity in world.get_entities_at_box(&area) {
+            if entity.get_entity().entity_id != self.entity.entity_id {
+                entity.on_struck_by_lightning(entity.as_ref()).await;
+            }
+        }
+        for player in world.get_players_at_box(&area) {
+            player.on_struck_by_lightning(player.as_ref()).await;
+        }
+    }
+}
+
+impl NBTStorage for LightningEntity {}
+
+impl EntityBase for LightningEntity {
+    fn tick<'a>(
+        &'a self,
+        _caller: Arc<dyn EntityBase>,
+        _server: &'a Server,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            let world = self.entity.world.load_full();
+
+            if self.ambient_tick.load(Ordering::Relaxed) == 2 {
+                if matches!(
+                    world.level_info.load().difficulty,
+                    Difficulty::Normal | Difficulty::Hard
+                ) {
+                    self.spawn_fire(&world, 4).await;
+                }
+                let affected = self.affected_block_pos();
+                LightningRodBlock::set_powered(&world, &affected).await;
+                clean_oxidation(&world, affected).await;
+            }
+
+            let ambient_tick = self.ambient_tick.fetch_sub(1, Ordering::Relaxed) - 1;
+            if ambient_tick < 0 {
+                let remaining_actions = self.remaining_actions.load(Ordering::Relaxed);
+                if remaining_actions == 0 {
+                    self.entity.remove().await;
+                    return;
+                }
+                let delay = rng().random_range(0..10);
+                if ambient_tick < -delay {
+                    self.remaining_actions
+                        .store(remaining_actions - 1, Ordering::Relaxed);
+                    self.ambient_tick.store(1, Ordering::Relaxed);
+                    self.spawn_fire(&world, 0).await;
+                }
+            }
+
+            if self.ambient_tick.load(Ordering::Relaxed) >= 0 {
+                self.strike_entities(&world).await;
+            }
+        })
+    }
+
+    fn get_entity(&self) -> &Entity {
+        &self.entity
+    }
+
+    fn get_living_entity(&self) -> Option<&LivingEntity> {
+        None
+    }
+
+    fn as_nbt_storage(&self) -> &dyn NBTStorage {
+        self
+    }
+
+    fn is_immune_to_explosion(&self) -> bool {
+        true
+    }
+}
+
+/// Lightning strips the oxidation of the copper block it hit, or the one a struck lightning rod is
+/// attached to, and partially cleans the copper around it.
+async fn clean_oxidation(world: &Arc<World>, pos: BlockPos) {
+    let (mut block, mut state) = world.get_block_and_state_id(&pos).await;
+    let mut pos = pos;
+    if LightningRodBlock::is_lightning_rod(block) {
+        let facing = LightningRodLikeProperties::from_state_id(state, block).facing;
+        pos = pos.offset(facing.to_block_direction().opposite().to_offset());
+        (block, state) = world.get_block_and_state_id(&pos).await;
+    }
+    if !is_oxidizable(block) {
+        return;
+    }
+
+    let mut unaffected = block;
+    while let Some(deoxidized) = deoxidized(unaffected) {
+        unaffected = deoxidized;
+    }
+    world
+        .set_block_state(
+            &pos,
+            with_block(state, block, unaffected),
+            BlockFlags::NOTIFY_ALL,
+        )
+        .await;
+    world
+        .sync_world_event(WorldEvent::ElectricitySparks, pos, -1)
+        .await;
+
+    let walks = rng().random_range(3..=5);
+    for _ in 0..walks {
+        let mut current = pos;
+        let steps = rng().random_range(1..=8);
+        for _ in 0..steps {
+            let Some(next) = clean_random_neighbor(world, current).await else {
+                break;
+            };
+            current = next;
+        }
+    }
+}
+
+/// Deoxidizes one of 10 random copper blocks next to `pos` by one level, returning its position.
+async fn clean_random_neighbor(world: &Arc<World>, pos: BlockPos) -> Option<BlockPos> {
+    for _ in 0..10 {
+        let candidate = pos.offset(Vector3::new(
+            rng().random_range(-1..=1),
+            rng().random_range(-1..=1),
+            rng().random_range(-1..=1),
+        ));
+        let (block, state) = world.get_block_and_state_id(&candidate).await;
+        if !is_oxidizable(block) {
+            continue;
+        }
+        if let Some(deoxidized) = deoxidized(block) {
+            world
+                .set_block_state(
+                    &candidate,
+                    with_block(state, block, deoxidized),
+                    BlockFlags::NOTIFY_ALL,
+                )
+                .await;
+            world
+                .sync_world_event(WorldEvent::ElectricitySparks, candidate, -1)
+                .await;
+        }
+        return Some(candidate);
+    }
+    None
+}
+
+fn is_oxidizable(block: &Block) -> bool {
+    UNAFFECTED_COPPER.contains(&block) || deoxidized(block).is_some()
+}
+
+fn deoxidized(block: &Block) -> Option<&'static Block> {
+    match get_deoxidized_equivalent(block) {
+        0 => LightningRodBlock::deoxidized(block),
+        id => Some(Block::from_id(id)),
+    }
+}
+
+/// `state` of `from` as a state of `to`, keeping all properties.
+fn with_block(state: BlockStateId, from: &Block, to: &Block) -> BlockStateId {
+    from.properties(state).map_or(to.default_state.id, |props| {
+        to.from_properties(&props.to_props()).to_state_id(to)
+    })
+}
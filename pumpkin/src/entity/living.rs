@@ -7,7 +7,6 @@ use pumpkin_inventory::player::player_inventory::PlayerInventory;
 use pumpkin_inventory::screen_handler::InventoryPlayer;
 use pumpkin_util::Hand;
 use pumpkin_util::math::position::BlockPos;
-use std::mem;
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::{
@@ -23,7 +22,10 @@ use crate::block::OnLandedUponArgs;
 use crate::entity::attributes::Modifier;
 use crate::entity::attributes::ModifierOperation;
 use crate::entity::attributes::{AttributeInstance, DEFAULT_ATTRIBUTE_REGISTRY};
+use crate::entity::experience_orb::ExperienceOrbEntity;
+use crate::entity::player::Player;
 use crate::entity::{EntityBaseFuture, NbtFuture};
+use crate::plugin::api::events::entity::entity_death::EntityDeathEvent;
 use crate::server::Server;
 use crate::world::loot::{LootContextParameters, LootTableExt};
 use crossbeam::atomic::AtomicCell;
@@ -32,7 +34,7 @@ use pumpkin_data::damage::DeathMessageType;
 use pumpkin_data::data_component_impl::Operation;
 use pumpkin_data::data_component_impl::{DeathProtectionImpl, EquipmentSlot, FoodImpl};
 use pumpkin_data::effect::StatusEffect;
-use pumpkin_data::entity::{EntityPose, EntityStatus, EntityType};
+use pumpkin_data::entity::{EntityPose, EntityStatus, EntityType, MobCategory};
 use pumpkin_data::sound::SoundCategory;
 use pumpkin_data::{Block, translation};
 use pumpkin_data::{damage::DamageType, sound::Sound};
@@ -295,8 +297,9 @@ impl LivingEntity {
 
     /// Sets the current absorption amount for this entity (yellow hearts)
     pub async fn set_absorption(&self, new_abs: f32) {
-        // Must be at least 0
-        let new_abs = new_abs.max(0.0);
+        // Absorption is its own pool, capped by the max absorption attribute rather than max health
+        let max_abs = self.get_attribute_value(&Attributes::MAX_ABSORPTION) as f32;
+        let new_abs = new_abs.min(max_abs).max(0.0);
 
         // Set local state
         self.absorption.store(new_abs);
@@ -466,12 +469,11 @@ impl LivingEntity {
                 }
             }
 
-            // Apply absorption effect (+4 absorption per level)
+            // Apply absorption effect (4 absorption per level), which tops up rather than stacks
             if effect.effect_type == &StatusEffect::ABSORPTION {
-                let added = 4.0 * (effect.amplifier as f32 + 1.0);
-                let max_abs = self.get_attribute_value(&Attributes::MAX_ABSORPTION) as f32;
-                let new_abs = (self.absorption.load() + added).min(max_abs);
-                self.set_absorption(new_abs).await;
+                let amount = 4.0 * (effect.amplifier as f32 + 1.0);
+                self.set_absorption(self.absorption.load().max(amount))
+                    .await;
             }
 
             // Apply invisible effect
@@ -774,17 +776,25 @@ impl LivingEntity {
 
         let touching_water = self.entity.touching_water.load(SeqCst);
 
+        // The client of a player steering this entity moves it
+        let steered_by_player = caller
+            .get_controlling_passenger()
+            .await
+            .is_some_and(|controller| controller.get_player().is_some());
+
         // Strider is the only entity that has canWalkOnFluid = false
 
-        if (touching_water || self.entity.touching_lava.load(SeqCst))
-            && should_swim_in_fluids
-            && self.entity.entity_type != &EntityType::STRIDER
-        {
-            self.travel_in_fluid(caller.clone(), touching_water).await;
-        } else {
-            // TODO: Gliding
+        if !steered_by_player {
+            if (touching_water || self.entity.touching_lava.load(SeqCst))
+                && should_swim_in_fluids
+                && self.entity.entity_type != &EntityType::STRIDER
+            {
+                self.travel_in_fluid(caller.clone(), touching_water).await;
+            } else {
+                // TODO: Gliding
 
-            self.travel_in_air(caller.clone()).await;
+                self.travel_in_air(caller.clone()).await;
+            }
         }
 
         //self.entity.tick_block_underneath(&caller);
@@ -1253,6 +1263,8 @@ impl LivingEntity {
         }
     }
 
+    /// Runs the death pipeline: fires the [`EntityDeathEvent`], then drops the entity's items and
+    /// experience and announces the death.
     pub async fn on_death(
         &self,
         damage_type: DamageType,
@@ -1266,65 +1278,157 @@ impl LivingEntity {
         if self
             .dead
             .compare_exchange(false, true, Relaxed, Relaxed)
-            .is_ok()
+            .is_err()
         {
-            self.movement_input.store(Vector3::default());
-            self.jumping.store(false, Relaxed);
+            return;
+        }
 
-            // Plays the death sound
-            world
-                .send_entity_status(
-                    &self.entity,
-                    EntityStatus::PlayDeathSoundOrAddProjectileHitParticles,
-                )
-                .await;
+        let player = dyn_self.get_player();
+        let keep_inventory = player.is_some() && world.level_info.load().game_rules.keep_inventory;
+        let (drops, experience) = if keep_inventory {
+            (Vec::new(), 0)
+        } else {
             let params = LootContextParameters {
                 killed_by_player: cause.map(|c| c.get_entity().entity_type == &EntityType::PLAYER),
                 ..Default::default()
             };
+            (
+                self.collect_drops(player, params).await,
+                self.get_death_experience(player, cause),
+            )
+        };
+        let death_message = Self::get_death_message(&*dyn_self, damage_type, source, cause).await;
+
+        let event = EntityDeathEvent::new(
+            dyn_self.clone(),
+            damage_type,
+            drops,
+            experience,
+            death_message,
+        );
+        let event = match world.server.upgrade() {
+            Some(server) => server.plugin_manager.fire(event).await,
+            None => event,
+        };
+        if event.cancelled {
+            self.dead.store(false, Relaxed);
+            self.set_health(1.0).await;
+            return;
+        }
 
-            // Drop loot
-            self.drop_loot(params).await;
-            self.entity.pose.store(EntityPose::Dying);
+        self.movement_input.store(Vector3::default());
+        self.jumping.store(false, Relaxed);
 
-            let block_pos = self.entity.block_pos.load();
+        // Plays the death sound
+        world
+            .send_entity_status(
+                &self.entity,
+                EntityStatus::PlayDeathSoundOrAddProjectileHitParticles,
+            )
+            .await;
+        self.entity.pose.store(EntityPose::Dying);
 
-            for slot in self.equipment_slots.values() {
-                let item = {
-                    let lock = self.entity_equipment.lock().await;
-                    let equipment = lock.get(slot);
-                    let mut item_lock = equipment.lock().await;
-                    mem::replace(&mut *item_lock, ItemStack::EMPTY.clone())
-                };
-                world.drop_stack(&block_pos, item).await;
+        if !keep_inventory {
+            self.clear_drops(player).await;
+            dyn_self.drop_inventory().await;
+        }
+        let pos = self.entity.pos.load();
+        let block_pos = self.entity.block_pos.load();
+        for stack in event.drops {
+            if !stack.is_empty() {
+                world.drop_stack(&block_pos, stack).await;
             }
+        }
+        if event.experience > 0 {
+            ExperienceOrbEntity::spawn(&world, pos, event.experience as u32).await;
+        }
 
-            // Broadcast death message if it's a player and the gamerule is enabled
+        if let Some(player) = player {
+            // Broadcast death message if the gamerule is enabled
             let show_death_messages = { world.level_info.load().game_rules.show_death_messages };
-            if self.entity.entity_type == &EntityType::PLAYER && show_death_messages {
+            if show_death_messages && let Some(server) = world.server.upgrade() {
                 //TODO: KillCredit
-                let death_message =
-                    Self::get_death_message(&*dyn_self, damage_type, source, cause).await;
-                if let Some(server) = world.server.upgrade() {
-                    for player in server.get_all_players() {
-                        player.send_system_message(&death_message).await;
-                    }
+                for player in server.get_all_players() {
+                    player.send_system_message(&event.death_message).await;
                 }
             }
+            player.handle_killed(&event.death_message).await;
+        }
+
+        self.reset_effects_and_attributes().await;
+    }
 
-            self.reset_effects_and_attributes().await;
+    /// The loot, equipment and, for players, inventory the entity drops when it dies.
+    async fn collect_drops(
+        &self,
+        player: Option<&Player>,
+        params: LootContextParameters,
+    ) -> Vec<ItemStack> {
+        let mut drops = Vec::new();
+        if let Some(loot_table) = &self.entity.entity_type.loot_table {
+            drops.extend(loot_table.get_loot(params));
+        }
+        for slot in self.death_drop_slots() {
+            let equipment = self.entity_equipment.lock().await.get(slot);
+            let stack = equipment.lock().await.clone();
+            drops.push(stack);
+        }
+        if let Some(player) = player {
+            for item in &player.inventory().main_inventory {
+                drops.push(item.lock().await.clone());
+            }
         }
+        drops.retain(|stack| !stack.is_empty());
+        drops
     }
 
-    async fn drop_loot(&self, params: LootContextParameters) {
-        if let Some(loot_table) = &self.get_entity().entity_type.loot_table {
-            let pos = self.entity.block_pos.load();
-            for stack in loot_table.get_loot(params) {
-                self.entity.world.load().drop_stack(&pos, stack).await;
+    /// Empties the slots [`Self::collect_drops`] took its items from.
+    async fn clear_drops(&self, player: Option<&Player>) {
+        for slot in self.death_drop_slots() {
+            let equipment = self.entity_equipment.lock().await.get(slot);
+            *equipment.lock().await = ItemStack::EMPTY.clone();
+        }
+        if let Some(player) = player {
+            for item in &player.inventory().main_inventory {
+                *item.lock().await = ItemStack::EMPTY.clone();
             }
         }
     }
 
+    fn death_drop_slots(&self) -> impl Iterator<Item = &EquipmentSlot> {
+        self.equipment_slots
+            .values()
+            .chain([&EquipmentSlot::SADDLE, &EquipmentSlot::BODY])
+    }
+
+    /// The experience the entity drops on death. Players drop 7 points per level, while mobs
+    /// only drop experience when killed by a player.
+    fn get_death_experience(&self, player: Option<&Player>, cause: Option<&dyn EntityBase>) -> i32 {
+        if let Some(player) = player {
+            return (player.experience_level.load(Relaxed) * 7).min(100);
+        }
+        let entity_type = self.entity.entity_type;
+        if !entity_type.mob
+            || self.entity.age.load(Relaxed) < 0
+            || cause.is_none_or(|cause| cause.get_player().is_none())
+        {
+            return 0;
+        }
+        let category = entity_type.category;
+        if category == &MobCategory::MONSTER {
+            5
+        } else if category == &MobCategory::CREATURE
+            || category == &MobCategory::AXOLOTLS
+            || category == &MobCategory::WATER_CREATURE
+            || category == &MobCategory::UNDERGROUND_WATER_CREATURE
+            || category == &MobCategory::WATER_AMBIENT
+        {
+            rand::rng().random_range(1..=3)
+        } else {
+            0
+        }
+    }
+
     async fn tick_effects(&self) {
         let mut effects_to_remove = Vec::new();
         let mut effects_to_apply = Vec::new();
@@ -1454,6 +1558,16 @@ impl LivingEntity {
         }
     }
 
+    /// Called when a hit would kill the entity, before [`Self::on_death`]. Returns true if the
+    /// death was prevented.
+    pub async fn pre_death(&self, caller: &dyn EntityBase, damage_type: DamageType) -> bool {
+        // These damage types bypass death protection
+        if damage_type == DamageType::GENERIC_KILL || damage_type == DamageType::OUT_OF_WORLD {
+            return false;
+        }
+        self.try_use_death_protector(caller).await
+    }
+
     /// Tries to use a totem of undying from the entity's hands. If successful, applies the totem effects and returns true.
     async fn try_use_death_protector(&self, caller: &dyn EntityBase) -> bool {
         for hand in Hand::all() {
@@ -1463,7 +1577,15 @@ impl LivingEntity {
             // Clear the stack and use the totem of undying
             if stack.get_data_component::<DeathProtectionImpl>().is_some() {
                 stack.clear();
+                drop(stack);
+                let slot = match hand {
+                    Hand::Left => EquipmentSlot::OFF_HAND,
+                    Hand::Right => EquipmentSlot::MAIN_HAND,
+                };
+                self.send_equipment_changes(&[(slot, ItemStack::EMPTY.clone())])
+                    .await;
                 self.set_health(1.0).await;
+                self.reset_effects_and_attributes().await;
                 self.entity
                     .world
                     .load()
@@ -1957,9 +2079,7 @@ impl EntityBase for LivingEntity {
             }
 
             // Check if the entity died and isn't protected by a death protection mechanic (ex. totem of undying)
-            if clamped_health <= 0.0
-                && (bypasses_cooldown_protection || !self.try_use_death_protector(caller).await)
-            {
+            if clamped_health <= 0.0 && !self.pre_death(caller, damage_type).await {
                 self.on_death(damage_type, source, cause).await;
             }
 
@@ -2000,14 +2120,6 @@ impl EntityBase for LivingEntity {
                 self.entity.tick_frozen(caller.as_ref()).await;
             }
 
-            // TODO
-            let player = caller.get_player();
-            let is_player = player.is_some();
-
-            if !is_player {
-                self.entity.send_pos_rot().await;
-            }
-
             // Fetch supporting blocks for players or other entities
             let supporting_pos = if let Some(player) = caller.get_player() {
                 // Handles player movement and detection along block edges
@@ -2073,12 +2185,11 @@ impl EntityBase for LivingEntity {
                             .await;
                     }
                     if let Some(player) = caller.get_player() {
-                        player
-                            .inventory
-                            .held_item()
-                            .lock()
-                            .await
-                            .decrement_unless_creative(player.gamemode.load(), 1);
+                        let held = player.inventory.held_item();
+                        let mut held = held.lock().await;
+                        if !server.item_registry.finish_using(&mut held, player).await {
+                            held.decrement_unless_creative(player.gamemode.load(), 1);
+                        }
                     }
 
                     self.clear_active_hand().await;
@@ -0,0 +1,270 @@
+use std::sync::{
+    Arc, Weak,
+    atomic::{AtomicI32, AtomicU8, Ordering},
+};
+
+use crate::entity::attributes::AttributeBuilder;
+use pumpkin_data::{
+    attributes::Attributes,
+    entity::{EntityPose, EntityType},
+    sound::{Sound, SoundCategory},
+};
+use pumpkin_util::math::vector3::Vector3;
+
+use crate::entity::{
+    Entity, EntityBase, EntityBaseFuture, NBTStorage,
+    ai::goal::{
+        active_target::ActiveTargetGoal, look_around::LookAroundGoal,
+        look_at_entity::LookAtEntityGoal, revenge::RevengeGoal, wander_around::WanderAroundGoal,
+    },
+    mob::{Mob, MobEntity},
+    projectile::{ThrownItemEntity, wind_charge::WindChargeEntity},
+};
+
+/// Targets closer than this are jumped away from instead of shot at.
+const MIN_SHOOT_RANGE_SQ: f64 = 4.0 * 4.0;
+const MAX_SHOOT_RANGE_SQ: f64 = 16.0 * 16.0;
+const MAX_JUMP_RANGE_SQ: f64 = 24.0 * 24.0;
+const SHOOT_CHARGING_TICKS: i32 = 15;
+const SHOOT_RECOVER_TICKS: i32 = 4;
+const SHOOT_COOLDOWN_TICKS: i32 = 10;
+const INHALE_TICKS: i32 = 10;
+const JUMP_COOLDOWN_TICKS: i32 = 10;
+const MAX_JUMP_VELOCITY: f64 = 1.4;
+const RETREAT_DISTANCE: f64 = 8.0;
+const GRAVITY: f64 = 0.08;
+const PROJECTILE_SPEED: f64 = 0.7;
+const PROJECTILE_DIVERGENCE: f64 = 1.0;
+
+const PHASE_IDLE: u8 = 0;
+const PHASE_CHARGING: u8 = 1;
+const PHASE_RECOVERING: u8 = 2;
+const PHASE_INHALING: u8 = 3;
+const PHASE_JUMPING: u8 = 4;
+
+pub struct BreezeEntity {
+    pub mob_entity: MobEntity,
+    phase: AtomicU8,
+    phase_ticks: AtomicI32,
+    shoot_cooldown: AtomicI32,
+    jump_cooldown: AtomicI32,
+}
+
+impl BreezeEntity {
+    pub async fn new(entity: Entity) -> Arc<Self> {
+        let mob_entity = MobEntity::new(entity);
+        let breeze = Self {
+            mob_entity,
+            phase: AtomicU8::new(PHASE_IDLE),
+            phase_ticks: AtomicI32::new(0),
+            shoot_cooldown: AtomicI32::new(0),
+            jump_cooldown: AtomicI32::new(0),
+        };
+        let mob_arc = Arc::new(breeze);
+        let mob_weak: Weak<dyn Mob> = {
+            let mob_arc: Arc<dyn Mob> = mob_arc.clone();
+            Arc::downgrade(&mob_arc)
+        };
+
+        {
+            let mut goal_selector = mob_arc.mob_entity.goals_selector.lock().await;
+            let mut target_selector = mob_arc.mob_entity.target_selector.lock().await;
+
+            goal_selector.add_goal(5, Box::new(WanderAroundGoal::new(0.6)));
+            goal_selector.add_goal(
+                6,
+                LookAtEntityGoal::with_default(mob_weak, &EntityType::PLAYER, 16.0),
+            );
+            goal_selector.add_goal(6, Box::new(LookAroundGoal::default()));
+
+            target_selector.add_goal(1, Box::new(RevengeGoal::new(true)));
+            target_selector.add_goal(
+                2,
+                ActiveTargetGoal::with_default(&mob_arc.mob_entity, &EntityType::PLAYER, true),
+            );
+        };
+
+        mob_arc
+    }
+
+    #[must_use]
+    pub fn create_attributes() -> AttributeBuilder {
+        AttributeBuilder::new()
+            .add(Attributes::MOVEMENT_SPEED, 0.63)
+            .add(Attributes::MAX_HEALTH, 30.0)
+            .add(Attributes::FOLLOW_RANGE, 24.0)
+            .add(Attributes::ATTACK_DAMAGE, 3.0)
+    }
+
+    fn set_phase(&self, phase: u8, ticks: i32) {
+        self.phase.store(phase, Ordering::Relaxed);
+        self.phase_ticks.store(ticks, Ordering::Relaxed);
+    }
+
+    async fn play_sound(&self, sound: Sound) {
+        let entity = &self.mob_entity.living_entity.entity;
+        entity
+            .world
+            .load()
+            .play_sound(sound, SoundCategory::Hostile, &entity.pos.load())
+            .await;
+    }
+
+    /// Fires a breeze wind charge at the target's body.
+    async fn shoot(&self, target: &Entity) {
+        let entity = &self.mob_entity.living_entity.entity;
+        let world = entity.world.load();
+
+        let projectile = Entity::new(
+            world.clone(),
+            entity.pos.load(),
+            &EntityType::BREEZE_WIND_CHARGE,
+        );
+        let wind_charge = ThrownItemEntity::new(projectile, entity);
+        let aim = target
+            .pos
+            .load()
+            .add_raw(0.0, f64::from(target.height()) * 0.5, 0.0);
+        let delta = aim.sub(&wind_charge.entity.pos.load());
+        wind_charge.set_velocity(
+            delta.x,
+            delta.y,
+            delta.z,
+            PROJECTILE_SPEED,
+            PROJECTILE_DIVERGENCE,
+        );
+
+        self.play_sound(Sound::EntityBreezeShoot).await;
+        world
+            .spawn_entity(Arc::new(WindChargeEntity::new(wind_charge)))
+            .await;
+    }
+
+    /// Leaps on a 45 degree arc, capped to the vanilla jump strength. Distant targets are
+    /// closed in on, targets that got too close are jumped away from.
+    async fn long_jump(&self, target: &Entity) {
+        let entity = &self.mob_entity.living_entity.entity;
+        let delta = target.pos.load().sub(&entity.pos.load());
+        let distance = delta.horizontal_length();
+        if distance < 1.0e-4 {
+            return;
+        }
+
+        let (direction, jump_distance) = if distance * distance < MIN_SHOOT_RANGE_SQ {
+            (-1.0 / distance, RETREAT_DISTANCE)
+        } else {
+            (1.0 / distance, distance)
+        };
+        let speed = (jump_distance * GRAVITY).sqrt().min(MAX_JUMP_VELOCITY);
+        let horizontal = speed * std::f64::consts::FRAC_1_SQRT_2;
+        let velocity = Vector3::new(
+            delta.x * direction * horizontal,
+            horizontal,
+            delta.z * direction * horizontal,
+        );
+
+        entity.set_pose(EntityPose::LongJumping).await;
+        self.play_sound(Sound::EntityBreezeJump).await;
+        entity.set_velocity(velocity).await;
+    }
+
+    async fn tick_phase(&self, target: &Entity) {
+        let entity = &self.mob_entity.living_entity.entity;
+        count_down(&self.phase_ticks);
+        let ticks = self.phase_ticks.load(Ordering::Relaxed);
+
+        match self.phase.load(Ordering::Relaxed) {
+            PHASE_CHARGING if ticks <= 0 => {
+                self.shoot(target).await;
+                self.set_phase(PHASE_RECOVERING, SHOOT_RECOVER_TICKS);
+            }
+            PHASE_RECOVERING if ticks <= 0 => {
+                entity.set_pose(EntityPose::Standing).await;
+                self.shoot_cooldown
+                    .store(SHOOT_COOLDOWN_TICKS, Ordering::Relaxed);
+                self.set_phase(PHASE_IDLE, 0);
+            }
+            PHASE_INHALING if ticks <= 0 => {
+                self.long_jump(target).await;
+                // Give the breeze a couple of ticks to leave the ground before checking for landing
+                self.set_phase(PHASE_JUMPING, 2);
+            }
+            PHASE_JUMPING if ticks <= 0 && entity.on_ground.load(Ordering::Relaxed) => {
+                entity.set_pose(EntityPose::Standing).await;
+                self.play_sound(Sound::EntityBreezeLand).await;
+                self.jump_cooldown
+                    .store(JUMP_COOLDOWN_TICKS, Ordering::Relaxed);
+                self.set_phase(PHASE_IDLE, 0);
+            }
+            _ => {}
+        }
+    }
+
+    async fn choose_action(&self, target: &Entity) {
+        let entity = &self.mob_entity.living_entity.entity;
+        let distance_sq = entity
+            .pos
+            .load()
+            .squared_distance_to_vec(&target.pos.load());
+
+        if entity.on_ground.load(Ordering::Relaxed)
+            && self.jump_cooldown.load(Ordering::Relaxed) <= 0
+            && (distance_sq < MIN_SHOOT_RANGE_SQ
+                || (distance_sq > MAX_SHOOT_RANGE_SQ && distance_sq < MAX_JUMP_RANGE_SQ))
+        {
+            entity.set_pose(EntityPose::Inhaling).await;
+            self.play_sound(Sound::EntityBreezeInhale).await;
+            self.set_phase(PHASE_INHALING, INHALE_TICKS);
+        } else if self.shoot_cooldown.load(Ordering::Relaxed) <= 0
+            && (MIN_SHOOT_RANGE_SQ..=MAX_SHOOT_RANGE_SQ).contains(&distance_sq)
+        {
+            entity.set_pose(EntityPose::Shooting).await;
+            self.play_sound(Sound::EntityBreezeCharge).await;
+            self.set_phase(PHASE_CHARGING, SHOOT_CHARGING_TICKS);
+        }
+    }
+}
+
+fn count_down(counter: &AtomicI32) {
+    if counter.load(Ordering::Relaxed) > 0 {
+        counter.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl NBTStorage for BreezeEntity {}
+
+impl Mob for BreezeEntity {
+    fn get_mob_entity(&self) -> &MobEntity {
+        &self.mob_entity
+    }
+
+    fn mob_tick<'a>(&'a self, _caller: &'a Arc<dyn EntityBase>) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            let entity = &self.mob_entity.living_entity.entity;
+            if !entity.is_alive() {
+                return;
+            }
+
+            count_down(&self.shoot_cooldown);
+            count_down(&self.jump_cooldown);
+
+            let Some(target) = self.mob_entity.target.lock().await.clone() else {
+                // A jump in progress still has to land, anything else is abandoned
+                let phase = self.phase.load(Ordering::Relaxed);
+                if phase != PHASE_IDLE && phase != PHASE_JUMPING {
+                    entity.set_pose(EntityPose::Standing).await;
+                    self.set_phase(PHASE_IDLE, 0);
+                }
+                return;
+            };
+            let target = target.get_entity();
+            entity.look_at(target.get_eye_pos());
+
+            if self.phase.load(Ordering::Relaxed) == PHASE_IDLE {
+                self.choose_action(target).await;
+            } else {
+                self.tick_phase(target).await;
+            }
+        })
+    }
+}
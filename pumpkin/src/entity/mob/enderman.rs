@@ -24,10 +24,7 @@ use pumpkin_data::{
     tracked_data::TrackedData,
 };
 use pumpkin_nbt::compound::NbtCompound;
-use pumpkin_protocol::{
-    codec::var_int::VarInt,
-    java::client::play::{CEntityPositionSync, Metadata},
-};
+use pumpkin_protocol::{codec::var_int::VarInt, java::client::play::Metadata};
 use pumpkin_util::math::{boundingbox::BoundingBox, position::BlockPos, vector3::Vector3};
 use rand::RngExt;
 
@@ -236,16 +233,7 @@ impl EndermanEntity {
 
         entity.set_pos(new_pos);
 
-        world
-            .broadcast_packet_all(&CEntityPositionSync::new(
-                entity.entity_id.into(),
-                new_pos,
-                Vector3::new(0.0, 0.0, 0.0),
-                entity.yaw.load(),
-                entity.pitch.load(),
-                entity.on_ground.load(Ordering::Relaxed),
-            ))
-            .await;
+        entity.send_position_sync().await;
 
         self.mob_entity.navigator.lock().await.stop();
 
@@ -1,7 +1,10 @@
-use super::{Entity, EntityBase, NBTStorage, ai::pathfinder::Navigator, living::LivingEntity};
+use super::{
+    Entity, EntityBase, NBTStorage, ai::pathfinder::Navigator, leash::Leash, living::LivingEntity,
+};
 use crate::entity::EntityBaseFuture;
 use crate::entity::ai::control::look_control::LookControl;
 use crate::entity::ai::goal::goal_selector::GoalSelector;
+use crate::entity::passive::horse::llama::Caravan;
 use crate::entity::player::Player;
 use crate::server::Server;
 use crate::world::World;
@@ -9,9 +12,11 @@ use crossbeam::atomic::AtomicCell;
 use pumpkin_data::attributes::Attributes;
 use pumpkin_data::damage::DamageType;
 use pumpkin_data::data_component_impl::EquipmentSlot;
+use pumpkin_data::entity::{EntityType, MobCategory};
 use pumpkin_data::meta_data_type::MetaDataType;
 use pumpkin_data::tracked_data::TrackedData;
-use pumpkin_protocol::java::client::play::{CHeadRot, CUpdateEntityRot, Metadata};
+use pumpkin_protocol::java::client::play::Metadata;
+use pumpkin_util::Difficulty;
 use pumpkin_util::math::boundingbox::BoundingBox;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector2::Vector2;
@@ -26,12 +31,29 @@ use tokio::sync::Mutex;
 use uuid::Uuid;
 
 pub mod bat;
+pub mod breeze;
 pub mod creeper;
 pub mod enderman;
 pub mod silverfish;
 pub mod skeleton;
 pub mod zombie;
 
+/// Monsters that stay around when the difficulty is set to peaceful.
+const PEACEFUL_PERSISTENT_MONSTERS: [u16; 3] = [
+    EntityType::ENDER_DRAGON.id,
+    EntityType::HOGLIN.id,
+    EntityType::SHULKER.id,
+];
+
+/// Monsters that still take a lead.
+const LEASHABLE_MONSTERS: [u16; 2] = [EntityType::HOGLIN.id, EntityType::ZOGLIN.id];
+
+/// Water mobs that still take a lead.
+const LEASHABLE_WATER_MOBS: [u16; 1] = [EntityType::DOLPHIN.id];
+
+/// Mobs that never take a lead despite not being monsters.
+const UNLEASHABLE_MOBS: [u16; 2] = [EntityType::VILLAGER.id, EntityType::WANDERING_TRADER.id];
+
 pub struct MobEntity {
     pub living_entity: LivingEntity,
     pub goals_selector: Mutex<GoalSelector>,
@@ -44,9 +66,6 @@ pub struct MobEntity {
     pub love_ticks: AtomicI32,
     pub breeding_cooldown: AtomicI32,
     mob_flags: AtomicU8,
-    last_sent_yaw: AtomicU8,
-    last_sent_pitch: AtomicU8,
-    last_sent_head_yaw: AtomicU8,
 }
 
 impl MobEntity {
@@ -70,9 +89,6 @@ impl MobEntity {
             love_ticks: AtomicI32::new(0),
             breeding_cooldown: AtomicI32::new(0),
             mob_flags: AtomicU8::new(0),
-            last_sent_yaw: AtomicU8::new(0),
-            last_sent_pitch: AtomicU8::new(0),
-            last_sent_head_yaw: AtomicU8::new(0),
         }
     }
     pub fn is_in_position_target_range(&self) -> bool {
@@ -280,6 +296,38 @@ pub trait Mob: EntityBase + Send + Sync {
         None
     }
 
+    /// Sends the tracked data of the mob that its spawn packet doesn't carry.
+    fn init_mob_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
+    fn get_mob_passenger_attachment(&self, index: usize, count: usize) -> Vector3<f64> {
+        self.get_mob_entity()
+            .living_entity
+            .entity
+            .get_passenger_attachment(index, count)
+    }
+
+    fn get_mob_controlling_passenger(&self) -> EntityBaseFuture<'_, Option<Arc<dyn EntityBase>>> {
+        Box::pin(async { None })
+    }
+
+    fn mob_boost(&self) -> EntityBaseFuture<'_, bool> {
+        Box::pin(async { false })
+    }
+
+    fn mob_start_riding_jump(&self, _strength: i32) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
+    fn open_mob_inventory<'a>(&'a self, _player: &'a Player) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async {})
+    }
+
+    fn drop_mob_inventory(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
     /// Set or clear the mob's target. Override to add side effects when targeting changes.
     fn set_mob_target(&self, target: Option<Arc<dyn EntityBase>>) -> EntityBaseFuture<'_, ()> {
         Box::pin(async move {
@@ -300,9 +348,37 @@ pub trait Mob: EntityBase + Send + Sync {
         None
     }
 
+    fn get_mob_caravan(&self) -> Option<&Caravan> {
+        None
+    }
+
     fn is_sitting(&self) -> bool {
         false
     }
+
+    /// Whether a player can tie a lead to the mob. Follows vanilla: no monsters except hoglins
+    /// and zoglins, no ambient mobs, no water mobs except dolphins and no traders.
+    fn can_be_leashed(&self) -> bool {
+        let entity_type = self.get_mob_entity().living_entity.entity.entity_type;
+        let category = entity_type.category;
+        if category == &MobCategory::MONSTER {
+            LEASHABLE_MONSTERS.contains(&entity_type.id)
+        } else if category == &MobCategory::WATER_CREATURE
+            || category == &MobCategory::WATER_AMBIENT
+            || category == &MobCategory::UNDERGROUND_WATER_CREATURE
+        {
+            LEASHABLE_WATER_MOBS.contains(&entity_type.id)
+        } else {
+            category != &MobCategory::AMBIENT && !UNLEASHABLE_MOBS.contains(&entity_type.id)
+        }
+    }
+
+    /// Whether the mob is removed while the world difficulty is peaceful.
+    fn should_despawn_in_peaceful(&self) -> bool {
+        let entity_type = self.get_mob_entity().living_entity.entity.entity_type;
+        entity_type.category == &MobCategory::MONSTER
+            && !PEACEFUL_PERSISTENT_MONSTERS.contains(&entity_type.id)
+    }
 }
 
 impl<T: Mob + Send + 'static> EntityBase for T {
@@ -314,6 +390,14 @@ impl<T: Mob + Send + 'static> EntityBase for T {
         Box::pin(async move {
             let mob_entity = self.get_mob_entity();
 
+            let entity = &mob_entity.living_entity.entity;
+            if entity.world.load().level_info.load().difficulty == Difficulty::Peaceful
+                && self.should_despawn_in_peaceful()
+            {
+                entity.remove().await;
+                return;
+            }
+
             if mob_entity.breeding_cooldown.load(Relaxed) > 0 {
                 mob_entity.breeding_cooldown.fetch_sub(1, Relaxed);
             }
@@ -351,41 +435,12 @@ impl<T: Mob + Send + 'static> EntityBase for T {
             look_control.tick(self).await;
             drop(look_control);
 
+            let alive = !mob_entity.living_entity.dead.load(Relaxed);
+            entity.leash.tick(entity, alive).await;
+
             mob_entity.living_entity.tick(caller, server).await;
 
             self.post_tick().await;
-
-            // Send rotation packets after look_control finalizes head_yaw and pitch
-            let entity = &mob_entity.living_entity.entity;
-            let yaw = (entity.yaw.load() * 256.0 / 360.0).rem_euclid(256.0) as u8;
-            let pitch = (entity.pitch.load() * 256.0 / 360.0).rem_euclid(256.0) as u8;
-            let head_yaw = (entity.head_yaw.load() * 256.0 / 360.0).rem_euclid(256.0) as u8;
-
-            let last_yaw = mob_entity.last_sent_yaw.load(Relaxed);
-            let last_pitch = mob_entity.last_sent_pitch.load(Relaxed);
-            let last_head_yaw = mob_entity.last_sent_head_yaw.load(Relaxed);
-
-            if yaw.abs_diff(last_yaw) >= 1 || pitch.abs_diff(last_pitch) >= 1 {
-                let world = entity.world.load();
-                world
-                    .broadcast_packet_all(&CUpdateEntityRot::new(
-                        entity.entity_id.into(),
-                        yaw,
-                        pitch,
-                        entity.on_ground.load(Relaxed),
-                    ))
-                    .await;
-                mob_entity.last_sent_yaw.store(yaw, Relaxed);
-                mob_entity.last_sent_pitch.store(pitch, Relaxed);
-            }
-
-            if head_yaw.abs_diff(last_head_yaw) >= 1 {
-                let world = entity.world.load();
-                world
-                    .broadcast_packet_all(&CHeadRot::new(entity.entity_id.into(), head_yaw))
-                    .await;
-                mob_entity.last_sent_head_yaw.store(head_yaw, Relaxed);
-            }
         })
     }
 
@@ -420,13 +475,31 @@ impl<T: Mob + Send + 'static> EntityBase for T {
         player: &'a Player,
         item_stack: &'a mut ItemStack,
     ) -> EntityBaseFuture<'a, bool> {
-        Box::pin(async move { self.mob_interact(player, item_stack).await })
+        Box::pin(async move {
+            let entity = &self.get_mob_entity().living_entity.entity;
+            if entity
+                .leash
+                .interact(entity, self.can_be_leashed(), player, item_stack)
+                .await
+            {
+                return true;
+            }
+            self.mob_interact(player, item_stack).await
+        })
     }
 
     fn get_entity(&self) -> &Entity {
         &self.get_mob_entity().living_entity.entity
     }
 
+    fn get_leash(&self) -> Option<&Leash> {
+        Some(&self.get_entity().leash)
+    }
+
+    fn get_caravan(&self) -> Option<&Caravan> {
+        self.get_mob_caravan()
+    }
+
     fn get_living_entity(&self) -> Option<&LivingEntity> {
         Some(&self.get_mob_entity().living_entity)
     }
@@ -465,6 +538,41 @@ impl<T: Mob + Send + 'static> EntityBase for T {
     fn get_y_velocity_drag(&self) -> Option<f64> {
         self.get_mob_y_velocity_drag()
     }
+
+    fn init_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            self.get_mob_entity()
+                .living_entity
+                .entity
+                .init_data_tracker()
+                .await;
+            self.init_mob_data_tracker().await;
+        })
+    }
+
+    fn get_passenger_attachment(&self, index: usize, count: usize) -> Vector3<f64> {
+        self.get_mob_passenger_attachment(index, count)
+    }
+
+    fn get_controlling_passenger(&self) -> EntityBaseFuture<'_, Option<Arc<dyn EntityBase>>> {
+        self.get_mob_controlling_passenger()
+    }
+
+    fn boost(&self) -> EntityBaseFuture<'_, bool> {
+        self.mob_boost()
+    }
+
+    fn start_riding_jump(&self, strength: i32) -> EntityBaseFuture<'_, ()> {
+        self.mob_start_riding_jump(strength)
+    }
+
+    fn open_inventory<'a>(&'a self, player: &'a Player) -> EntityBaseFuture<'a, ()> {
+        self.open_mob_inventory(player)
+    }
+
+    fn drop_inventory(&self) -> EntityBaseFuture<'_, ()> {
+        self.drop_mob_inventory()
+    }
 }
 
 #[expect(dead_code)]
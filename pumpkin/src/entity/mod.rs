@@ -8,7 +8,9 @@ use crate::{
 use arc_swap::ArcSwap;
 use bytes::BufMut;
 use crossbeam::atomic::AtomicCell;
+use leash::Leash;
 use living::LivingEntity;
+use passive::horse::llama::Caravan;
 use player::Player;
 use pumpkin_data::BlockState;
 use pumpkin_data::block_properties::{EnumVariants, Integer0To15, blocks_movement};
@@ -23,6 +25,7 @@ use pumpkin_data::{
     block_properties::{Facing, HorizontalFacing},
     damage::DamageType,
     entity::{EntityPose, EntityType},
+    item::Item,
     sound::{Sound, SoundCategory},
 };
 use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
@@ -31,8 +34,9 @@ use pumpkin_protocol::{
     PositionFlag,
     codec::var_int::VarInt,
     java::client::play::{
-        CEntityPositionSync, CEntityVelocity, CHeadRot, CPlayerPosition, CSetEntityMetadata,
-        CSetPassengers, CSpawnEntity, CUpdateEntityRot, Metadata,
+        CEntityPositionSync, CEntityVelocity, CHeadRot, CPlayerPosition, CRemoveEntities,
+        CSetEntityLink, CSetEntityMetadata, CSetPassengers, CSpawnEntity, CUpdateEntityRot,
+        Metadata,
     },
 };
 use pumpkin_util::math::vector3::Axis;
@@ -50,6 +54,7 @@ use pumpkin_util::version::MinecraftVersion;
 use pumpkin_world::item::ItemStack;
 use serde::Serialize;
 use std::collections::BTreeMap;
+use std::num::NonZeroI32;
 use std::pin::Pin;
 use std::sync::{
     Arc,
@@ -59,6 +64,7 @@ use std::sync::{
     },
 };
 use tokio::sync::Mutex;
+use tracker::{EntityTracker, MovementUpdate, TrackingSettings, encode_angle};
 use uuid::Uuid;
 
 pub mod ai;
@@ -71,13 +77,17 @@ pub mod experience_orb;
 pub mod falling;
 pub mod hunger;
 pub mod item;
+pub mod leash;
+pub mod lightning;
 pub mod living;
 pub mod mob;
 pub mod passive;
 pub mod player;
 pub mod projectile;
 pub mod projectile_deflection;
+pub mod recipe_book;
 pub mod tnt;
+pub mod tracker;
 pub mod r#type;
 pub mod vehicle;
 
@@ -238,10 +248,70 @@ pub trait EntityBase: Send + Sync + NBTStorage {
         Box::pin(async {})
     }
 
+    /// Called every tick a lightning bolt strikes this entity. `caller` is this entity.
+    fn on_struck_by_lightning<'a>(
+        &'a self,
+        caller: &'a dyn EntityBase,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            let entity = self.get_entity();
+            if entity.fire_ticks.fetch_add(1, Ordering::Relaxed) == -1 {
+                entity.set_on_fire_for(8.0);
+            }
+            self.damage(caller, 5.0, DamageType::LIGHTNING_BOLT).await;
+        })
+    }
+
     fn set_paddle_state(&self, _left: bool, _right: bool) -> EntityBaseFuture<'_, ()> {
         Box::pin(async {})
     }
 
+    /// Whether `passenger` may start riding this entity. Only checked when mounting isn't forced.
+    fn can_add_passenger<'a>(
+        &'a self,
+        _passenger: &'a dyn EntityBase,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move { !self.get_entity().has_passengers().await })
+    }
+
+    /// Where the passenger at `index` out of `count` passengers sits, relative to this entity's
+    /// position when it faces south.
+    fn get_passenger_attachment(&self, _index: usize, _count: usize) -> Vector3<f64> {
+        Vector3::new(0.0, f64::from(self.get_entity().height()), 0.0)
+    }
+
+    /// The point of this entity that rests on the seat of its vehicle, relative to its position.
+    fn get_vehicle_attachment(&self) -> Vector3<f64> {
+        Vector3::default()
+    }
+
+    /// The passenger steering this entity. Vehicles steered by a player are moved by its client.
+    fn get_controlling_passenger(&self) -> EntityBaseFuture<'_, Option<Arc<dyn EntityBase>>> {
+        Box::pin(async { None })
+    }
+
+    /// Speeds up a vehicle steered with an item on a stick. Returns `false` if it can't be boosted
+    /// or is already boosting.
+    fn boost(&self) -> EntityBaseFuture<'_, bool> {
+        Box::pin(async { false })
+    }
+
+    /// Starts a jump of a vehicle steered by a player, charged to `strength` out of 100. The
+    /// client of the steering player performs the jump itself.
+    fn start_riding_jump(&self, _strength: i32) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
+    /// Opens the inventory of this entity for `player`, for mounts that carry one.
+    fn open_inventory<'a>(&'a self, _player: &'a Player) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async {})
+    }
+
+    /// Drops the items this entity carries besides its equipment, like the chest of a donkey.
+    fn drop_inventory(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async {})
+    }
+
     fn is_in_love(&self) -> bool {
         false
     }
@@ -265,6 +335,16 @@ pub trait EntityBase: Send + Sync + NBTStorage {
         None
     }
 
+    /// The lead attached to this entity, for entities that can be leashed.
+    fn get_leash(&self) -> Option<&Leash> {
+        None
+    }
+
+    /// The caravan this entity travels in, for llamas.
+    fn get_caravan(&self) -> Option<&Caravan> {
+        None
+    }
+
     fn get_player(&self) -> Option<&Player> {
         None
     }
@@ -459,6 +539,10 @@ pub struct Entity {
     pub velocity_dirty: AtomicBool,
     /// Set when an Entity is to be removed but could still be referenced
     pub removed: AtomicBool,
+    /// What clients were last told about this entity, plus metadata waiting to be sent
+    pub tracker: Mutex<EntityTracker>,
+    /// The lead tied to this entity, only ever attached to mobs that can be leashed
+    pub leash: Leash,
 }
 
 impl Entity {
@@ -548,6 +632,8 @@ impl Entity {
             movement_multiplier: AtomicCell::new(Vector3::default()),
             velocity_dirty: AtomicBool::new(true),
             removed: AtomicBool::new(false),
+            tracker: Mutex::new(EntityTracker::default()),
+            leash: Leash::default(),
         }
     }
 
@@ -607,6 +693,15 @@ impl Entity {
         f64::from(Self::get_entity_dimensions(self.pose.load()).eye_height)
     }
 
+    /// The item a creative player gets when picking this entity: its spawn egg, or the item the
+    /// entity was placed from (boats, minecarts, armor stands, ...).
+    pub fn get_pick_item(&self) -> Option<&'static Item> {
+        let name = self.entity_type.resource_name;
+        Item::from_registry_key(&format!("{name}_spawn_egg"))
+            .or_else(|| Item::from_registry_key(name))
+            .or_else(|| (self.entity_type == &EntityType::LEASH_KNOT).then_some(&Item::LEAD))
+    }
+
     /// Updates the entity's position, block position, and chunk position.
     ///
     /// This function calculates the new position, block position, and chunk position based on the provided coordinates. If any of these values change, the corresponding fields are updated.
@@ -1013,40 +1108,233 @@ impl Entity {
         suffocating
     }
 
-    pub async fn send_pos_rot(&self) {
-        let old = self.update_last_pos();
+    /// Sends movement updates to the players tracking this entity.
+    ///
+    /// Only players within the entity type's tracking range receive updates, and only every
+    /// [`TrackingSettings::update_interval`] ticks. Small movements are sent as deltas, players
+    /// that just came into range get the entity spawned with its full state and players that
+    /// left the range are told to remove it. `leash_holder` is the id of the entity holding this
+    /// one's lead, so new watchers also see the lead.
+    #[allow(clippy::too_many_lines)]
+    pub async fn send_tracking_update(&self, leash_holder: Option<i32>) {
+        let world = self.world.load();
+        let settings = TrackingSettings::of(self.entity_type);
+        let range = Self::tracking_range(&world, settings);
+        let position = self.pos.load();
+        let players = world.players.load();
+        let in_range: Vec<&Arc<Player>> = players
+            .iter()
+            .filter(|player| {
+                let offset = player.position() - position;
+                offset.x.mul_add(offset.x, offset.z * offset.z) <= range * range
+            })
+            .collect();
 
-        let new = self.pos.load();
+        let yaw = self.yaw.load();
+        let pitch = self.pitch.load();
+        // Only mobs turn their head independently of their body
+        let head_yaw = if self.entity_type.mob {
+            self.head_yaw.load()
+        } else {
+            yaw
+        };
+        let Some(update) = self.tracker.lock().await.tick(
+            settings,
+            in_range.iter().map(|player| player.entity_id()).collect(),
+            position,
+            yaw,
+            pitch,
+            head_yaw,
+        ) else {
+            return;
+        };
 
-        let converted = Vector3::new(
-            new.x.mul_add(4096.0, -(old.x * 4096.0)) as i16,
-            new.y.mul_add(4096.0, -(old.y * 4096.0)) as i16,
-            new.z.mul_add(4096.0, -(old.z * 4096.0)) as i16,
+        let entity_id = VarInt(self.entity_id);
+        let on_ground = self.on_ground.load(Relaxed);
+        let (new_watchers, watchers): (Vec<_>, Vec<_>) = in_range
+            .iter()
+            .partition(|player| update.new_watchers.contains(&player.entity_id()));
+        let position_sync = CEntityPositionSync::new(
+            entity_id,
+            position,
+            Vector3::new(0.0, 0.0, 0.0),
+            yaw,
+            pitch,
+            on_ground,
         );
 
-        let yaw = self.yaw.load();
+        match update.movement {
+            MovementUpdate::None => {}
+            MovementUpdate::Rotation => {
+                World::broadcast_packet_to(
+                    watchers.iter().copied(),
+                    &CUpdateEntityRot::new(
+                        entity_id,
+                        encode_angle(yaw),
+                        encode_angle(pitch),
+                        on_ground,
+                    ),
+                )
+                .await;
+            }
+            MovementUpdate::Delta {
+                delta,
+                rotated: true,
+            } => {
+                World::broadcast_packet_to(
+                    watchers.iter().copied(),
+                    &CUpdateEntityPosRot::new(
+                        entity_id,
+                        delta,
+                        encode_angle(yaw),
+                        encode_angle(pitch),
+                        on_ground,
+                    ),
+                )
+                .await;
+            }
+            MovementUpdate::Delta {
+                delta,
+                rotated: false,
+            } => {
+                World::broadcast_packet_to(
+                    watchers.iter().copied(),
+                    &CUpdateEntityPos::new(entity_id, delta, on_ground),
+                )
+                .await;
+            }
+            MovementUpdate::Sync => {
+                World::broadcast_packet_to(watchers.iter().copied(), &position_sync).await;
+            }
+        }
 
-        let pitch = self.pitch.load();
+        if !new_watchers.is_empty() {
+            self.send_tracking_start(
+                &new_watchers,
+                &position_sync,
+                encode_angle(head_yaw),
+                leash_holder,
+            )
+            .await;
+        }
 
-        // Broadcast the update packet.
+        if !update.removed_watchers.is_empty() {
+            World::broadcast_packet_to(
+                players
+                    .iter()
+                    .filter(|player| update.removed_watchers.contains(&player.entity_id())),
+                &CRemoveEntities::new(&[entity_id]),
+            )
+            .await;
+        }
 
-        // TODO: Do caching to only send the packet when needed.
+        if let Some(head_yaw) = update.head_yaw {
+            World::broadcast_packet_to(
+                watchers.iter().copied(),
+                &CHeadRot::new(entity_id, head_yaw),
+            )
+            .await;
+        }
+    }
 
-        let yaw = (yaw * 256.0 / 360.0).rem_euclid(256.0) as u8;
+    /// Returns how far away players can be to still track an entity, in blocks.
+    fn tracking_range(world: &World, settings: TrackingSettings) -> f64 {
+        let view_distance = world.server.upgrade().map_or(i32::MAX, |server| {
+            NonZeroI32::from(server.basic_config.view_distance).get()
+        });
+        settings.range_blocks(view_distance)
+    }
 
-        let pitch = (pitch * 256.0 / 360.0).rem_euclid(256.0);
+    /// Spawns this entity for players that just started tracking it, along with its metadata,
+    /// full movement state and riding links.
+    async fn send_tracking_start(
+        &self,
+        players: &[&Arc<Player>],
+        position_sync: &CEntityPositionSync,
+        head_yaw: u8,
+        leash_holder: Option<i32>,
+    ) {
+        let entity_id = VarInt(self.entity_id);
+        World::broadcast_packet_to(players.iter().copied(), &self.create_spawn_packet()).await;
+        {
+            let tracker = self.tracker.lock().await;
+            for player in players {
+                if let ClientPlatform::Java(client) = &player.client {
+                    let mut buf = tracker.encode_metadata(client.version.load(), None);
+                    if buf.is_empty() {
+                        continue;
+                    }
+                    buf.put_u8(255);
+                    player
+                        .client
+                        .enqueue_packet(&CSetEntityMetadata::new(entity_id, buf.into()))
+                        .await;
+                }
+            }
+        }
+        World::broadcast_packet_to(players.iter().copied(), position_sync).await;
+        World::broadcast_packet_to(players.iter().copied(), &CHeadRot::new(entity_id, head_yaw))
+            .await;
+        let passengers: Vec<VarInt> = self
+            .passengers
+            .lock()
+            .await
+            .iter()
+            .map(|passenger| passenger.get_entity().entity_id.into())
+            .collect();
+        if !passengers.is_empty() {
+            World::broadcast_packet_to(
+                players.iter().copied(),
+                &CSetPassengers::new(entity_id, &passengers),
+            )
+            .await;
+        }
 
+        // If this entity is riding something, the new watchers also need to know so
+        let vehicle = self.vehicle.lock().await.clone();
+        if let Some(vehicle) = vehicle {
+            let vehicle = vehicle.get_entity();
+            let passengers: Vec<VarInt> = vehicle
+                .passengers
+                .lock()
+                .await
+                .iter()
+                .map(|passenger| passenger.get_entity().entity_id.into())
+                .collect();
+            World::broadcast_packet_to(
+                players.iter().copied(),
+                &CSetPassengers::new(vehicle.entity_id.into(), &passengers),
+            )
+            .await;
+        }
+
+        if let Some(holder_id) = leash_holder {
+            World::broadcast_packet_to(
+                players.iter().copied(),
+                &CSetEntityLink::new(self.entity_id, holder_id),
+            )
+            .await;
+        }
+    }
+
+    /// Broadcasts this entity's absolute position, e.g. after a teleport, and records it as
+    /// the last position clients know about.
+    pub async fn send_position_sync(&self) {
+        let position = self.pos.load();
+        let yaw = self.yaw.load();
+        let pitch = self.pitch.load();
+        self.tracker.lock().await.mark_synced(position, yaw, pitch);
         self.world
             .load()
-            .broadcast_packet_all(&CUpdateEntityPosRot::new(
+            .broadcast_packet_all(&CEntityPositionSync::new(
                 self.entity_id.into(),
-                Vector3::new(converted.x, converted.y, converted.z),
+                position,
+                Vector3::new(0.0, 0.0, 0.0),
                 yaw,
-                pitch as u8,
-                self.on_ground.load(Relaxed),
+                pitch,
+                self.on_ground.load(Ordering::SeqCst),
             ))
             .await;
-        self.send_head_rot(yaw).await;
     }
 
     pub fn update_last_pos(&self) -> Vector3<f64> {
@@ -2050,19 +2338,44 @@ impl Entity {
             .await;
     }
 
+    /// Queues metadata changes to be sent with the next [`Self::flush_metadata`].
+    ///
+    /// The values are remembered independently of any protocol version, so players joining or
+    /// starting to track the entity later are sent them too. A later change to the same index
+    /// within a tick replaces the earlier one.
     pub async fn send_meta_data<T: Serialize>(&self, meta: &[Metadata<T>]) {
-        let mut buf = Vec::new();
+        let mut tracker = self.tracker.lock().await;
         for meta in meta {
-            meta.write(&mut buf, &MinecraftVersion::V_1_21_11).unwrap();
+            tracker.queue_metadata(meta.to_serialized().unwrap());
         }
-        buf.put_u8(255);
+    }
+
+    /// Sends all metadata changes queued during this tick in a single packet per player.
+    ///
+    /// Players are always sent to everyone in their world, while other entities only send their
+    /// changes to the players tracking them.
+    pub async fn flush_metadata(&self) {
+        let mut tracker = self.tracker.lock().await;
+        let pending = tracker.take_metadata();
+        if pending.is_empty() {
+            return;
+        }
+        let is_player = self.entity_type == &EntityType::PLAYER;
         let world = self.world.load();
+        let mut encoded: BTreeMap<MinecraftVersion, Vec<u8>> = BTreeMap::new();
         for player in world.players.load().iter() {
+            if !is_player && !tracker.is_watched_by(player.entity_id()) {
+                continue;
+            }
             if let ClientPlatform::Java(client) = &player.client {
-                let mut buf = Vec::new();
-                for meta in meta {
-                    meta.write(&mut buf, &client.version.load()).unwrap();
+                let version = client.version.load();
+                let entries = encoded
+                    .entry(version)
+                    .or_insert_with(|| tracker.encode_metadata(version, Some(&pending)));
+                if entries.is_empty() {
+                    continue;
                 }
+                let mut buf = entries.clone();
                 buf.put_u8(255);
                 player
                     .client
@@ -2170,17 +2483,7 @@ impl Entity {
         if let Some(pitch) = pitch {
             self.set_pitch(pitch);
         }
-        self.world
-            .load()
-            .broadcast_packet_all(&CEntityPositionSync::new(
-                self.entity_id.into(),
-                position,
-                Vector3::new(0.0, 0.0, 0.0),
-                yaw.unwrap_or(self.yaw.load()),
-                pitch.unwrap_or(self.pitch.load()),
-                self.on_ground.load(Ordering::SeqCst),
-            ))
-            .await;
+        self.send_position_sync().await;
     }
 
     pub fn get_eye_pos(&self) -> Vector3<f64> {
@@ -2213,6 +2516,121 @@ impl Entity {
         vehicle.is_some()
     }
 
+    /// Makes this entity, `caller`, ride `vehicle`, leaving its current vehicle first. Unless
+    /// `force` is set, the riding cooldown and `vehicle` can refuse it. Returns whether it mounted.
+    pub async fn start_riding(
+        &self,
+        caller: Arc<dyn EntityBase>,
+        vehicle: Arc<dyn EntityBase>,
+        force: bool,
+    ) -> bool {
+        let current = self.vehicle.lock().await.clone();
+        if current
+            .is_some_and(|current| current.get_entity().entity_id == vehicle.get_entity().entity_id)
+        {
+            return false;
+        }
+
+        // An entity can't ride itself or anything riding it
+        let mut ancestor = vehicle.clone();
+        loop {
+            if ancestor.get_entity().entity_id == self.entity_id {
+                return false;
+            }
+            let Some(next) = ancestor.get_entity().vehicle.lock().await.clone() else {
+                break;
+            };
+            ancestor = next;
+        }
+
+        if !force
+            && (self.riding_cooldown.load(Relaxed) > 0
+                || !vehicle.can_add_passenger(caller.as_ref()).await)
+        {
+            return false;
+        }
+
+        self.stop_riding().await;
+        self.set_pose(EntityPose::Standing).await;
+        vehicle
+            .get_entity()
+            .add_passenger(vehicle.clone(), caller)
+            .await;
+        Self::update_passenger_positions(vehicle.as_ref()).await;
+        true
+    }
+
+    /// Dismounts this entity from its vehicle, if it has one.
+    pub async fn stop_riding(&self) {
+        let vehicle = self.vehicle.lock().await.clone();
+        if let Some(vehicle) = vehicle {
+            vehicle.get_entity().remove_passenger(self.entity_id).await;
+        }
+    }
+
+    /// Dismounts every passenger of this entity.
+    pub async fn remove_all_passengers(&self) {
+        let passengers = self.passengers.lock().await.clone();
+        for passenger in passengers {
+            self.remove_passenger(passenger.get_entity().entity_id)
+                .await;
+        }
+    }
+
+    /// Ticks the passengers of `vehicle` right after it, then moves them onto their seats.
+    /// Passengers frozen by `/tick freeze` keep their seat without ticking.
+    pub fn tick_passengers<'a>(
+        vehicle: &'a dyn EntityBase,
+        server: &'a Server,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let passengers = vehicle.get_entity().passengers.lock().await.clone();
+            for passenger in &passengers {
+                // Players are ticked with the other players
+                if passenger.get_player().is_none()
+                    && !server
+                        .tick_rate_manager
+                        .should_skip_tick(passenger.as_ref())
+                        .await
+                {
+                    let entity = passenger.get_entity();
+                    entity.velocity.store(Vector3::default());
+                    entity.age.fetch_add(1, Relaxed);
+                    passenger.tick(passenger.clone(), server).await;
+                }
+                Self::tick_passengers(passenger.as_ref(), server).await;
+            }
+            if !passengers.is_empty() {
+                Self::update_passenger_positions(vehicle).await;
+            }
+        })
+    }
+
+    /// Moves the passengers of `vehicle`, and theirs, onto their seats.
+    pub fn update_passenger_positions(
+        vehicle: &dyn EntityBase,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            let vehicle_entity = vehicle.get_entity();
+            let passengers = vehicle_entity.passengers.lock().await.clone();
+            let count = passengers.len();
+            let pos = vehicle_entity.pos.load();
+            let yaw = f64::from(-vehicle_entity.yaw.load()).to_radians();
+            let (sin, cos) = yaw.sin_cos();
+
+            for (index, passenger) in passengers.iter().enumerate() {
+                let seat = vehicle.get_passenger_attachment(index, count);
+                let attachment = passenger.get_vehicle_attachment();
+                passenger.get_entity().set_pos(Vector3::new(
+                    pos.x + seat.x.mul_add(cos, seat.z * sin) - attachment.x,
+                    pos.y + seat.y - attachment.y,
+                    pos.z + seat.z.mul_add(cos, -seat.x * sin) - attachment.z,
+                ));
+                Self::update_passenger_positions(passenger.as_ref()).await;
+            }
+        })
+    }
+
     pub async fn add_passenger(
         &self,
         vehicle: Arc<dyn EntityBase>,
@@ -2503,6 +2921,7 @@ impl NBTStorage for Entity {
             if self.has_visual_fire.load(Relaxed) {
                 nbt.put_bool("HasVisualFire", true);
             }
+            self.leash.write_nbt(self, nbt);
 
             // todo more...
         })
@@ -2537,6 +2956,7 @@ impl NBTStorage for Entity {
                 .store(nbt.get_int("PortalCooldown").unwrap_or(0) as u32, Relaxed);
             self.has_visual_fire
                 .store(nbt.get_bool("HasVisualFire").unwrap_or(false), Relaxed);
+            self.leash.read_nbt(nbt);
             // todo more...
         })
     }
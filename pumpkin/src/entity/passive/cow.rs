@@ -1,11 +1,15 @@
 use std::sync::{Arc, Weak};
 
 use crate::entity::attributes::AttributeBuilder;
+use crate::entity::player::Player;
+use crate::item::items::bucket::exchange_held_stack;
 use pumpkin_data::attributes::Attributes;
+use pumpkin_data::sound::{Sound, SoundCategory};
 use pumpkin_data::{entity::EntityType, item::Item};
+use pumpkin_world::item::ItemStack;
 
 use crate::entity::{
-    Entity, NBTStorage,
+    Entity, EntityBaseFuture, NBTStorage,
     ai::goal::{
         escape_danger::EscapeDangerGoal, look_around::LookAroundGoal,
         look_at_entity::LookAtEntityGoal, swim::SwimGoal, tempt::TemptGoal,
@@ -61,4 +65,28 @@ impl Mob for CowEntity {
     fn get_mob_entity(&self) -> &MobEntity {
         &self.mob_entity
     }
+
+    fn mob_interact<'a>(
+        &'a self,
+        player: &'a Player,
+        item_stack: &'a mut ItemStack,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            if item_stack.item.id != Item::BUCKET.id {
+                return false;
+            }
+            let entity = &self.mob_entity.living_entity.entity;
+            entity
+                .world
+                .load()
+                .play_sound(
+                    Sound::EntityCowMilk,
+                    SoundCategory::Players,
+                    &entity.pos.load(),
+                )
+                .await;
+            exchange_held_stack(player, item_stack, ItemStack::new(1, &Item::MILK_BUCKET));
+            true
+        })
+    }
 }
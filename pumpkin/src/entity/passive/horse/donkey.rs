@@ -0,0 +1,184 @@
+use std::sync::{Arc, Weak};
+
+use pumpkin_data::attributes::Attributes;
+use pumpkin_data::damage::DamageType;
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::sound::Sound;
+use pumpkin_inventory::horse::horse_inventory::HorseInventory;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_world::item::ItemStack;
+
+use crate::entity::attributes::AttributeBuilder;
+use crate::entity::{
+    Entity, EntityBase, EntityBaseFuture, NBTStorage, NbtFuture,
+    ai::goal::{
+        escape_danger::EscapeDangerGoal, follow_parent::FollowParentGoal,
+        look_around::LookAroundGoal, look_at_entity::LookAtEntityGoal,
+        run_around_like_crazy::RunAroundLikeCrazyGoal, swim::SwimGoal, tempt::TemptGoal,
+        wander_around::WanderAroundGoal,
+    },
+    mob::{Mob, MobEntity},
+    passive::horse::{
+        AbstractHorseEntity, HorseKind,
+        horse::{HORSE_FOOD, HORSE_TEMPT_ITEMS},
+    },
+    player::Player,
+};
+
+static DONKEY_KIND: HorseKind = HorseKind {
+    max_temper: 100,
+    food: HORSE_FOOD,
+    can_stand: true,
+    angry_sound: Sound::EntityDonkeyAngry,
+    eat_sound: Sound::EntityDonkeyEat,
+    jump_sound: Some(Sound::EntityDonkeyJump),
+    chest_sound: Some(Sound::EntityDonkeyChest),
+};
+
+static MULE_KIND: HorseKind = HorseKind {
+    max_temper: 100,
+    food: HORSE_FOOD,
+    can_stand: true,
+    angry_sound: Sound::EntityMuleAngry,
+    eat_sound: Sound::EntityMuleEat,
+    jump_sound: Some(Sound::EntityMuleJump),
+    chest_sound: Some(Sound::EntityMuleChest),
+};
+
+/// A donkey or a mule, which can carry a chest but can't wear armor.
+pub struct DonkeyEntity {
+    pub horse: AbstractHorseEntity,
+}
+
+impl DonkeyEntity {
+    pub async fn new(entity: Entity) -> Arc<Self> {
+        let kind = if entity.entity_type == &EntityType::MULE {
+            &MULE_KIND
+        } else {
+            &DONKEY_KIND
+        };
+        let horse = AbstractHorseEntity::new(entity, kind, HorseInventory::MAX_COLUMNS);
+        horse.randomize_max_health();
+        let mob_arc = Arc::new(Self { horse });
+        let mob_weak: Weak<dyn Mob> = {
+            let mob_arc: Arc<dyn Mob> = mob_arc.clone();
+            Arc::downgrade(&mob_arc)
+        };
+
+        {
+            let mut goal_selector = mob_arc.horse.mob_entity.goals_selector.lock().await;
+
+            goal_selector.add_goal(0, Box::new(SwimGoal::default()));
+            goal_selector.add_goal(1, EscapeDangerGoal::new(1.2));
+            goal_selector.add_goal(1, Box::new(RunAroundLikeCrazyGoal::new(1.2)));
+            goal_selector.add_goal(3, Box::new(TemptGoal::new(1.25, HORSE_TEMPT_ITEMS)));
+            goal_selector.add_goal(4, Box::new(FollowParentGoal::new(1.0)));
+            goal_selector.add_goal(6, Box::new(WanderAroundGoal::new(0.7)));
+            goal_selector.add_goal(
+                7,
+                LookAtEntityGoal::with_default(mob_weak, &EntityType::PLAYER, 6.0),
+            );
+            goal_selector.add_goal(8, Box::new(LookAroundGoal::default()));
+        };
+
+        mob_arc
+    }
+
+    #[must_use]
+    pub fn create_attributes() -> AttributeBuilder {
+        AttributeBuilder::new()
+            .add(Attributes::MOVEMENT_SPEED, 0.175)
+            .add(Attributes::JUMP_STRENGTH, 0.5)
+            .add(Attributes::MAX_HEALTH, 53.0)
+    }
+}
+
+impl NBTStorage for DonkeyEntity {
+    fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.horse.write_nbt(nbt).await;
+        })
+    }
+
+    fn read_nbt_non_mut<'a>(&'a self, nbt: &'a NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.horse.read_nbt(nbt).await;
+        })
+    }
+}
+
+impl Mob for DonkeyEntity {
+    fn get_mob_entity(&self) -> &MobEntity {
+        &self.horse.mob_entity
+    }
+
+    fn mob_tick<'a>(&'a self, _caller: &'a Arc<dyn EntityBase>) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            self.horse.tick().await;
+        })
+    }
+
+    fn on_damage<'a>(
+        &'a self,
+        _damage_type: DamageType,
+        _source: Option<&'a dyn EntityBase>,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            self.horse.on_damage().await;
+        })
+    }
+
+    fn mob_interact<'a>(
+        &'a self,
+        player: &'a Player,
+        item_stack: &'a mut ItemStack,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            self.horse
+                .interact(player, item_stack, Sound::EntityHorseArmor)
+                .await
+        })
+    }
+
+    fn init_mob_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            self.horse.init_data_tracker().await;
+        })
+    }
+
+    fn get_mob_passenger_attachment(&self, _index: usize, _count: usize) -> Vector3<f64> {
+        let y = if self.horse.mob_entity.living_entity.entity.entity_type == &EntityType::MULE {
+            1.212_5
+        } else {
+            1.112_5
+        };
+        Vector3::new(0.0, y, 0.0)
+    }
+
+    fn get_mob_controlling_passenger(&self) -> EntityBaseFuture<'_, Option<Arc<dyn EntityBase>>> {
+        Box::pin(async move { self.horse.controlling_player().await })
+    }
+
+    fn mob_start_riding_jump(&self, _strength: i32) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            self.horse.start_jump().await;
+        })
+    }
+
+    fn open_mob_inventory<'a>(&'a self, player: &'a Player) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            self.horse.open_inventory(player).await;
+        })
+    }
+
+    fn drop_mob_inventory(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            self.horse.drop_inventory().await;
+        })
+    }
+
+    fn get_owner_uuid(&self) -> Option<uuid::Uuid> {
+        self.horse.get_owner()
+    }
+}
@@ -0,0 +1,324 @@
+use std::sync::atomic::{AtomicI32, Ordering::Relaxed};
+use std::sync::{Arc, Weak};
+
+use crossbeam::atomic::AtomicCell;
+use pumpkin_data::attributes::Attributes;
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::item::Item;
+use pumpkin_data::meta_data_type::MetaDataType;
+use pumpkin_data::sound::Sound;
+use pumpkin_data::tracked_data::TrackedData;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_protocol::{codec::var_int::VarInt, java::client::play::Metadata};
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_world::item::ItemStack;
+use rand::{RngExt, rng};
+
+use crate::entity::attributes::AttributeBuilder;
+use crate::entity::{
+    Entity, EntityBase, EntityBaseFuture, NBTStorage, NbtFuture,
+    ai::goal::{
+        active_target::ActiveTargetGoal, escape_danger::EscapeDangerGoal,
+        follow_caravan::FollowCaravanGoal, follow_parent::FollowParentGoal,
+        look_around::LookAroundGoal, look_at_entity::LookAtEntityGoal, revenge::RevengeGoal,
+        run_around_like_crazy::RunAroundLikeCrazyGoal, swim::SwimGoal, tempt::TemptGoal,
+        wander_around::WanderAroundGoal,
+    },
+    ai::pathfinder::NavigatorGoal,
+    mob::{Mob, MobEntity},
+    passive::horse::{AbstractHorseEntity, HorseFood, HorseKind},
+    player::Player,
+    projectile::{ThrownItemEntity, llama_spit::LlamaSpitEntity},
+};
+
+const LLAMA_TEMPT_ITEMS: &[&Item] = &[&Item::HAY_BLOCK];
+
+const LLAMA_FOOD: &[HorseFood] = &[
+    HorseFood {
+        item: &Item::WHEAT,
+        heal: 2.0,
+        temper: 3,
+    },
+    HorseFood {
+        item: &Item::HAY_BLOCK,
+        heal: 10.0,
+        temper: 6,
+    },
+];
+
+static LLAMA_KIND: HorseKind = HorseKind {
+    max_temper: 30,
+    food: LLAMA_FOOD,
+    can_stand: false,
+    angry_sound: Sound::EntityLlamaAngry,
+    eat_sound: Sound::EntityLlamaEat,
+    jump_sound: None,
+    chest_sound: Some(Sound::EntityLlamaChest),
+};
+
+const SPIT_RANGE_SQ: f64 = 20.0 * 20.0;
+const SPIT_COOLDOWN: i32 = 40;
+const SPIT_SPEED: f64 = 1.5;
+const SPIT_DIVERGENCE: f64 = 10.0;
+const CHASE_SPEED: f64 = 1.25;
+
+/// The llamas a llama follows and is followed by in a caravan, by entity id.
+#[derive(Default)]
+pub struct Caravan {
+    head: AtomicCell<Option<i32>>,
+    tail: AtomicCell<Option<i32>>,
+}
+
+impl Caravan {
+    pub fn head(&self) -> Option<i32> {
+        self.head.load()
+    }
+
+    pub fn is_in_caravan(&self) -> bool {
+        self.head.load().is_some()
+    }
+
+    pub fn has_tail(&self) -> bool {
+        self.tail.load().is_some()
+    }
+
+    /// Makes the llama `id` follow the llama `head_id`, whose caravan is `head`.
+    pub fn join(&self, head: &Self, id: i32, head_id: i32) {
+        self.head.store(Some(head_id));
+        head.tail.store(Some(id));
+    }
+
+    /// Leaves the caravan, freeing up the spot behind `head`.
+    pub fn leave(&self, head: Option<&dyn EntityBase>) {
+        if let Some(caravan) = head.and_then(EntityBase::get_caravan) {
+            caravan.tail.store(None);
+        }
+        self.head.store(None);
+    }
+
+    /// Forgets a follower that died or left without telling.
+    fn tick(&self, entity: &Entity) {
+        let Some(tail) = self.tail.load() else {
+            return;
+        };
+        let follows = entity
+            .world
+            .load()
+            .get_entity_by_id(tail)
+            .and_then(|tail| tail.get_caravan().and_then(Self::head));
+        if follows != Some(entity.entity_id) {
+            self.tail.store(None);
+        }
+    }
+}
+
+pub struct LlamaEntity {
+    pub horse: AbstractHorseEntity,
+    /// How many chest columns the llama has, and how likely wolves are to flee from it.
+    strength: AtomicI32,
+    caravan: Caravan,
+    spit_cooldown: AtomicI32,
+}
+
+impl LlamaEntity {
+    pub async fn new(entity: Entity) -> Arc<Self> {
+        let max_strength = if rng().random::<f32>() < 0.04 { 5 } else { 3 };
+        let strength = 1 + rng().random_range(0..max_strength);
+        let horse = AbstractHorseEntity::new(entity, &LLAMA_KIND, strength as u8);
+        horse.randomize_max_health();
+        let llama = Self {
+            horse,
+            strength: AtomicI32::new(strength),
+            caravan: Caravan::default(),
+            spit_cooldown: AtomicI32::new(0),
+        };
+        let mob_arc = Arc::new(llama);
+        let mob_weak: Weak<dyn Mob> = {
+            let mob_arc: Arc<dyn Mob> = mob_arc.clone();
+            Arc::downgrade(&mob_arc)
+        };
+
+        {
+            let mut goal_selector = mob_arc.horse.mob_entity.goals_selector.lock().await;
+            let mut target_selector = mob_arc.horse.mob_entity.target_selector.lock().await;
+
+            goal_selector.add_goal(0, Box::new(SwimGoal::default()));
+            goal_selector.add_goal(1, Box::new(RunAroundLikeCrazyGoal::new(1.2)));
+            goal_selector.add_goal(2, Box::new(FollowCaravanGoal::new(2.1)));
+            goal_selector.add_goal(3, EscapeDangerGoal::new(1.2));
+            goal_selector.add_goal(5, Box::new(TemptGoal::new(1.25, LLAMA_TEMPT_ITEMS)));
+            goal_selector.add_goal(6, Box::new(FollowParentGoal::new(1.0)));
+            goal_selector.add_goal(7, Box::new(WanderAroundGoal::new(0.7)));
+            goal_selector.add_goal(
+                8,
+                LookAtEntityGoal::with_default(mob_weak, &EntityType::PLAYER, 6.0),
+            );
+            goal_selector.add_goal(9, Box::new(LookAroundGoal::default()));
+
+            target_selector.add_goal(1, Box::new(RevengeGoal::new(false)));
+            target_selector.add_goal(
+                2,
+                ActiveTargetGoal::with_default(&mob_arc.horse.mob_entity, &EntityType::WOLF, false),
+            );
+        };
+
+        mob_arc
+    }
+
+    #[must_use]
+    pub fn create_attributes() -> AttributeBuilder {
+        AttributeBuilder::new()
+            .add(Attributes::MOVEMENT_SPEED, 0.175)
+            .add(Attributes::JUMP_STRENGTH, 0.5)
+            .add(Attributes::MAX_HEALTH, 53.0)
+            .add(Attributes::FOLLOW_RANGE, 40.0)
+    }
+
+    /// Spits at the target once in range, chasing it until then. Llamas only spit once per
+    /// provocation, so the target is dropped afterwards.
+    async fn tick_spit(&self) {
+        if self.spit_cooldown.load(Relaxed) > 0 {
+            self.spit_cooldown.fetch_sub(1, Relaxed);
+        }
+        let mob_entity = &self.horse.mob_entity;
+        let Some(target) = mob_entity.target.lock().await.clone() else {
+            return;
+        };
+        let entity = &mob_entity.living_entity.entity;
+        let target_entity = target.get_entity();
+        if !target_entity.is_alive() {
+            self.set_mob_target(None).await;
+            return;
+        }
+        let pos = entity.pos.load();
+        let target_pos = target_entity.pos.load();
+        if pos.squared_distance_to_vec(&target_pos) > SPIT_RANGE_SQ {
+            if entity.age.load(Relaxed) % 10 == 0 {
+                let mut navigator = mob_entity.navigator.lock().await;
+                navigator.set_progress(NavigatorGoal::new(pos, target_pos, CHASE_SPEED));
+            }
+            return;
+        }
+        if self.spit_cooldown.load(Relaxed) > 0 {
+            return;
+        }
+        self.spit(target_entity).await;
+        self.spit_cooldown.store(SPIT_COOLDOWN, Relaxed);
+        self.set_mob_target(None).await;
+    }
+
+    async fn spit(&self, target: &Entity) {
+        let entity = &self.horse.mob_entity.living_entity.entity;
+        let world = entity.world.load();
+
+        let projectile = Entity::new(world.clone(), entity.pos.load(), &EntityType::LLAMA_SPIT);
+        let spit = ThrownItemEntity::new(projectile, entity);
+        // Spit from the mouth, which sits in front of the body
+        let yaw = f64::from(entity.body_yaw.load()).to_radians();
+        let offset = f64::from(entity.width() + 1.0) * 0.5;
+        let mouth = spit
+            .entity
+            .pos
+            .load()
+            .add_raw(-offset * yaw.sin(), 0.0, offset * yaw.cos());
+        spit.entity.pos.store(mouth);
+
+        let target_pos = target.pos.load();
+        let delta = target_pos.sub(&mouth);
+        let aim_y = target_pos.y + f64::from(target.height()) / 3.0 - mouth.y
+            + delta.horizontal_length() * 0.2;
+        spit.set_velocity(delta.x, aim_y, delta.z, SPIT_SPEED, SPIT_DIVERGENCE);
+
+        entity.play_sound(Sound::EntityLlamaSpit).await;
+        world
+            .spawn_entity(Arc::new(LlamaSpitEntity::new(spit)))
+            .await;
+    }
+}
+
+impl NBTStorage for LlamaEntity {
+    fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.horse.write_nbt(nbt).await;
+            nbt.put_int("Strength", self.strength.load(Relaxed));
+        })
+    }
+
+    fn read_nbt_non_mut<'a>(&'a self, nbt: &'a NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            if let Some(strength) = nbt.get_int("Strength") {
+                let strength = strength.clamp(1, 5);
+                self.strength.store(strength, Relaxed);
+                self.horse.set_chest_columns(strength as u8);
+            }
+            self.horse.read_nbt(nbt).await;
+        })
+    }
+}
+
+impl Mob for LlamaEntity {
+    fn get_mob_entity(&self) -> &MobEntity {
+        &self.horse.mob_entity
+    }
+
+    fn mob_tick<'a>(&'a self, _caller: &'a Arc<dyn EntityBase>) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            self.horse.tick().await;
+            self.caravan
+                .tick(&self.horse.mob_entity.living_entity.entity);
+            self.tick_spit().await;
+        })
+    }
+
+    fn mob_interact<'a>(
+        &'a self,
+        player: &'a Player,
+        item_stack: &'a mut ItemStack,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            self.horse
+                .interact(player, item_stack, Sound::EntityLlamaSwag)
+                .await
+        })
+    }
+
+    fn init_mob_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            self.horse
+                .mob_entity
+                .living_entity
+                .entity
+                .send_meta_data(&[Metadata::new(
+                    TrackedData::DATA_STRENGTH,
+                    MetaDataType::INTEGER,
+                    VarInt(self.strength.load(Relaxed)),
+                )])
+                .await;
+            self.horse.init_data_tracker().await;
+        })
+    }
+
+    fn get_mob_passenger_attachment(&self, _index: usize, _count: usize) -> Vector3<f64> {
+        Vector3::new(0.0, 1.37, -0.3)
+    }
+
+    fn open_mob_inventory<'a>(&'a self, player: &'a Player) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            self.horse.open_inventory(player).await;
+        })
+    }
+
+    fn drop_mob_inventory(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            self.horse.drop_inventory().await;
+        })
+    }
+
+    fn get_owner_uuid(&self) -> Option<uuid::Uuid> {
+        self.horse.get_owner()
+    }
+
+    fn get_mob_caravan(&self) -> Option<&Caravan> {
+        Some(&self.caravan)
+    }
+}
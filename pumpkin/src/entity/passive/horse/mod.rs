@@ -0,0 +1,610 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU8, Ordering::Relaxed};
+
+use crossbeam::atomic::AtomicCell;
+use pumpkin_data::attributes::Attributes;
+use pumpkin_data::data_component_impl::EquipmentSlot;
+use pumpkin_data::entity::EntityStatus;
+use pumpkin_data::item::Item;
+use pumpkin_data::meta_data_type::MetaDataType;
+use pumpkin_data::sound::Sound;
+use pumpkin_data::tracked_data::TrackedData;
+use pumpkin_inventory::horse::horse_inventory::HorseInventory;
+use pumpkin_inventory::horse::horse_screen_handler::HorseScreenHandler;
+use pumpkin_inventory::player::player_inventory::PlayerInventory;
+use pumpkin_inventory::screen_handler::{
+    BoxFuture, InventoryPlayer, ScreenHandlerFactory, SharedScreenHandler,
+};
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+use pumpkin_protocol::java::client::play::Metadata;
+use pumpkin_util::text::TextComponent;
+use pumpkin_world::block::entities::player_data::{uuid_from_tag, uuid_to_tag};
+use pumpkin_world::inventory::Inventory;
+use pumpkin_world::item::ItemStack;
+use rand::{RngExt, rng};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::entity::attributes::send_attribute_updates_for_living;
+use crate::entity::{
+    Entity, EntityBase, NBTStorage, mob::MobEntity, passive::steering, player::Player,
+};
+
+pub mod donkey;
+#[allow(clippy::module_inception)]
+pub mod horse;
+pub mod llama;
+
+const TAME_FLAG: u8 = 0x02;
+/// Clients before 1.21.5 only let the rider steer while this flag is set.
+const SADDLED_FLAG: u8 = 0x04;
+const EATING_FLAG: u8 = 0x10;
+const STANDING_FLAG: u8 = 0x20;
+const OPEN_MOUTH_FLAG: u8 = 0x40;
+
+const STAND_TICKS: i32 = 20;
+const OPEN_MOUTH_TICKS: i32 = 30;
+/// One in this many ticks an untamed mount decides whether to accept its rider.
+const TAMING_CHANCE: i32 = 50;
+
+/// The attributes saved with a mount, since they are rolled when it spawns.
+const SAVED_ATTRIBUTES: [(&Attributes, &str); 3] = [
+    (&Attributes::MAX_HEALTH, "minecraft:max_health"),
+    (&Attributes::MOVEMENT_SPEED, "minecraft:movement_speed"),
+    (&Attributes::JUMP_STRENGTH, "minecraft:jump_strength"),
+];
+
+/// An item a mount eats, with the health it restores and the temper it adds.
+pub struct HorseFood {
+    pub item: &'static Item,
+    pub heal: f32,
+    pub temper: i32,
+}
+
+/// What tells the kinds of horse-like mounts apart.
+pub struct HorseKind {
+    /// Taming succeeds once a random roll below this lands under the temper.
+    pub max_temper: i32,
+    pub food: &'static [HorseFood],
+    /// Whether the mount rears up when it is angered, hurt or jumps.
+    pub can_stand: bool,
+    pub angry_sound: Sound,
+    pub eat_sound: Sound,
+    pub jump_sound: Option<Sound>,
+    /// Played when a chest is attached; mounts without one can't carry chests.
+    pub chest_sound: Option<Sound>,
+}
+
+/// Taming, equipment and the inventory shared by horses, donkeys, mules and llamas.
+pub struct AbstractHorseEntity {
+    pub mob_entity: MobEntity,
+    pub inventory: Arc<HorseInventory>,
+    kind: &'static HorseKind,
+    tame: AtomicBool,
+    temper: AtomicI32,
+    owner: AtomicCell<Option<Uuid>>,
+    flags: AtomicU8,
+    chested: AtomicBool,
+    /// The chest columns a carried chest provides.
+    chest_columns: AtomicU8,
+    stand_ticks: AtomicI32,
+    open_mouth_ticks: AtomicI32,
+}
+
+impl AbstractHorseEntity {
+    pub fn new(entity: Entity, kind: &'static HorseKind, chest_columns: u8) -> Self {
+        let mob_entity = MobEntity::new(entity);
+        let living = &mob_entity.living_entity;
+        let inventory = Arc::new(HorseInventory::new(
+            living.entity.entity_type,
+            living.entity_equipment.clone(),
+        ));
+        Self {
+            mob_entity,
+            inventory,
+            kind,
+            tame: AtomicBool::new(false),
+            temper: AtomicI32::new(0),
+            owner: AtomicCell::new(None),
+            flags: AtomicU8::new(0),
+            chested: AtomicBool::new(false),
+            chest_columns: AtomicU8::new(chest_columns),
+            stand_ticks: AtomicI32::new(0),
+            open_mouth_ticks: AtomicI32::new(0),
+        }
+    }
+
+    /// Rolls the max health of a freshly spawned mount between 15 and 30 and heals it fully.
+    pub fn randomize_max_health(&self) {
+        let living = &self.mob_entity.living_entity;
+        let max_health = 15 + rng().random_range(0..8) + rng().random_range(0..9);
+        living.set_attribute_base(&Attributes::MAX_HEALTH, f64::from(max_health));
+        living.health.store(max_health as f32);
+    }
+
+    pub fn is_tame(&self) -> bool {
+        self.tame.load(Relaxed)
+    }
+
+    pub fn get_owner(&self) -> Option<Uuid> {
+        self.owner.load()
+    }
+
+    pub fn is_chested(&self) -> bool {
+        self.chested.load(Relaxed)
+    }
+
+    pub fn set_chest_columns(&self, columns: u8) {
+        self.chest_columns.store(columns, Relaxed);
+        if self.is_chested() {
+            self.inventory.set_columns(columns);
+        }
+    }
+
+    async fn set_flag(&self, flag: u8, value: bool) {
+        let flags = if value {
+            self.flags.fetch_or(flag, Relaxed) | flag
+        } else {
+            self.flags.fetch_and(!flag, Relaxed) & !flag
+        };
+        self.mob_entity
+            .living_entity
+            .entity
+            .send_meta_data(&[Metadata::new(
+                TrackedData::DATA_HORSE_FLAGS,
+                MetaDataType::BYTE,
+                flags as i8,
+            )])
+            .await;
+    }
+
+    async fn set_chested(&self, chested: bool) {
+        self.chested.store(chested, Relaxed);
+        self.inventory.set_columns(if chested {
+            self.chest_columns.load(Relaxed)
+        } else {
+            0
+        });
+        self.mob_entity
+            .living_entity
+            .entity
+            .send_meta_data(&[Metadata::new(
+                TrackedData::DATA_CHEST,
+                MetaDataType::BOOLEAN,
+                chested,
+            )])
+            .await;
+    }
+
+    fn add_temper(&self, temper: i32) {
+        let current = self.temper.load(Relaxed);
+        self.temper
+            .store((current + temper).clamp(0, self.kind.max_temper), Relaxed);
+    }
+
+    async fn stand(&self) {
+        if self.kind.can_stand {
+            self.stand_ticks.store(STAND_TICKS, Relaxed);
+            self.set_flag(STANDING_FLAG, true).await;
+        }
+    }
+
+    async fn open_mouth(&self) {
+        self.open_mouth_ticks.store(OPEN_MOUTH_TICKS, Relaxed);
+        self.set_flag(OPEN_MOUTH_FLAG, true).await;
+    }
+
+    /// Throws a fit, like when an untamed mount is handed an item or bucks off its rider.
+    pub async fn make_mad(&self) {
+        self.open_mouth().await;
+        self.mob_entity
+            .living_entity
+            .entity
+            .play_sound(self.kind.angry_sound)
+            .await;
+        self.stand().await;
+    }
+
+    /// Makes `player` the owner of the mount.
+    pub async fn tame(&self, player: &Player) {
+        self.tame.store(true, Relaxed);
+        self.owner.store(Some(player.gameprofile.id));
+        self.set_flag(TAME_FLAG, true).await;
+        let entity = &self.mob_entity.living_entity.entity;
+        entity
+            .world
+            .load()
+            .send_entity_status(entity, EntityStatus::AddPositivePlayerReactionParticles)
+            .await;
+    }
+
+    /// Rolls whether an untamed mount accepts the player riding it. Every refusal throws the
+    /// player off and makes the next roll more likely to succeed.
+    async fn tick_taming(&self) {
+        let entity = &self.mob_entity.living_entity.entity;
+        if self.is_tame() || rng().random_range(0..TAMING_CHANCE) != 0 {
+            return;
+        }
+        let Some(passenger) = entity.passengers.lock().await.first().cloned() else {
+            return;
+        };
+        let Some(player) = passenger.get_player() else {
+            return;
+        };
+        let max_temper = self.kind.max_temper;
+        if max_temper > 0 && rng().random_range(0..max_temper) < self.temper.load(Relaxed) {
+            self.tame(player).await;
+            return;
+        }
+        self.add_temper(5);
+        entity.remove_all_passengers().await;
+        self.make_mad().await;
+        entity
+            .world
+            .load()
+            .send_entity_status(entity, EntityStatus::AddNegativePlayerReactionParticles)
+            .await;
+    }
+
+    /// The per-tick logic of every mount: animation timers, taming and syncing equipment that
+    /// was changed through the inventory screen.
+    pub async fn tick(&self) {
+        if self.stand_ticks.load(Relaxed) > 0 && self.stand_ticks.fetch_sub(1, Relaxed) == 1 {
+            self.set_flag(STANDING_FLAG, false).await;
+        }
+        if self.open_mouth_ticks.load(Relaxed) > 0
+            && self.open_mouth_ticks.fetch_sub(1, Relaxed) == 1
+        {
+            self.set_flag(OPEN_MOUTH_FLAG | EATING_FLAG, false).await;
+        }
+        self.tick_taming().await;
+        if self.inventory.take_dirty() {
+            self.sync_equipment().await;
+        }
+    }
+
+    async fn sync_equipment(&self) {
+        let living = &self.mob_entity.living_entity;
+        let (saddle, body) = {
+            let equipment = living.entity_equipment.lock().await;
+            (
+                equipment.get(&EquipmentSlot::SADDLE),
+                equipment.get(&EquipmentSlot::BODY),
+            )
+        };
+        let saddle = saddle.lock().await.clone();
+        let body = body.lock().await.clone();
+        self.set_flag(SADDLED_FLAG, !saddle.is_empty()).await;
+        living
+            .send_equipment_changes(&[(EquipmentSlot::SADDLE, saddle), (EquipmentSlot::BODY, body)])
+            .await;
+    }
+
+    /// Feeds the mount, healing it and making it easier to tame. Returns whether it ate.
+    async fn feed(&self, player: &Player, item_stack: &mut ItemStack) -> bool {
+        let Some(food) = self
+            .kind
+            .food
+            .iter()
+            .find(|food| food.item.id == item_stack.item.id)
+        else {
+            return false;
+        };
+        let living = &self.mob_entity.living_entity;
+        let mut ate = living.health.load() < living.get_max_health();
+        if ate {
+            living.heal(food.heal).await;
+        }
+        if food.temper > 0
+            && (ate || !self.is_tame())
+            && self.temper.load(Relaxed) < self.kind.max_temper
+        {
+            self.add_temper(food.temper);
+            ate = true;
+        }
+        if ate {
+            item_stack.decrement_unless_creative(player.gamemode.load(), 1);
+            self.open_mouth().await;
+            self.set_flag(EATING_FLAG, true).await;
+            living.entity.play_sound(self.kind.eat_sound).await;
+        }
+        ate
+    }
+
+    /// Puts a saddle, horse armor or carpet from `item_stack` on a tamed mount.
+    async fn equip(&self, player: &Player, item_stack: &mut ItemStack, sound: Sound) -> bool {
+        if !self.is_tame() {
+            return false;
+        }
+        for slot in [EquipmentSlot::SADDLE, EquipmentSlot::BODY] {
+            if !self.inventory.can_equip(&slot, item_stack) {
+                continue;
+            }
+            let living = &self.mob_entity.living_entity;
+            let stack = living.entity_equipment.lock().await.get_or_insert(&slot);
+            let mut stack = stack.lock().await;
+            if !stack.is_empty() {
+                return false;
+            }
+            *stack = item_stack.split_unless_creative(player.gamemode.load(), 1);
+            drop(stack);
+            self.inventory.mark_dirty();
+            let sound = if slot == EquipmentSlot::SADDLE {
+                Sound::EntityHorseSaddle
+            } else {
+                sound
+            };
+            living.entity.play_sound(sound).await;
+            return true;
+        }
+        false
+    }
+
+    async fn add_chest(&self, player: &Player, item_stack: &mut ItemStack) -> bool {
+        let Some(chest_sound) = self.kind.chest_sound else {
+            return false;
+        };
+        if item_stack.item.id != Item::CHEST.id || !self.is_tame() || self.is_chested() {
+            return false;
+        }
+        item_stack.decrement_unless_creative(player.gamemode.load(), 1);
+        self.set_chested(true).await;
+        self.mob_entity
+            .living_entity
+            .entity
+            .play_sound(chest_sound)
+            .await;
+        true
+    }
+
+    /// Handles a player using `item_stack` on the mount: opening its inventory, feeding,
+    /// equipping it or getting on. `body_sound` is played when body armor is put on.
+    pub async fn interact(
+        &self,
+        player: &Player,
+        item_stack: &mut ItemStack,
+        body_sound: Sound,
+    ) -> bool {
+        let entity = &self.mob_entity.living_entity.entity;
+        if entity.has_passengers().await || entity.age.load(Relaxed) < 0 {
+            return false;
+        }
+        if self.is_tame() && player.living_entity.entity.sneaking.load(Relaxed) {
+            self.open_inventory(player).await;
+            return true;
+        }
+        if self.feed(player, item_stack).await {
+            return true;
+        }
+        if !item_stack.is_empty() && !self.is_tame() {
+            self.make_mad().await;
+            return true;
+        }
+        if self.add_chest(player, item_stack).await
+            || self.equip(player, item_stack, body_sound).await
+        {
+            return true;
+        }
+        steering::mount(entity, player).await
+    }
+
+    /// Opens the mount inventory for `player`, as long as nobody else is riding it.
+    pub async fn open_inventory(&self, player: &Player) {
+        let entity = &self.mob_entity.living_entity.entity;
+        if !self.is_tame() {
+            return;
+        }
+        let passengers = entity.passengers.lock().await.clone();
+        if passengers
+            .iter()
+            .any(|passenger| passenger.get_entity().entity_id != player.entity_id())
+        {
+            return;
+        }
+        let factory = MountScreenFactory {
+            inventory: self.inventory.clone(),
+            name: entity.get_display_name().await,
+        };
+        player
+            .open_mount_screen(&factory, entity.entity_id, self.inventory.columns())
+            .await;
+    }
+
+    /// Drops the chest contents and the chest itself. Equipment is dropped with the rest of
+    /// the equipment on death.
+    pub async fn drop_inventory(&self) {
+        let entity = &self.mob_entity.living_entity.entity;
+        let world = entity.world.load();
+        let block_pos = entity.block_pos.load();
+        for stack in self.inventory.take_chest_items().await {
+            world.drop_stack(&block_pos, stack).await;
+        }
+        if self.is_chested() {
+            world
+                .drop_stack(&block_pos, ItemStack::new(1, &Item::CHEST))
+                .await;
+            self.set_chested(false).await;
+        }
+    }
+
+    /// Rears up and plays the jump sound when the rider starts charging a jump.
+    pub async fn start_jump(&self) {
+        if !steering::is_saddled(&self.mob_entity.living_entity).await {
+            return;
+        }
+        self.stand().await;
+        if let Some(sound) = self.kind.jump_sound {
+            self.mob_entity.living_entity.entity.play_sound(sound).await;
+        }
+    }
+
+    /// Rears up now and then when hurt.
+    pub async fn on_damage(&self) {
+        if rng().random_range(0..3) == 0 {
+            self.stand().await;
+        }
+    }
+
+    /// The first passenger of a saddled mount, if it is a player.
+    pub async fn controlling_player(&self) -> Option<Arc<dyn EntityBase>> {
+        if !steering::is_saddled(&self.mob_entity.living_entity).await {
+            return None;
+        }
+        let passenger = self
+            .mob_entity
+            .living_entity
+            .entity
+            .passengers
+            .lock()
+            .await
+            .first()
+            .cloned()?;
+        passenger.get_player().is_some().then_some(passenger)
+    }
+
+    /// Sends the flags, equipment and attributes that the spawn packet doesn't carry.
+    pub async fn init_data_tracker(&self) {
+        let living = &self.mob_entity.living_entity;
+        self.set_flag(SADDLED_FLAG, steering::is_saddled(living).await)
+            .await;
+        if self.is_chested() {
+            self.set_chested(true).await;
+        }
+        self.sync_equipment().await;
+        send_attribute_updates_for_living(
+            living,
+            SAVED_ATTRIBUTES
+                .iter()
+                .map(|(attribute, _)| (*attribute).clone())
+                .collect(),
+        )
+        .await;
+    }
+
+    pub async fn write_nbt(&self, nbt: &mut NbtCompound) {
+        let living = &self.mob_entity.living_entity;
+        living.write_nbt(nbt).await;
+        nbt.put_bool("Tame", self.is_tame());
+        nbt.put_int("Temper", self.temper.load(Relaxed));
+        if let Some(owner) = self.get_owner() {
+            nbt.put("Owner", uuid_to_tag(owner));
+        }
+
+        let attributes = SAVED_ATTRIBUTES
+            .iter()
+            .map(|(attribute, id)| {
+                let mut attribute_nbt = NbtCompound::new();
+                attribute_nbt.put_string("id", (*id).to_string());
+                attribute_nbt.put_double("base", living.get_attribute_base(attribute));
+                NbtTag::Compound(attribute_nbt)
+            })
+            .collect();
+        nbt.put_list("attributes", attributes);
+
+        let mut equipment = NbtCompound::new();
+        for (slot, key) in [
+            (EquipmentSlot::SADDLE, "saddle"),
+            (EquipmentSlot::BODY, "body"),
+        ] {
+            let stack = living.entity_equipment.lock().await.get(&slot);
+            let stack = stack.lock().await;
+            if !stack.is_empty() {
+                let mut stack_nbt = NbtCompound::new();
+                stack.write_item_stack(&mut stack_nbt);
+                equipment.put_compound(key, stack_nbt);
+            }
+        }
+        nbt.put_compound("equipment", equipment);
+
+        if self.kind.chest_sound.is_some() {
+            nbt.put_bool("ChestedHorse", self.is_chested());
+            self.inventory.write_chest_nbt(nbt).await;
+        }
+    }
+
+    pub async fn read_nbt(&self, nbt: &NbtCompound) {
+        let living = &self.mob_entity.living_entity;
+        // Attributes first, so the saved health isn't clamped to the default max health
+        for attribute_nbt in nbt
+            .get_list("attributes")
+            .unwrap_or_default()
+            .iter()
+            .filter_map(NbtTag::extract_compound)
+        {
+            let (Some(id), Some(base)) = (
+                attribute_nbt.get_string("id"),
+                attribute_nbt.get_double("base"),
+            ) else {
+                continue;
+            };
+            if let Some((attribute, _)) = SAVED_ATTRIBUTES.iter().find(|(_, key)| *key == id) {
+                living.set_attribute_base(attribute, base);
+            }
+        }
+        living.read_nbt_non_mut(nbt).await;
+
+        let tame = nbt.get_bool("Tame").unwrap_or(false);
+        self.tame.store(tame, Relaxed);
+        if tame {
+            self.flags.fetch_or(TAME_FLAG, Relaxed);
+        }
+        self.temper
+            .store(nbt.get_int("Temper").unwrap_or(0), Relaxed);
+        self.owner.store(nbt.get("Owner").and_then(uuid_from_tag));
+
+        if let Some(equipment) = nbt.get_compound("equipment") {
+            for (slot, key) in [
+                (EquipmentSlot::SADDLE, "saddle"),
+                (EquipmentSlot::BODY, "body"),
+            ] {
+                if let Some(stack) = equipment
+                    .get_compound(key)
+                    .and_then(ItemStack::read_item_stack)
+                {
+                    living.entity_equipment.lock().await.put(&slot, stack).await;
+                }
+            }
+        }
+
+        if self.kind.chest_sound.is_some() && nbt.get_bool("ChestedHorse").unwrap_or(false) {
+            self.chested.store(true, Relaxed);
+            self.inventory.set_columns(self.chest_columns.load(Relaxed));
+            self.inventory.read_chest_nbt(nbt);
+        }
+    }
+}
+
+/// Rolls the movement speed of a horse, in blocks per tick.
+fn random_horse_speed() -> f64 {
+    (0.45 + rng().random::<f64>() * 0.3 + rng().random::<f64>() * 0.3 + rng().random::<f64>() * 0.3)
+        * 0.25
+}
+
+/// Rolls the jump strength of a horse.
+fn random_horse_jump_strength() -> f64 {
+    0.4 + rng().random::<f64>() * 0.2 + rng().random::<f64>() * 0.2 + rng().random::<f64>() * 0.2
+}
+
+struct MountScreenFactory {
+    inventory: Arc<HorseInventory>,
+    name: TextComponent,
+}
+
+impl ScreenHandlerFactory for MountScreenFactory {
+    fn create_screen_handler<'a>(
+        &'a self,
+        sync_id: u8,
+        player_inventory: &'a Arc<PlayerInventory>,
+        _player: &'a dyn InventoryPlayer,
+    ) -> BoxFuture<'a, Option<SharedScreenHandler>> {
+        Box::pin(async move {
+            let handler =
+                HorseScreenHandler::new(sync_id, player_inventory, self.inventory.clone()).await;
+            Some(Arc::new(Mutex::new(handler)) as SharedScreenHandler)
+        })
+    }
+
+    fn get_display_name(&self) -> TextComponent {
+        self.name.clone()
+    }
+}
@@ -1,8 +1,11 @@
 pub mod cat;
 pub mod chicken;
 pub mod cow;
+pub mod horse;
 pub mod iron_golem;
 pub mod pig;
 pub mod sheep;
 pub mod snow_golem;
+pub mod steering;
+pub mod strider;
 pub mod wolf;
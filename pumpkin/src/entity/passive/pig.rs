@@ -1,18 +1,25 @@
+use std::sync::atomic::Ordering;
 use std::sync::{Arc, Weak};
 
 use crate::entity::attributes::AttributeBuilder;
 use pumpkin_data::attributes::Attributes;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
+use pumpkin_data::sound::Sound;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_world::item::ItemStack;
 
 use crate::entity::{
-    Entity, NBTStorage,
+    Entity, EntityBase, EntityBaseFuture, NBTStorage, NbtFuture,
     ai::goal::{
         escape_danger::EscapeDangerGoal, look_around::LookAroundGoal,
         look_at_entity::LookAtEntityGoal, swim::SwimGoal, tempt::TemptGoal,
         wander_around::WanderAroundGoal,
     },
     mob::{Mob, MobEntity},
+    passive::steering::{self, ItemBasedSteering},
+    player::Player,
 };
 
 const PIG_FOOD: &[&Item] = &[
@@ -22,14 +29,21 @@ const PIG_FOOD: &[&Item] = &[
     &Item::CARROT_ON_A_STICK,
 ];
 
+/// Items pigs breed with, which a player can't mount a saddled pig while holding.
+const PIG_BREEDING_FOOD: &[&Item] = &[&Item::CARROT, &Item::POTATO, &Item::BEETROOT];
+
 pub struct PigEntity {
     pub mob_entity: MobEntity,
+    steering: ItemBasedSteering,
 }
 
 impl PigEntity {
     pub async fn new(entity: Entity) -> Arc<Self> {
         let mob_entity = MobEntity::new(entity);
-        let pig = Self { mob_entity };
+        let pig = Self {
+            mob_entity,
+            steering: ItemBasedSteering::new(),
+        };
         let mob_arc = Arc::new(pig);
         let mob_weak: Weak<dyn Mob> = {
             let mob_arc: Arc<dyn Mob> = mob_arc.clone();
@@ -61,10 +75,82 @@ impl PigEntity {
     }
 }
 
-impl NBTStorage for PigEntity {}
+impl NBTStorage for PigEntity {
+    fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity.living_entity.entity.write_nbt(nbt).await;
+            steering::write_saddle(&self.mob_entity.living_entity, nbt).await;
+        })
+    }
+
+    fn read_nbt_non_mut<'a>(&'a self, nbt: &'a NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity
+                .living_entity
+                .entity
+                .read_nbt_non_mut(nbt)
+                .await;
+            steering::read_saddle(&self.mob_entity.living_entity, nbt).await;
+        })
+    }
+}
 
 impl Mob for PigEntity {
     fn get_mob_entity(&self) -> &MobEntity {
         &self.mob_entity
     }
+
+    fn mob_tick<'a>(&'a self, _caller: &'a Arc<dyn EntityBase>) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            self.steering.tick();
+        })
+    }
+
+    fn mob_interact<'a>(
+        &'a self,
+        player: &'a Player,
+        item_stack: &'a mut ItemStack,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            let living = &self.mob_entity.living_entity;
+            if steering::try_saddle(living, player, item_stack, Sound::EntityPigSaddle).await {
+                return true;
+            }
+            if PIG_BREEDING_FOOD
+                .iter()
+                .any(|food| food.id == item_stack.item.id)
+                || player.living_entity.entity.sneaking.load(Ordering::Relaxed)
+                || !steering::is_saddled(living).await
+                || living.entity.has_passengers().await
+            {
+                return false;
+            }
+            steering::mount(&living.entity, player).await
+        })
+    }
+
+    fn init_mob_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            steering::sync_saddle(&self.mob_entity.living_entity).await;
+        })
+    }
+
+    fn get_mob_passenger_attachment(&self, _index: usize, _count: usize) -> Vector3<f64> {
+        Vector3::new(0.0, 0.868_75, 0.0)
+    }
+
+    fn get_mob_controlling_passenger(&self) -> EntityBaseFuture<'_, Option<Arc<dyn EntityBase>>> {
+        Box::pin(async move {
+            steering::controlling_player(&self.mob_entity.living_entity, &Item::CARROT_ON_A_STICK)
+                .await
+        })
+    }
+
+    fn mob_boost(&self) -> EntityBaseFuture<'_, bool> {
+        Box::pin(async move {
+            self.steering
+                .boost(&self.mob_entity.living_entity.entity)
+                .await
+        })
+    }
 }
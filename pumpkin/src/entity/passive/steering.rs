@@ -0,0 +1,185 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering::Relaxed};
+
+use pumpkin_data::data_component_impl::EquipmentSlot;
+use pumpkin_data::item::Item;
+use pumpkin_data::meta_data_type::MetaDataType;
+use pumpkin_data::sound::{Sound, SoundCategory};
+use pumpkin_data::tracked_data::TrackedData;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_protocol::{codec::var_int::VarInt, java::client::play::Metadata};
+use pumpkin_world::item::ItemStack;
+use rand::{RngExt, rng};
+
+use crate::entity::player::Player;
+use crate::entity::{Entity, EntityBase, living::LivingEntity};
+
+/// Boost state of a mob steered with an item on a stick, like pigs and striders.
+pub struct ItemBasedSteering {
+    boosting: AtomicBool,
+    boost_time: AtomicI32,
+    boost_time_total: AtomicI32,
+}
+
+impl Default for ItemBasedSteering {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ItemBasedSteering {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            boosting: AtomicBool::new(false),
+            boost_time: AtomicI32::new(0),
+            boost_time_total: AtomicI32::new(0),
+        }
+    }
+
+    /// Starts a boost that lasts 7 to 49 seconds, unless one is running already. The client of
+    /// the steering player speeds the mob up from the synced boost time.
+    pub async fn boost(&self, entity: &Entity) -> bool {
+        if self.boosting.swap(true, Relaxed) {
+            return false;
+        }
+        let total = rng().random_range(140..=980);
+        self.boost_time.store(0, Relaxed);
+        self.boost_time_total.store(total, Relaxed);
+        entity
+            .send_meta_data(&[Metadata::new(
+                TrackedData::DATA_BOOST_TIME,
+                MetaDataType::INTEGER,
+                VarInt(total),
+            )])
+            .await;
+        true
+    }
+
+    pub fn tick(&self) {
+        if self.boosting.load(Relaxed)
+            && self.boost_time.fetch_add(1, Relaxed) + 1 > self.boost_time_total.load(Relaxed)
+        {
+            self.boosting.store(false, Relaxed);
+        }
+    }
+}
+
+pub async fn is_saddled(living: &LivingEntity) -> bool {
+    let saddle = living
+        .entity_equipment
+        .lock()
+        .await
+        .get(&EquipmentSlot::SADDLE);
+    !saddle.lock().await.is_empty()
+}
+
+/// Puts the saddle in `item_stack` on an adult mob without one. Returns whether it was saddled.
+pub async fn try_saddle(
+    living: &LivingEntity,
+    player: &Player,
+    item_stack: &mut ItemStack,
+    sound: Sound,
+) -> bool {
+    if item_stack.item.id != Item::SADDLE.id
+        || living.entity.age.load(Relaxed) < 0
+        || is_saddled(living).await
+    {
+        return false;
+    }
+    let saddle = item_stack.split_unless_creative(player.gamemode.load(), 1);
+    living
+        .entity_equipment
+        .lock()
+        .await
+        .put(&EquipmentSlot::SADDLE, saddle.clone())
+        .await;
+    living
+        .send_equipment_changes(&[(EquipmentSlot::SADDLE, saddle)])
+        .await;
+    let entity = &living.entity;
+    entity
+        .world
+        .load()
+        .play_sound(sound, SoundCategory::Neutral, &entity.pos.load())
+        .await;
+    true
+}
+
+/// Lets `player` ride the saddled mob `vehicle`.
+pub async fn mount(vehicle: &Entity, player: &Player) -> bool {
+    let world = vehicle.world.load();
+    let (Some(vehicle), Some(passenger)) = (
+        world.get_entity_by_id(vehicle.entity_id),
+        world.get_player_by_id(player.entity_id()),
+    ) else {
+        return false;
+    };
+    player
+        .living_entity
+        .entity
+        .start_riding(passenger as Arc<dyn EntityBase>, vehicle, false)
+        .await
+}
+
+/// The first passenger of a saddled mob, if it is a player holding `stick` in either hand.
+pub async fn controlling_player(
+    living: &LivingEntity,
+    stick: &Item,
+) -> Option<Arc<dyn EntityBase>> {
+    if !is_saddled(living).await {
+        return None;
+    }
+    let passenger = living.entity.passengers.lock().await.first().cloned()?;
+    let player = passenger.get_player()?;
+    let main_hand = player.inventory.held_item().lock().await.item.id;
+    let off_hand = player.inventory.off_hand_item().await.lock().await.item.id;
+    (main_hand == stick.id || off_hand == stick.id).then_some(passenger)
+}
+
+/// Sends the saddle to the clients, which don't get the equipment of mobs with their spawn.
+pub async fn sync_saddle(living: &LivingEntity) {
+    let saddle = living
+        .entity_equipment
+        .lock()
+        .await
+        .get(&EquipmentSlot::SADDLE);
+    let saddle = saddle.lock().await.clone();
+    if !saddle.is_empty() {
+        living
+            .send_equipment_changes(&[(EquipmentSlot::SADDLE, saddle)])
+            .await;
+    }
+}
+
+pub async fn write_saddle(living: &LivingEntity, nbt: &mut NbtCompound) {
+    let saddle = living
+        .entity_equipment
+        .lock()
+        .await
+        .get(&EquipmentSlot::SADDLE);
+    let saddle = saddle.lock().await;
+    if saddle.is_empty() {
+        return;
+    }
+    let mut saddle_nbt = NbtCompound::new();
+    saddle.write_item_stack(&mut saddle_nbt);
+    let mut equipment = NbtCompound::new();
+    equipment.put_compound("saddle", saddle_nbt);
+    nbt.put_compound("equipment", equipment);
+}
+
+pub async fn read_saddle(living: &LivingEntity, nbt: &NbtCompound) {
+    if let Some(saddle) = nbt
+        .get_compound("equipment")
+        .and_then(|equipment| equipment.get_compound("saddle"))
+        .and_then(ItemStack::read_item_stack)
+    {
+        living
+            .entity_equipment
+            .lock()
+            .await
+            .put(&EquipmentSlot::SADDLE, saddle)
+            .await;
+    }
+}
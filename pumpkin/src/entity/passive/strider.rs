@@ -0,0 +1,143 @@
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Weak};
+
+use crate::entity::attributes::AttributeBuilder;
+use pumpkin_data::attributes::Attributes;
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::item::Item;
+use pumpkin_data::sound::Sound;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_world::item::ItemStack;
+
+use crate::entity::{
+    Entity, EntityBase, EntityBaseFuture, NBTStorage, NbtFuture,
+    ai::goal::{
+        escape_danger::EscapeDangerGoal, look_around::LookAroundGoal,
+        look_at_entity::LookAtEntityGoal, tempt::TemptGoal, wander_around::WanderAroundGoal,
+    },
+    mob::{Mob, MobEntity},
+    passive::steering::{self, ItemBasedSteering},
+    player::Player,
+};
+
+const STRIDER_TEMPT_ITEMS: &[&Item] = &[&Item::WARPED_FUNGUS, &Item::WARPED_FUNGUS_ON_A_STICK];
+
+pub struct StriderEntity {
+    pub mob_entity: MobEntity,
+    steering: ItemBasedSteering,
+}
+
+impl StriderEntity {
+    pub async fn new(entity: Entity) -> Arc<Self> {
+        let mob_entity = MobEntity::new(entity);
+        let strider = Self {
+            mob_entity,
+            steering: ItemBasedSteering::new(),
+        };
+        let mob_arc = Arc::new(strider);
+        let mob_weak: Weak<dyn Mob> = {
+            let mob_arc: Arc<dyn Mob> = mob_arc.clone();
+            Arc::downgrade(&mob_arc)
+        };
+
+        {
+            let mut goal_selector = mob_arc.mob_entity.goals_selector.lock().await;
+
+            goal_selector.add_goal(1, EscapeDangerGoal::new(1.65));
+            goal_selector.add_goal(3, Box::new(TemptGoal::new(1.4, STRIDER_TEMPT_ITEMS)));
+            goal_selector.add_goal(7, Box::new(WanderAroundGoal::new(1.0)));
+            goal_selector.add_goal(
+                8,
+                LookAtEntityGoal::with_default(mob_weak, &EntityType::PLAYER, 8.0),
+            );
+            goal_selector.add_goal(8, Box::new(LookAroundGoal::default()));
+        };
+
+        mob_arc
+    }
+
+    #[must_use]
+    pub fn create_attributes() -> AttributeBuilder {
+        AttributeBuilder::new()
+            .add(Attributes::MOVEMENT_SPEED, 0.175)
+            .add(Attributes::FOLLOW_RANGE, 16.0)
+    }
+}
+
+impl NBTStorage for StriderEntity {
+    fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity.living_entity.entity.write_nbt(nbt).await;
+            steering::write_saddle(&self.mob_entity.living_entity, nbt).await;
+        })
+    }
+
+    fn read_nbt_non_mut<'a>(&'a self, nbt: &'a NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity
+                .living_entity
+                .entity
+                .read_nbt_non_mut(nbt)
+                .await;
+            steering::read_saddle(&self.mob_entity.living_entity, nbt).await;
+        })
+    }
+}
+
+impl Mob for StriderEntity {
+    fn get_mob_entity(&self) -> &MobEntity {
+        &self.mob_entity
+    }
+
+    fn mob_tick<'a>(&'a self, _caller: &'a Arc<dyn EntityBase>) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            self.steering.tick();
+        })
+    }
+
+    fn mob_interact<'a>(
+        &'a self,
+        player: &'a Player,
+        item_stack: &'a mut ItemStack,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            let living = &self.mob_entity.living_entity;
+            if steering::try_saddle(living, player, item_stack, Sound::EntityStriderSaddle).await {
+                return true;
+            }
+            // Striders breed with warped fungus
+            if item_stack.item.id == Item::WARPED_FUNGUS.id
+                || player.living_entity.entity.sneaking.load(Ordering::Relaxed)
+                || !steering::is_saddled(living).await
+                || living.entity.has_passengers().await
+            {
+                return false;
+            }
+            steering::mount(&living.entity, player).await
+        })
+    }
+
+    fn init_mob_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            steering::sync_saddle(&self.mob_entity.living_entity).await;
+        })
+    }
+
+    fn get_mob_controlling_passenger(&self) -> EntityBaseFuture<'_, Option<Arc<dyn EntityBase>>> {
+        Box::pin(async move {
+            steering::controlling_player(
+                &self.mob_entity.living_entity,
+                &Item::WARPED_FUNGUS_ON_A_STICK,
+            )
+            .await
+        })
+    }
+
+    fn mob_boost(&self) -> EntityBaseFuture<'_, bool> {
+        Box::pin(async move {
+            self.steering
+                .boost(&self.mob_entity.living_entity.entity)
+                .await
+        })
+    }
+}
@@ -1,8 +1,8 @@
 use core::f32;
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::f64::consts::TAU;
-use std::mem;
 use std::num::NonZeroU8;
+use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, AtomicI32, AtomicI64, AtomicU8, AtomicU32, Ordering};
 use std::sync::{Arc, Weak};
 use std::time::{Duration, Instant};
@@ -31,7 +31,7 @@ use uuid::Uuid;
 use crate::entity::attributes::AttributeBuilder;
 use pumpkin_data::attributes::Attributes;
 use pumpkin_data::block_properties::{BlockProperties, EnumVariants, HorizontalFacing};
-use pumpkin_data::damage::DamageType;
+use pumpkin_data::damage::{DamageScaling, DamageType};
 use pumpkin_data::data_component_impl::{AttributeModifiersImpl, Operation};
 use pumpkin_data::data_component_impl::{EquipmentSlot, EquippableImpl, ToolImpl};
 use pumpkin_data::effect::StatusEffect;
@@ -44,7 +44,8 @@ use pumpkin_inventory::player::{
     player_inventory::PlayerInventory, player_screen_handler::PlayerScreenHandler,
 };
 use pumpkin_inventory::screen_handler::{
-    InventoryPlayer, PlayerFuture, ScreenHandler, ScreenHandlerFactory, ScreenHandlerListener,
+    InventoryPlayer, PlayerFuture, ScreenHandler, ScreenHandlerBehaviour, ScreenHandlerFactory,
+    ScreenHandlerListener,
 };
 use pumpkin_inventory::sync_handler::SyncHandler;
 use pumpkin_macros::send_cancellable;
@@ -56,7 +57,7 @@ use pumpkin_protocol::java::client::play::{
     Animation, CAcknowledgeBlockChange, CActionBar, CChangeDifficulty, CChunkBatchEnd,
     CChunkBatchStart, CChunkData, CCloseContainer, CCombatDeath, CCustomPayload,
     CDisguisedChatMessage, CEntityAnimation, CEntityPositionSync, CGameEvent, CKeepAlive,
-    COpenScreen, CParticle, CPlayerAbilities, CPlayerInfoUpdate, CPlayerPosition,
+    CMountScreenOpen, COpenScreen, CParticle, CPlayerAbilities, CPlayerInfoUpdate, CPlayerPosition,
     CPlayerSpawnPosition, CRespawn, CSetContainerContent, CSetContainerProperty, CSetContainerSlot,
     CSetCursorItem, CSetEquipment, CSetExperience, CSetHealth, CSetPlayerInventory,
     CSetSelectedSlot, CSoundEffect, CStopSound, CSubtitle, CSystemChatMessage, CTitleAnimation,
@@ -88,6 +89,7 @@ use crate::net::{DisconnectReason, PlayerConfig};
 use crate::plugin::player::exp_change::PlayerExpChangeEvent;
 use crate::plugin::player::player_change_world::PlayerChangeWorldEvent;
 use crate::plugin::player::player_gamemode_change::PlayerGamemodeChangeEvent;
+use crate::plugin::player::player_idle_change::PlayerIdleChangeEvent;
 use crate::plugin::player::player_permission_check::PlayerPermissionCheckEvent;
 use crate::plugin::player::player_teleport::PlayerTeleportEvent;
 use crate::server::Server;
@@ -98,6 +100,7 @@ use super::combat::{self, AttackType, player_attack_sound};
 use super::hunger::HungerManager;
 use super::item::ItemEntity;
 use super::living::LivingEntity;
+use super::recipe_book::RecipeBook;
 use super::{Entity, EntityBase, NBTStorage, NBTStorageInit};
 use pumpkin_data::potion::Effect;
 use pumpkin_world::chunk_system::ChunkLoading;
@@ -406,10 +409,13 @@ pub struct Player {
     pub breath_manager: BreathManager,
     /// Manages the player's hunger level.
     pub hunger_manager: HungerManager,
+    pub recipe_book: Arc<RecipeBook>,
     /// The ID of the currently open container (if any).
     pub open_container: AtomicCell<Option<u64>>,
     /// The block position of the currently open container screen (if any).
     pub open_container_pos: AtomicCell<Option<BlockPos>>,
+    /// The entity ID of the mount whose inventory screen is open (if any).
+    pub open_mount: AtomicCell<Option<i32>>,
     /// The item currently being held by the player.
     pub carried_item: Mutex<Option<ItemStack>>,
     /// The player's abilities and special powers.
@@ -440,10 +446,16 @@ pub struct Player {
     pub last_keep_alive_time: AtomicCell<Instant>,
     /// The last time the player performed an action (for idle timeout).
     pub last_action_time: AtomicCell<Instant>,
+    /// Whether the player is currently considered idle (AFK).
+    pub idle: AtomicBool,
+    /// The idle state last offered to plugins, so a cancelled change isn't offered again every tick.
+    idle_evaluated: AtomicBool,
     /// The ping in millis.
     pub ping: AtomicU32,
     /// The amount of ticks since the player's last attack.
     pub last_attacked_ticks: AtomicU32,
+    /// Throttles items dropped from the creative inventory, decays by one every tick.
+    pub creative_drop_spam: AtomicI32,
     /// The player's last known experience level.
     pub last_sent_xp: AtomicI32,
     pub last_sent_health: AtomicI32,
@@ -473,7 +485,34 @@ pub struct Player {
     pub screen_handler_sync_handler: Arc<SyncHandler>,
 }
 
+/// Feeds the items entering the player's inventory to their recipe book.
+struct ScreenListener {
+    inventory: Arc<PlayerInventory>,
+    recipe_book: Arc<RecipeBook>,
+}
+
+impl ScreenHandlerListener for ScreenListener {
+    fn on_slot_update<'a>(
+        &'a self,
+        screen_handler: &'a ScreenHandlerBehaviour,
+        slot: u8,
+        stack: ItemStack,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            // Only the player's own inventory counts, not the container they look into
+            let in_inventory = screen_handler.slots.get(slot as usize).is_some_and(|slot| {
+                Arc::as_ptr(&slot.get_inventory()).cast::<()>()
+                    == Arc::as_ptr(&self.inventory).cast::<()>()
+            });
+            if in_inventory {
+                self.recipe_book.inventory_changed(stack.item).await;
+            }
+        })
+    }
+}
+
 impl Player {
+    #[expect(clippy::too_many_lines)]
     pub async fn new(
         client: ClientPlatform,
         gameprofile: GameProfile,
@@ -481,10 +520,6 @@ impl Player {
         world: Arc<World>,
         gamemode: GameMode,
     ) -> Self {
-        struct ScreenListener;
-
-        impl ScreenHandlerListener for ScreenListener {}
-
         let server = world.server.upgrade().unwrap();
 
         let player_uuid = gameprofile.id;
@@ -506,6 +541,11 @@ impl Player {
         ));
 
         let ender_chest_inventory = Arc::new(EnderChestInventory::new());
+        let recipe_book = Arc::new(RecipeBook::default());
+        let screen_handler_listener = Arc::new(ScreenListener {
+            inventory: inventory.clone(),
+            recipe_book: recipe_book.clone(),
+        });
 
         let player_screen_handler = Arc::new(Mutex::new(
             PlayerScreenHandler::new(&inventory, None, 0).await,
@@ -524,9 +564,11 @@ impl Player {
             breath_manager: BreathManager::default(),
             // TODO: Load this from previous instance
             hunger_manager: HungerManager::default(),
+            recipe_book,
             current_block_destroy_stage: AtomicI32::new(-1),
             open_container: AtomicCell::new(None),
             open_container_pos: AtomicCell::new(None),
+            open_mount: AtomicCell::new(None),
             tick_counter: AtomicI32::new(0),
             packet_sequence: AtomicI32::new(-1),
             start_mining_time: AtomicI32::new(0),
@@ -553,8 +595,11 @@ impl Player {
             keep_alive_id: AtomicI64::new(0),
             last_keep_alive_time: AtomicCell::new(std::time::Instant::now()),
             last_action_time: AtomicCell::new(std::time::Instant::now()),
+            idle: AtomicBool::new(false),
+            idle_evaluated: AtomicBool::new(false),
             ping: AtomicU32::new(0),
             last_attacked_ticks: AtomicU32::new(0),
+            creative_drop_spam: AtomicI32::new(0),
             client_loaded: AtomicBool::new(false),
             client_loaded_timeout: AtomicU32::new(60),
             // Minecraft has no way to change the default permission level of new players.
@@ -590,7 +635,7 @@ impl Player {
             player_screen_handler: player_screen_handler.clone(),
             current_screen_handler: Mutex::new(player_screen_handler),
             screen_handler_sync_id: AtomicU8::new(0),
-            screen_handler_listener: Arc::new(ScreenListener {}),
+            screen_handler_listener,
             screen_handler_sync_handler: Arc::new(SyncHandler::new()),
         }
     }
@@ -624,6 +669,7 @@ impl Player {
     /// Removes the [`Player`] out of the current [`World`].
     pub async fn remove(self: &Arc<Self>) {
         let world = self.world();
+        self.get_entity().stop_riding().await;
         world.remove_player(self, true).await;
 
         let cylindrical = self.watched_section.load();
@@ -1173,7 +1219,7 @@ impl Player {
     }
 
     pub async fn sleep(&self, bed_head_pos: BlockPos) {
-        // TODO: Stop riding
+        self.get_entity().stop_riding().await;
 
         self.get_entity().set_pose(EntityPose::Sleeping).await;
         self.living_entity
@@ -1364,7 +1410,7 @@ impl Player {
         position: &Vector3<f64>,
         volume: f32,
         pitch: f32,
-        seed: f64,
+        seed: i64,
     ) {
         self.client
             .enqueue_packet(&CSoundEffect::new(
@@ -1404,6 +1450,7 @@ impl Player {
             .await
             .send_content_updates()
             .await;
+        self.tick_mount_screen().await;
 
         // if self.client.closed.load(Ordering::Relaxed) {
         //     return;
@@ -1500,12 +1547,18 @@ impl Player {
         }
 
         self.last_attacked_ticks.fetch_add(1, Ordering::Relaxed);
+        let _ =
+            self.creative_drop_spam
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |spam| {
+                    (spam > 0).then(|| spam - 1)
+                });
 
         self.living_entity.tick(self.clone(), server).await;
         // Vanilla updates pose in PlayerEntity#tick after super.tick().
         self.update_player_pose().await;
         self.breath_manager.tick(self).await;
         self.hunger_manager.tick(self).await;
+        self.recipe_book.tick(self, &server.recipe_manager).await;
 
         // experience handling
         self.tick_experience().await;
@@ -1516,17 +1569,18 @@ impl Player {
 
         // Idle timeout handling
         let now = Instant::now();
+        let idle_duration = now.duration_since(self.last_action_time.load());
+        self.tick_idle(server, idle_duration).await;
         let idle_timeout_minutes = server.player_idle_timeout.load(Ordering::Relaxed);
-        if idle_timeout_minutes > 0 {
-            let idle_duration = now.duration_since(self.last_action_time.load());
-            if idle_duration >= Duration::from_secs(idle_timeout_minutes as u64 * 60) {
-                self.kick(
-                    DisconnectReason::KickedForIdle,
-                    TextComponent::translate(translation::MULTIPLAYER_DISCONNECT_IDLING, []),
-                )
-                .await;
-                return;
-            }
+        if idle_timeout_minutes > 0
+            && idle_duration >= Duration::from_secs(idle_timeout_minutes as u64 * 60)
+        {
+            self.kick(
+                DisconnectReason::KickedForIdle,
+                TextComponent::translate(translation::MULTIPLAYER_DISCONNECT_IDLING, []),
+            )
+            .await;
+            return;
         }
 
         // TODO This should only be handled by the ClientPlatform
@@ -1974,6 +2028,25 @@ impl Player {
         }) < d * d
     }
 
+    pub fn entity_interaction_range(&self) -> f64 {
+        if self.gamemode.load() == GameMode::Creative {
+            5.0
+        } else {
+            3.0
+        }
+    }
+
+    pub fn can_interact_with_entity(&self, entity: &Entity, additional_range: f64) -> bool {
+        let d = self.entity_interaction_range() + additional_range;
+        let entity_pos = self.living_entity.entity.pos.load();
+        let eye_height = self.living_entity.entity.get_eye_height();
+        entity.bounding_box.load().squared_magnitude(Vector3 {
+            x: entity_pos.x,
+            y: entity_pos.y + eye_height,
+            z: entity_pos.z,
+        }) < d * d
+    }
+
     pub async fn kick(&self, reason: DisconnectReason, message: TextComponent) {
         self.client.kick(reason, message).await;
     }
@@ -1983,6 +2056,28 @@ impl Player {
         self.last_action_time.store(std::time::Instant::now());
     }
 
+    /// Returns whether the player is currently considered idle (AFK).
+    pub fn is_idle(&self) -> bool {
+        self.idle.load(Ordering::Relaxed)
+    }
+
+    /// Updates the player's idle state, firing a `PlayerIdleChangeEvent` once per transition.
+    async fn tick_idle(self: &Arc<Self>, server: &Server, idle_duration: Duration) {
+        let should_be_idle = is_afk(server.basic_config.player_afk_timeout, idle_duration);
+        if self.idle_evaluated.swap(should_be_idle, Ordering::Relaxed) == should_be_idle {
+            return;
+        }
+
+        send_cancellable! {{
+            server;
+            PlayerIdleChangeEvent::new(self.clone(), should_be_idle);
+
+            'after: {
+                self.idle.store(event.idle, Ordering::Relaxed);
+            }
+        }}
+    }
+
     pub fn can_food_heal(&self) -> bool {
         let health = self.living_entity.health.load();
         let max_health = self.living_entity.get_max_health();
@@ -2042,29 +2137,15 @@ impl Player {
         }
     }
 
-    async fn handle_killed(&self, death_msg: TextComponent) {
+    /// Shows the death screen. Called by [`LivingEntity::on_death`] once the player's drops are dropped.
+    pub async fn handle_killed(&self, death_msg: &TextComponent) {
         self.set_client_loaded(false);
-        let block_pos = self.position().to_block_pos();
-
-        let keep_inventory = { self.world().level_info.load().game_rules.keep_inventory };
-
-        if !keep_inventory {
-            for item in &self.inventory().main_inventory {
-                let mut lock = item.lock().await;
-                self.world()
-                    .drop_stack(
-                        &block_pos,
-                        mem::replace(&mut *lock, ItemStack::EMPTY.clone()),
-                    )
-                    .await;
-            }
-        }
 
         // Reset air supply & drowning ticks on death
         self.breath_manager.reset(self).await;
 
         self.client
-            .send_packet_now(&CCombatDeath::new(self.entity_id().into(), &death_msg))
+            .send_packet_now(&CCombatDeath::new(self.entity_id().into(), death_msg))
             .await;
     }
 
@@ -2646,6 +2727,7 @@ impl Player {
 
         *self.current_screen_handler.lock().await = self.player_screen_handler.clone();
         self.open_container_pos.store(None);
+        self.open_mount.store(None);
     }
 
     pub async fn on_screen_handler_opened(&self, screen_handler: Arc<Mutex<dyn ScreenHandler>>) {
@@ -2710,6 +2792,70 @@ impl Player {
         }
     }
 
+    /// Opens the inventory of the mount `entity_id`, whose client screen is built from the mount
+    /// itself and `columns` chest columns rather than from a window type.
+    pub async fn open_mount_screen(
+        &self,
+        screen_handler_factory: &dyn ScreenHandlerFactory,
+        entity_id: i32,
+        columns: u8,
+    ) -> Option<u8> {
+        if !self
+            .current_screen_handler
+            .lock()
+            .await
+            .lock()
+            .await
+            .as_any()
+            .is::<PlayerScreenHandler>()
+        {
+            self.close_handled_screen().await;
+        }
+
+        self.increment_screen_handler_sync_id();
+
+        let screen_handler = screen_handler_factory
+            .create_screen_handler(
+                self.screen_handler_sync_id.load(Ordering::Relaxed),
+                &self.inventory,
+                self,
+            )
+            .await?;
+        let sync_id = screen_handler.lock().await.sync_id();
+        self.client
+            .enqueue_packet(&CMountScreenOpen::new(
+                sync_id.into(),
+                i32::from(columns).into(),
+                entity_id,
+            ))
+            .await;
+        self.on_screen_handler_opened(screen_handler.clone()).await;
+        *self.current_screen_handler.lock().await = screen_handler;
+        self.open_container_pos.store(None);
+        self.open_mount.store(Some(entity_id));
+        Some(sync_id)
+    }
+
+    /// Closes the open mount inventory once the mount died, was removed or is out of reach.
+    async fn tick_mount_screen(&self) {
+        let Some(entity_id) = self.open_mount.load() else {
+            return;
+        };
+        let still_valid = self
+            .world()
+            .get_entity_by_id(entity_id)
+            .is_some_and(|mount| {
+                mount.get_entity().is_alive()
+                    && mount
+                        .get_living_entity()
+                        .is_none_or(|living| !living.dead.load(Ordering::Relaxed))
+                    && self.can_interact_with_entity(mount.get_entity(), 4.0)
+            });
+        if !still_valid {
+            self.close_handled_screen().await;
+        }
+    }
+
     pub async fn on_slot_click(&self, packet: SClickSlot) {
         self.update_last_action_time();
         let screen_handler = self.current_screen_handler.lock().await;
@@ -2732,6 +2878,8 @@ impl Player {
                 self.gameprofile.name,
                 screen_handler.window_type()
             );
+            // Revert whatever the client predicted
+            screen_handler.sync_state().await;
             return;
         }
 
@@ -2744,9 +2892,12 @@ impl Player {
                 slot,
                 screen_handler.get_behaviour().slots.len()
             );
+            screen_handler.sync_state().await;
             return;
         }
 
+        // The client predicts the outcome of every click. If its state id is stale it predicted
+        // from outdated contents, so it gets the full state instead of single slot updates
         let not_in_sync = packet.revision.0 != (behaviour.revision.load(Ordering::Relaxed) as i32);
 
         screen_handler.disable_sync();
@@ -2925,6 +3076,7 @@ impl NBTStorage for Player {
 
             // Store food level, saturation, exhaustion, and tick timer
             self.hunger_manager.write_nbt(nbt).await;
+            self.recipe_book.write_nbt(nbt).await;
 
             nbt.put_string(
                 "Dimension",
@@ -2957,6 +3109,7 @@ impl NBTStorage for Player {
 
             // Load food level, saturation, exhaustion, and tick timer
             self.hunger_manager.read_nbt(nbt).await;
+            self.recipe_book.read_nbt(nbt).await;
 
             // Load from total XP
             let total_exp = nbt.get_int("XpTotal").unwrap_or(0);
@@ -3140,6 +3293,17 @@ impl NBTStorage for EnderChestInventory {
 
 impl NBTStorageInit for EnderChestInventory {}
 
+/// Whether damage of this type dealt by `cause` gets scaled by the world difficulty.
+fn scales_with_difficulty(damage_type: DamageType, cause: Option<&dyn EntityBase>) -> bool {
+    match damage_type.scaling {
+        DamageScaling::Never => false,
+        DamageScaling::Always => true,
+        DamageScaling::WhenCausedByLivingNonPlayer => cause.is_some_and(|cause| {
+            cause.get_living_entity().is_some() && cause.get_player().is_none()
+        }),
+    }
+}
+
 impl EntityBase for Player {
     fn damage_with_context<'a>(
         &'a self,
@@ -3157,19 +3321,21 @@ impl EntityBase for Player {
             {
                 return false;
             }
-            let result = self
-                .living_entity
-                .damage_with_context(caller, amount, damage_type, position, source, cause)
-                .await;
-            if result {
-                let health = self.living_entity.health.load();
-                if health <= 0.0 {
-                    let death_message =
-                        LivingEntity::get_death_message(caller, damage_type, source, cause).await;
-                    self.handle_killed(death_message).await;
-                }
+            let amount = if scales_with_difficulty(damage_type, cause) {
+                self.world()
+                    .level_info
+                    .load()
+                    .difficulty
+                    .scale_damage(amount)
+            } else {
+                amount
+            };
+            if amount == 0.0 {
+                return false;
             }
-            result
+            self.living_entity
+                .damage_with_context(caller, amount, damage_type, position, source, cause)
+                .await
         })
     }
 
@@ -3230,6 +3396,10 @@ impl EntityBase for Player {
         Some(self)
     }
 
+    fn get_vehicle_attachment(&self) -> Vector3<f64> {
+        Vector3::new(0.0, 0.6, 0.0)
+    }
+
     fn is_spectator(&self) -> bool {
         self.gamemode.load() == GameMode::Spectator
     }
@@ -3650,3 +3820,28 @@ impl InventoryPlayer for Player {
         })
     }
 }
+
+/// Whether a player who has been inactive for `idle_duration` is AFK. A timeout of `0` disables
+/// AFK detection.
+fn is_afk(afk_timeout_minutes: u32, idle_duration: Duration) -> bool {
+    afk_timeout_minutes > 0
+        && idle_duration >= Duration::from_secs(u64::from(afk_timeout_minutes) * 60)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::is_afk;
+
+    #[test]
+    fn afk_disabled_with_zero_timeout() {
+        assert!(!is_afk(0, Duration::from_secs(u64::MAX / 2)));
+    }
+
+    #[test]
+    fn afk_after_timeout() {
+        assert!(!is_afk(5, Duration::from_secs(5 * 60 - 1)));
+        assert!(is_afk(5, Duration::from_secs(5 * 60)));
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use pumpkin_data::damage::DamageType;
+
+use crate::{
+    entity::{
+        Entity, EntityBase, EntityBaseFuture, NBTStorage,
+        living::LivingEntity,
+        projectile::{ProjectileHit, ThrownItemEntity},
+    },
+    server::Server,
+};
+
+const DAMAGE: f32 = 1.0;
+/// Spit falls twice as fast as thrown items.
+pub const GRAVITY: f64 = 0.06;
+
+pub struct LlamaSpitEntity {
+    pub thrown: ThrownItemEntity,
+}
+
+impl LlamaSpitEntity {
+    #[must_use]
+    pub const fn new(thrown: ThrownItemEntity) -> Self {
+        Self { thrown }
+    }
+}
+
+impl NBTStorage for LlamaSpitEntity {}
+
+impl EntityBase for LlamaSpitEntity {
+    fn tick<'a>(
+        &'a self,
+        caller: Arc<dyn EntityBase>,
+        server: &'a Server,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move { self.thrown.process_tick(caller, server).await })
+    }
+
+    fn get_entity(&self) -> &Entity {
+        &self.thrown.entity
+    }
+
+    fn get_living_entity(&self) -> Option<&LivingEntity> {
+        None
+    }
+
+    fn as_nbt_storage(&self) -> &dyn NBTStorage {
+        self
+    }
+
+    fn get_gravity(&self) -> f64 {
+        GRAVITY
+    }
+
+    fn on_hit(&self, hit: ProjectileHit) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            if let ProjectileHit::Entity { entity, .. } = hit {
+                let owner = self
+                    .thrown
+                    .owner_id
+                    .and_then(|id| self.get_entity().world.load().get_entity_by_id(id));
+                entity
+                    .damage_with_context(
+                        entity.as_ref(),
+                        DAMAGE,
+                        DamageType::SPIT,
+                        None,
+                        Some(self),
+                        owner.as_deref(),
+                    )
+                    .await;
+            }
+        })
+    }
+}
@@ -11,6 +11,7 @@ use std::{
 };
 pub mod egg;
 pub mod firework_rocket;
+pub mod llama_spit;
 pub mod snowball;
 pub mod wind_charge;
 
@@ -19,7 +20,13 @@ pub fn is_projectile(entity_type: &EntityType) -> bool {
     *entity_type == EntityType::EGG
         || *entity_type == EntityType::SNOWBALL
         || *entity_type == EntityType::FIREWORK_ROCKET
+        || *entity_type == EntityType::LLAMA_SPIT
         || *entity_type == EntityType::WIND_CHARGE
+        || *entity_type == EntityType::BREEZE_WIND_CHARGE
+}
+
+fn is_wind_charge(entity_type: &EntityType) -> bool {
+    *entity_type == EntityType::WIND_CHARGE || *entity_type == EntityType::BREEZE_WIND_CHARGE
 }
 
 pub struct ThrownItemEntity {
@@ -105,7 +112,7 @@ impl ThrownItemEntity {
         let inertia = if entity.touching_water.load(Ordering::Relaxed) {
             0.8
         } else {
-            0.99
+            self.get_drag()
         };
         velocity = velocity.multiply(inertia, inertia, inertia);
 
@@ -235,9 +242,23 @@ impl ThrownItemEntity {
     const fn as_nbt_storage(&self) -> &dyn NBTStorage {
         self
     }
-    #[allow(clippy::unused_self)]
-    const fn get_gravity(&self) -> f64 {
-        0.03
+    fn get_gravity(&self) -> f64 {
+        // Wind charges fly in a straight line
+        if is_wind_charge(self.entity.entity_type) {
+            0.0
+        } else if *self.entity.entity_type == EntityType::LLAMA_SPIT {
+            llama_spit::GRAVITY
+        } else {
+            0.03
+        }
+    }
+
+    fn get_drag(&self) -> f64 {
+        if is_wind_charge(self.entity.entity_type) {
+            1.0
+        } else {
+            0.99
+        }
     }
 }
 
@@ -1,3 +1,4 @@
+use pumpkin_data::{damage::DamageType, entity::EntityType, particle::Particle, sound::Sound};
 use pumpkin_util::math::vector3::Vector3;
 use std::{
     f64,
@@ -9,13 +10,18 @@ use std::{
 
 use crate::{
     entity::{
-        Entity, EntityBase, EntityBaseFuture, NBTStorage, living::LivingEntity,
-        projectile::ThrownItemEntity, projectile_deflection::ProjectileDeflectionType,
+        Entity, EntityBase, EntityBaseFuture, NBTStorage,
+        living::LivingEntity,
+        projectile::{ProjectileHit, ThrownItemEntity},
+        projectile_deflection::ProjectileDeflectionType,
     },
     server::Server,
 };
 
 const EXPLOSION_POWER: f32 = 1.2;
+const KNOCKBACK_MULTIPLIER: f64 = 1.22;
+const BREEZE_EXPLOSION_POWER: f32 = 3.0;
+const DAMAGE: f32 = 1.0;
 // square(3.5)
 const MAX_RENDER_DISTANCE_WHEN_NEWLY_SPAWNED: f32 = 3.5 * 3.5;
 const DEFAULT_DEFLECT_COOLDOWN: u8 = 5;
@@ -42,12 +48,30 @@ impl WindChargeEntity {
         self.deflect_cooldown.store(value, Ordering::Relaxed);
     }
 
+    /// Bursts the charge, knocking back everything nearby and triggering blocks in range.
     pub async fn create_explosion(&self, position: Vector3<f64>) {
-        self.get_entity()
-            .world
-            .load()
-            .explode(position, EXPLOSION_POWER)
-            .await;
+        let world = self.get_entity().world.load();
+        if self.get_entity().entity_type == &EntityType::BREEZE_WIND_CHARGE {
+            world
+                .wind_burst(
+                    position,
+                    BREEZE_EXPLOSION_POWER,
+                    1.0,
+                    Particle::GustEmitterLarge,
+                    Sound::EntityBreezeWindBurst,
+                )
+                .await;
+        } else {
+            world
+                .wind_burst(
+                    position,
+                    EXPLOSION_POWER,
+                    KNOCKBACK_MULTIPLIER,
+                    Particle::GustEmitterSmall,
+                    Sound::EntityWindChargeWindBurst,
+                )
+                .await;
+        }
     }
 
     pub fn should_render(&self, distance: f64) -> bool {
@@ -122,4 +146,34 @@ impl EntityBase for WindChargeEntity {
     fn as_nbt_storage(&self) -> &dyn NBTStorage {
         self
     }
+
+    fn on_hit(&self, hit: ProjectileHit) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            let position = match hit {
+                ProjectileHit::Block { face, hit_pos, .. } => {
+                    // Burst slightly in front of the hit face so the blast isn't buried in the block
+                    let offset = face.to_offset().to_f64();
+                    hit_pos.add(&offset.multiply(0.25, 0.25, 0.25))
+                }
+                ProjectileHit::Entity { entity, .. } => {
+                    let owner = self
+                        .thrown_item_entity
+                        .owner_id
+                        .and_then(|id| self.get_entity().world.load().get_entity_by_id(id));
+                    entity
+                        .damage_with_context(
+                            entity.as_ref(),
+                            DAMAGE,
+                            DamageType::WIND_CHARGE,
+                            None,
+                            Some(self),
+                            owner.as_deref(),
+                        )
+                        .await;
+                    self.get_entity().pos.load()
+                }
+            };
+            self.create_explosion(position).await;
+        })
+    }
 }
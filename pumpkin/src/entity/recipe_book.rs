@@ -0,0 +1,201 @@
+use std::collections::BTreeSet;
+
+use pumpkin_data::item::Item;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_nbt::tag::NbtTag;
+use pumpkin_protocol::codec::var_int::VarInt;
+use pumpkin_protocol::java::client::play::{CRecipeBookAdd, CRecipeBookRemove, RecipeBookEntry};
+use pumpkin_util::version::MinecraftVersion;
+use tokio::sync::Mutex;
+
+use super::player::Player;
+use crate::net::ClientPlatform;
+use crate::server::recipe_manager::{RecipeHolder, RecipeManager};
+
+/// The recipes a player has unlocked.
+///
+/// Recipes are unlocked the way vanilla's recipe advancements do it: an `inventory_changed`
+/// criterion fires for every item entering the inventory, which is checked against the recipe
+/// materials on the next tick. `/recipe` and plugins can award or take recipes directly.
+///
+/// Unlocked recipes are saved in the vanilla `recipeBook` compound. Ids that do not name a known
+/// recipe, e.g. from a vanilla or modded world, are kept as they are so they are saved again.
+#[derive(Default)]
+pub struct RecipeBook {
+    known: Mutex<BTreeSet<String>>,
+    /// Unlocked recipes the player has not looked at yet.
+    highlighted: Mutex<BTreeSet<String>>,
+    new_items: Mutex<Vec<&'static Item>>,
+    /// The `recipeBook` compound as it was read, so the fields not handled here are kept.
+    saved: Mutex<Option<NbtCompound>>,
+}
+
+impl RecipeBook {
+    /// The `inventory_changed` criterion trigger, called for every stack put into the inventory.
+    pub async fn inventory_changed(&self, item: &'static Item) {
+        if item != &Item::AIR {
+            self.new_items.lock().await.push(item);
+        }
+    }
+
+    /// Unlocks the recipes using any item that entered the inventory since the last tick.
+    pub async fn tick(&self, player: &Player, manager: &RecipeManager) {
+        let new_items = std::mem::take(&mut *self.new_items.lock().await);
+        if new_items.is_empty() {
+            return;
+        }
+        let recipes: Vec<_> = {
+            let known = self.known.lock().await;
+            new_items
+                .into_iter()
+                .flat_map(|item| manager.unlocked_by(item))
+                .filter(|recipe| !known.contains(&recipe.id))
+                .collect()
+        };
+        self.award(player, recipes).await;
+    }
+
+    /// Unlocks `recipes`, showing a toast for them. Returns how many were newly unlocked.
+    pub async fn award<'a>(
+        &self,
+        player: &Player,
+        recipes: impl IntoIterator<Item = &'a RecipeHolder>,
+    ) -> usize {
+        let mut entries = Vec::new();
+        {
+            let mut known = self.known.lock().await;
+            let mut highlighted = self.highlighted.lock().await;
+            for recipe in recipes {
+                if !known.insert(recipe.id.clone()) {
+                    continue;
+                }
+                highlighted.insert(recipe.id.clone());
+                entries.push(RecipeBookEntry {
+                    display: recipe.display_entry(),
+                    notification: recipe.show_notification(),
+                    highlight: true,
+                });
+            }
+        }
+        let count = entries.len();
+        if count > 0 && supports_recipe_book(player) {
+            player
+                .client
+                .enqueue_packet(&CRecipeBookAdd::new(entries, false))
+                .await;
+        }
+        count
+    }
+
+    /// Locks `recipes` again. Returns how many were known before.
+    pub async fn take<'a>(
+        &self,
+        player: &Player,
+        recipes: impl IntoIterator<Item = &'a RecipeHolder>,
+    ) -> usize {
+        let mut removed = Vec::new();
+        {
+            let mut known = self.known.lock().await;
+            let mut highlighted = self.highlighted.lock().await;
+            for recipe in recipes {
+                if known.remove(&recipe.id) {
+                    highlighted.remove(&recipe.id);
+                    removed.push(VarInt(recipe.display_id));
+                }
+            }
+        }
+        if !removed.is_empty() && supports_recipe_book(player) {
+            player
+                .client
+                .enqueue_packet(&CRecipeBookRemove::new(&removed))
+                .await;
+        }
+        removed.len()
+    }
+
+    /// Clears the highlight of a recipe the player looked at.
+    pub async fn mark_seen(&self, recipe: &RecipeHolder) {
+        self.highlighted.lock().await.remove(&recipe.id);
+    }
+
+    /// Resolves the ids read from NBT and sends every unlocked recipe, replacing whatever the
+    /// client had.
+    pub async fn send_initial(&self, player: &Player, manager: &RecipeManager) {
+        self.resolve_ids(manager).await;
+        if !supports_recipe_book(player) {
+            return;
+        }
+        let entries = {
+            let known = self.known.lock().await;
+            let highlighted = self.highlighted.lock().await;
+            known
+                .iter()
+                .filter_map(|id| manager.get(id))
+                .map(|recipe| RecipeBookEntry {
+                    display: recipe.display_entry(),
+                    notification: false,
+                    highlight: highlighted.contains(&recipe.id),
+                })
+                .collect()
+        };
+        player
+            .client
+            .enqueue_packet(&CRecipeBookAdd::new(entries, true))
+            .await;
+    }
+
+    /// Replaces the ids read from NBT by the ids of the recipes they name. Ids of unknown
+    /// recipes are kept so they are saved again.
+    pub async fn resolve_ids(&self, manager: &RecipeManager) {
+        for ids in [&self.known, &self.highlighted] {
+            let mut ids = ids.lock().await;
+            *ids = std::mem::take(&mut *ids)
+                .into_iter()
+                .map(|id| manager.get(&id).map_or(id, |recipe| recipe.id.clone()))
+                .collect();
+        }
+    }
+
+    pub async fn write_nbt(&self, nbt: &mut NbtCompound) {
+        let mut compound = self.saved.lock().await.clone().unwrap_or_default();
+        // `put` keeps an existing tag, so drop the lists as they were read
+        compound
+            .child_tags
+            .retain(|(name, _)| name != "recipes" && name != "toBeDisplayed");
+        compound.put_list("recipes", string_list(&*self.known.lock().await));
+        compound.put_list(
+            "toBeDisplayed",
+            string_list(&*self.highlighted.lock().await),
+        );
+        nbt.put_compound("recipeBook", compound);
+    }
+
+    pub async fn read_nbt(&self, nbt: &NbtCompound) {
+        let Some(compound) = nbt.get_compound("recipeBook") else {
+            return;
+        };
+        *self.known.lock().await = read_string_list(compound, "recipes");
+        *self.highlighted.lock().await = read_string_list(compound, "toBeDisplayed");
+        *self.saved.lock().await = Some(compound.clone());
+    }
+}
+
+/// Clients before 1.21.2 use the older recipe book protocol, which is not supported.
+fn supports_recipe_book(player: &Player) -> bool {
+    match &player.client {
+        ClientPlatform::Java(java) => java.version.load() >= MinecraftVersion::V_1_21_2,
+        ClientPlatform::Bedrock(_) => false,
+    }
+}
+
+fn string_list(ids: &BTreeSet<String>) -> Vec<NbtTag> {
+    ids.iter().map(|id| NbtTag::String(id.clone())).collect()
+}
+
+fn read_string_list(nbt: &NbtCompound, name: &str) -> BTreeSet<String> {
+    nbt.get_list(name)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|tag| tag.extract_string().map(str::to_string))
+        .collect()
+}
@@ -55,7 +55,6 @@ impl EntityBase for TNTEntity {
             }
 
             if entity.velocity_dirty.swap(false, Ordering::SeqCst) {
-                entity.send_pos_rot().await;
                 entity.send_velocity().await;
             }
 
@@ -0,0 +1,450 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use pumpkin_data::entity::{EntityType, MobCategory};
+use pumpkin_protocol::java::client::play::SerializedMetadata;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::version::MinecraftVersion;
+
+/// Vanilla forces a full position resync after this many delta updates to correct drift.
+const FORCED_SYNC_INTERVAL: u32 = 400;
+
+/// How far and how often an entity type is tracked by clients.
+///
+/// Mirrors vanilla's `maxTrackingRange` and `trackingTickInterval` entity type settings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TrackingSettings {
+    /// The tracking range in chunks.
+    pub range_chunks: i32,
+    /// The number of ticks between movement updates. `u32::MAX` means the entity never moves
+    /// on its own, so movement is only sent when a player starts tracking it.
+    pub update_interval: u32,
+}
+
+impl TrackingSettings {
+    const fn new(range_chunks: i32, update_interval: u32) -> Self {
+        Self {
+            range_chunks,
+            update_interval,
+        }
+    }
+
+    /// Returns the tracking settings for the given entity type.
+    #[must_use]
+    pub fn of(entity_type: &EntityType) -> Self {
+        match entity_type.id {
+            id if id == EntityType::PLAYER.id => Self::new(32, 2),
+
+            // Projectiles
+            id if id == EntityType::ARROW.id
+                || id == EntityType::SPECTRAL_ARROW.id
+                || id == EntityType::TRIDENT.id =>
+            {
+                Self::new(4, 20)
+            }
+            id if id == EntityType::SNOWBALL.id
+                || id == EntityType::EGG.id
+                || id == EntityType::ENDER_PEARL.id
+                || id == EntityType::EXPERIENCE_BOTTLE.id
+                || id == EntityType::SPLASH_POTION.id
+                || id == EntityType::LINGERING_POTION.id
+                || id == EntityType::FIREBALL.id
+                || id == EntityType::SMALL_FIREBALL.id
+                || id == EntityType::DRAGON_FIREBALL.id
+                || id == EntityType::WITHER_SKULL.id
+                || id == EntityType::LLAMA_SPIT.id
+                || id == EntityType::WIND_CHARGE.id
+                || id == EntityType::BREEZE_WIND_CHARGE.id
+                || id == EntityType::FIREWORK_ROCKET.id =>
+            {
+                Self::new(4, 10)
+            }
+            id if id == EntityType::SHULKER_BULLET.id => Self::new(8, 1),
+            id if id == EntityType::EYE_OF_ENDER.id => Self::new(4, 4),
+            id if id == EntityType::FISHING_BOBBER.id => Self::new(4, 5),
+
+            // Drops
+            id if id == EntityType::ITEM.id || id == EntityType::EXPERIENCE_ORB.id => {
+                Self::new(6, 20)
+            }
+            id if id == EntityType::TNT.id => Self::new(10, 10),
+            id if id == EntityType::FALLING_BLOCK.id => Self::new(10, 20),
+
+            // Static entities
+            id if id == EntityType::ITEM_FRAME.id
+                || id == EntityType::GLOW_ITEM_FRAME.id
+                || id == EntityType::PAINTING.id
+                || id == EntityType::LEASH_KNOT.id
+                || id == EntityType::AREA_EFFECT_CLOUD.id =>
+            {
+                Self::new(10, u32::MAX)
+            }
+            id if id == EntityType::LIGHTNING_BOLT.id || id == EntityType::END_CRYSTAL.id => {
+                Self::new(16, u32::MAX)
+            }
+            id if id == EntityType::MARKER.id => Self::new(0, u32::MAX),
+
+            id if id == EntityType::ENDER_DRAGON.id => Self::new(10, 3),
+            _ if entity_type.mob => {
+                if entity_type.category == &MobCategory::MONSTER {
+                    Self::new(8, 3)
+                } else if entity_type.category == &MobCategory::AMBIENT {
+                    Self::new(5, 3)
+                } else {
+                    Self::new(10, 3)
+                }
+            }
+            _ => Self::new(5, 3),
+        }
+    }
+
+    /// Returns the tracking range in blocks, capped by the server's view distance in chunks.
+    #[must_use]
+    pub fn range_blocks(&self, view_distance: i32) -> f64 {
+        f64::from(self.range_chunks.min(view_distance) * 16)
+    }
+}
+
+/// Encodes a coordinate in the 1/4096 block units used by relative move packets.
+fn encode_coordinate(value: f64) -> i64 {
+    (value * 4096.0).round() as i64
+}
+
+/// Encodes an angle in 1/256 of a full turn, as used by rotation packets.
+#[must_use]
+pub fn encode_angle(degrees: f32) -> u8 {
+    (degrees * 256.0 / 360.0).floor().rem_euclid(256.0) as u8
+}
+
+/// The movement update an entity should send to its watchers this tick.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MovementUpdate {
+    /// Nothing changed since the last update.
+    None,
+    /// The entity only rotated.
+    Rotation,
+    /// The entity moved by the given delta (in 1/4096 block units), optionally rotating.
+    Delta { delta: Vector3<i16>, rotated: bool },
+    /// The entity moved too far for a delta packet, so its absolute position is resent.
+    Sync,
+}
+
+/// The result of a tracker update, telling the entity which packets to send to whom.
+pub struct TrackingUpdate {
+    /// The movement packet to send to players that were already tracking the entity.
+    pub movement: MovementUpdate,
+    /// The encoded head yaw, if it changed since the last update.
+    pub head_yaw: Option<u8>,
+    /// Players that just started tracking the entity and need its full state.
+    pub new_watchers: Vec<i32>,
+    /// Players that stopped tracking the entity and should forget about it.
+    pub removed_watchers: Vec<i32>,
+}
+
+/// Per-entity network tracking state.
+///
+/// Remembers what clients were last told about an entity so that only changes are sent, and
+/// batches metadata changes so that they go out in a single packet per tick.
+#[derive(Default)]
+pub struct EntityTracker {
+    /// The last position sent to clients, encoded in 1/4096 block units.
+    last_sent_pos: Option<Vector3<i64>>,
+    last_sent_yaw: u8,
+    last_sent_pitch: u8,
+    last_sent_head_yaw: u8,
+    /// Ticks since the last tracker update.
+    ticks: u32,
+    /// Delta updates sent since the last absolute position sync.
+    updates_since_sync: u32,
+    /// Entity IDs of the players currently in tracking range.
+    watchers: HashSet<i32>,
+    /// Every metadata entry set so far, keyed by [`SerializedMetadata::key`], so that new
+    /// watchers can be sent the full state whatever their protocol version.
+    metadata: BTreeMap<u8, SerializedMetadata>,
+    /// Keys of the metadata entries changed since the last flush.
+    pending_metadata: BTreeSet<u8>,
+}
+
+impl EntityTracker {
+    /// Advances the tracker by one tick.
+    ///
+    /// `in_range` are the entity IDs of the players currently within tracking range. Returns
+    /// `None` when nothing has to be sent this tick.
+    pub fn tick(
+        &mut self,
+        settings: TrackingSettings,
+        in_range: HashSet<i32>,
+        position: Vector3<f64>,
+        yaw: f32,
+        pitch: f32,
+        head_yaw: f32,
+    ) -> Option<TrackingUpdate> {
+        let new_watchers: Vec<i32> = in_range.difference(&self.watchers).copied().collect();
+        let removed_watchers: Vec<i32> = self.watchers.difference(&in_range).copied().collect();
+        self.watchers = in_range;
+
+        self.ticks = self.ticks.saturating_add(1);
+        let due = self.ticks >= settings.update_interval || self.last_sent_pos.is_none();
+        if !due && new_watchers.is_empty() && removed_watchers.is_empty() {
+            return None;
+        }
+
+        let encoded = Vector3::new(
+            encode_coordinate(position.x),
+            encode_coordinate(position.y),
+            encode_coordinate(position.z),
+        );
+        let yaw = encode_angle(yaw);
+        let pitch = encode_angle(pitch);
+        let head_yaw = encode_angle(head_yaw);
+
+        let movement = if due {
+            self.ticks = 0;
+            let rotated = yaw != self.last_sent_yaw || pitch != self.last_sent_pitch;
+            let movement = self.movement_since_last_update(encoded, rotated);
+            if movement != MovementUpdate::None {
+                self.last_sent_pos = Some(encoded);
+                self.last_sent_yaw = yaw;
+                self.last_sent_pitch = pitch;
+            }
+            movement
+        } else {
+            MovementUpdate::None
+        };
+
+        let head_yaw = (due && head_yaw != self.last_sent_head_yaw).then(|| {
+            self.last_sent_head_yaw = head_yaw;
+            head_yaw
+        });
+
+        Some(TrackingUpdate {
+            movement,
+            head_yaw,
+            new_watchers,
+            removed_watchers,
+        })
+    }
+
+    fn movement_since_last_update(
+        &mut self,
+        encoded: Vector3<i64>,
+        rotated: bool,
+    ) -> MovementUpdate {
+        let Some(last) = self.last_sent_pos else {
+            self.updates_since_sync = 0;
+            return MovementUpdate::Sync;
+        };
+
+        let delta = encoded.sub(&last);
+        let fits = |value: i64| i16::try_from(value).is_ok();
+        self.updates_since_sync += 1;
+        if self.updates_since_sync > FORCED_SYNC_INTERVAL
+            || !(fits(delta.x) && fits(delta.y) && fits(delta.z))
+        {
+            self.updates_since_sync = 0;
+            return MovementUpdate::Sync;
+        }
+
+        if delta.x == 0 && delta.y == 0 && delta.z == 0 {
+            if rotated {
+                MovementUpdate::Rotation
+            } else {
+                MovementUpdate::None
+            }
+        } else {
+            MovementUpdate::Delta {
+                delta: Vector3::new(delta.x as i16, delta.y as i16, delta.z as i16),
+                rotated,
+            }
+        }
+    }
+
+    /// Records that clients received the entity's absolute position, e.g. after a teleport.
+    pub fn mark_synced(&mut self, position: Vector3<f64>, yaw: f32, pitch: f32) {
+        self.last_sent_pos = Some(Vector3::new(
+            encode_coordinate(position.x),
+            encode_coordinate(position.y),
+            encode_coordinate(position.z),
+        ));
+        self.last_sent_yaw = encode_angle(yaw);
+        self.last_sent_pitch = encode_angle(pitch);
+        self.updates_since_sync = 0;
+    }
+
+    /// Queues a metadata entry, replacing any entry with the same index.
+    pub fn queue_metadata(&mut self, entry: SerializedMetadata) {
+        let key = entry.key();
+        self.metadata.insert(key, entry);
+        self.pending_metadata.insert(key);
+    }
+
+    /// Takes the keys of all pending metadata entries, leaving the queue empty.
+    pub fn take_metadata(&mut self) -> BTreeSet<u8> {
+        std::mem::take(&mut self.pending_metadata)
+    }
+
+    /// Encodes the metadata entries for the given protocol version, without the terminator.
+    ///
+    /// Only the entries in `keys` are encoded if given, otherwise every entry set so far.
+    #[must_use]
+    pub fn encode_metadata(
+        &self,
+        version: MinecraftVersion,
+        keys: Option<&BTreeSet<u8>>,
+    ) -> Vec<u8> {
+        let mut buf = Vec::new();
+        for (key, entry) in &self.metadata {
+            if keys.is_none_or(|keys| keys.contains(key)) {
+                entry.write(&mut buf, &version).unwrap();
+            }
+        }
+        buf
+    }
+
+    /// Returns whether the player with the given entity ID is tracking the entity.
+    #[must_use]
+    pub fn is_watched_by(&self, player_id: i32) -> bool {
+        self.watchers.contains(&player_id)
+    }
+
+    /// Forgets that a player was tracking the entity, so that it is sent again on the next tick,
+    /// e.g. after the player respawned and their client dropped all entities.
+    pub fn forget_watcher(&mut self, player_id: i32) {
+        self.watchers.remove(&player_id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_data::meta_data_type::MetaDataType;
+    use pumpkin_data::tracked_data::TrackedData;
+    use pumpkin_protocol::java::client::play::Metadata;
+    use pumpkin_util::math::vector3::Vector3;
+    use pumpkin_util::version::MinecraftVersion;
+
+    use super::{EntityTracker, MovementUpdate, TrackingSettings};
+
+    const SETTINGS: TrackingSettings = TrackingSettings::new(8, 3);
+
+    fn tick(
+        tracker: &mut EntityTracker,
+        watchers: &[i32],
+        position: Vector3<f64>,
+    ) -> Option<super::TrackingUpdate> {
+        tracker.tick(
+            SETTINGS,
+            watchers.iter().copied().collect::<HashSet<_>>(),
+            position,
+            0.0,
+            0.0,
+            0.0,
+        )
+    }
+
+    #[test]
+    fn range_capped_by_view_distance() {
+        assert_eq!(SETTINGS.range_blocks(10), 128.0);
+        assert_eq!(SETTINGS.range_blocks(4), 64.0);
+        assert_eq!(TrackingSettings::of(&EntityType::PLAYER).range_chunks, 32);
+    }
+
+    #[test]
+    fn first_tick_syncs() {
+        let mut tracker = EntityTracker::default();
+        let update = tick(&mut tracker, &[1], Vector3::new(0.0, 0.0, 0.0)).unwrap();
+        assert_eq!(update.movement, MovementUpdate::Sync);
+        assert_eq!(update.new_watchers, vec![1]);
+    }
+
+    #[test]
+    fn updates_only_on_interval() {
+        let mut tracker = EntityTracker::default();
+        let position = Vector3::new(0.0, 0.0, 0.0);
+        tick(&mut tracker, &[1], position);
+        assert!(tick(&mut tracker, &[1], position).is_none());
+        assert!(tick(&mut tracker, &[1], position).is_none());
+        let update = tick(&mut tracker, &[1], Vector3::new(1.0, 0.0, 0.0)).unwrap();
+        assert_eq!(
+            update.movement,
+            MovementUpdate::Delta {
+                delta: Vector3::new(4096, 0, 0),
+                rotated: false
+            }
+        );
+    }
+
+    #[test]
+    fn large_move_syncs() {
+        let mut tracker = EntityTracker::default();
+        tick(&mut tracker, &[1], Vector3::new(0.0, 0.0, 0.0));
+        tick(&mut tracker, &[1], Vector3::new(0.0, 0.0, 0.0));
+        tick(&mut tracker, &[1], Vector3::new(0.0, 0.0, 0.0));
+        let update = tick(&mut tracker, &[1], Vector3::new(100.0, 0.0, 0.0)).unwrap();
+        assert_eq!(update.movement, MovementUpdate::Sync);
+    }
+
+    #[test]
+    fn watchers_come_and_go() {
+        let mut tracker = EntityTracker::default();
+        let position = Vector3::new(0.0, 0.0, 0.0);
+        tick(&mut tracker, &[1], position);
+
+        let update = tick(&mut tracker, &[1, 2], position).unwrap();
+        assert_eq!(update.new_watchers, vec![2]);
+        assert_eq!(update.removed_watchers, Vec::<i32>::new());
+
+        let update = tick(&mut tracker, &[2], position).unwrap();
+        assert_eq!(update.new_watchers, Vec::<i32>::new());
+        assert_eq!(update.removed_watchers, vec![1]);
+        assert!(!tracker.is_watched_by(1));
+
+        tracker.forget_watcher(2);
+        let update = tick(&mut tracker, &[2], position).unwrap();
+        assert_eq!(update.new_watchers, vec![2]);
+    }
+
+    #[test]
+    fn metadata_is_remembered() {
+        let mut tracker = EntityTracker::default();
+        let version = MinecraftVersion::V_1_21;
+        let entry = |index, value: u8| {
+            Metadata::new(index, MetaDataType::BYTE, value)
+                .to_serialized()
+                .unwrap()
+        };
+        tracker.queue_metadata(entry(TrackedData::DATA_FLAGS, 1));
+        tracker.queue_metadata(entry(TrackedData::DATA_SILENT, 1));
+        assert_eq!(tracker.take_metadata().len(), 2);
+        assert!(tracker.take_metadata().is_empty());
+
+        tracker.queue_metadata(entry(TrackedData::DATA_FLAGS, 5));
+        let pending = tracker.take_metadata();
+        let flags = TrackedData::DATA_FLAGS.get(&version);
+        assert_eq!(
+            tracker.encode_metadata(version, Some(&pending)),
+            vec![flags, 0, 5]
+        );
+        assert_eq!(tracker.encode_metadata(version, None).len(), 6);
+    }
+
+    #[test]
+    fn metadata_reaches_later_watchers() {
+        let mut tracker = EntityTracker::default();
+        // Set and flushed while no player is online
+        tracker.queue_metadata(
+            Metadata::new(TrackedData::DATA_FLAGS, MetaDataType::BYTE, 1u8)
+                .to_serialized()
+                .unwrap(),
+        );
+        tracker.take_metadata();
+
+        let update = tick(&mut tracker, &[1], Vector3::new(0.0, 0.0, 0.0)).unwrap();
+        assert_eq!(update.new_watchers, vec![1]);
+        for version in [MinecraftVersion::V_1_21, MinecraftVersion::V_1_21_11] {
+            let flags = TrackedData::DATA_FLAGS.get(&version);
+            assert_eq!(tracker.encode_metadata(version, None), vec![flags, 0, 1]);
+        }
+    }
+}
@@ -10,11 +10,14 @@ use crate::{
         Entity, EntityBase,
         boss::wither::WitherEntity,
         decoration::{
-            armor_stand::ArmorStandEntity, end_crystal::EndCrystalEntity, painting::PaintingEntity,
+            armor_stand::ArmorStandEntity, end_crystal::EndCrystalEntity,
+            leash_knot::LeashKnotEntity, painting::PaintingEntity,
         },
+        lightning::LightningEntity,
         living::LivingEntity,
         mob::{
             bat::BatEntity,
+            breeze::BreezeEntity,
             creeper::CreeperEntity,
             enderman::EndermanEntity,
             silverfish::SilverfishEntity,
@@ -25,8 +28,16 @@ use crate::{
             zombie::{drowned::DrownedEntity, husk::HuskEntity, zombie::ZombieEntity},
         },
         passive::{
-            cat::CatEntity, chicken::ChickenEntity, cow::CowEntity, iron_golem::IronGolemEntity,
-            pig::PigEntity, sheep::SheepEntity, snow_golem::SnowGolemEntity, wolf::WolfEntity,
+            cat::CatEntity,
+            chicken::ChickenEntity,
+            cow::CowEntity,
+            horse::{donkey::DonkeyEntity, horse::HorseEntity, llama::LlamaEntity},
+            iron_golem::IronGolemEntity,
+            pig::PigEntity,
+            sheep::SheepEntity,
+            snow_golem::SnowGolemEntity,
+            strider::StriderEntity,
+            wolf::WolfEntity,
         },
     },
     world::World,
@@ -55,21 +66,30 @@ pub async fn from_type(
         id if id == EntityType::STRAY.id => StraySkeletonEntity::new(entity).await,
 
         id if id == EntityType::BAT.id => BatEntity::new(entity).await,
+        id if id == EntityType::BREEZE.id => BreezeEntity::new(entity).await,
         id if id == EntityType::CREEPER.id => CreeperEntity::new(entity).await,
         id if id == EntityType::ENDERMAN.id => EndermanEntity::new(entity).await,
 
         id if id == EntityType::CAT.id => CatEntity::new(entity).await,
         id if id == EntityType::CHICKEN.id => ChickenEntity::new(entity).await,
         id if id == EntityType::COW.id => CowEntity::new(entity).await,
+        id if id == EntityType::DONKEY.id || id == EntityType::MULE.id => {
+            DonkeyEntity::new(entity).await
+        }
+        id if id == EntityType::HORSE.id => HorseEntity::new(entity).await,
+        id if id == EntityType::LLAMA.id => LlamaEntity::new(entity).await,
         id if id == EntityType::PIG.id => PigEntity::new(entity).await,
         id if id == EntityType::SNOW_GOLEM.id => SnowGolemEntity::new(entity).await,
         id if id == EntityType::IRON_GOLEM.id => IronGolemEntity::new(entity).await,
         id if id == EntityType::SHEEP.id => SheepEntity::new(entity).await,
+        id if id == EntityType::STRIDER.id => StriderEntity::new(entity).await,
         id if id == EntityType::WOLF.id => WolfEntity::new(entity).await,
         id if id == EntityType::WITHER.id => WitherEntity::new(entity).await,
         id if id == EntityType::ARMOR_STAND.id => Arc::new(ArmorStandEntity::new(entity)),
         id if id == EntityType::PAINTING.id => Arc::new(PaintingEntity::new(entity)),
         id if id == EntityType::END_CRYSTAL.id => Arc::new(EndCrystalEntity::new(entity)),
+        id if id == EntityType::LEASH_KNOT.id => Arc::new(LeashKnotEntity::new(entity)),
+        id if id == EntityType::LIGHTNING_BOLT.id => Arc::new(LightningEntity::new(entity)),
         id if id == EntityType::SILVERFISH.id => SilverfishEntity::new(entity).await,
         // Fallback Entity
         _ => {
@@ -16,6 +16,8 @@ use pumpkin_util::GameMode;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::item::ItemStack;
 
+const MAX_PASSENGERS: usize = 2;
+
 pub struct BoatEntity {
     entity: Entity,
     damage_wobble_ticks: AtomicI32,
@@ -205,14 +207,6 @@ impl EntityBase for BoatEntity {
                 return false;
             }
 
-            if self.entity.passengers.lock().await.len() >= 2 {
-                return false;
-            }
-
-            if player.living_entity.entity.has_vehicle().await {
-                return false;
-            }
-
             let world = self.entity.world.load();
             let Some(vehicle) = world.get_entity_by_id(self.entity.entity_id) else {
                 return false;
@@ -222,11 +216,42 @@ impl EntityBase for BoatEntity {
                 return false;
             };
 
-            self.entity
-                .add_passenger(vehicle, passenger as Arc<dyn EntityBase>)
-                .await;
+            player
+                .living_entity
+                .entity
+                .start_riding(passenger as Arc<dyn EntityBase>, vehicle, false)
+                .await
+        })
+    }
 
-            true
+    fn can_add_passenger<'a>(
+        &'a self,
+        _passenger: &'a dyn EntityBase,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            self.entity.passengers.lock().await.len() < MAX_PASSENGERS
+                && self.ticks_underwater.load() < 60.0
+        })
+    }
+
+    fn get_passenger_attachment(&self, index: usize, count: usize) -> Vector3<f64> {
+        let z = match (count, index) {
+            (1, _) => 0.0,
+            (_, 0) => 0.2,
+            _ => -0.6,
+        };
+        Vector3::new(0.0, f64::from(self.entity.height()) / 3.0, z)
+    }
+
+    fn get_controlling_passenger(&self) -> EntityBaseFuture<'_, Option<Arc<dyn EntityBase>>> {
+        Box::pin(async move {
+            self.entity
+                .passengers
+                .lock()
+                .await
+                .first()
+                .filter(|passenger| passenger.get_living_entity().is_some())
+                .cloned()
         })
     }
 
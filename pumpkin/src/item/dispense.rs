@@ -0,0 +1,187 @@
+use std::pin::Pin;
+use std::sync::Arc;
+
+use pumpkin_data::block_properties::Facing;
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::item::Item;
+use pumpkin_data::{BlockDirection, FacingExt};
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_world::inventory::Inventory;
+use pumpkin_world::item::ItemStack;
+use rand::{Rng, RngExt, rng};
+use rustc_hash::FxHashMap;
+
+use super::ItemMetadata;
+use super::items::bucket::{EmptyBucketItem, FilledBucketItem};
+use crate::entity::Entity;
+use crate::entity::item::ItemEntity;
+use crate::world::World;
+
+pub type DispenseFuture<'a> = Pin<Box<dyn Future<Output = DispenseResult> + Send + 'a>>;
+
+/// The dispenser an item is being dispensed from.
+pub struct DispenseSource<'a> {
+    pub world: &'a Arc<World>,
+    pub position: BlockPos,
+    pub facing: Facing,
+}
+
+impl DispenseSource<'_> {
+    #[must_use]
+    pub fn direction(&self) -> BlockDirection {
+        self.facing.to_block_direction()
+    }
+
+    /// The block the dispenser is facing.
+    #[must_use]
+    pub fn front(&self) -> BlockPos {
+        self.position.offset(self.direction().to_offset())
+    }
+}
+
+pub enum DispenseResult {
+    /// The item was dispensed. The remainder, like the empty bucket left by a water bucket, goes
+    /// back into the dispenser.
+    Success(Option<ItemStack>),
+    /// Nothing happened, the dispenser plays its fail sound.
+    Fail,
+}
+
+pub trait DispenseBehaviour: Send + Sync {
+    /// Dispenses from `stack`, which is the picked dispenser slot.
+    fn dispense<'a>(
+        &'a self,
+        source: &'a DispenseSource<'a>,
+        stack: &'a mut ItemStack,
+    ) -> DispenseFuture<'a>;
+}
+
+/// Shoots a single item out of the dispenser, used for every item without its own behaviour.
+pub struct DefaultDispenseBehaviour;
+
+impl DispenseBehaviour for DefaultDispenseBehaviour {
+    fn dispense<'a>(
+        &'a self,
+        source: &'a DispenseSource<'a>,
+        stack: &'a mut ItemStack,
+    ) -> DispenseFuture<'a> {
+        Box::pin(async move {
+            let item = stack.split(1);
+            spawn_item(source.world, item, 6.0, source.facing, &source.position).await;
+            DispenseResult::Success(None)
+        })
+    }
+}
+
+fn triangle<R: Rng>(rng: &mut R, min: f64, max: f64) -> f64 {
+    (rng.random::<f64>() - rng.random::<f64>()).mul_add(max, min)
+}
+
+const fn to_normal(facing: Facing) -> Vector3<f64> {
+    match facing {
+        Facing::North => Vector3::new(0., 0., -1.),
+        Facing::East => Vector3::new(1., 0., 0.),
+        Facing::South => Vector3::new(0., 0., 1.),
+        Facing::West => Vector3::new(-1., 0., 0.),
+        Facing::Up => Vector3::new(0., 1., 0.),
+        Facing::Down => Vector3::new(0., -1., 0.),
+    }
+}
+
+/// The data of the `DispenserActivated` world event, which tells the client where to put the
+/// smoke particles.
+#[must_use]
+pub const fn to_data3d(facing: Facing) -> i32 {
+    match facing {
+        Facing::North => 2,
+        Facing::East => 5,
+        Facing::South => 3,
+        Facing::West => 4,
+        Facing::Up => 1,
+        Facing::Down => 0,
+    }
+}
+
+/// Spawns `stack` as an item entity flying out of the dispenser at `position`.
+pub async fn spawn_item(
+    world: &Arc<World>,
+    stack: ItemStack,
+    speed: f64,
+    facing: Facing,
+    position: &BlockPos,
+) {
+    let normal = to_normal(facing);
+    let mut pos = position.to_centered_f64().add(&(normal * 0.7));
+    pos.y -= match facing {
+        Facing::Up | Facing::Down => 0.125,
+        _ => 0.15625,
+    };
+    let entity = Entity::new(world.clone(), pos, &EntityType::ITEM);
+    let rd = rng().random::<f64>().mul_add(0.1, 0.2);
+    let velocity = Vector3::new(
+        triangle(&mut rng(), normal.x * rd, 0.017_227_5 * speed),
+        triangle(&mut rng(), 0.2, 0.017_227_5 * speed),
+        triangle(&mut rng(), normal.z * rd, 0.017_227_5 * speed),
+    );
+    let item_entity = Arc::new(ItemEntity::new_with_velocity(entity, stack, velocity, 40).await);
+    world.spawn_entity(item_entity).await;
+}
+
+/// Puts a dispense remainder into the dispenser, or shoots it out when there is no room.
+pub async fn add_or_drop(source: &DispenseSource<'_>, inventory: &dyn Inventory, stack: ItemStack) {
+    let mut stack = stack;
+    for slot in 0..inventory.size() {
+        let slot = inventory.get_stack(slot).await;
+        let mut slot = slot.lock().await;
+        if slot.is_empty() {
+            *slot = stack;
+            inventory.mark_dirty();
+            return;
+        }
+        if slot.are_items_and_components_equal(&stack) {
+            let room = slot.get_max_stack_size().saturating_sub(slot.item_count);
+            let moved = room.min(stack.item_count);
+            slot.increment(moved);
+            stack.decrement(moved);
+            inventory.mark_dirty();
+            if stack.is_empty() {
+                return;
+            }
+        }
+    }
+    spawn_item(source.world, stack, 6.0, source.facing, &source.position).await;
+}
+
+#[derive(Default)]
+pub struct DispenseRegistry {
+    behaviours: FxHashMap<u16, Arc<dyn DispenseBehaviour>>,
+}
+
+impl DispenseRegistry {
+    pub fn register<T: DispenseBehaviour + ItemMetadata + 'static>(&mut self, behaviour: T) {
+        let val = Arc::new(behaviour);
+        self.behaviours.reserve(T::ids().len());
+        for i in T::ids() {
+            self.behaviours.insert(i, val.clone());
+        }
+    }
+
+    /// The behaviour used to dispense `item`, falling back to [`DefaultDispenseBehaviour`].
+    #[must_use]
+    pub fn get(&self, item: &Item) -> &dyn DispenseBehaviour {
+        self.behaviours
+            .get(&item.id)
+            .map_or(&DefaultDispenseBehaviour, |behaviour| behaviour.as_ref())
+    }
+}
+
+#[must_use]
+pub fn default_registry() -> Arc<DispenseRegistry> {
+    let mut manager = DispenseRegistry::default();
+
+    manager.register(EmptyBucketItem);
+    manager.register(FilledBucketItem);
+
+    Arc::new(manager)
+}
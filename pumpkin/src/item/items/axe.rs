@@ -133,7 +133,9 @@ fn get_stripped_equivalent(block: &Block) -> u16 {
     }
 }
 
-fn get_deoxidized_equivalent(block: &Block) -> u16 {
+/// The id of the copper block one oxidation level below `block`, `0` if there is none.
+#[must_use]
+pub fn get_deoxidized_equivalent(block: &Block) -> u16 {
     match block.id {
         id if id == Block::OXIDIZED_COPPER.id => Block::WEATHERED_COPPER.id,
         id if id == Block::WEATHERED_COPPER.id => Block::EXPOSED_COPPER.id,
@@ -1,26 +1,38 @@
 use std::{pin::Pin, sync::Arc};
 
 use crate::{
-    entity::player::Player,
-    item::{ItemBehaviour, ItemMetadata},
+    entity::{EntityBase, player::Player, r#type::from_type},
+    item::{
+        ItemBehaviour, ItemMetadata,
+        dispense::{
+            DefaultDispenseBehaviour, DispenseBehaviour, DispenseFuture, DispenseResult,
+            DispenseSource,
+        },
+    },
+    server::spawn_protection,
 };
 use pumpkin_data::{
     Block, BlockDirection,
+    data_component_impl::BucketEntityDataImpl,
     dimension::Dimension,
+    entity::EntityType,
     fluid::Fluid,
     item::Item,
     sound::{Sound, SoundCategory},
 };
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
 use pumpkin_util::{
     GameMode,
-    math::{position::BlockPos, vector3::Vector3},
+    math::{position::BlockPos, vector3::Vector3, wrap_degrees},
 };
-use pumpkin_world::{inventory::Inventory, item::ItemStack, tick::TickPriority, world::BlockFlags};
+use pumpkin_world::{item::ItemStack, tick::TickPriority, world::BlockFlags};
+use uuid::Uuid;
 
 use crate::world::World;
 
 pub struct EmptyBucketItem;
 pub struct FilledBucketItem;
+pub struct MilkBucketItem;
 
 impl ItemMetadata for EmptyBucketItem {
     fn ids() -> Box<[u16]> {
@@ -45,11 +57,86 @@ impl ItemMetadata for FilledBucketItem {
     }
 }
 
-// impl ItemMetadata for MilkBucketItem {
-//     fn ids() -> Box<[u16]> {
-//         [Item::MILK_BUCKET.id].into()
-//     }
-// }
+impl ItemMetadata for MilkBucketItem {
+    fn ids() -> Box<[u16]> {
+        [Item::MILK_BUCKET.id].into()
+    }
+}
+
+/// Mobs that can be scooped up with a water bucket, and the bucket they end up in.
+const MOB_BUCKETS: &[(&EntityType, &Item)] = &[
+    (&EntityType::AXOLOTL, &Item::AXOLOTL_BUCKET),
+    (&EntityType::COD, &Item::COD_BUCKET),
+    (&EntityType::SALMON, &Item::SALMON_BUCKET),
+    (&EntityType::TROPICAL_FISH, &Item::TROPICAL_FISH_BUCKET),
+    (&EntityType::PUFFERFISH, &Item::PUFFERFISH_BUCKET),
+    (&EntityType::TADPOLE, &Item::TADPOLE_BUCKET),
+];
+
+/// Entity data describing where the mob was rather than the mob itself, not kept in the bucket.
+const BUCKET_SKIPPED_NBT: &[&str] = &[
+    "id",
+    "UUID",
+    "Pos",
+    "Motion",
+    "Rotation",
+    "OnGround",
+    "Fire",
+    "PortalCooldown",
+    "fall_distance",
+];
+
+fn bucket_for_mob(entity_type: &EntityType) -> Option<&'static Item> {
+    MOB_BUCKETS
+        .iter()
+        .find(|(mob, _)| mob.id == entity_type.id)
+        .map(|(_, bucket)| *bucket)
+}
+
+fn mob_in_bucket(item: &Item) -> Option<&'static EntityType> {
+    MOB_BUCKETS
+        .iter()
+        .find(|(_, bucket)| bucket.id == item.id)
+        .map(|(mob, _)| *mob)
+}
+
+const fn fill_sound(item: &Item) -> Sound {
+    match item.id {
+        id if id == Item::WATER_BUCKET.id => Sound::ItemBucketFill,
+        id if id == Item::LAVA_BUCKET.id => Sound::ItemBucketFillLava,
+        id if id == Item::POWDER_SNOW_BUCKET.id => Sound::ItemBucketFillPowderSnow,
+        id if id == Item::AXOLOTL_BUCKET.id => Sound::ItemBucketFillAxolotl,
+        id if id == Item::TADPOLE_BUCKET.id => Sound::ItemBucketFillTadpole,
+        _ => Sound::ItemBucketFillFish,
+    }
+}
+
+const fn empty_sound(item: &Item) -> Sound {
+    match item.id {
+        id if id == Item::WATER_BUCKET.id => Sound::ItemBucketEmpty,
+        id if id == Item::LAVA_BUCKET.id => Sound::ItemBucketEmptyLava,
+        id if id == Item::POWDER_SNOW_BUCKET.id => Sound::ItemBucketEmptyPowderSnow,
+        id if id == Item::AXOLOTL_BUCKET.id => Sound::ItemBucketEmptyAxolotl,
+        id if id == Item::TADPOLE_BUCKET.id => Sound::ItemBucketEmptyTadpole,
+        _ => Sound::ItemBucketEmptyFish,
+    }
+}
+
+/// Whether `player` may pick up or pour a bucket at `pos`, see [`spawn_protection::may_modify`].
+async fn may_use_bucket_at(
+    world: &Arc<World>,
+    player: &Player,
+    pos: &BlockPos,
+    block_to_build: &'static Block,
+) -> bool {
+    let (Some(server), Some(player)) = (
+        world.server.upgrade(),
+        world.get_player_by_id(player.entity_id()),
+    ) else {
+        return false;
+    };
+    spawn_protection::may_modify(&server, world, &player, pos, block_to_build).await
+}
 
 fn get_start_and_end_pos(player: &Player) -> (Vector3<f64>, Vector3<f64>) {
     let start_pos = player.eye_position();
@@ -97,32 +184,40 @@ fn set_waterlogged(block: &Block, state: u16, waterlogged: bool) -> u16 {
     block.from_properties(&props).to_state_id(block)
 }
 
-async fn give_player_bucket_item(player: &Player, item: &'static Item) {
-    if player.gamemode.load() == GameMode::Creative {
-        for i in 0..player.inventory.main_inventory.len() {
-            if player.inventory.main_inventory[i].lock().await.item.id == item.id {
-                return;
+/// Gives `output` to the player in exchange for one item of their `held` stack, like filling a
+/// bucket does. Creative players keep `held` and only get `output` if they don't have it yet.
+///
+/// `held` is usually locked by the caller, so anything that has to go elsewhere in the inventory
+/// is handed over once the lock is released.
+pub fn exchange_held_stack(player: &Player, held: &mut ItemStack, output: ItemStack) {
+    let creative = player.gamemode.load() == GameMode::Creative;
+    if !creative {
+        held.decrement(1);
+        if held.is_empty() {
+            *held = output;
+            return;
+        }
+    }
+    let Some(player_arc) = player.world().get_player_by_id(player.entity_id()) else {
+        return;
+    };
+    player.spawn_task(async move {
+        let inventory = &player_arc.inventory;
+        if creative {
+            for slot in &inventory.main_inventory {
+                if slot.lock().await.item.id == output.item.id {
+                    return;
+                }
             }
         }
-        let mut item_stack = ItemStack::new(1, item);
-        player
-            .inventory
-            .insert_stack_anywhere(&mut item_stack)
-            .await;
-    } else {
-        let item_stack = ItemStack::new(1, item);
-        player
-            .inventory
-            .set_stack(player.inventory.get_selected_slot().into(), item_stack)
+        inventory
+            .offer_or_drop_stack(output, player_arc.as_ref())
             .await;
-    }
+    });
 }
 
-async fn try_pickup_bucket_item(
-    world: &Arc<World>,
-    block_pos: BlockPos,
-    direction: BlockDirection,
-) -> Option<&'static Item> {
+/// Takes the fluid or powder snow at `block_pos` into a bucket, returning the filled bucket.
+async fn pickup_fluid(world: &Arc<World>, block_pos: BlockPos) -> Option<&'static Item> {
     let (block, state) = world.get_block_and_state_id(&block_pos).await;
 
     if block == &Block::POWDER_SNOW {
@@ -165,17 +260,22 @@ async fn try_pickup_bucket_item(
         });
     }
 
+    None
+}
+
+async fn try_pickup_bucket_item(
+    world: &Arc<World>,
+    block_pos: BlockPos,
+    direction: BlockDirection,
+) -> Option<&'static Item> {
+    if let Some(item) = pickup_fluid(world, block_pos).await {
+        return Some(item);
+    }
+
     let target_pos = block_pos.offset(direction.to_offset());
     let (block, state) = world.get_block_and_state_id(&target_pos).await;
-    if waterlogged_check(block, state).is_some() {
-        let state_id = set_waterlogged(block, state, false);
-        world
-            .set_block_state(&target_pos, state_id, BlockFlags::NOTIFY_NEIGHBORS)
-            .await;
-        world
-            .schedule_fluid_tick(&Fluid::WATER, target_pos, 5, TickPriority::Normal)
-            .await;
-        return Some(&Item::WATER_BUCKET);
+    if is_waterlogged(block, state) {
+        return pickup_fluid(world, target_pos).await;
     }
 
     None
@@ -187,12 +287,12 @@ fn should_evaporate_in_nether(item: &Item, world: &World) -> bool {
         && world.dimension == Dimension::THE_NETHER
 }
 
-async fn play_bucket_evaporation(world: &Arc<World>, player: &Player) {
+async fn play_bucket_evaporation(world: &Arc<World>, position: &Vector3<f64>) {
     world
         .play_sound_raw(
             Sound::BlockFireExtinguish as u16,
             SoundCategory::Blocks,
-            &player.position(),
+            position,
             0.5,
             (rand::random::<f32>() - rand::random::<f32>()).mul_add(0.8, 2.6),
         )
@@ -203,7 +303,7 @@ async fn try_place_powder_snow(
     world: &Arc<World>,
     pos: BlockPos,
     direction: BlockDirection,
-) -> bool {
+) -> Option<BlockPos> {
     let state = world.get_block_state(&pos).await;
     let target_pos = if state.replaceable() {
         pos
@@ -212,7 +312,7 @@ async fn try_place_powder_snow(
     };
     let target_state = world.get_block_state(&target_pos).await;
     if !target_state.is_air() && !target_state.is_liquid() && !target_state.replaceable() {
-        return false;
+        return None;
     }
     world
         .set_block_state(
@@ -221,21 +321,23 @@ async fn try_place_powder_snow(
             BlockFlags::NOTIFY_NEIGHBORS,
         )
         .await;
-    true
+    Some(target_pos)
 }
 
+/// Places the contents of a filled bucket at `pos`, or next to it on the `direction` side.
+/// Returns where they ended up.
 async fn try_place_filled_bucket(
     world: &Arc<World>,
     item: &Item,
     pos: BlockPos,
     direction: BlockDirection,
-) -> bool {
+) -> Option<BlockPos> {
     let (block, state) = world.get_block_and_state(&pos).await;
     if item.id == Item::POWDER_SNOW_BUCKET.id {
         return try_place_powder_snow(world, pos, direction).await;
     }
 
-    if is_waterlogged(block, state.id) && item.id == Item::WATER_BUCKET.id {
+    if waterlogged_check(block, state.id) == Some(false) && item.id != Item::LAVA_BUCKET.id {
         let state_id = set_waterlogged(block, state.id, true);
         world
             .set_block_state(&pos, state_id, BlockFlags::NOTIFY_NEIGHBORS)
@@ -243,15 +345,15 @@ async fn try_place_filled_bucket(
         world
             .schedule_fluid_tick(&Fluid::WATER, pos, 5, TickPriority::Normal)
             .await;
-        return true;
+        return Some(pos);
     }
 
     let target_pos = pos.offset(direction.to_offset());
     let (block, state) = world.get_block_and_state(&target_pos).await;
 
-    if waterlogged_check(block, state.id).is_some() {
-        if item.id == Item::LAVA_BUCKET.id {
-            return false;
+    if let Some(waterlogged) = waterlogged_check(block, state.id) {
+        if waterlogged || item.id == Item::LAVA_BUCKET.id {
+            return None;
         }
         let state_id = set_waterlogged(block, state.id, true);
         world
@@ -260,7 +362,7 @@ async fn try_place_filled_bucket(
         world
             .schedule_fluid_tick(&Fluid::WATER, target_pos, 5, TickPriority::Normal)
             .await;
-        return true;
+        return Some(target_pos);
     }
 
     if state.id == Block::AIR.default_state.id || state.is_liquid() {
@@ -275,10 +377,92 @@ async fn try_place_filled_bucket(
                 BlockFlags::NOTIFY_NEIGHBORS,
             )
             .await;
-        return true;
+        return Some(target_pos);
     }
 
-    false
+    None
+}
+
+/// Releases the mob of a mob bucket at `pos`, restoring the data it was caught with.
+async fn spawn_bucket_entity(world: &Arc<World>, stack: &ItemStack, pos: BlockPos) {
+    let Some(entity_type) = mob_in_bucket(stack.item) else {
+        return;
+    };
+    let position = Vector3::new(
+        f64::from(pos.0.x) + 0.5,
+        f64::from(pos.0.y),
+        f64::from(pos.0.z) + 0.5,
+    );
+    let yaw = wrap_degrees(rand::random::<f32>() * 360.0);
+    let mob = from_type(entity_type, position, world, Uuid::new_v4()).await;
+    if let Some(bucket_data) = stack.get_data_component::<BucketEntityDataImpl>() {
+        let mut data = bucket_data.data.clone();
+        data.put(
+            "Pos",
+            NbtTag::List(vec![
+                position.x.into(),
+                position.y.into(),
+                position.z.into(),
+            ]),
+        );
+        data.put(
+            "Motion",
+            NbtTag::List(vec![0.0f64.into(), 0.0f64.into(), 0.0f64.into()]),
+        );
+        data.put("Rotation", NbtTag::List(vec![yaw.into(), 0.0f32.into()]));
+        mob.read_nbt_non_mut(&data).await;
+    } else {
+        mob.get_entity().set_rotation(yaw, 0.0);
+    }
+    world.spawn_entity(mob).await;
+}
+
+/// Empties a filled bucket like [`try_place_filled_bucket`], releasing the mob of mob buckets.
+/// Returns whether anything was placed.
+async fn empty_filled_bucket(
+    world: &Arc<World>,
+    stack: &ItemStack,
+    pos: BlockPos,
+    direction: BlockDirection,
+) -> bool {
+    let Some(placed) = try_place_filled_bucket(world, stack.item, pos, direction).await else {
+        return false;
+    };
+    spawn_bucket_entity(world, stack, placed).await;
+    world
+        .play_sound(
+            empty_sound(stack.item),
+            SoundCategory::Blocks,
+            &placed.to_centered_f64(),
+        )
+        .await;
+    true
+}
+
+/// Puts `entity` into a bucket if it is a mob that fits in one, removing it from the world.
+async fn catch_in_bucket(entity: &Arc<dyn EntityBase>) -> Option<ItemStack> {
+    let base = entity.get_entity();
+    let bucket = bucket_for_mob(base.entity_type)?;
+    if entity
+        .get_living_entity()
+        .is_none_or(|living| living.dead.load(std::sync::atomic::Ordering::Relaxed))
+    {
+        return None;
+    }
+
+    let mut nbt = NbtCompound::new();
+    entity.write_nbt(&mut nbt).await;
+    let mut data = NbtCompound::new();
+    for (key, value) in nbt.child_tags {
+        if !BUCKET_SKIPPED_NBT.contains(&key.as_str()) {
+            data.put(&key, value);
+        }
+    }
+    base.remove().await;
+
+    let mut stack = ItemStack::new(1, bucket);
+    stack.set_data_component(BucketEntityDataImpl { data });
+    Some(stack)
 }
 
 impl ItemBehaviour for EmptyBucketItem {
@@ -310,11 +494,24 @@ impl ItemBehaviour for EmptyBucketItem {
                 return;
             };
 
+            if !may_use_bucket_at(&world, player, &block_pos, &Block::AIR).await {
+                return;
+            }
+
             let Some(item) = try_pickup_bucket_item(&world, block_pos, direction).await else {
                 return;
             };
 
-            give_player_bucket_item(player, item).await;
+            world
+                .play_sound(
+                    fill_sound(item),
+                    SoundCategory::Players,
+                    &block_pos.to_centered_f64(),
+                )
+                .await;
+            let held = player.inventory.held_item();
+            let mut held = held.lock().await;
+            exchange_held_stack(player, &mut held, ItemStack::new(1, item));
         })
     }
 
@@ -323,6 +520,31 @@ impl ItemBehaviour for EmptyBucketItem {
     }
 }
 
+impl DispenseBehaviour for EmptyBucketItem {
+    fn dispense<'a>(
+        &'a self,
+        source: &'a DispenseSource<'a>,
+        stack: &'a mut ItemStack,
+    ) -> DispenseFuture<'a> {
+        Box::pin(async move {
+            let front = source.front();
+            let Some(item) = pickup_fluid(source.world, front).await else {
+                return DefaultDispenseBehaviour.dispense(source, stack).await;
+            };
+            source
+                .world
+                .play_sound(
+                    fill_sound(item),
+                    SoundCategory::Blocks,
+                    &front.to_centered_f64(),
+                )
+                .await;
+            stack.decrement(1);
+            DispenseResult::Success(Some(ItemStack::new(1, item)))
+        })
+    }
+}
+
 impl ItemBehaviour for FilledBucketItem {
     fn normal_use<'a>(
         &'a self,
@@ -345,21 +567,62 @@ impl ItemBehaviour for FilledBucketItem {
             };
 
             if should_evaporate_in_nether(item, &world) {
-                play_bucket_evaporation(&world, player).await;
+                play_bucket_evaporation(&world, &player.position()).await;
                 return;
             }
-            if !try_place_filled_bucket(&world, item, pos, direction).await {
+
+            let fluid_block = match item.id {
+                id if id == Item::LAVA_BUCKET.id => &Block::LAVA,
+                id if id == Item::POWDER_SNOW_BUCKET.id => &Block::POWDER_SNOW,
+                _ => &Block::WATER,
+            };
+            if !may_use_bucket_at(&world, player, &pos, fluid_block).await
+                || !may_use_bucket_at(
+                    &world,
+                    player,
+                    &pos.offset(direction.to_offset()),
+                    fluid_block,
+                )
+                .await
+            {
+                return;
+            }
+
+            let held = player.inventory.held_item();
+            let mut held = held.lock().await;
+            if held.item.id != item.id || !empty_filled_bucket(&world, &held, pos, direction).await
+            {
                 return;
             }
 
-            //TODO: Spawn entity if applicable
             if player.gamemode.load() != GameMode::Creative {
-                let item_stack = ItemStack::new(1, &Item::BUCKET);
-                player
-                    .inventory
-                    .set_stack(player.inventory.get_selected_slot().into(), item_stack)
-                    .await;
+                *held = ItemStack::new(1, &Item::BUCKET);
+            }
+        })
+    }
+
+    fn use_on_entity<'a>(
+        &'a self,
+        item: &'a mut ItemStack,
+        player: &'a Player,
+        entity: Arc<dyn EntityBase>,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            if item.item.id != Item::WATER_BUCKET.id {
+                return;
             }
+            let Some(bucket) = catch_in_bucket(&entity).await else {
+                return;
+            };
+            player
+                .world()
+                .play_sound(
+                    fill_sound(bucket.item),
+                    SoundCategory::Neutral,
+                    &entity.get_entity().pos.load(),
+                )
+                .await;
+            exchange_held_stack(player, item, bucket);
         })
     }
 
@@ -368,4 +631,40 @@ impl ItemBehaviour for FilledBucketItem {
     }
 }
 
-//TODO: Implement MilkBucketItem
+impl DispenseBehaviour for FilledBucketItem {
+    fn dispense<'a>(
+        &'a self,
+        source: &'a DispenseSource<'a>,
+        stack: &'a mut ItemStack,
+    ) -> DispenseFuture<'a> {
+        Box::pin(async move {
+            if should_evaporate_in_nether(stack.item, source.world) {
+                play_bucket_evaporation(source.world, &source.front().to_centered_f64()).await;
+            } else if !empty_filled_bucket(source.world, stack, source.position, source.direction())
+                .await
+            {
+                return DefaultDispenseBehaviour.dispense(source, stack).await;
+            }
+            stack.decrement(1);
+            DispenseResult::Success(Some(ItemStack::new(1, &Item::BUCKET)))
+        })
+    }
+}
+
+impl ItemBehaviour for MilkBucketItem {
+    fn finish_using<'a>(
+        &'a self,
+        held: &'a mut ItemStack,
+        player: &'a Player,
+    ) -> Pin<Box<dyn Future<Output = bool> + Send + 'a>> {
+        Box::pin(async move {
+            player.remove_all_effects().await;
+            exchange_held_stack(player, held, ItemStack::new(1, &Item::BUCKET));
+            true
+        })
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
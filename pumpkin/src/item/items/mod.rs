@@ -15,6 +15,7 @@ pub mod ink_sac;
 pub mod mace;
 pub mod minecart;
 pub mod name_tag;
+pub mod on_a_stick;
 pub mod shovel;
 pub mod snowball;
 pub mod spawn_egg;
@@ -28,12 +29,13 @@ use crate::item::items::end_crystal::EndCrystalItem;
 use crate::item::items::firework_rocket::FireworkRocketItem;
 use crate::item::items::minecart::MinecartItem;
 use crate::item::items::name_tag::NameTagItem;
+use crate::item::items::on_a_stick::OnAStickItem;
 use crate::item::items::spawn_egg::SpawnEggItem;
 use crate::item::items::wind_charge::WindChargeItem;
 
 use super::registry::ItemRegistry;
 use axe::AxeItem;
-use bucket::{EmptyBucketItem, FilledBucketItem};
+use bucket::{EmptyBucketItem, FilledBucketItem, MilkBucketItem};
 use dye::DyeItem;
 use egg::EggItem;
 use ender_eye::EnderEyeItem;
@@ -63,6 +65,7 @@ pub fn default_registry() -> Arc<ItemRegistry> {
     manager.register(TridentItem);
     manager.register(EmptyBucketItem);
     manager.register(FilledBucketItem);
+    manager.register(MilkBucketItem);
     manager.register(ShovelItem);
     manager.register(SpawnEggItem);
     manager.register(AxeItem);
@@ -70,6 +73,7 @@ pub fn default_registry() -> Arc<ItemRegistry> {
     manager.register(MinecartItem);
     manager.register(HoneyCombItem);
     manager.register(NameTagItem);
+    manager.register(OnAStickItem);
     manager.register(EnderEyeItem);
     manager.register(FireChargeItem);
     manager.register(DyeItem);
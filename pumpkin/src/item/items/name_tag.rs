@@ -4,9 +4,7 @@ use std::sync::Arc;
 use crate::entity::EntityBase;
 use crate::entity::player::Player;
 use crate::item::{ItemBehaviour, ItemMetadata};
-use pumpkin_data::data_component_impl::CustomNameImpl;
 use pumpkin_data::item::Item;
-use pumpkin_util::text::TextComponent;
 use pumpkin_world::item::ItemStack;
 
 pub struct NameTagItem;
@@ -27,10 +25,9 @@ impl ItemBehaviour for NameTagItem {
         Box::pin(async move {
             let entity = entity.get_entity();
             if entity.entity_type.saveable
-                && let Some(name) = item.get_data_component::<CustomNameImpl>()
+                && let Some(name) = item.get_custom_name().cloned()
             {
-                // TODO
-                entity.set_custom_name(TextComponent::text(name.name)).await;
+                entity.set_custom_name(name).await;
                 item.decrement_unless_creative(player.gamemode.load(), 1);
             }
         })
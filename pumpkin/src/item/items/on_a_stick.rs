@@ -0,0 +1,85 @@
+use std::pin::Pin;
+
+use crate::entity::EntityBase;
+use crate::entity::player::Player;
+use crate::item::{ItemBehaviour, ItemMetadata};
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::item::Item;
+use pumpkin_util::GameMode;
+use pumpkin_world::item::ItemStack;
+
+/// Carrots and warped fungi on a stick, which boost the pig or strider they steer.
+pub struct OnAStickItem;
+
+impl OnAStickItem {
+    /// The vehicle the item steers and the durability a boost costs.
+    const fn steers(item: &Item) -> (&'static EntityType, i32) {
+        if item.id == Item::CARROT_ON_A_STICK.id {
+            (&EntityType::PIG, 7)
+        } else {
+            (&EntityType::STRIDER, 1)
+        }
+    }
+}
+
+impl ItemMetadata for OnAStickItem {
+    fn ids() -> Box<[u16]> {
+        [
+            Item::CARROT_ON_A_STICK.id,
+            Item::WARPED_FUNGUS_ON_A_STICK.id,
+        ]
+        .into()
+    }
+}
+
+impl ItemBehaviour for OnAStickItem {
+    fn normal_use<'a>(
+        &'a self,
+        item: &'a Item,
+        player: &'a Player,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let (vehicle_type, damage) = Self::steers(item);
+            let vehicle = player.get_entity().vehicle.lock().await.clone();
+            let Some(vehicle) = vehicle else {
+                return;
+            };
+            if vehicle.get_entity().entity_type != vehicle_type
+                || !vehicle
+                    .get_controlling_passenger()
+                    .await
+                    .is_some_and(|controller| {
+                        controller.get_entity().entity_id == player.entity_id()
+                    })
+                || !vehicle.boost().await
+                || matches!(
+                    player.gamemode.load(),
+                    GameMode::Creative | GameMode::Spectator
+                )
+            {
+                return;
+            }
+
+            let stack_arc = player.inventory.held_item();
+            let updated = {
+                let mut stack = stack_arc.lock().await;
+                if stack.item.id != item.id {
+                    return;
+                }
+                stack.damage_item(damage);
+                // The stick is left over once the bait is used up
+                if stack.is_empty() {
+                    *stack = ItemStack::new(1, &Item::FISHING_ROD);
+                }
+                stack.clone()
+            };
+            player
+                .sync_hand_slot(player.inventory.get_selected_slot() as usize, updated)
+                .await;
+        })
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
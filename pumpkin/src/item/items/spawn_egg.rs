@@ -10,6 +10,7 @@ use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_util::math::wrap_degrees;
 use pumpkin_world::block::entities::mob_spawner::MobSpawnerBlockEntity;
+use pumpkin_world::block::entities::trial_spawner::TrialSpawnerBlockEntity;
 use pumpkin_world::item::ItemStack;
 use uuid::Uuid;
 
@@ -36,15 +37,21 @@ impl ItemBehaviour for SpawnEggItem {
             if let Some(entity_type) = entity_from_egg(item.item.id) {
                 let world = player.world();
 
-                if let Some(block_entity) = player.world().get_block_entity(&location).await
-                    && let Some(spawner) = block_entity
-                        .as_any()
+                if let Some(block_entity) = player.world().get_block_entity(&location).await {
+                    let any = block_entity.as_any();
+                    let is_spawner = any
                         .downcast_ref::<MobSpawnerBlockEntity>()
-                {
-                    spawner.set_entity_type(entity_type);
-                    world.update_block_entity(&block_entity).await;
-                    item.decrement_unless_creative(player.gamemode.load(), 1);
-                    return;
+                        .map(|spawner| spawner.set_entity_type(entity_type))
+                        .or_else(|| {
+                            any.downcast_ref::<TrialSpawnerBlockEntity>()
+                                .map(|spawner| spawner.set_entity_type(entity_type))
+                        })
+                        .is_some();
+                    if is_spawner {
+                        world.update_block_entity(&block_entity).await;
+                        item.decrement_unless_creative(player.gamemode.load(), 1);
+                        return;
+                    }
                 }
                 let pos = BlockPos(location.0 + face.to_offset());
                 let pos = Vector3::new(
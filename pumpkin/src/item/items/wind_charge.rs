@@ -57,7 +57,6 @@ impl ItemBehaviour for WindChargeItem {
             );
             // TODO: player.incrementStat(Stats.USED)
 
-            // TODO: Implement that the projectile will explode on impact on ground
             world
                 .spawn_entity(Arc::new(WindChargeEntity::new(wind_charge)))
                 .await;
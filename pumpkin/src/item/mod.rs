@@ -1,3 +1,4 @@
+pub mod dispense;
 pub mod items;
 pub mod registry;
 
@@ -51,6 +52,16 @@ pub trait ItemBehaviour: Send + Sync {
         Box::pin(async {})
     }
 
+    /// Called when the player finishes consuming their `held` item. Returns whether the item took
+    /// care of using up `held`; otherwise one item is removed from it.
+    fn finish_using<'a>(
+        &'a self,
+        _held: &'a mut ItemStack,
+        _player: &'a Player,
+    ) -> Pin<Box<dyn Future<Output = bool> + Send + 'a>> {
+        Box::pin(async { false })
+    }
+
     fn can_mine(&self, _player: &Player) -> bool {
         true
     }
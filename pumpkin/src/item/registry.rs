@@ -64,6 +64,14 @@ impl ItemRegistry {
         }
     }
 
+    pub async fn finish_using(&self, held: &mut ItemStack, player: &Player) -> bool {
+        let pumpkin_item = self.get_pumpkin_item(held.item.id);
+        if let Some(pumpkin_item) = pumpkin_item {
+            return pumpkin_item.finish_using(held, player).await;
+        }
+        false
+    }
+
     pub fn can_mine(&self, item: &Item, player: &Player) -> bool {
         let pumpkin_block = self.get_pumpkin_item(item.id);
         if let Some(pumpkin_block) = pumpkin_block {
@@ -5,8 +5,9 @@ use crate::data::VanillaData;
 use crate::logging::{GzipRollingLogger, PumpkinCommandCompleter, ReadlineLogWrapper};
 use crate::net::bedrock::BedrockClient;
 use crate::net::java::{JavaClient, PacketHandlerResult};
+use crate::net::proxy::haproxy;
 use crate::net::{ClientPlatform, DisconnectReason};
-use crate::net::{lan_broadcast::LANBroadcast, query, rcon::RCONServer};
+use crate::net::{lan_broadcast::LANBroadcast, listener, query, rcon::RCONServer};
 use crate::server::{Server, ticker::Ticker};
 use plugin::server::server_command::ServerCommandEvent;
 use pumpkin_config::{AdvancedConfiguration, BasicConfiguration};
@@ -183,7 +184,7 @@ fn resolve_some<T: Future, D, F: FnOnce(D) -> T>(
 
 pub struct PumpkinServer {
     pub server: Arc<Server>,
-    pub tcp_listener: Option<TcpListener>,
+    pub tcp_listeners: Vec<TcpListener>,
     pub udp_socket: Option<Arc<UdpSocket>>,
 }
 
@@ -226,34 +227,27 @@ impl PumpkinServer {
             });
         }
 
-        let tcp_listener = if server.basic_config.java_edition {
-            let address = server.basic_config.java_edition_address;
-            // Setup the TCP server socket.
-            let listener = match TcpListener::bind(address).await {
-                Ok(l) => l,
-                Err(e) => match e.kind() {
-                    ErrorKind::AddrInUse => {
-                        error!("Error: Address {address} is already in use.");
-                        error!("Make sure another instance of the server isn't already running");
-                        std::process::exit(1);
-                    }
-                    ErrorKind::PermissionDenied => {
-                        error!("Error: Permission denied when binding to {address}.");
-                        error!("You might need sudo/admin privileges to use ports below 1024");
-                        std::process::exit(1);
-                    }
-                    ErrorKind::AddrNotAvailable => {
-                        error!("Error: The address {address} is not available on this machine");
-                        std::process::exit(1);
-                    }
-                    _ => {
-                        error!("Failed to start TcpListener on {address}: {e}");
-                        std::process::exit(1);
-                    }
-                },
-            };
+        let tcp_listeners = if server.basic_config.java_edition {
+            let proxy_protocol = &server.advanced_config.networking.proxy.proxy_protocol;
+            if proxy_protocol.enabled && proxy_protocol.trusted_proxies.is_empty() {
+                warn!(
+                    "The PROXY protocol is enabled without any trusted proxies, so every Java Edition connection will be refused"
+                );
+            }
+            let addresses = listener::resolve_addresses(
+                server.basic_config.java_edition_address,
+                &server.basic_config.java_edition_additional_addresses,
+            )
+            .await;
+            // Setup the TCP server sockets.
+            let listeners: Vec<_> = addresses
+                .iter()
+                .map(|address| {
+                    bind_or_exit(*address, listener::needs_only_v6(*address, &addresses))
+                })
+                .collect();
             // In the event the user puts 0 for their port, this will allow us to know what port it is running on
-            let addr = listener
+            let addr = listeners[0]
                 .local_addr()
                 .expect("Unable to get the address of the server!");
 
@@ -275,9 +269,9 @@ impl PumpkinServer {
                 server.spawn_task(lan_broadcast.start(addr));
             }
 
-            Some(listener)
+            listeners
         } else {
-            None
+            Vec::new()
         };
 
         // Ticker
@@ -300,11 +294,21 @@ impl PumpkinServer {
 
         Self {
             server,
-            tcp_listener,
+            tcp_listeners,
             udp_socket,
         }
     }
 
+    /// The addresses the Java Edition listeners are bound to.
+    #[must_use]
+    pub fn java_edition_addresses(&self) -> Vec<String> {
+        self.tcp_listeners
+            .iter()
+            .filter_map(|listener| listener.local_addr().ok())
+            .map(|address| address.to_string())
+            .collect()
+    }
+
     pub async fn init_plugins(&self) {
         self.server
             .plugin_manager
@@ -390,9 +394,9 @@ impl PumpkinServer {
 
         select! {
             // Branch for TCP connections (Java Edition)
-            tcp_result = resolve_some(self.tcp_listener.as_ref(), tokio::net::TcpListener::accept) => {
+            tcp_result = listener::accept_any(&self.tcp_listeners) => {
                 match tcp_result {
-                    Ok((connection, client_addr)) => {
+                    Ok((mut connection, peer_addr)) => {
                         if let Err(e) = connection.set_nodelay(true) {
                             warn!("Failed to set TCP_NODELAY: {e}");
                         }
@@ -400,15 +404,25 @@ impl PumpkinServer {
                         let client_id = *master_client_id_counter;
                         *master_client_id_counter += 1;
 
-                        let formatted_address = if self.server.basic_config.scrub_ips {
-                            scrub_address(&format!("{client_addr}"))
-                        } else {
-                            format!("{client_addr}")
-                        };
-                        debug!("Accepted connection from Java Edition: {formatted_address} (id {client_id})");
                         let server_clone = self.server.clone();
 
                         tasks.spawn(async move {
+                            let scrub_ips = server_clone.basic_config.scrub_ips;
+                            let client_addr = match haproxy::resolve_client_address(
+                                &mut connection,
+                                peer_addr,
+                                &server_clone.advanced_config.networking.proxy.proxy_protocol,
+                            )
+                            .await
+                            {
+                                Ok(client_addr) => client_addr,
+                                Err(e) => {
+                                    debug!("Refused Java Edition connection from {}: {e}", format_address(peer_addr, scrub_ips));
+                                    return;
+                                }
+                            };
+                            debug!("Accepted connection from Java Edition: {} (id {client_id})", format_address(client_addr, scrub_ips));
+
                             let mut java_client = JavaClient::new(connection, client_addr, client_id);
                             java_client.start_outgoing_packet_task();
                             let login_result = java_client.handle_login_sequence(&server_clone).await;
@@ -619,6 +633,40 @@ fn setup_console(mut rl: Editor<PumpkinCommandCompleter, FileHistory>, server: A
     });
 }
 
+fn format_address(address: SocketAddr, scrub_ips: bool) -> String {
+    if scrub_ips {
+        scrub_address(&format!("{address}"))
+    } else {
+        format!("{address}")
+    }
+}
+
+fn bind_or_exit(address: SocketAddr, only_v6: bool) -> TcpListener {
+    match listener::bind(address, only_v6) {
+        Ok(l) => l,
+        Err(e) => match e.kind() {
+            ErrorKind::AddrInUse => {
+                error!("Error: Address {address} is already in use.");
+                error!("Make sure another instance of the server isn't already running");
+                std::process::exit(1);
+            }
+            ErrorKind::PermissionDenied => {
+                error!("Error: Permission denied when binding to {address}.");
+                error!("You might need sudo/admin privileges to use ports below 1024");
+                std::process::exit(1);
+            }
+            ErrorKind::AddrNotAvailable => {
+                error!("Error: The address {address} is not available on this machine");
+                std::process::exit(1);
+            }
+            _ => {
+                error!("Failed to start TcpListener on {address}: {e}");
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
 fn scrub_address(ip: &str) -> String {
     ip.chars()
         .map(|ch| if ch == '.' || ch == ':' { ch } else { 'x' })
@@ -16,12 +16,13 @@ use tokio::signal::ctrl_c;
 use tokio::signal::unix::{SignalKind, signal};
 
 use pumpkin::data::VanillaData;
+use pumpkin::server::{Server, pregenerate::ChunkPregenerator};
 use pumpkin::{LoggerOption, PumpkinServer, SHOULD_STOP, STOP_INTERRUPT, stop_server};
 
 use pumpkin_config::{AdvancedConfiguration, BasicConfiguration, LoadConfiguration};
 use pumpkin_util::text::{TextComponent, color::NamedColor};
 use std::time::Instant;
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, warn};
 
 // Setup some tokens to allow us to identify which event is for which socket.
 
@@ -104,6 +105,10 @@ async fn main() {
             .expect("Unable to setup signal handlers");
     });
 
+    if let Some(radius) = generate_radius() {
+        return generate_headless(basic_config, advanced_config, vanilla_data, radius).await;
+    }
+
     let pumpkin_server = PumpkinServer::new(basic_config, advanced_config, vanilla_data).await;
     pumpkin_server.init_plugins().await;
 
@@ -122,7 +127,7 @@ async fn main() {
                 TextComponent::text("Java Edition:")
                     .color_named(NamedColor::Yellow)
                     .to_pretty_console(),
-                TextComponent::text(format!("{}", basic_config.java_edition_address))
+                TextComponent::text(pumpkin_server.java_edition_addresses().join(", "))
                     .color_named(NamedColor::DarkBlue)
                     .to_pretty_console()
             )
@@ -157,6 +162,33 @@ async fn main() {
             .to_pretty_console()
     );
 }
+
+/// Reads the radius passed as `--generate <radius>`, exiting if it is not a valid number.
+fn generate_radius() -> Option<u32> {
+    let mut args = std::env::args().skip_while(|arg| arg != "--generate");
+    args.next()?;
+    let Some(radius) = args.next().and_then(|radius| radius.parse().ok()) else {
+        error!("Usage: --generate <radius in chunks>");
+        std::process::exit(1);
+    };
+    Some(radius)
+}
+
+/// Headless mode: only brings up the worlds, without any listeners or ticking.
+async fn generate_headless(
+    basic_config: BasicConfiguration,
+    advanced_config: AdvancedConfiguration,
+    vanilla_data: VanillaData,
+    radius: u32,
+) {
+    info!("Pre-generating worlds with a radius of {radius} chunks");
+    let server = Server::new(basic_config, advanced_config, vanilla_data).await;
+    ChunkPregenerator::run(&server, radius).await;
+    info!("Starting save.");
+    server.shutdown().await;
+    info!("Completed save!");
+}
+
 fn print_support_links_and_warning() {
     warn!(
         "{}",
@@ -17,16 +17,18 @@ use pumpkin_protocol::{
         },
     },
     codec::{bedrock_block_pos::NetworkPos, var_int::VarInt, var_long::VarLong},
-    java::client::play::CSystemChatMessage,
 };
-use pumpkin_util::{math::position::BlockPos, text::TextComponent};
+use pumpkin_util::math::position::BlockPos;
 
 use crate::{
     command::CommandSender,
     entity::{EntityBase, player::Player},
     net::{DisconnectReason, bedrock::BedrockClient},
-    plugin::player::{player_chat::PlayerChatEvent, player_command_send::PlayerCommandSendEvent},
-    server::{Server, seasonal_events},
+    plugin::player::{
+        player_chat::{DefaultChatRenderer, PlayerChatEvent},
+        player_command_send::PlayerCommandSendEvent,
+    },
+    server::{Server, chat, seasonal_events},
     world::chunker::{self},
 };
 use tracing::{debug, info};
@@ -137,44 +139,21 @@ impl BedrockClient {
     }
 
     pub async fn handle_chat_message(&self, server: &Server, player: &Arc<Player>, packet: SText) {
-        let gameprofile = &player.gameprofile;
+        let config = &server.advanced_config;
+        let message =
+            seasonal_events::modify_chat_message(&packet.message, config).unwrap_or(packet.message);
+        let recipients = player.world().players.load().to_vec();
+        let renderer = Arc::new(DefaultChatRenderer {
+            format: config.chat.format.clone(),
+        });
 
         send_cancellable! {{
             server;
-            PlayerChatEvent::new(player.clone(), packet.message, vec![]);
+            PlayerChatEvent::new(player.clone(), message, recipients, renderer);
 
             'after: {
-                info!("<chat> {}: {}", gameprofile.name, event.message);
-
-                let config = &server.advanced_config;
-
-                let message = match seasonal_events::modify_chat_message(&event.message, config) {
-                    Some(m) => m,
-                    None => event.message.clone(),
-                };
-
-                let decorated_message = TextComponent::chat_decorated(
-                    &config.chat.format,
-                    &gameprofile.name,
-                    &message,
-                );
-
-                let entity = &player.living_entity.entity;
-                if server.basic_config.allow_chat_reports {
-                    //TODO Alex help, what is this?
-                    //world.broadcast_secure_player_chat(player, &message, decorated_message).await;
-                } else {
-                    let je_packet = CSystemChatMessage::new(
-                        &decorated_message,
-                        false,
-                    );
-
-                    let be_packet = SText::new(
-                        message, gameprofile.name.clone()
-                    );
-
-                    entity.world.load().broadcast_editioned(&je_packet, &be_packet).await;
-                }
+                // Bedrock messages are never signed, so they are delivered as system messages
+                chat::broadcast_player_chat(&event, None).await;
             }
         }}
     }
@@ -1,3 +1,5 @@
+use std::net::SocketAddr;
+
 use pumpkin_data::translation;
 use pumpkin_protocol::{
     ConnectionState, KnownPack, Label, Link, LinkType,
@@ -62,8 +64,10 @@ impl JavaClient {
                 )
                 .await
                 {
-                    Ok((_ip, profile)) => {
-                        // self.address.lock() = ip;
+                    Ok((ip, profile)) => {
+                        let mut address = self.address.lock().await;
+                        *address = SocketAddr::new(ip, address.port());
+                        drop(address);
                         self.finish_login(&profile).await;
                         *gameprofile = Some(profile);
                     }
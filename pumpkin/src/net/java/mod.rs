@@ -9,10 +9,11 @@ use pumpkin_protocol::java::server::play::{
     SChangeGameMode, SChatCommand, SChatMessage, SChunkBatch, SClickSlot, SClientCommand,
     SClientInformationPlay, SClientTickEnd, SCloseContainer, SCommandSuggestion, SConfirmTeleport,
     SCookieResponse as SPCookieResponse, SCustomPayload, SInteract, SKeepAlive, SMoveVehicle,
-    SPaddleBoat, SPickItemFromBlock, SPlayPingRequest, SPlayerAbilities, SPlayerAction,
-    SPlayerCommand, SPlayerInput, SPlayerLoaded, SPlayerPosition, SPlayerPositionRotation,
-    SPlayerRotation, SPlayerSession, SSetCommandBlock, SSetCreativeSlot, SSetHeldItem,
-    SSetPlayerGround, SSwingArm, SUpdateSign, SUseItem, SUseItemOn,
+    SPaddleBoat, SPickItemFromBlock, SPickItemFromEntity, SPlayPingRequest, SPlayerAbilities,
+    SPlayerAction, SPlayerCommand, SPlayerInput, SPlayerLoaded, SPlayerPosition,
+    SPlayerPositionRotation, SPlayerRotation, SPlayerSession, SRecipeBookSeenRecipe,
+    SSetCommandBlock, SSetCreativeSlot, SSetHeldItem, SSetPlayerGround, SSwingArm, SUpdateSign,
+    SUseItem, SUseItemOn,
 };
 use pumpkin_protocol::packet::MultiVersionJavaPacket;
 use pumpkin_protocol::{
@@ -760,6 +761,13 @@ impl JavaClient {
                 )
                 .await;
             }
+            id if id == SPickItemFromEntity::to_id(version) => {
+                self.handle_pick_item_from_entity(
+                    player,
+                    SPickItemFromEntity::read(payload, &version)?,
+                )
+                .await;
+            }
             id if id == SPlayerAbilities::to_id(version) => {
                 self.handle_player_abilities(player, SPlayerAbilities::read(payload, &version)?)
                     .await;
@@ -796,6 +804,14 @@ impl JavaClient {
                 self.handle_set_creative_slot(player, SSetCreativeSlot::read(payload, &version)?)
                     .await?;
             }
+            id if id == SRecipeBookSeenRecipe::to_id(version) => {
+                self.handle_recipe_book_seen_recipe(
+                    player,
+                    SRecipeBookSeenRecipe::read(payload, &version)?,
+                    server,
+                )
+                .await;
+            }
             id if id == SSwingArm::to_id(version) => {
                 self.handle_swing_arm(player, SSwingArm::read(payload, &version)?)
                     .await;
@@ -1,4 +1,4 @@
-use pumpkin_protocol::bedrock::server::text::SText;
+use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_util::{Hand, PermissionLvl};
 use rsa::pkcs1v15::{Signature as RsaPkcs1v15Signature, VerifyingKey};
 use rsa::signature::Verifier;
@@ -14,8 +14,8 @@ use crate::block::BlockHitResult;
 use crate::block::registry::BlockActionResult;
 use crate::block::{self, BlockIsReplacing};
 use crate::command::CommandSender;
-use crate::entity::EntityBase;
 use crate::entity::player::{ChatMode, ChatSession, Player};
+use crate::entity::{Entity, EntityBase};
 use crate::error::PumpkinError;
 use crate::log_at_level;
 use crate::net::PlayerConfig;
@@ -24,18 +24,20 @@ use crate::plugin::block::block_place::BlockPlaceEvent;
 use crate::plugin::player::changed_main_hand::PlayerChangedMainHandEvent;
 use crate::plugin::player::fish::{PlayerFishEvent, PlayerFishState};
 use crate::plugin::player::item_held::PlayerItemHeldEvent;
-use crate::plugin::player::player_chat::PlayerChatEvent;
+use crate::plugin::player::player_chat::{DefaultChatRenderer, PlayerChatEvent};
 use crate::plugin::player::player_command_send::PlayerCommandSendEvent;
 use crate::plugin::player::player_interact_entity_event::PlayerInteractEntityEvent;
 use crate::plugin::player::player_interact_event::{InteractAction, PlayerInteractEvent};
 use crate::plugin::player::player_interact_unknown_entity_event::PlayerInteractUnknownEntityEvent;
 use crate::plugin::player::player_move::PlayerMoveEvent;
-use crate::server::{Server, seasonal_events};
+use crate::server::{Server, chat, seasonal_events, spawn_protection};
 use crate::world::{World, chunker};
 use pumpkin_data::block_properties::{
     BlockProperties, CommandBlockLikeProperties, WaterLikeProperties,
 };
-use pumpkin_data::data_component_impl::{ConsumableImpl, EquipmentSlot, EquippableImpl, FoodImpl};
+use pumpkin_data::data_component_impl::{
+    BlockEntityDataImpl, ConsumableImpl, EquipmentSlot, EquippableImpl, FoodImpl,
+};
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::sound::{Sound, SoundCategory};
@@ -47,17 +49,18 @@ use pumpkin_macros::send_cancellable;
 use pumpkin_protocol::codec::var_int::VarInt;
 use pumpkin_protocol::java::client::play::{
     CBlockUpdate, CCommandSuggestions, CEntityPositionSync, CHeadRot, COpenSignEditor,
-    CPingResponse, CPlayerInfoUpdate, CPlayerPosition, CSetSelectedSlot, CSystemChatMessage,
-    CUpdateEntityPos, CUpdateEntityPosRot, CUpdateEntityRot, InitChat, PlayerAction,
+    CPingResponse, CPlayerInfoUpdate, CPlayerPosition, CSetSelectedSlot, CUpdateEntityPos,
+    CUpdateEntityPosRot, CUpdateEntityRot, InitChat, PlayerAction,
 };
 use pumpkin_protocol::java::server::play::{
     Action, ActionType, CommandBlockMode, FLAG_ON_GROUND, SChangeGameMode, SChatCommand,
     SChatMessage, SChunkBatch, SClientCommand, SClientInformationPlay, SCloseContainer,
     SCommandSuggestion, SConfirmTeleport, SCookieResponse as SPCookieResponse, SInteract,
-    SKeepAlive, SMoveVehicle, SPaddleBoat, SPickItemFromBlock, SPlayPingRequest, SPlayerAbilities,
-    SPlayerAction, SPlayerCommand, SPlayerInput, SPlayerPosition, SPlayerPositionRotation,
-    SPlayerRotation, SPlayerSession, SSetCommandBlock, SSetCreativeSlot, SSetHeldItem,
-    SSetPlayerGround, SSwingArm, SUpdateSign, SUseItem, SUseItemOn, Status,
+    SKeepAlive, SMoveVehicle, SPaddleBoat, SPickItemFromBlock, SPickItemFromEntity,
+    SPlayPingRequest, SPlayerAbilities, SPlayerAction, SPlayerCommand, SPlayerInput,
+    SPlayerPosition, SPlayerPositionRotation, SPlayerRotation, SPlayerSession,
+    SRecipeBookSeenRecipe, SSetCommandBlock, SSetCreativeSlot, SSetHeldItem, SSetPlayerGround,
+    SSwingArm, SUpdateSign, SUseItem, SUseItemOn, Status,
 };
 use pumpkin_util::math::boundingbox::BoundingBox;
 use pumpkin_util::math::vector3::Vector3;
@@ -66,6 +69,7 @@ use pumpkin_util::text::color::NamedColor;
 use pumpkin_util::{GameMode, text::TextComponent};
 use pumpkin_world::block::entities::command_block::CommandBlockEntity;
 use pumpkin_world::block::entities::sign::SignBlockEntity;
+use pumpkin_world::block::entities::{OP_ONLY_ITEM_DATA, block_entity_from_nbt};
 use pumpkin_world::item::ItemStack;
 use pumpkin_world::world::BlockFlags;
 use tokio::sync::Mutex;
@@ -74,6 +78,12 @@ use tokio::sync::Mutex;
 /// Vanilla: 2 minutes
 const CHAT_MESSAGE_MAX_AGE: i64 = 1000 * 60 * 2;
 
+/// Every creative drop adds this to the player's drop counter, which decays by one per tick.
+/// Vanilla: 20
+const CREATIVE_DROP_SPAM_INCREMENT: i32 = 20;
+/// Drops are refused while the counter is above this. Vanilla: 1480
+const CREATIVE_DROP_SPAM_THRESHOLD: i32 = 1480;
+
 #[derive(Debug, Error)]
 pub enum BlockPlacingError {
     BlockOutOfReach,
@@ -650,8 +660,44 @@ impl JavaClient {
             return;
         }
 
-        let stack = ItemStack::new(1, Item::from_id(block.item_id).unwrap());
+        let mut stack = ItemStack::new(1, Item::from_id(block.item_id).unwrap());
+
+        // Ctrl + middle click copies the block entity data, which only creative players may do
+        if pick_item.include_data
+            && player.is_creative()
+            && let Some(block_entity) = world.get_block_entity(&pick_item.pos).await
+        {
+            let mut data = NbtCompound::new();
+            data.put_string("id", block_entity.resource_location().to_string());
+            block_entity.write_nbt(&mut data).await;
+            stack.set_data_component(BlockEntityDataImpl { data });
+        }
+
+        Self::pick_stack(player, stack).await;
+    }
 
+    pub async fn handle_pick_item_from_entity(
+        &self,
+        player: &Arc<Player>,
+        pick_item: SPickItemFromEntity,
+    ) {
+        let Some(entity) = player.world().get_entity_by_id(pick_item.id) else {
+            return;
+        };
+        let entity = entity.get_entity();
+        if !player.can_interact_with_entity(entity, 3.0) {
+            return;
+        }
+        let Some(item) = entity.get_pick_item() else {
+            return;
+        };
+
+        Self::pick_stack(player, ItemStack::new(1, item)).await;
+    }
+
+    /// Selects a picked stack: moves it into the hotbar if the player already has it, or gives
+    /// it to creative players.
+    async fn pick_stack(player: &Arc<Player>, stack: ItemStack) {
         let slot_with_stack = player.inventory().get_slot_with_stack(&stack).await;
 
         if slot_with_stack != -1 {
@@ -681,10 +727,6 @@ impl JavaClient {
             .await;
     }
 
-    // pub fn handle_pick_item_from_entity(&self, _pick_item: SPickItemFromEntity) {
-    //     // TODO: Implement and merge any redundant code with pick_item_from_block
-    // }
-
     pub async fn handle_set_command_block(
         &self,
         player: &Arc<Player>,
@@ -802,8 +844,21 @@ impl JavaClient {
             }
             Action::LeaveBed => player.wake_up().await,
 
-            Action::StartHorseJump | Action::StopHorseJump | Action::OpenVehicleInventory => {
-                debug!("todo");
+            Action::StartHorseJump => {
+                let vehicle = entity.vehicle.lock().await.clone();
+                if let Some(vehicle) = vehicle
+                    && command.jump_boost.0 > 0
+                {
+                    vehicle.start_riding_jump(command.jump_boost.0).await;
+                }
+            }
+            // The jump is performed by the client once it is released
+            Action::StopHorseJump => {}
+            Action::OpenVehicleInventory => {
+                let vehicle = entity.vehicle.lock().await.clone();
+                if let Some(vehicle) = vehicle {
+                    vehicle.open_inventory(player).await;
+                }
             }
             Action::StartFlyingElytra => {
                 let fall_flying = entity.check_fall_flying();
@@ -831,24 +886,27 @@ impl JavaClient {
         }
 
         if sneak {
-            let vehicle = player.get_entity().vehicle.lock().await.clone();
-            if let Some(vehicle) = vehicle {
-                vehicle
-                    .get_entity()
-                    .remove_passenger(player.entity_id())
-                    .await;
-            }
+            player.get_entity().stop_riding().await;
         }
     }
 
     pub async fn handle_move_vehicle(&self, player: &Arc<Player>, packet: SMoveVehicle) {
-        let entity = player.get_entity();
-        let vehicle = entity.vehicle.lock().await;
-        if let Some(vehicle) = vehicle.as_ref() {
-            let vehicle_entity = vehicle.get_entity();
-            vehicle_entity.set_pos(Vector3::new(packet.x, packet.y, packet.z));
-            vehicle_entity.set_rotation(packet.yaw, packet.pitch);
+        let vehicle = player.get_entity().vehicle.lock().await.clone();
+        let Some(vehicle) = vehicle else {
+            return;
+        };
+        // Only the player steering the vehicle moves it
+        let controlled = vehicle
+            .get_controlling_passenger()
+            .await
+            .is_some_and(|controller| controller.get_entity().entity_id == player.entity_id());
+        if !controlled {
+            return;
         }
+        let vehicle_entity = vehicle.get_entity();
+        vehicle_entity.set_pos(Vector3::new(packet.x, packet.y, packet.z));
+        vehicle_entity.set_rotation(packet.yaw, packet.pitch);
+        Entity::update_passenger_positions(vehicle.as_ref()).await;
     }
 
     pub async fn handle_paddle_boat(&self, player: &Arc<Player>, packet: SPaddleBoat) {
@@ -860,6 +918,20 @@ impl JavaClient {
         }
     }
 
+    pub async fn handle_recipe_book_seen_recipe(
+        &self,
+        player: &Player,
+        seen_recipe: SRecipeBookSeenRecipe,
+        server: &Server,
+    ) {
+        if let Some(recipe) = server
+            .recipe_manager
+            .get_by_display_id(seen_recipe.recipe.0)
+        {
+            player.recipe_book.mark_seen(recipe).await;
+        }
+    }
+
     pub async fn handle_swing_arm(&self, player: &Arc<Player>, swing_arm: SSwingArm) {
         player.update_last_action_time();
         let Ok(hand) = Hand::try_from(swing_arm.hand.0) else {
@@ -942,41 +1014,21 @@ impl JavaClient {
             return;
         }
 
+        let config = &server.advanced_config;
+        let message = seasonal_events::modify_chat_message(&chat_message.message, config)
+            .unwrap_or_else(|| chat_message.message.clone());
+        let recipients = player.world().players.load().to_vec();
+        let renderer = Arc::new(DefaultChatRenderer {
+            format: config.chat.format.clone(),
+        });
+
         send_cancellable! {{
             server;
-            PlayerChatEvent::new(player.clone(), chat_message.message.clone(), vec![]);
+            PlayerChatEvent::new(player.clone(), message, recipients, renderer);
 
             'after: {
-                info!("<chat> {}: {}", gameprofile.name, event.message);
-
-                let config = &server.advanced_config;
-
-                let message = match seasonal_events::modify_chat_message(&event.message, config) {
-                    Some(m) => m,
-                    None => event.message.clone(),
-                };
-
-                let decorated_message = TextComponent::chat_decorated(
-                    &config.chat.format,
-                    &gameprofile.name,
-                    &message,
-                );
-
-                let entity = &player.living_entity.entity;
-                let world = entity.world.load_full();
-                if server.basic_config.allow_chat_reports {
-                    world.broadcast_secure_player_chat(player, &chat_message, &decorated_message).await;
-                } else {
-                    let je_packet = CSystemChatMessage::new(
-                        &decorated_message,
-                        false,
-                    );
-                    let be_packet = SText::new(
-                        message, player.gameprofile.name.clone()
-                    );
-
-                    world.broadcast_editioned(&je_packet, &be_packet).await;
-                }
+                let signed = server.basic_config.allow_chat_reports.then_some(&chat_message);
+                chat::broadcast_player_chat(&event, signed).await;
             }
         }}
     }
@@ -1386,6 +1438,13 @@ impl JavaClient {
                     let position = player_action.position;
                     let entity = &player.living_entity.entity;
                     let world = entity.world.load_full();
+                    if !spawn_protection::may_modify(server, &world, player, &position, &Block::AIR)
+                        .await
+                    {
+                        self.sync_block_state_to_client(&world, position).await;
+                        self.update_sequence(player, player_action.sequence.0);
+                        return;
+                    }
                     let (block, state) = world.get_block_and_state(&position).await;
 
                     let inventory = player.inventory();
@@ -1497,6 +1556,14 @@ impl JavaClient {
                     player.mining.store(false, Ordering::Relaxed);
                     world.set_block_breaking(entity, location, -1).await;
 
+                    if !spawn_protection::may_modify(server, &world, player, &location, &Block::AIR)
+                        .await
+                    {
+                        self.sync_block_state_to_client(&world, location).await;
+                        self.update_sequence(player, player_action.sequence.0);
+                        return;
+                    }
+
                     let (block, state) = world.get_block_and_state(&location).await;
                     let block_drop = player.gamemode.load() != GameMode::Creative
                         && player.can_harvest(state, block).await;
@@ -1649,6 +1716,22 @@ impl JavaClient {
         let world = entity.world.load_full();
         let block = world.get_block(&position).await;
 
+        let block_to_build = Block::from_item_id(item.lock().await.item.id).unwrap_or(&Block::AIR);
+        if !spawn_protection::may_modify(server, &world, player, &position, block_to_build).await {
+            // Undo what the client predicted, both on the clicked block and where it would place
+            self.sync_block_state_to_client(&world, position).await;
+            self.sync_block_state_to_client(&world, position.offset(face.to_offset()))
+                .await;
+            let slot_index = if matches!(hand, Hand::Left) {
+                inventory.get_selected_slot() as usize
+            } else {
+                PlayerInventory::OFF_HAND_SLOT
+            };
+            let stack = item.lock().await.clone();
+            player.sync_hand_slot(slot_index, stack).await;
+            return Ok(());
+        }
+
         let sneaking = player.living_entity.entity.sneaking.load(Ordering::Relaxed);
 
         // Code based on the java class ServerPlayerInteractionManager
@@ -1701,7 +1784,7 @@ impl JavaClient {
         let item_id = stack.item.id;
         if let Some(block) = Block::from_item_id(item_id) {
             should_try_decrement = self
-                .run_is_block_place(player, block, server, use_item_on, position, face)
+                .run_is_block_place(player, &stack, block, server, use_item_on, position, face)
                 .await?;
         }
 
@@ -2041,9 +2124,23 @@ impl JavaClient {
             player_screen_handler.set_received_stack(packet.slot as usize, item_stack);
             player_screen_handler.send_content_updates().await;
             drop(player_screen_handler);
-        } else if is_negative && is_legal {
-            // Item drop
-            player.drop_item(item_stack).await;
+        } else if is_negative && is_legal && !item_stack.is_empty() {
+            // Item drop, throttled like vanilla so a client can't flood the world with items.
+            // An empty stack on a negative slot is the destroy slot and needs no handling
+            let spam = player
+                .creative_drop_spam
+                .fetch_add(CREATIVE_DROP_SPAM_INCREMENT, Ordering::Relaxed);
+            if spam < CREATIVE_DROP_SPAM_THRESHOLD {
+                player.drop_item(item_stack).await;
+            } else {
+                player
+                    .creative_drop_spam
+                    .fetch_sub(CREATIVE_DROP_SPAM_INCREMENT, Ordering::Relaxed);
+                warn!(
+                    "{} is dropping items from the creative inventory too quickly",
+                    player.gameprofile.name
+                );
+            }
         }
         Ok(())
     }
@@ -2159,10 +2256,11 @@ impl JavaClient {
         })
     }
 
-    #[expect(clippy::too_many_lines)]
+    #[expect(clippy::too_many_lines, clippy::too_many_arguments)]
     async fn run_is_block_place(
         &self,
         player: &Arc<Player>,
+        stack: &ItemStack,
         block: &'static Block,
         server: &Server,
         use_item_on: SUseItemOn,
@@ -2338,11 +2436,57 @@ impl JavaClient {
             .block_registry
             .player_placed(&world, block, new_state, &final_block_pos, face, player)
             .await;
+        Self::apply_block_entity_data(&world, player, stack, final_block_pos).await;
 
         // The block was placed successfully, so decrement their inventory
         Ok(true)
     }
 
+    /// Applies the `block_entity_data` component of a placed item to the new block entity.
+    /// The position always comes from the placed block, and data for block entities that can run
+    /// commands or spawn entities is only accepted from operators.
+    async fn apply_block_entity_data(
+        world: &Arc<World>,
+        player: &Player,
+        stack: &ItemStack,
+        pos: BlockPos,
+    ) {
+        let Some(item_data) = stack.get_data_component::<BlockEntityDataImpl>() else {
+            return;
+        };
+        let Some(block_entity) = world.get_block_entity(&pos).await else {
+            return;
+        };
+        let id = block_entity.resource_location();
+        if item_data.id() != id {
+            return;
+        }
+        if OP_ONLY_ITEM_DATA.contains(&id) && player.permission_lvl.load() < PermissionLvl::Two {
+            return;
+        }
+
+        let mut nbt = NbtCompound::new();
+        nbt.put_string("id", id.to_string());
+        nbt.put_int("x", pos.0.x);
+        nbt.put_int("y", pos.0.y);
+        nbt.put_int("z", pos.0.z);
+        for (name, tag) in &item_data.data.child_tags {
+            if !matches!(name.as_str(), "id" | "x" | "y" | "z") {
+                nbt.put(name, tag.clone());
+            }
+        }
+        // Anything the item doesn't set keeps the value of the freshly placed block entity
+        let mut current = NbtCompound::new();
+        block_entity.write_nbt(&mut current).await;
+        for (name, tag) in current.child_tags {
+            nbt.put(&name, tag);
+        }
+
+        if let Some(block_entity) = block_entity_from_nbt(&nbt) {
+            world.add_block_entity(block_entity).await;
+        }
+    }
+
     /// Checks if the block placed was a sign, then opens a dialog.
     pub async fn send_sign_packet(&self, block_position: BlockPos, is_front_text: bool) {
         self.enqueue_packet(&COpenSignEditor::new(block_position, is_front_text))
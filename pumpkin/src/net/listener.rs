@@ -0,0 +1,103 @@
+use std::io;
+use std::net::SocketAddr;
+
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::net::{TcpListener, TcpStream, lookup_host};
+use tracing::error;
+
+/// The number of pending connections the OS queues for a listener.
+const BACKLOG: i32 = 1024;
+
+/// Resolves the addresses the Java Edition server binds to.
+///
+/// Host names are looked up without blocking the runtime, and every address they resolve to is
+/// kept, so `localhost:25565` binds both `127.0.0.1` and `[::1]`.
+pub async fn resolve_addresses(primary: SocketAddr, additional: &[String]) -> Vec<SocketAddr> {
+    let mut addresses = vec![primary];
+    for host in additional {
+        match lookup_host(host.as_str()).await {
+            Ok(resolved) => {
+                for address in resolved {
+                    if !addresses.contains(&address) {
+                        addresses.push(address);
+                    }
+                }
+            }
+            Err(e) => error!("Failed to resolve listener address {host}: {e}"),
+        }
+    }
+    addresses
+}
+
+/// Whether the IPv6 listener for `address` must only accept IPv6.
+///
+/// That is the case when `addresses` also binds `0.0.0.0` on the same port, as a dual stack
+/// `[::]` would take that port for IPv4 as well and make the second bind fail.
+#[must_use]
+pub fn needs_only_v6(address: SocketAddr, addresses: &[SocketAddr]) -> bool {
+    address.is_ipv6()
+        && addresses.iter().any(|other| {
+            other.is_ipv4() && other.ip().is_unspecified() && other.port() == address.port()
+        })
+}
+
+/// Binds a TCP listener to `address`.
+///
+/// With `only_v6` an IPv6 listener only accepts IPv6, see [`needs_only_v6`]. Otherwise the
+/// system default applies, which on most systems also accepts IPv4 on `[::]`.
+pub fn bind(address: SocketAddr, only_v6: bool) -> io::Result<TcpListener> {
+    let socket = Socket::new(
+        Domain::for_address(address),
+        Type::STREAM,
+        Some(Protocol::TCP),
+    )?;
+    if only_v6 {
+        socket.set_only_v6(true)?;
+    }
+    // Same as `TcpListener::bind`, allows restarting while old connections linger in TIME_WAIT
+    #[cfg(not(windows))]
+    socket.set_reuse_address(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&address.into())?;
+    socket.listen(BACKLOG)?;
+    TcpListener::from_std(socket.into())
+}
+
+/// Accepts the next connection on any of the listeners. Never completes if there are none.
+pub async fn accept_any(listeners: &[TcpListener]) -> io::Result<(TcpStream, SocketAddr)> {
+    if listeners.is_empty() {
+        return std::future::pending().await;
+    }
+    let accepts = listeners.iter().map(|listener| Box::pin(listener.accept()));
+    futures::future::select_all(accepts).await.0
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::SocketAddr;
+
+    use super::needs_only_v6;
+
+    fn addr(address: &str) -> SocketAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn only_v6_next_to_ipv4_wildcard() {
+        let addresses = [addr("0.0.0.0:25565"), addr("[::]:25565")];
+        assert!(needs_only_v6(addresses[1], &addresses));
+        assert!(!needs_only_v6(addresses[0], &addresses));
+    }
+
+    #[test]
+    fn dual_stack_without_ipv4_wildcard() {
+        let addresses = [addr("[::]:25565")];
+        assert!(!needs_only_v6(addresses[0], &addresses));
+
+        let addresses = [addr("127.0.0.1:25565"), addr("[::]:25565")];
+        assert!(!needs_only_v6(addresses[1], &addresses));
+
+        let addresses = [addr("0.0.0.0:25566"), addr("[::]:25565")];
+        assert!(!needs_only_v6(addresses[1], &addresses));
+    }
+}
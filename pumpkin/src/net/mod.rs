@@ -24,7 +24,8 @@ pub mod authentication;
 pub mod bedrock;
 pub mod java;
 pub mod lan_broadcast;
-mod proxy;
+pub mod listener;
+pub mod proxy;
 pub mod query;
 pub mod rcon;
 
@@ -0,0 +1,233 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
+
+use pumpkin_config::networking::proxy::ProxyProtocolConfig;
+use thiserror::Error;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// The first bytes of every version 2 header.
+const V2_SIGNATURE: [u8; 12] = *b"\r\n\r\n\0\r\nQUIT\n";
+/// The longest possible version 1 header, including the trailing CRLF.
+const V1_MAX_LENGTH: usize = 107;
+/// How long a connection has to send its header.
+const HEADER_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Error, Debug)]
+pub enum ProxyProtocolError {
+    #[error("Connection from untrusted proxy {0}")]
+    UntrustedProxy(IpAddr),
+    #[error("Timed out waiting for the PROXY protocol header")]
+    Timeout,
+    #[error("Missing PROXY protocol header")]
+    MissingHeader,
+    #[error("Malformed PROXY protocol header")]
+    MalformedHeader,
+    #[error("Unsupported PROXY protocol version {0}")]
+    UnsupportedVersion(u8),
+    #[error("Failed to read PROXY protocol header: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Returns the address of the client behind `peer`, reading the PROXY protocol header the load
+/// balancer sends before anything else when the PROXY protocol is enabled.
+///
+/// Headers without a source address, like health checks of the load balancer itself, keep `peer`.
+pub async fn resolve_client_address<R: AsyncRead + Unpin>(
+    stream: &mut R,
+    peer: SocketAddr,
+    config: &ProxyProtocolConfig,
+) -> Result<SocketAddr, ProxyProtocolError> {
+    if !config.enabled {
+        return Ok(peer);
+    }
+    // An empty list trusts nobody, since a forged header would let anyone pick their address
+    if !config.trusted_proxies.contains(&peer.ip()) {
+        return Err(ProxyProtocolError::UntrustedProxy(peer.ip()));
+    }
+    let source = tokio::time::timeout(HEADER_TIMEOUT, read_header(stream))
+        .await
+        .map_err(|_| ProxyProtocolError::Timeout)??;
+    Ok(source.unwrap_or(peer))
+}
+
+/// Reads a version 1 or 2 header, returning the source address it carries.
+pub async fn read_header<R: AsyncRead + Unpin>(
+    stream: &mut R,
+) -> Result<Option<SocketAddr>, ProxyProtocolError> {
+    let mut start = [0; 12];
+    stream.read_exact(&mut start).await?;
+
+    if start == V2_SIGNATURE {
+        let mut header = [0; 4];
+        stream.read_exact(&mut header).await?;
+        let mut body = vec![0; u16::from_be_bytes([header[2], header[3]]) as usize];
+        stream.read_exact(&mut body).await?;
+        return parse_v2(header[0], header[1], &body);
+    }
+
+    if !start.starts_with(b"PROXY ") {
+        return Err(ProxyProtocolError::MissingHeader);
+    }
+    // The line has to be read byte by byte so nothing after the header is consumed
+    let mut line = start.to_vec();
+    while !line.ends_with(b"\r\n") {
+        if line.len() >= V1_MAX_LENGTH {
+            return Err(ProxyProtocolError::MalformedHeader);
+        }
+        line.push(stream.read_u8().await?);
+    }
+    let line = std::str::from_utf8(&line).map_err(|_| ProxyProtocolError::MalformedHeader)?;
+    parse_v1(line)
+}
+
+/// Parses a version 1 header line like `PROXY TCP4 192.0.2.1 192.0.2.2 56324 25565\r\n`.
+fn parse_v1(line: &str) -> Result<Option<SocketAddr>, ProxyProtocolError> {
+    let line = line
+        .strip_prefix("PROXY ")
+        .and_then(|line| line.strip_suffix("\r\n"))
+        .ok_or(ProxyProtocolError::MalformedHeader)?;
+    let mut parts = line.split(' ');
+
+    let is_v4 = match parts.next() {
+        Some("TCP4") => true,
+        Some("TCP6") => false,
+        // The rest of the line is to be ignored for unknown connections
+        Some("UNKNOWN") => return Ok(None),
+        _ => return Err(ProxyProtocolError::MalformedHeader),
+    };
+    let (Some(source), Some(_destination), Some(source_port), Some(_destination_port), None) = (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) else {
+        return Err(ProxyProtocolError::MalformedHeader);
+    };
+
+    let source: IpAddr = source
+        .parse()
+        .map_err(|_| ProxyProtocolError::MalformedHeader)?;
+    if source.is_ipv4() != is_v4 {
+        return Err(ProxyProtocolError::MalformedHeader);
+    }
+    let source_port = source_port
+        .parse()
+        .map_err(|_| ProxyProtocolError::MalformedHeader)?;
+    Ok(Some(SocketAddr::new(source, source_port)))
+}
+
+/// Parses the part of a version 2 header following the signature and the length.
+fn parse_v2(
+    version_command: u8,
+    family: u8,
+    body: &[u8],
+) -> Result<Option<SocketAddr>, ProxyProtocolError> {
+    let version = version_command >> 4;
+    if version != 2 {
+        return Err(ProxyProtocolError::UnsupportedVersion(version));
+    }
+    match version_command & 0x0F {
+        // LOCAL, the load balancer connected on its own behalf
+        0 => return Ok(None),
+        // PROXY
+        1 => {}
+        _ => return Err(ProxyProtocolError::MalformedHeader),
+    }
+
+    match family >> 4 {
+        // AF_INET
+        1 => {
+            let addresses = body.get(..12).ok_or(ProxyProtocolError::MalformedHeader)?;
+            let ip = Ipv4Addr::new(addresses[0], addresses[1], addresses[2], addresses[3]);
+            let port = u16::from_be_bytes([addresses[8], addresses[9]]);
+            Ok(Some(SocketAddr::new(IpAddr::V4(ip), port)))
+        }
+        // AF_INET6
+        2 => {
+            let addresses = body.get(..36).ok_or(ProxyProtocolError::MalformedHeader)?;
+            let ip: [u8; 16] = addresses[..16].try_into().unwrap();
+            let port = u16::from_be_bytes([addresses[32], addresses[33]]);
+            Ok(Some(SocketAddr::new(IpAddr::V6(Ipv6Addr::from(ip)), port)))
+        }
+        // AF_UNSPEC and AF_UNIX carry no address usable for bans
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_v1_headers() {
+        assert_eq!(
+            parse_v1("PROXY TCP4 192.0.2.1 192.0.2.2 56324 25565\r\n").unwrap(),
+            Some("192.0.2.1:56324".parse().unwrap())
+        );
+        assert_eq!(
+            parse_v1("PROXY TCP6 2001:db8::1 2001:db8::2 4000 25565\r\n").unwrap(),
+            Some("[2001:db8::1]:4000".parse().unwrap())
+        );
+        assert_eq!(parse_v1("PROXY UNKNOWN\r\n").unwrap(), None);
+        assert!(parse_v1("PROXY TCP4 2001:db8::1 192.0.2.2 4000 25565\r\n").is_err());
+        assert!(parse_v1("PROXY TCP4 192.0.2.1 192.0.2.2 56324\r\n").is_err());
+    }
+
+    #[tokio::test]
+    async fn reads_v2_header_without_consuming_the_payload() {
+        let mut data = V2_SIGNATURE.to_vec();
+        data.extend_from_slice(&[0x21, 0x11, 0, 12]);
+        data.extend_from_slice(&[198, 51, 100, 7, 10, 0, 0, 1]);
+        data.extend_from_slice(&40000u16.to_be_bytes());
+        data.extend_from_slice(&25565u16.to_be_bytes());
+        data.extend_from_slice(b"handshake");
+
+        let mut stream = data.as_slice();
+        let source = read_header(&mut stream).await.unwrap();
+        assert_eq!(source, Some("198.51.100.7:40000".parse().unwrap()));
+        assert_eq!(stream, b"handshake");
+    }
+
+    #[tokio::test]
+    async fn rejects_untrusted_proxies_and_missing_headers() {
+        let trusted: IpAddr = "10.0.0.1".parse().unwrap();
+        let config = ProxyProtocolConfig {
+            enabled: true,
+            trusted_proxies: vec![trusted],
+        };
+
+        let mut stream = b"PROXY TCP4 192.0.2.1 10.0.0.2 56324 25565\r\n".as_slice();
+        let peer = SocketAddr::new(trusted, 1234);
+        let address = resolve_client_address(&mut stream, peer, &config)
+            .await
+            .unwrap();
+        assert_eq!(address, "192.0.2.1:56324".parse().unwrap());
+
+        let mut stream = b"\x10\x00\xfb\x05\x09localhost".as_slice();
+        assert!(matches!(
+            resolve_client_address(&mut stream, peer, &config).await,
+            Err(ProxyProtocolError::MissingHeader)
+        ));
+
+        let untrusted = "192.0.2.9:1234".parse().unwrap();
+        assert!(matches!(
+            resolve_client_address(&mut stream, untrusted, &config).await,
+            Err(ProxyProtocolError::UntrustedProxy(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn empty_trusted_proxies_reject_everyone() {
+        let config = ProxyProtocolConfig {
+            enabled: true,
+            trusted_proxies: Vec::new(),
+        };
+        let mut stream = b"PROXY TCP4 192.0.2.1 10.0.0.2 56324 25565\r\n".as_slice();
+        let peer = "10.0.0.1:1234".parse().unwrap();
+        assert!(matches!(
+            resolve_client_address(&mut stream, peer, &config).await,
+            Err(ProxyProtocolError::UntrustedProxy(_))
+        ));
+    }
+}
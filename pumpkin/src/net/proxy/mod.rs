@@ -1,4 +1,5 @@
 pub mod bungeecord;
+pub mod haproxy;
 pub mod velocity;
 
 // TODO: Maybe make a trait for proxies
@@ -1,15 +1,18 @@
 use pumpkin_data::Block;
 use pumpkin_macros::{Event, cancellable};
+use pumpkin_util::math::position::BlockPos;
 use std::sync::Arc;
 
 use crate::entity::player::Player;
 
 use super::BlockEvent;
 
-/// An event that occurs when a player attempts to build on a block.
+/// An event that occurs when a player attempts to build on, break or use a block.
 ///
 /// This event contains information about the block to build, whether building is allowed,
-/// the player attempting to build, and the block being built upon.
+/// the player attempting to build, and the block being built upon. `buildable` starts out
+/// reflecting the server's own checks, such as spawn protection, and plugins may flip it either
+/// way. Cancelling the event denies the action.
 #[cancellable]
 #[derive(Event, Clone)]
 pub struct BlockCanBuildEvent {
@@ -24,6 +27,40 @@ pub struct BlockCanBuildEvent {
 
     /// The block being built upon.
     pub block: &'static Block,
+
+    /// The position of the block being built upon.
+    pub block_position: BlockPos,
+}
+
+impl BlockCanBuildEvent {
+    /// Creates a new instance of `BlockCanBuildEvent`.
+    ///
+    /// # Arguments
+    /// - `block_to_build`: The block that the player is attempting to build, air when breaking.
+    /// - `buildable`: Whether the server allows the action.
+    /// - `player`: The player attempting to build.
+    /// - `block`: The block being built upon.
+    /// - `block_position`: The position of the block being built upon.
+    ///
+    /// # Returns
+    /// A new instance of `BlockCanBuildEvent`.
+    #[must_use]
+    pub const fn new(
+        block_to_build: &'static Block,
+        buildable: bool,
+        player: Arc<Player>,
+        block: &'static Block,
+        block_position: BlockPos,
+    ) -> Self {
+        Self {
+            block_to_build,
+            buildable,
+            player,
+            block,
+            block_position,
+            cancelled: false,
+        }
+    }
 }
 
 impl BlockEvent for BlockCanBuildEvent {
@@ -0,0 +1,67 @@
+use pumpkin_data::damage::DamageType;
+use pumpkin_macros::{Event, cancellable};
+use pumpkin_util::text::TextComponent;
+use pumpkin_world::item::ItemStack;
+use std::sync::Arc;
+
+use crate::entity::EntityBase;
+
+use super::EntityEvent;
+
+/// An event that occurs when a living entity dies, after any totem of undying failed to save it.
+///
+/// Cancelling the event keeps the entity alive with half a heart.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct EntityDeathEvent {
+    /// The entity that is dying.
+    pub entity: Arc<dyn EntityBase>,
+
+    /// The damage type of the killing blow.
+    pub damage_type: DamageType,
+
+    /// The items the entity will drop.
+    pub drops: Vec<ItemStack>,
+
+    /// The amount of experience the entity will drop.
+    pub experience: i32,
+
+    /// The death message. It is only broadcast for players.
+    pub death_message: TextComponent,
+}
+
+impl EntityDeathEvent {
+    /// Creates a new instance of `EntityDeathEvent`.
+    ///
+    /// # Arguments
+    /// - `entity`: The entity that is dying.
+    /// - `damage_type`: The damage type of the killing blow.
+    /// - `drops`: The items the entity will drop.
+    /// - `experience`: The amount of experience the entity will drop.
+    /// - `death_message`: The death message.
+    ///
+    /// # Returns
+    /// A new instance of `EntityDeathEvent`.
+    pub const fn new(
+        entity: Arc<dyn EntityBase>,
+        damage_type: DamageType,
+        drops: Vec<ItemStack>,
+        experience: i32,
+        death_message: TextComponent,
+    ) -> Self {
+        Self {
+            entity,
+            damage_type,
+            drops,
+            experience,
+            death_message,
+            cancelled: false,
+        }
+    }
+}
+
+impl EntityEvent for EntityDeathEvent {
+    fn get_entity(&self) -> &Arc<dyn EntityBase> {
+        &self.entity
+    }
+}
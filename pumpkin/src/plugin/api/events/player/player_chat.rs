@@ -1,10 +1,42 @@
 use pumpkin_macros::{Event, cancellable};
+use pumpkin_util::text::TextComponent;
 use std::sync::Arc;
 
 use crate::entity::player::Player;
 
 use super::PlayerEvent;
 
+/// Renders a chat message for a single viewer.
+///
+/// Plugins can replace the renderer of a [`PlayerChatEvent`] to change how the message is
+/// displayed, e.g. to add rank prefixes, colors or channel tags. The renderer is called once per
+/// recipient, so the output may differ between viewers.
+pub trait ChatRenderer: Send + Sync {
+    /// Renders `message` sent by `source`. `viewer` is `None` when rendering the console echo.
+    fn render(
+        &self,
+        source: &Arc<Player>,
+        message: &str,
+        viewer: Option<&Arc<Player>>,
+    ) -> TextComponent;
+}
+
+/// The default renderer, which fills in the `chat.format` of the advanced config.
+pub struct DefaultChatRenderer {
+    pub format: String,
+}
+
+impl ChatRenderer for DefaultChatRenderer {
+    fn render(
+        &self,
+        source: &Arc<Player>,
+        message: &str,
+        _viewer: Option<&Arc<Player>>,
+    ) -> TextComponent {
+        TextComponent::chat_decorated(&self.format, &source.gameprofile.name, message)
+    }
+}
+
 /// An event that occurs when a player sends a chat message.
 ///
 /// This event contains information about the sender, message, and recipients.
@@ -15,10 +47,20 @@ pub struct PlayerChatEvent {
     pub player: Arc<Player>,
 
     /// The message being sent.
+    ///
+    /// When secure chat is enabled, the signature still covers the original message, so clients
+    /// may mark a changed message as modified by the server.
     pub message: String,
 
-    /// The recipients of the message. If empty, the message is broadcasted to all players.
+    /// The players that receive the message. Starts out as every player in the sender's world
+    /// and can be filtered, e.g. for ignore lists or ranged chat.
     pub recipients: Vec<Arc<Player>>,
+
+    /// Renders the message for each recipient and for the console.
+    pub renderer: Arc<dyn ChatRenderer>,
+
+    /// Whether the rendered message is logged to the console.
+    pub echo_to_console: bool,
 }
 
 impl PlayerChatEvent {
@@ -27,15 +69,23 @@ impl PlayerChatEvent {
     /// # Arguments
     /// - `player`: A reference to the player sending the message.
     /// - `message`: The message being sent.
-    /// - `recipients`: The recipients of the message. If empty, the message is broadcasted to all players.
+    /// - `recipients`: The players that receive the message.
+    /// - `renderer`: Renders the message for each recipient.
     ///
     /// # Returns
     /// A new instance of `PlayerChatEvent`.
-    pub const fn new(player: Arc<Player>, message: String, recipients: Vec<Arc<Player>>) -> Self {
+    pub fn new(
+        player: Arc<Player>,
+        message: String,
+        recipients: Vec<Arc<Player>>,
+        renderer: Arc<dyn ChatRenderer>,
+    ) -> Self {
         Self {
             player,
             message,
             recipients,
+            renderer,
+            echo_to_console: true,
             cancelled: false,
         }
     }
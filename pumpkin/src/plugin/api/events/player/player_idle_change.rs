@@ -0,0 +1,47 @@
+use pumpkin_macros::{Event, cancellable};
+use std::sync::Arc;
+
+use crate::entity::player::Player;
+
+use super::PlayerEvent;
+
+/// An event that occurs when a player becomes idle (AFK) or returns from being idle.
+///
+/// A player becomes idle once they have not moved, chatted, or interacted for the
+/// configured `player_afk_timeout`. Idle players are excluded from the sleeping
+/// percentage and do not count towards natural mob spawning.
+///
+/// If the event is cancelled, the player's idle state is left unchanged.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct PlayerIdleChangeEvent {
+    /// The player whose idle state is changing.
+    pub player: Arc<Player>,
+
+    /// Whether the player is becoming idle (`true`) or active again (`false`).
+    pub idle: bool,
+}
+
+impl PlayerIdleChangeEvent {
+    /// Creates a new instance of `PlayerIdleChangeEvent`.
+    ///
+    /// # Arguments
+    /// - `player`: A reference to the player whose idle state is changing.
+    /// - `idle`: Whether the player is becoming idle.
+    ///
+    /// # Returns
+    /// A new instance of `PlayerIdleChangeEvent`.
+    pub const fn new(player: Arc<Player>, idle: bool) -> Self {
+        Self {
+            player,
+            idle,
+            cancelled: false,
+        }
+    }
+}
+
+impl PlayerEvent for PlayerIdleChangeEvent {
+    fn get_player(&self) -> &Arc<Player> {
+        &self.player
+    }
+}
@@ -1,5 +1,6 @@
 use pumpkin_macros::{Event, cancellable};
 use pumpkin_util::text::TextComponent;
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use crate::entity::player::Player;
@@ -17,6 +18,9 @@ pub struct PlayerLoginEvent {
     /// The player who is joining the game.
     pub player: Arc<Player>,
 
+    /// The address the player connects from. Behind a proxy this is the forwarded address of the client.
+    pub address: SocketAddr,
+
     /// The kick message to display if the event is cancelled.
     pub kick_message: TextComponent,
 }
@@ -26,13 +30,19 @@ impl PlayerLoginEvent {
     ///
     /// # Arguments
     /// - `player`: A reference to the player joining the game.
+    /// - `address`: The address the player connects from.
     /// - `kick_message`: The message to display upon joining.
     ///
     /// # Returns
     /// A new instance of `PlayerLoginEvent`.
-    pub const fn new(player: Arc<Player>, kick_message: TextComponent) -> Self {
+    pub const fn new(
+        player: Arc<Player>,
+        address: SocketAddr,
+        kick_message: TextComponent,
+    ) -> Self {
         Self {
             player,
+            address,
             kick_message,
             cancelled: false,
         }
@@ -0,0 +1,366 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crossbeam::atomic::AtomicCell;
+use pumpkin_config::backup::BackupConfig;
+use thiserror::Error;
+use time::OffsetDateTime;
+use tokio::sync::Mutex;
+use tracing::{error, info, warn};
+
+use crate::server::Server;
+
+/// Held by the running server, copying it would leave a stale lock in the backup.
+const SESSION_LOCK_FILE_NAME: &str = "session.lock";
+/// Names the backup to restore on the next start.
+const RESTORE_FILE_NAME: &str = "restore";
+const NAME_FORMAT: &[time::format_description::FormatItem<'static>] =
+    time::macros::format_description!("[year]-[month]-[day]_[hour]-[minute]-[second]");
+
+#[derive(Debug, Error)]
+pub enum BackupError {
+    #[error("A backup is already in progress")]
+    InProgress,
+    #[error("There is no backup named {0}")]
+    NotFound(String),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// How many files a snapshot had to copy and how many it could link to the previous one.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotStats {
+    pub copied: usize,
+    pub linked: usize,
+}
+
+/// Creates and restores backups of the world folder.
+///
+/// Every backup is a complete copy of the world that can be started on its own. Files that did
+/// not change since the previous backup are hard linked to it, so a backup only takes up the
+/// space of what changed.
+pub struct BackupManager {
+    folder: PathBuf,
+    world_folder: PathBuf,
+    interval: Option<Duration>,
+    max_backups: usize,
+    hard_links: bool,
+    last_backup: AtomicCell<Instant>,
+    /// Locked while a backup is created.
+    running: Mutex<()>,
+}
+
+impl BackupManager {
+    pub fn new(config: &BackupConfig, world_folder: impl Into<PathBuf>) -> Self {
+        Self {
+            folder: PathBuf::from(&config.folder),
+            world_folder: world_folder.into(),
+            interval: config
+                .enabled
+                .then(|| Duration::from_secs(config.interval.max(1))),
+            max_backups: config.max_backups,
+            hard_links: config.hard_links,
+            last_backup: AtomicCell::new(Instant::now()),
+            running: Mutex::new(()),
+        }
+    }
+
+    /// Starts a scheduled backup once the configured interval passed.
+    pub fn tick(&self, server: &Arc<Server>) {
+        let Some(interval) = self.interval else {
+            return;
+        };
+        if self.last_backup.load().elapsed() < interval {
+            return;
+        }
+        self.last_backup.store(Instant::now());
+
+        let task_server = server.clone();
+        server.spawn_task(async move {
+            match task_server.backup_manager.create(&task_server).await {
+                Ok((name, _)) => info!("Created scheduled backup {name}"),
+                Err(BackupError::InProgress) => {}
+                Err(err) => error!("Failed to create scheduled backup: {err}"),
+            }
+        });
+    }
+
+    /// Saves everything that can be saved, flushing every world to disk like `save-all flush`, and
+    /// snapshots the world folder, pausing chunk and player data saving while the files are
+    /// copied so the backup is consistent.
+    pub async fn create(&self, server: &Server) -> Result<(String, SnapshotStats), BackupError> {
+        let _running = self
+            .running
+            .try_lock()
+            .map_err(|_| BackupError::InProgress)?;
+        self.last_backup.store(Instant::now());
+
+        server.save_level_info();
+        if let Err(err) = server.player_data_storage.save_all_players(server).await {
+            warn!("Failed to save player data before the backup: {err}");
+        }
+
+        let worlds = server.worlds.load();
+        for world in worlds.iter() {
+            world.flush().await;
+        }
+        let mut level_guards = Vec::with_capacity(worlds.len());
+        for world in worlds.iter() {
+            level_guards.push(world.level.pause_saving().await);
+        }
+        let player_guard = server.player_data_storage.pause_saving().await;
+
+        let folder = self.folder.clone();
+        let world_folder = self.world_folder.clone();
+        let hard_links = self.hard_links;
+        let result = tokio::task::spawn_blocking(move || {
+            let previous = list_backups(&folder)?.pop().map(|name| folder.join(name));
+            let name = unique_name(&folder, OffsetDateTime::now_utc());
+            let stats = snapshot(
+                &world_folder,
+                &folder.join(&name),
+                previous.as_deref(),
+                hard_links,
+            )?;
+            Ok::<_, io::Error>((name, stats))
+        })
+        .await
+        .map_err(io::Error::other)?;
+
+        drop(player_guard);
+        drop(level_guards);
+
+        let (name, stats) = result?;
+        if let Err(err) = prune(&self.folder, self.max_backups) {
+            warn!("Failed to delete old backups: {err}");
+        }
+        Ok((name, stats))
+    }
+
+    /// The names of all backups, oldest first.
+    pub async fn list(&self) -> Result<Vec<String>, BackupError> {
+        let folder = self.folder.clone();
+        Ok(tokio::task::spawn_blocking(move || list_backups(&folder))
+            .await
+            .map_err(io::Error::other)??)
+    }
+
+    /// Replaces the world with the backup `name` on the next start, as the world can't be swapped
+    /// out while it is loaded.
+    pub async fn schedule_restore(&self, name: &str) -> Result<(), BackupError> {
+        if !self.list().await?.iter().any(|backup| backup == name) {
+            return Err(BackupError::NotFound(name.to_string()));
+        }
+        tokio::fs::write(self.folder.join(RESTORE_FILE_NAME), name).await?;
+        Ok(())
+    }
+
+    /// Restores the backup scheduled by [`Self::schedule_restore`]. Must be called before the world
+    /// is loaded. The replaced world is kept next to it, suffixed with `_before_restore`.
+    pub fn apply_pending_restore(&self) {
+        let restore_file = self.folder.join(RESTORE_FILE_NAME);
+        let Ok(name) = fs::read_to_string(&restore_file) else {
+            return;
+        };
+        let name = name.trim();
+        if let Err(err) = fs::remove_file(&restore_file) {
+            error!("Failed to remove {}: {err}", restore_file.display());
+            return;
+        }
+        match restore(&self.folder.join(name), &self.world_folder) {
+            Ok(()) => info!("Restored backup {name}"),
+            Err(err) => error!("Failed to restore backup {name}: {err}"),
+        }
+    }
+}
+
+/// A folder name that sorts by creation time.
+fn unique_name(folder: &Path, now: OffsetDateTime) -> String {
+    let base = now
+        .format(NAME_FORMAT)
+        .unwrap_or_else(|_| now.unix_timestamp().to_string());
+    let mut name = base.clone();
+    let mut index = 1;
+    while folder.join(&name).exists() {
+        index += 1;
+        name = format!("{base}_{index}");
+    }
+    name
+}
+
+fn list_backups(folder: &Path) -> io::Result<Vec<String>> {
+    if !folder.exists() {
+        return Ok(Vec::new());
+    }
+    let mut names = Vec::new();
+    for entry in fs::read_dir(folder)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir()
+            && let Ok(name) = entry.file_name().into_string()
+        {
+            names.push(name);
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Deletes the oldest backups until at most `max_backups` are left. `0` keeps all of them.
+fn prune(folder: &Path, max_backups: usize) -> io::Result<()> {
+    if max_backups == 0 {
+        return Ok(());
+    }
+    let names = list_backups(folder)?;
+    for name in names.iter().take(names.len().saturating_sub(max_backups)) {
+        fs::remove_dir_all(folder.join(name))?;
+    }
+    Ok(())
+}
+
+/// Copies `source` into `target`. Files with the same size and modification time as in the
+/// `previous` snapshot are hard linked to it instead when `hard_links` is set.
+fn snapshot(
+    source: &Path,
+    target: &Path,
+    previous: Option<&Path>,
+    hard_links: bool,
+) -> io::Result<SnapshotStats> {
+    let mut stats = SnapshotStats::default();
+    fs::create_dir_all(target)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        if file_name == SESSION_LOCK_FILE_NAME {
+            continue;
+        }
+        let source_path = entry.path();
+        let target_path = target.join(&file_name);
+        let previous_path = previous.map(|previous| previous.join(&file_name));
+        let metadata = entry.metadata()?;
+
+        if metadata.is_dir() {
+            let nested = snapshot(
+                &source_path,
+                &target_path,
+                previous_path.as_deref(),
+                hard_links,
+            )?;
+            stats.copied += nested.copied;
+            stats.linked += nested.linked;
+            continue;
+        }
+
+        let modified = metadata.modified()?;
+        if hard_links
+            && let Some(previous_path) = &previous_path
+            && is_unchanged(previous_path, metadata.len(), modified)
+            && fs::hard_link(previous_path, &target_path).is_ok()
+        {
+            stats.linked += 1;
+            continue;
+        }
+        fs::copy(&source_path, &target_path)?;
+        // Keeps the modification time so the next snapshot can tell the file didn't change
+        fs::File::options()
+            .write(true)
+            .open(&target_path)?
+            .set_modified(modified)?;
+        stats.copied += 1;
+    }
+    Ok(stats)
+}
+
+fn is_unchanged(previous: &Path, len: u64, modified: SystemTime) -> bool {
+    fs::metadata(previous)
+        .is_ok_and(|metadata| metadata.len() == len && metadata.modified().ok() == Some(modified))
+}
+
+fn restore(backup: &Path, world_folder: &Path) -> io::Result<()> {
+    if !backup.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} does not exist", backup.display()),
+        ));
+    }
+    if world_folder.exists() {
+        let mut replaced = world_folder.as_os_str().to_owned();
+        replaced.push("_before_restore");
+        let replaced = PathBuf::from(replaced);
+        if replaced.exists() {
+            fs::remove_dir_all(&replaced)?;
+        }
+        fs::rename(world_folder, &replaced)?;
+    }
+    snapshot(backup, world_folder, None, false)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshots_link_unchanged_files_and_prune_old_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        let world = dir.path().join("world");
+        let backups = dir.path().join("backups");
+        fs::create_dir_all(world.join("region")).unwrap();
+        fs::write(world.join("level.dat"), b"level").unwrap();
+        fs::write(world.join("region/r.0.0.mca"), b"region").unwrap();
+        fs::write(world.join(SESSION_LOCK_FILE_NAME), b"lock").unwrap();
+
+        let first = snapshot(&world, &backups.join("1"), None, true).unwrap();
+        assert_eq!(
+            first,
+            SnapshotStats {
+                copied: 2,
+                linked: 0
+            }
+        );
+        assert!(!backups.join("1").join(SESSION_LOCK_FILE_NAME).exists());
+
+        fs::write(world.join("level.dat"), b"changed level").unwrap();
+        let second = snapshot(&world, &backups.join("2"), Some(&backups.join("1")), true).unwrap();
+        assert_eq!(
+            second,
+            SnapshotStats {
+                copied: 1,
+                linked: 1
+            }
+        );
+        assert_eq!(
+            fs::read(backups.join("2/level.dat")).unwrap(),
+            b"changed level"
+        );
+        assert_eq!(fs::read(backups.join("1/level.dat")).unwrap(), b"level");
+
+        prune(&backups, 1).unwrap();
+        assert_eq!(list_backups(&backups).unwrap(), ["2"]);
+        assert_eq!(
+            fs::read(backups.join("2/region/r.0.0.mca")).unwrap(),
+            b"region"
+        );
+    }
+
+    #[test]
+    fn restore_keeps_the_replaced_world() {
+        let dir = tempfile::tempdir().unwrap();
+        let world = dir.path().join("world");
+        let backup = dir.path().join("backup");
+        fs::create_dir_all(&world).unwrap();
+        fs::create_dir_all(&backup).unwrap();
+        fs::write(world.join("level.dat"), b"current").unwrap();
+        fs::write(backup.join("level.dat"), b"backed up").unwrap();
+
+        restore(&backup, &world).unwrap();
+        assert_eq!(fs::read(world.join("level.dat")).unwrap(), b"backed up");
+        assert_eq!(
+            fs::read(dir.path().join("world_before_restore/level.dat")).unwrap(),
+            b"current"
+        );
+    }
+}
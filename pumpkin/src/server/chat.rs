@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use pumpkin_data::world::RAW;
+use pumpkin_protocol::codec::var_int::VarInt;
+use pumpkin_protocol::java::client::play::{CPlayerChatMessage, FilterType};
+use pumpkin_protocol::java::server::play::SChatMessage;
+use pumpkin_util::text::TextComponent;
+use tracing::info;
+
+use crate::entity::player::Player;
+use crate::net::ClientPlatform;
+use crate::plugin::player::player_chat::PlayerChatEvent;
+
+/// Delivers a chat message to the recipients of `event`, rendering it for each of them.
+///
+/// `signed` is the original packet when secure chat is enabled. Java recipients then receive the
+/// signed message with the rendered text as its unsigned content, so the signature chain stays
+/// intact whatever the renderer produces. Everything else is sent as a system message.
+pub async fn broadcast_player_chat(event: &PlayerChatEvent, signed: Option<&SChatMessage>) {
+    let sender = &event.player;
+    if event.echo_to_console {
+        let rendered = event.renderer.render(sender, &event.message, None);
+        info!("<chat> {}", rendered.to_pretty_console());
+    }
+
+    match signed {
+        Some(chat_message) => {
+            broadcast_secure_player_chat(sender, chat_message, &event.recipients, |viewer| {
+                event.renderer.render(sender, &event.message, Some(viewer))
+            })
+            .await;
+        }
+        None => {
+            for recipient in &event.recipients {
+                let rendered = event
+                    .renderer
+                    .render(sender, &event.message, Some(recipient));
+                recipient.send_system_message(&rendered).await;
+            }
+        }
+    }
+}
+
+async fn broadcast_secure_player_chat(
+    sender: &Arc<Player>,
+    chat_message: &SChatMessage,
+    recipients: &[Arc<Player>],
+    render: impl Fn(&Arc<Player>) -> TextComponent,
+) {
+    let messages_sent: i32 = sender.chat_session.lock().await.messages_sent;
+    let sender_last_seen = {
+        let cache = sender.signature_cache.lock().await;
+        cache.last_seen.clone()
+    };
+
+    for recipient in recipients {
+        let rendered = render(recipient);
+        let ClientPlatform::Java(client) = &recipient.client else {
+            recipient.send_system_message(&rendered).await;
+            continue;
+        };
+        let messages_received: i32 = recipient.chat_session.lock().await.messages_received;
+        let packet = &CPlayerChatMessage::new(
+            VarInt(messages_received),
+            sender.gameprofile.id,
+            VarInt(messages_sent),
+            chat_message.signature.clone(),
+            chat_message.message.clone(),
+            chat_message.timestamp,
+            chat_message.salt,
+            sender_last_seen.indexed_for(recipient).await,
+            Some(rendered),
+            FilterType::PassThrough,
+            (RAW + 1).into(),        // Custom registry chat_type with no sender name
+            TextComponent::text(""), // Not needed since we're injecting the name in the message for custom formatting
+            None,
+        );
+        client.enqueue_packet(packet).await;
+
+        recipient
+            .signature_cache
+            .lock()
+            .await
+            .add_seen_signature(&chat_message.signature.clone().unwrap()); // Unwrap is safe because we check for None in validate_chat_message
+
+        if recipient.gameprofile.id != sender.gameprofile.id {
+            // Sender may update recipient on signatures recipient hasn't seen
+            recipient
+                .signature_cache
+                .lock()
+                .await
+                .cache_signatures(sender_last_seen.as_ref());
+        }
+        recipient.chat_session.lock().await.messages_received += 1;
+    }
+
+    sender.chat_session.lock().await.messages_sent += 1;
+}
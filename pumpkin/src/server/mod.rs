@@ -4,12 +4,16 @@ use crate::command::commands::defaultgamemode::DefaultGamemode;
 use crate::data::VanillaData;
 use crate::data::player_server::ServerPlayerData;
 use crate::entity::{EntityBase, NBTStorage};
+use crate::item::dispense::DispenseRegistry;
 use crate::item::registry::ItemRegistry;
 use crate::net::authentication::fetch_mojang_public_keys;
 use crate::net::{ClientPlatform, DisconnectReason, EncryptionError, GameProfile, PlayerConfig};
 use crate::plugin::PluginManager;
 use crate::plugin::player::player_login::PlayerLoginEvent;
 use crate::plugin::server::server_broadcast::ServerBroadcastEvent;
+use crate::server::backup::BackupManager;
+use crate::server::recipe_manager::RecipeManager;
+use crate::server::schematic::SchematicManager;
 use crate::server::tick_rate_manager::ServerTickRateManager;
 use crate::world::custom_bossbar::CustomBossbars;
 use crate::{command::dispatcher::CommandDispatcher, entity::player::Player, world::World};
@@ -49,9 +53,15 @@ use tokio::sync::{Mutex, OnceCell, RwLock};
 use tokio::task::{JoinHandle, JoinSet};
 use tokio_util::task::TaskTracker;
 
+pub mod backup;
+pub mod chat;
 mod connection_cache;
 mod key_store;
+pub mod pregenerate;
+pub mod recipe_manager;
+pub mod schematic;
 pub mod seasonal_events;
+pub mod spawn_protection;
 pub mod tick_rate_manager;
 pub mod ticker;
 
@@ -86,6 +96,10 @@ pub struct Server {
     pub block_registry: Arc<BlockRegistry>,
     /// Item behaviour.
     pub item_registry: Arc<ItemRegistry>,
+    /// How items are dispensed from dispensers.
+    pub dispense_registry: Arc<DispenseRegistry>,
+    /// The recipes players can unlock in their recipe book.
+    pub recipe_manager: Arc<RecipeManager>,
     /// Manages multiple worlds within the server.
     pub worlds: ArcSwap<Vec<Arc<World>>>,
     /// All the dimensions that exist on the server.
@@ -115,6 +129,10 @@ pub struct Server {
     pub server_guid: u64,
     /// Player idle timeout in minutes (0 = disabled)
     pub player_idle_timeout: AtomicI32,
+    /// Stores schematics and the schematic clipboards of command senders
+    pub schematic_manager: SchematicManager,
+    /// Creates and restores world backups
+    pub backup_manager: BackupManager,
     tasks: TaskTracker,
 
     // world stuff which maybe should be put into a struct
@@ -139,6 +157,9 @@ impl Server {
             RwLock::new(default_dispatcher(&permission_registry, &basic_config).await);
         let world_path = basic_config.get_world_path();
 
+        let backup_manager = BackupManager::new(&advanced_config.backup, &world_path);
+        backup_manager.apply_pending_restore();
+
         let block_registry = super::block::registry::default_registry();
 
         let level_info = AnvilLevelInfo.read_world_info(&world_path);
@@ -191,6 +212,8 @@ impl Server {
             advanced_config.player_data.save_player_data,
         );
         let white_list = AtomicBool::new(basic_config.white_list);
+        let player_idle_timeout =
+            AtomicI32::new(i32::try_from(basic_config.player_idle_timeout).unwrap_or(i32::MAX));
 
         let tick_rate_manager = Arc::new(ServerTickRateManager::new(basic_config.tps));
 
@@ -228,6 +251,8 @@ impl Server {
             command_dispatcher,
             block_registry: block_registry.clone(),
             item_registry: super::item::items::default_registry(),
+            dispense_registry: super::item::dispense::default_registry(),
+            recipe_manager: Arc::new(RecipeManager::default()),
             key_store: OnceCell::new(),
             listing,
             branding: CachedBranding::new(),
@@ -241,7 +266,9 @@ impl Server {
             tick_count: AtomicI32::new(0),
             tasks: TaskTracker::new(),
             server_guid: rand::random(),
-            player_idle_timeout: AtomicI32::new(0),
+            player_idle_timeout,
+            schematic_manager: SchematicManager::new("schematics"),
+            backup_manager,
             mojang_public_keys: ArcSwap::from_pointee(Vec::new()),
             world_info_writer: Arc::new(AnvilLevelInfo),
             level_info,
@@ -414,10 +441,11 @@ impl Server {
 
         // Wrap in Arc after data is loaded
         let player = Arc::new(player);
+        let address = player.client.address().await;
 
         send_cancellable! {{
             self;
-            PlayerLoginEvent::new(player.clone(), TextComponent::text("You have been kicked from the server"));
+            PlayerLoginEvent::new(player.clone(), address, TextComponent::text("You have been kicked from the server"));
             'after: {
                 player.screen_handler_sync_handler.store_player(player.clone()).await;
                 #[expect(clippy::if_then_some_else_none)]
@@ -460,16 +488,20 @@ impl Server {
         for world in self.worlds.load().iter() {
             world.shutdown().await;
         }
-        let level_data = self.level_info.load();
         // then lets save the world info
+        self.save_level_info();
+        info!("Completed worlds");
+    }
 
+    /// Writes the current level info to `level.dat`.
+    pub fn save_level_info(&self) {
+        let level_data = self.level_info.load();
         if let Err(err) = self
             .world_info_writer
             .write_world_info(&level_data, &self.basic_config.get_world_path())
         {
             error!("Failed to save level.dat: {err}");
         }
-        info!("Completed worlds");
     }
 
     /// Broadcasts a packet to all players in all worlds.
@@ -545,11 +577,12 @@ impl Server {
         self.level_info.store(Arc::new(new_info));
 
         for world in self.worlds.load().iter() {
-            world.set_difficulty(difficulty);
+            world.set_difficulty(new_difficulty);
         }
 
-        self.broadcast_packet_all(&CChangeDifficulty::new(difficulty as u8, locked))
+        self.broadcast_packet_all(&CChangeDifficulty::new(new_difficulty as u8, locked))
             .await;
+        self.save_level_info();
     }
 
     /// Searches for a player by their username across all worlds.
@@ -704,32 +737,14 @@ impl Server {
             .get_digest(secret)
     }
 
-    /// Main server tick method. This now handles both player/network ticking (which always runs)
-    /// and world/game logic ticking (which is affected by freeze state).
+    /// Main server tick method. Worlds are always ticked, they skip the game logic that is paused
+    /// while the game is frozen themselves, so players can keep moving.
     pub async fn tick(self: &Arc<Self>) {
-        if self.tick_rate_manager.runs_normally() || self.tick_rate_manager.is_sprinting() {
-            self.tick_worlds().await;
-            // Always run player and network ticking, even when game is frozen
-        } else {
-            self.tick_players_and_network().await;
-        }
+        self.tick_worlds().await;
+        self.backup_manager.tick(self);
     }
 
-    /// Ticks essential server functions that must run even when the game is frozen.
-    /// This includes player ticking (network, keep-alives) and flushing world updates to clients.
-    pub async fn tick_players_and_network(&self) {
-        // First, flush pending block updates and synced block events to clients
-        for world in self.worlds.load().iter() {
-            world.flush_block_updates().await;
-            world.flush_synced_block_events().await;
-        }
-
-        let players_to_tick: Vec<_> = self.get_all_players();
-        for player in players_to_tick {
-            player.tick(self).await;
-        }
-    }
-    /// Ticks the game logic for all worlds. This is the part that is affected by `/tick freeze`.
+    /// Ticks all worlds and the global tasks.
     pub async fn tick_worlds(self: &Arc<Self>) {
         let mut set = JoinSet::new();
 
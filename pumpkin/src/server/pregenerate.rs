@@ -0,0 +1,86 @@
+use crate::{SHOULD_STOP, server::Server};
+use pumpkin_data::dimension::Dimension;
+use pumpkin_util::math::vector2::Vector2;
+use std::{
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+
+/// Chunks requested from the chunk system at once, enough to keep every worker thread busy.
+const BATCH_SIZE: usize = 1024;
+const REPORT_INTERVAL: Duration = Duration::from_secs(5);
+
+pub struct ChunkPregenerator;
+
+impl ChunkPregenerator {
+    /// Generates all chunks within `radius` chunks around the world spawn and reports the
+    /// throughput. The chunks end up in the region files once the server shuts down.
+    pub async fn run(server: &Server, radius: u32) {
+        let Some(world) = server
+            .worlds
+            .load()
+            .iter()
+            .find(|world| world.dimension == Dimension::OVERWORLD)
+            .cloned()
+        else {
+            warn!("There is no overworld to generate");
+            return;
+        };
+
+        let radius = radius.min(i32::MAX as u32 / 2) as i32;
+        let side = 2 * radius as usize + 1;
+        let total = side * side;
+        let level_info = server.level_info.load();
+        let center = Vector2::new(level_info.spawn_x >> 4, level_info.spawn_z >> 4);
+        info!(
+            "Generating {total} chunks around chunk {}, {}",
+            center.x, center.y
+        );
+
+        let start = Instant::now();
+        let mut last_report = start;
+        let generated = world
+            .level
+            .generate_area(center, radius, BATCH_SIZE, |finished| {
+                if last_report.elapsed() >= REPORT_INTERVAL {
+                    last_report = Instant::now();
+                    info!(
+                        "{finished}/{total} chunks ({:.1}%), {:.1} chunks/s",
+                        finished as f64 * 100.0 / total as f64,
+                        chunks_per_second(finished, start.elapsed())
+                    );
+                }
+                !SHOULD_STOP.load(Ordering::Relaxed)
+            })
+            .await;
+
+        info!(
+            "Generated {generated} chunks in {:.1}s ({:.1} chunks/s)",
+            start.elapsed().as_secs_f64(),
+            chunks_per_second(generated, start.elapsed())
+        );
+    }
+}
+
+fn chunks_per_second(chunks: usize, elapsed: Duration) -> f64 {
+    let seconds = elapsed.as_secs_f64();
+    if seconds > 0.0 {
+        chunks as f64 / seconds
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::chunks_per_second;
+
+    #[test]
+    fn throughput() {
+        assert!((chunks_per_second(500, Duration::from_secs(2)) - 250.0).abs() < f64::EPSILON);
+        assert!(chunks_per_second(500, Duration::ZERO).abs() < f64::EPSILON);
+    }
+}
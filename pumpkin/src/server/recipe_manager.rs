@@ -0,0 +1,565 @@
+use pumpkin_data::item::Item;
+use pumpkin_data::recipes::{
+    CookingRecipe, CookingRecipeType, CraftingRecipeTypes, RECIPES_COOKING, RECIPES_CRAFTING,
+    RecipeCategoryTypes, RecipeIngredientTypes, RecipeResultStruct,
+};
+use pumpkin_data::tag::{RegistryKey, get_tag_ids};
+use pumpkin_protocol::codec::var_int::VarInt;
+use pumpkin_protocol::java::client::play::{
+    Ingredient, RecipeBookCategory, RecipeDisplay, RecipeDisplayEntry, SlotDisplay,
+};
+use pumpkin_world::item::ItemStack;
+use rustc_hash::FxHashMap;
+
+pub enum Recipe {
+    Crafting(&'static CraftingRecipeTypes),
+    Cooking(&'static CookingRecipeType),
+}
+
+/// A recipe that can appear in the recipe book.
+pub struct RecipeHolder {
+    /// The vanilla style id, e.g. `minecraft:oak_door`, used by `/recipe`. It is generated from
+    /// the result and does not always match vanilla's, so it is never saved.
+    pub id: String,
+    /// The id the client knows this recipe by.
+    pub display_id: i32,
+    pub recipe: Recipe,
+    group: Option<i32>,
+}
+
+impl RecipeHolder {
+    /// Whether unlocking the recipe shows a toast.
+    #[must_use]
+    pub const fn show_notification(&self) -> bool {
+        match self.recipe {
+            Recipe::Crafting(CraftingRecipeTypes::CraftingShaped {
+                show_notification, ..
+            }) => *show_notification,
+            _ => true,
+        }
+    }
+
+    /// The ingredient whose `has_<material>` criterion unlocks the recipe: the one filling the
+    /// most slots, the earliest one on ties. This is the material vanilla's generated recipe
+    /// advancements check for, e.g. planks for doors and fences or the ore for its smelting.
+    fn unlock_ingredient(&self) -> Option<&'static RecipeIngredientTypes> {
+        match self.recipe {
+            Recipe::Crafting(CraftingRecipeTypes::CraftingShaped { key, pattern, .. }) => key
+                .iter()
+                .rev()
+                .max_by_key(|(symbol, _)| {
+                    pattern
+                        .iter()
+                        .map(|row| row.chars().filter(|c| c == symbol).count())
+                        .sum::<usize>()
+                })
+                .map(|(_, ingredient)| ingredient),
+            Recipe::Crafting(CraftingRecipeTypes::CraftingShapeless { ingredients, .. }) => {
+                ingredients.iter().rev().max_by_key(|ingredient| {
+                    let items = ingredient_items(ingredient);
+                    ingredients
+                        .iter()
+                        .filter(|other| ingredient_items(other) == items)
+                        .count()
+                })
+            }
+            Recipe::Crafting(CraftingRecipeTypes::CraftingTransmute { input, .. }) => Some(input),
+            Recipe::Crafting(_) => None,
+            Recipe::Cooking(recipe) => Some(&cooking_recipe(recipe).ingredient),
+        }
+    }
+
+    fn ingredients(&self) -> Vec<&'static RecipeIngredientTypes> {
+        match self.recipe {
+            Recipe::Crafting(CraftingRecipeTypes::CraftingShaped { key, .. }) => {
+                key.iter().map(|(_, ingredient)| ingredient).collect()
+            }
+            Recipe::Crafting(CraftingRecipeTypes::CraftingShapeless { ingredients, .. }) => {
+                ingredients.iter().collect()
+            }
+            Recipe::Crafting(CraftingRecipeTypes::CraftingTransmute {
+                input, material, ..
+            }) => vec![input, material],
+            Recipe::Crafting(_) => Vec::new(),
+            Recipe::Cooking(recipe) => vec![&cooking_recipe(recipe).ingredient],
+        }
+    }
+
+    /// How the recipe is shown in the client's recipe book.
+    #[must_use]
+    pub fn display_entry(&self) -> RecipeDisplayEntry {
+        let (display, category) = match self.recipe {
+            Recipe::Crafting(recipe) => crafting_display(recipe),
+            Recipe::Cooking(recipe) => cooking_display(recipe),
+        };
+        RecipeDisplayEntry {
+            id: VarInt(self.display_id),
+            display,
+            group: self.group.map(VarInt),
+            category,
+            crafting_requirements: Some(
+                self.ingredients()
+                    .into_iter()
+                    .map(ingredient_requirement)
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Every recipe book recipe, and which items unlock which recipes.
+///
+/// Like vanilla's recipe advancements, a recipe is unlocked once its main material enters the
+/// player's inventory, see [`RecipeHolder::unlock_ingredient`].
+pub struct RecipeManager {
+    recipes: Vec<RecipeHolder>,
+    by_id: FxHashMap<String, usize>,
+    by_ingredient: FxHashMap<u16, Vec<usize>>,
+}
+
+impl Default for RecipeManager {
+    fn default() -> Self {
+        let mut manager = Self {
+            recipes: Vec::new(),
+            by_id: FxHashMap::default(),
+            by_ingredient: FxHashMap::default(),
+        };
+        let mut groups = FxHashMap::default();
+
+        for recipe in RECIPES_CRAFTING {
+            let (group, result, first_ingredient) = match recipe {
+                CraftingRecipeTypes::CraftingShaped {
+                    group, key, result, ..
+                } => (
+                    *group,
+                    result,
+                    key.first().map(|(_, ingredient)| ingredient),
+                ),
+                CraftingRecipeTypes::CraftingShapeless {
+                    group,
+                    ingredients,
+                    result,
+                    ..
+                } => (*group, result, ingredients.first()),
+                CraftingRecipeTypes::CraftingTransmute {
+                    group,
+                    input,
+                    result,
+                    ..
+                } => (*group, result, Some(input)),
+                // Special recipes never show up in the recipe book
+                CraftingRecipeTypes::CraftingDecoratedPot { .. }
+                | CraftingRecipeTypes::CraftingSpecial => continue,
+            };
+            let name = strip_namespace(result.id);
+            let id = if manager.by_id.contains_key(&format!("minecraft:{name}")) {
+                format!(
+                    "minecraft:{name}_from_{}",
+                    first_ingredient.map_or("unknown", ingredient_name)
+                )
+            } else {
+                format!("minecraft:{name}")
+            };
+            manager.add(id, Recipe::Crafting(recipe), group, &mut groups);
+        }
+        for recipe in RECIPES_COOKING {
+            let cooking = cooking_recipe(recipe);
+            manager.add(
+                cooking.recipe_id.to_string(),
+                Recipe::Cooking(recipe),
+                cooking.group,
+                &mut groups,
+            );
+        }
+
+        manager
+    }
+}
+
+impl RecipeManager {
+    fn add(
+        &mut self,
+        id: String,
+        recipe: Recipe,
+        group: Option<&'static str>,
+        groups: &mut FxHashMap<&'static str, i32>,
+    ) {
+        let index = self.recipes.len();
+        // Fall back to numbering recipes whose generated id is still taken
+        let id = if self.by_id.contains_key(&id) {
+            format!("{id}_{index}")
+        } else {
+            id
+        };
+        let group = group.map(|group| {
+            let next = groups.len() as i32;
+            *groups.entry(group).or_insert(next)
+        });
+        let holder = RecipeHolder {
+            id: id.clone(),
+            display_id: index as i32,
+            recipe,
+            group,
+        };
+
+        let mut items: Vec<u16> = holder
+            .unlock_ingredient()
+            .into_iter()
+            .flat_map(ingredient_items)
+            .map(|item| item.id)
+            .collect();
+        items.sort_unstable();
+        items.dedup();
+        for item in items {
+            self.by_ingredient.entry(item).or_default().push(index);
+        }
+
+        self.by_id.insert(id, index);
+        self.recipes.push(holder);
+    }
+
+    #[must_use]
+    pub fn get(&self, id: &str) -> Option<&RecipeHolder> {
+        let id = if id.contains(':') {
+            id.to_string()
+        } else {
+            format!("minecraft:{id}")
+        };
+        self.by_id.get(&id).map(|index| &self.recipes[*index])
+    }
+
+    #[must_use]
+    pub fn get_by_display_id(&self, display_id: i32) -> Option<&RecipeHolder> {
+        usize::try_from(display_id)
+            .ok()
+            .and_then(|index| self.recipes.get(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &RecipeHolder> {
+        self.recipes.iter()
+    }
+
+    /// The recipes unlocked by getting `item` into the inventory.
+    pub fn unlocked_by(&self, item: &Item) -> impl Iterator<Item = &RecipeHolder> {
+        self.by_ingredient
+            .get(&item.id)
+            .into_iter()
+            .flatten()
+            .map(|index| &self.recipes[*index])
+    }
+}
+
+const fn cooking_recipe(recipe: &CookingRecipeType) -> &CookingRecipe {
+    match recipe {
+        CookingRecipeType::Blasting(recipe)
+        | CookingRecipeType::Smelting(recipe)
+        | CookingRecipeType::Smoking(recipe)
+        | CookingRecipeType::CampfireCooking(recipe) => recipe,
+    }
+}
+
+fn strip_namespace(id: &str) -> &str {
+    id.strip_prefix("minecraft:").unwrap_or(id)
+}
+
+fn ingredient_name(ingredient: &RecipeIngredientTypes) -> &str {
+    match ingredient {
+        RecipeIngredientTypes::Simple(item) => strip_namespace(item),
+        RecipeIngredientTypes::Tagged(tag) => strip_namespace(tag.trim_start_matches('#')),
+        RecipeIngredientTypes::OneOf(items) => items
+            .first()
+            .map_or("unknown", |item| strip_namespace(item)),
+    }
+}
+
+fn ingredient_items(ingredient: &RecipeIngredientTypes) -> Vec<&'static Item> {
+    match ingredient {
+        RecipeIngredientTypes::Simple(item) => Item::from_registry_key(strip_namespace(item))
+            .into_iter()
+            .collect(),
+        RecipeIngredientTypes::Tagged(tag) => {
+            get_tag_ids(RegistryKey::Item, tag.trim_start_matches('#'))
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|id| Item::from_id(*id))
+                .collect()
+        }
+        RecipeIngredientTypes::OneOf(items) => items
+            .iter()
+            .filter_map(|item| Item::from_registry_key(strip_namespace(item)))
+            .collect(),
+    }
+}
+
+fn ingredient_display(ingredient: &RecipeIngredientTypes) -> SlotDisplay {
+    if let RecipeIngredientTypes::Tagged(tag) = ingredient {
+        return SlotDisplay::Tag(tag.trim_start_matches('#').to_string());
+    }
+    let mut items: Vec<_> = ingredient_items(ingredient)
+        .into_iter()
+        .map(|item| SlotDisplay::Item(item.id))
+        .collect();
+    if items.len() == 1 {
+        items.remove(0)
+    } else {
+        SlotDisplay::Composite(items)
+    }
+}
+
+fn ingredient_requirement(ingredient: &RecipeIngredientTypes) -> Ingredient {
+    match ingredient {
+        RecipeIngredientTypes::Tagged(tag) => {
+            Ingredient::Tag(tag.trim_start_matches('#').to_string())
+        }
+        _ => Ingredient::Items(
+            ingredient_items(ingredient)
+                .into_iter()
+                .map(|item| item.id)
+                .collect(),
+        ),
+    }
+}
+
+fn result_display(result: &RecipeResultStruct) -> SlotDisplay {
+    Item::from_registry_key(strip_namespace(result.id)).map_or(SlotDisplay::Empty, |item| {
+        SlotDisplay::ItemStack(ItemStack::new(result.count, item))
+    })
+}
+
+fn crafting_display(recipe: &CraftingRecipeTypes) -> (RecipeDisplay, RecipeBookCategory) {
+    let crafting_station = SlotDisplay::Item(Item::CRAFTING_TABLE.id);
+    let (display, category) = match recipe {
+        CraftingRecipeTypes::CraftingShaped {
+            category,
+            key,
+            pattern,
+            result,
+            ..
+        } => {
+            let width = pattern.iter().map(|row| row.len()).max().unwrap_or(0);
+            let ingredients = pattern
+                .iter()
+                .flat_map(|row| {
+                    let padding = width - row.len();
+                    row.chars()
+                        .map(|symbol| {
+                            key.iter()
+                                .find(|(key, _)| *key == symbol)
+                                .map_or(SlotDisplay::Empty, |(_, ingredient)| {
+                                    ingredient_display(ingredient)
+                                })
+                        })
+                        .chain(std::iter::repeat_with(|| SlotDisplay::Empty).take(padding))
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            (
+                RecipeDisplay::CraftingShaped {
+                    width: width as i32,
+                    height: pattern.len() as i32,
+                    ingredients,
+                    result: result_display(result),
+                    crafting_station,
+                },
+                category,
+            )
+        }
+        CraftingRecipeTypes::CraftingShapeless {
+            category,
+            ingredients,
+            result,
+            ..
+        } => (
+            RecipeDisplay::CraftingShapeless {
+                ingredients: ingredients.iter().map(ingredient_display).collect(),
+                result: result_display(result),
+                crafting_station,
+            },
+            category,
+        ),
+        CraftingRecipeTypes::CraftingTransmute {
+            category,
+            input,
+            material,
+            result,
+            ..
+        } => (
+            RecipeDisplay::CraftingShapeless {
+                ingredients: vec![ingredient_display(input), ingredient_display(material)],
+                result: result_display(result),
+                crafting_station,
+            },
+            category,
+        ),
+        // Never added to the recipe book
+        CraftingRecipeTypes::CraftingDecoratedPot { .. } | CraftingRecipeTypes::CraftingSpecial => {
+            (
+                RecipeDisplay::CraftingShapeless {
+                    ingredients: Vec::new(),
+                    result: SlotDisplay::Empty,
+                    crafting_station,
+                },
+                &RecipeCategoryTypes::Misc,
+            )
+        }
+    };
+    let category = match category {
+        RecipeCategoryTypes::Building => RecipeBookCategory::CraftingBuildingBlocks,
+        RecipeCategoryTypes::Restone => RecipeBookCategory::CraftingRedstone,
+        RecipeCategoryTypes::Equipment => RecipeBookCategory::CraftingEquipment,
+        _ => RecipeBookCategory::CraftingMisc,
+    };
+    (display, category)
+}
+
+fn cooking_display(recipe: &CookingRecipeType) -> (RecipeDisplay, RecipeBookCategory) {
+    let cooking = cooking_recipe(recipe);
+    let (station, category) = match (recipe, &cooking.category) {
+        (CookingRecipeType::Smelting(_), RecipeCategoryTypes::Food) => {
+            (&Item::FURNACE, RecipeBookCategory::FurnaceFood)
+        }
+        (CookingRecipeType::Smelting(_), RecipeCategoryTypes::Blocks) => {
+            (&Item::FURNACE, RecipeBookCategory::FurnaceBlocks)
+        }
+        (CookingRecipeType::Smelting(_), _) => (&Item::FURNACE, RecipeBookCategory::FurnaceMisc),
+        (CookingRecipeType::Blasting(_), RecipeCategoryTypes::Blocks) => {
+            (&Item::BLAST_FURNACE, RecipeBookCategory::BlastFurnaceBlocks)
+        }
+        (CookingRecipeType::Blasting(_), _) => {
+            (&Item::BLAST_FURNACE, RecipeBookCategory::BlastFurnaceMisc)
+        }
+        (CookingRecipeType::Smoking(_), _) => (&Item::SMOKER, RecipeBookCategory::SmokerFood),
+        (CookingRecipeType::CampfireCooking(_), _) => {
+            (&Item::CAMPFIRE, RecipeBookCategory::Campfire)
+        }
+    };
+    (
+        RecipeDisplay::Furnace {
+            ingredient: ingredient_display(&cooking.ingredient),
+            fuel: SlotDisplay::AnyFuel,
+            result: result_display(&cooking.result),
+            crafting_station: SlotDisplay::Item(station.id),
+            duration: cooking.cookingtime,
+            experience: cooking.experience,
+        },
+        category,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_nbt::compound::NbtCompound;
+    use pumpkin_nbt::tag::NbtTag;
+
+    use super::*;
+    use crate::entity::recipe_book::RecipeBook;
+
+    #[test]
+    fn recipe_ids_are_unique() {
+        let manager = RecipeManager::default();
+        let mut ids: Vec<_> = manager.iter().map(|recipe| recipe.id.as_str()).collect();
+        let count = ids.len();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), count);
+    }
+
+    #[test]
+    fn ingredients_unlock_their_recipes() {
+        let manager = RecipeManager::default();
+        let door = manager.get("oak_door").expect("oak door recipe exists");
+        assert_eq!(
+            manager
+                .get_by_display_id(door.display_id)
+                .map(|recipe| &recipe.id),
+            Some(&door.id)
+        );
+        assert!(
+            manager
+                .unlocked_by(&Item::OAK_PLANKS)
+                .any(|recipe| recipe.id == door.id)
+        );
+        assert!(
+            !manager
+                .unlocked_by(&Item::DIAMOND)
+                .any(|recipe| recipe.id == door.id)
+        );
+    }
+
+    #[test]
+    fn only_the_main_material_unlocks() {
+        let manager = RecipeManager::default();
+        let fence = manager.get("oak_fence").expect("oak fence recipe exists");
+        assert!(
+            manager
+                .unlocked_by(&Item::OAK_PLANKS)
+                .any(|recipe| recipe.id == fence.id)
+        );
+        assert!(
+            !manager
+                .unlocked_by(&Item::STICK)
+                .any(|recipe| recipe.id == fence.id)
+        );
+
+        let iron = manager
+            .iter()
+            .find(|recipe| {
+                matches!(
+                    recipe.recipe,
+                    Recipe::Cooking(CookingRecipeType::Smelting(_))
+                ) && recipe
+                    .id
+                    .starts_with("minecraft:iron_ingot_from_smelting_raw_iron")
+            })
+            .expect("raw iron smelting recipe exists");
+        assert!(
+            manager
+                .unlocked_by(&Item::RAW_IRON)
+                .any(|recipe| recipe.id == iron.id)
+        );
+    }
+
+    #[tokio::test]
+    async fn recipe_book_round_trips() {
+        let manager = RecipeManager::default();
+        let strings = |ids: &[&str]| {
+            ids.iter()
+                .map(|id| NbtTag::String((*id).to_string()))
+                .collect::<Vec<_>>()
+        };
+        let mut saved = NbtCompound::new();
+        saved.put_list(
+            "recipes",
+            strings(&["oak_door", "minecraft:oak_fence", "mymod:widget"]),
+        );
+        saved.put_list("toBeDisplayed", strings(&["oak_door"]));
+        saved.put_bool("isGuiOpen", true);
+        let mut nbt = NbtCompound::new();
+        nbt.put_compound("recipeBook", saved);
+
+        let book = RecipeBook::default();
+        book.read_nbt(&nbt).await;
+        book.resolve_ids(&manager).await;
+        let mut written = NbtCompound::new();
+        book.write_nbt(&mut written).await;
+
+        let written = written.get_compound("recipeBook").unwrap();
+        assert_eq!(
+            written.get_list("recipes").unwrap(),
+            strings(&["minecraft:oak_door", "minecraft:oak_fence", "mymod:widget"])
+        );
+        assert_eq!(
+            written.get_list("toBeDisplayed").unwrap(),
+            strings(&["minecraft:oak_door"])
+        );
+        assert_eq!(written.get_bool("isGuiOpen"), Some(true));
+
+        let mut nbt = NbtCompound::new();
+        nbt.put_compound("recipeBook", written.clone());
+        let reread = RecipeBook::default();
+        reread.read_nbt(&nbt).await;
+        let mut rewritten = NbtCompound::new();
+        reread.write_nbt(&mut rewritten).await;
+        assert_eq!(rewritten.get_compound("recipeBook"), Some(written));
+    }
+}
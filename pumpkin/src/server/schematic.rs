@@ -0,0 +1,181 @@
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+use pumpkin_world::{
+    block::entities::block_entity_from_nbt,
+    generation::structure::template::{BlockMirror, BlockRotation, BlockStateResolver},
+    schematic::{SCHEMATIC_EXTENSION, Schematic, SchematicBlockEntity, SchematicError},
+    world::BlockFlags,
+};
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use crate::world::World;
+
+#[derive(Debug, Error)]
+pub enum SchematicIoError {
+    #[error("Invalid schematic name {0:?}")]
+    InvalidName(String),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Schematic(#[from] SchematicError),
+}
+
+/// Copies, pastes and stores Sponge schematics, and keeps a clipboard per command sender.
+pub struct SchematicManager {
+    folder: PathBuf,
+    clipboards: Mutex<HashMap<String, Arc<Schematic>>>,
+}
+
+impl SchematicManager {
+    pub fn new(folder: impl Into<PathBuf>) -> Self {
+        Self {
+            folder: folder.into(),
+            clipboards: Mutex::new(HashMap::new()),
+        }
+    }
+
+    #[must_use]
+    pub fn folder(&self) -> &Path {
+        &self.folder
+    }
+
+    /// Resolves a schematic name to its file, rejecting names that would escape the folder.
+    pub fn path(&self, name: &str) -> Result<PathBuf, SchematicIoError> {
+        let name = name
+            .strip_suffix(&format!(".{SCHEMATIC_EXTENSION}"))
+            .unwrap_or(name);
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+            || name.starts_with('.')
+        {
+            return Err(SchematicIoError::InvalidName(name.to_string()));
+        }
+        Ok(self.folder.join(format!("{name}.{SCHEMATIC_EXTENSION}")))
+    }
+
+    pub async fn save(&self, name: &str, schematic: &Schematic) -> Result<(), SchematicIoError> {
+        let path = self.path(name)?;
+        let bytes = schematic.to_nbt_bytes()?;
+        tokio::fs::create_dir_all(&self.folder).await?;
+        tokio::fs::write(path, bytes).await?;
+        Ok(())
+    }
+
+    pub async fn load(&self, name: &str) -> Result<Schematic, SchematicIoError> {
+        let bytes = tokio::fs::read(self.path(name)?).await?;
+        Ok(Schematic::from_nbt_bytes(&bytes)?)
+    }
+
+    pub async fn clipboard(&self, owner: &str) -> Option<Arc<Schematic>> {
+        self.clipboards.lock().await.get(owner).cloned()
+    }
+
+    pub async fn set_clipboard(&self, owner: String, schematic: Arc<Schematic>) {
+        self.clipboards.lock().await.insert(owner, schematic);
+    }
+
+    /// Copies the cuboid between `from` and `to` into a schematic. `origin` is the point the
+    /// schematic is later pasted relative to.
+    pub async fn copy(
+        world: &World,
+        from: BlockPos,
+        to: BlockPos,
+        origin: BlockPos,
+    ) -> Result<Schematic, SchematicError> {
+        let min = Vector3::new(
+            from.0.x.min(to.0.x),
+            from.0.y.min(to.0.y),
+            from.0.z.min(to.0.z),
+        );
+        let max = Vector3::new(
+            from.0.x.max(to.0.x),
+            from.0.y.max(to.0.y),
+            from.0.z.max(to.0.z),
+        );
+        let size = max.sub(&min).add(&Vector3::new(1, 1, 1));
+        let mut schematic = Schematic::new(size, min.sub(&origin.0))?;
+
+        for index in 0..schematic.blocks.len() {
+            let relative = schematic.position(index);
+            let pos = BlockPos(min.add(&relative));
+            schematic.set_block_state(relative, world.get_block_state(&pos).await);
+
+            if let Some(block_entity) = world.get_block_entity(&pos).await {
+                let mut data = NbtCompound::new();
+                block_entity.write_nbt(&mut data).await;
+                schematic.block_entities.push(SchematicBlockEntity {
+                    pos: relative,
+                    id: block_entity.resource_location().to_string(),
+                    data,
+                });
+            }
+        }
+        Ok(schematic)
+    }
+
+    /// Pastes a schematic relative to `origin`, rotated around it. Returns the number of blocks
+    /// placed.
+    pub async fn paste(
+        world: &Arc<World>,
+        schematic: &Schematic,
+        origin: BlockPos,
+        rotation: BlockRotation,
+        ignore_air: bool,
+    ) -> usize {
+        let target = |relative: Vector3<i32>| {
+            let pos = schematic.offset.add(&relative);
+            let (x, z) = rotation.rotate_offset(pos.x, pos.z);
+            BlockPos(origin.0.add(&Vector3::new(x, pos.y, z)))
+        };
+
+        let mut placed = 0;
+        for index in 0..schematic.blocks.len() {
+            let relative = schematic.position(index);
+            let entry = &schematic.palette[schematic.blocks[index] as usize];
+            if ignore_air && entry.name == "minecraft:air" {
+                continue;
+            }
+            let Some(state) = BlockStateResolver::resolve(entry, rotation, BlockMirror::None)
+            else {
+                continue;
+            };
+            let pos = target(relative);
+            if !world.is_in_build_limit(pos) {
+                continue;
+            }
+            world
+                .set_block_state(&pos, state.id, BlockFlags::FORCE_STATE)
+                .await;
+            placed += 1;
+        }
+
+        for block_entity in &schematic.block_entities {
+            let pos = target(block_entity.pos);
+            if !world.is_in_build_limit(pos) {
+                continue;
+            }
+            let mut data = NbtCompound::new();
+            data.put_string("id", block_entity.id.clone());
+            data.put_int("x", pos.0.x);
+            data.put_int("y", pos.0.y);
+            data.put_int("z", pos.0.z);
+            for (name, tag) in &block_entity.data.child_tags {
+                data.put(name, tag.clone());
+            }
+            if let Some(block_entity) = block_entity_from_nbt(&data) {
+                world.add_block_entity(block_entity).await;
+            }
+        }
+        placed
+    }
+}
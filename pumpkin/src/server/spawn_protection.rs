@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use pumpkin_data::{Block, dimension::Dimension};
+use pumpkin_util::math::position::BlockPos;
+
+use super::Server;
+use crate::entity::player::Player;
+use crate::plugin::block::block_can_build::BlockCanBuildEvent;
+use crate::world::World;
+
+/// Whether `pos` lies within the spawn protection that keeps `player` from modifying it.
+///
+/// Like vanilla, only the overworld is protected, operators are exempt and protection is off
+/// while there are no operators at all. The protected square is centered on the current world
+/// spawn, so it follows `/setworldspawn`.
+pub async fn is_under_spawn_protection(
+    server: &Server,
+    world: &World,
+    pos: &BlockPos,
+    player: &Player,
+) -> bool {
+    let radius = server.basic_config.spawn_protection;
+    if radius == 0 || world.dimension != Dimension::OVERWORLD {
+        return false;
+    }
+    {
+        let operators = server.data.operator_config.read().await;
+        if operators.ops.is_empty() || operators.get_entry(&player.gameprofile.id).is_some() {
+            return false;
+        }
+    }
+    let info = world.level_info.load();
+    let distance = pos
+        .0
+        .x
+        .abs_diff(info.spawn_x)
+        .max(pos.0.z.abs_diff(info.spawn_z));
+    distance <= radius
+}
+
+/// Whether `player` may modify the block at `pos`, by placing `block_to_build` on it, or by
+/// breaking or using it when `block_to_build` is air.
+///
+/// Spawn protection decides the outcome, which plugins can override through
+/// [`BlockCanBuildEvent`].
+pub async fn may_modify(
+    server: &Server,
+    world: &World,
+    player: &Arc<Player>,
+    pos: &BlockPos,
+    block_to_build: &'static Block,
+) -> bool {
+    let buildable = !is_under_spawn_protection(server, world, pos, player).await;
+    let block = world.get_block(pos).await;
+    let event = BlockCanBuildEvent::new(block_to_build, buildable, player.clone(), block, *pos);
+    let event = server.plugin_manager.fire(event).await;
+    event.buildable && !event.cancelled
+}
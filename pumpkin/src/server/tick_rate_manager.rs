@@ -6,6 +6,7 @@ use pumpkin_data::translation;
 use pumpkin_protocol::java::client::play::{CSystemChatMessage, CTickingState, CTickingStep};
 use pumpkin_util::text::{TextComponent, color::NamedColor};
 
+use crate::entity::EntityBase;
 use crate::entity::player::Player;
 use crate::server::Server;
 const NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
@@ -79,6 +80,21 @@ impl ServerTickRateManager {
         self.frozen_ticks_to_run.load(Ordering::Relaxed) > 0
     }
 
+    /// Whether `entity` skips this tick. While the game is frozen only players and the entities
+    /// they ride keep ticking.
+    pub async fn should_skip_tick(&self, entity: &dyn EntityBase) -> bool {
+        if self.runs_normally() || entity.get_player().is_some() {
+            return false;
+        }
+        !entity
+            .get_entity()
+            .passengers
+            .lock()
+            .await
+            .iter()
+            .any(|passenger| passenger.get_player().is_some())
+    }
+
     pub async fn set_tick_rate(&self, server: &Server, rate: f32) {
         self.tickrate.store(rate.max(1.0));
         self.nanoseconds_per_tick.store(
@@ -91,6 +107,7 @@ impl ServerTickRateManager {
 
     pub async fn set_frozen(&self, server: &Server, frozen: bool) {
         self.is_frozen.store(frozen, Ordering::Relaxed);
+
         self.update_state_to_clients(server).await;
     }
 
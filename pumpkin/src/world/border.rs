@@ -121,6 +121,15 @@ impl Worldborder {
         x >= min_x && x < max_x && z >= min_z && z < max_z
     }
 
+    /// How far the point is from the nearest edge of the border.
+    #[must_use]
+    pub fn distance_inside(&self, x: f64, z: f64) -> f64 {
+        let half = self.new_diameter / 2.0;
+        let to_x = (x - (self.center_x - half)).min(self.center_x + half - x);
+        let to_z = (z - (self.center_z - half)).min(self.center_z + half - z);
+        to_x.min(to_z)
+    }
+
     #[must_use]
     pub fn contains_block(&self, x: i32, z: i32) -> bool {
         self.contains(f64::from(x), f64::from(z))
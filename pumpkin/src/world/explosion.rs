@@ -1,26 +1,80 @@
 use std::sync::Arc;
 
-use pumpkin_data::{Block, BlockState, damage::DamageType, entity::EntityType};
+use pumpkin_data::{
+    Block, BlockState,
+    damage::DamageType,
+    entity::EntityType,
+    tag::{self, Taggable},
+};
 use pumpkin_util::math::{boundingbox::BoundingBox, position::BlockPos, vector3::Vector3};
 use rustc_hash::FxHashMap;
 
 use crate::{
-    block::{ExplodeArgs, drop_loot},
+    block::{ExplodeArgs, WindTriggeredArgs, drop_loot},
     entity::{Entity, EntityBase},
     world::loot::LootContextParameters,
 };
 
 use super::{BlockFlags, World};
 
+/// What an explosion does to the blocks its rays reach.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DestructionType {
+    /// Blocks are broken and drop their loot.
+    Destroy,
+    /// Blocks are left intact but get to react to the blast, like wind charges opening doors.
+    TriggerBlock,
+}
+
 pub struct Explosion {
     power: f32,
     pos: Vector3<f64>,
+    destruction_type: DestructionType,
+    damages_entities: bool,
+    knockback_multiplier: f64,
 }
 
 impl Explosion {
     #[must_use]
     pub const fn new(power: f32, pos: Vector3<f64>) -> Self {
-        Self { power, pos }
+        Self {
+            power,
+            pos,
+            destruction_type: DestructionType::Destroy,
+            damages_entities: true,
+            knockback_multiplier: 1.0,
+        }
+    }
+
+    /// A wind burst only pushes entities away and triggers blocks, it never damages either.
+    #[must_use]
+    pub const fn wind_burst(power: f32, pos: Vector3<f64>, knockback_multiplier: f64) -> Self {
+        Self {
+            power,
+            pos,
+            destruction_type: DestructionType::TriggerBlock,
+            damages_entities: false,
+            knockback_multiplier,
+        }
+    }
+
+    #[must_use]
+    pub const fn power(&self) -> f32 {
+        self.power
+    }
+
+    #[must_use]
+    pub const fn position(&self) -> Vector3<f64> {
+        self.pos
+    }
+
+    fn block_resistance(&self, block: &Block, fluid_resistance: f32) -> f32 {
+        if self.destruction_type == DestructionType::TriggerBlock
+            && block.has_tag(&tag::Block::MINECRAFT_BLOCKS_WIND_CHARGE_EXPLOSIONS)
+        {
+            return 3_600_000.0;
+        }
+        fluid_resistance.max(block.blast_resistance)
     }
 
     async fn get_blocks_to_destroy(
@@ -63,7 +117,7 @@ impl Explosion {
 
                         if !state.is_air() || !fluid_state.is_empty {
                             let resistance =
-                                fluid_state.blast_resistance.max(block.blast_resistance);
+                                self.block_resistance(block, fluid_state.blast_resistance);
                             h -= resistance * 0.3;
                             if h > 0.0 {
                                 map.insert(block_pos, (block, state));
@@ -123,16 +177,19 @@ impl Explosion {
                 continue;
             }
 
-            let damage_multiplier = (1.0 - distance) * exposure;
-            let damage = (f64::midpoint(damage_multiplier * damage_multiplier, damage_multiplier)
-                * 7.0
-                * self.power as f64
-                + 1.0) as f32;
-
-            // TODO: damage type
-            entity
-                .damage(entity_base.as_ref(), damage, DamageType::EXPLOSION)
-                .await;
+            if self.damages_entities {
+                let damage_multiplier = (1.0 - distance) * exposure;
+                let damage =
+                    (f64::midpoint(damage_multiplier * damage_multiplier, damage_multiplier)
+                        * 7.0
+                        * self.power as f64
+                        + 1.0) as f32;
+
+                // TODO: damage type
+                entity
+                    .damage(entity_base.as_ref(), damage, DamageType::EXPLOSION)
+                    .await;
+            }
 
             // Calculate and apply knockback
             let dir_pos = if entity.entity_type == &EntityType::TNT {
@@ -144,7 +201,10 @@ impl Explosion {
             // TODO
             let knockback_resistance = 0.0;
 
-            let knockback_multiplier = (1.0 - distance) * exposure * (1.0 - knockback_resistance);
+            let knockback_multiplier = (1.0 - distance)
+                * exposure
+                * self.knockback_multiplier
+                * (1.0 - knockback_resistance);
             let knockback = direction * knockback_multiplier;
             entity.add_velocity(knockback).await;
         }
@@ -209,10 +269,25 @@ impl Explosion {
         visible_points as f32 / total_points as f32
     }
 
-    /// Returns the removed block count
+    /// Returns the affected block count
     pub async fn explode(&self, world: &Arc<World>) -> u32 {
         let blocks = self.get_blocks_to_destroy(world).await;
         self.damage_entities(world).await;
+        if self.destruction_type == DestructionType::TriggerBlock {
+            for (pos, (block, state)) in &blocks {
+                if let Some(pumpkin_block) = world.block_registry.get_pumpkin_block(block.id) {
+                    pumpkin_block
+                        .on_wind_triggered(WindTriggeredArgs {
+                            world,
+                            block,
+                            state,
+                            position: pos,
+                        })
+                        .await;
+                }
+            }
+            return blocks.len() as u32;
+        }
         for (pos, (block, state)) in &blocks {
             world.set_block_state(pos, 0, BlockFlags::NOTIFY_ALL).await;
             world.close_container_screens_at(pos).await;
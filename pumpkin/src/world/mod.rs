@@ -14,6 +14,7 @@ pub mod portal;
 pub mod time;
 
 use crate::block::RandomTickArgs;
+use crate::block::blocks::lightning_rod::LightningRodBlock;
 use crate::world::loot::LootContextParameters;
 use crate::{
     block::BlockEvent, entity::experience_orb::ExperienceOrbEntity, entity::item::ItemEntity,
@@ -25,7 +26,10 @@ use crate::{
         {OnNeighborUpdateArgs, OnScheduledTickArgs},
     },
     command::client_suggestions,
-    entity::{Entity, EntityBase, player::Player, r#type::from_type},
+    entity::{
+        Entity, EntityBase, leash::Leash, lightning::LightningEntity, player::Player,
+        r#type::from_type,
+    },
     error::PumpkinError,
     net::{ClientPlatform, java::JavaClient},
     plugin::{
@@ -53,7 +57,7 @@ use pumpkin_data::{
     fluid::Fluid,
     particle::Particle,
     sound::{Sound, SoundCategory},
-    world::{RAW, WorldEvent},
+    world::WorldEvent,
 };
 use pumpkin_data::{BlockDirection, BlockState, translation};
 use pumpkin_inventory::screen_handler::InventoryPlayer;
@@ -86,11 +90,9 @@ use pumpkin_protocol::{
         self,
         client::play::{
             CBlockEntityData, CEntityStatus, CGameEvent, CLogin, CMultiBlockUpdate,
-            CPlayerChatMessage, CPlayerInfoUpdate, CRemoveEntities, CRemovePlayerInfo,
-            CSetSelectedSlot, CSoundEffect, CSpawnEntity, FilterType, GameEvent, InitChat,
-            PlayerAction, PlayerInfoFlags,
+            CPlayerInfoUpdate, CRemoveEntities, CRemovePlayerInfo, CSetSelectedSlot, CSoundEffect,
+            CSpawnEntity, GameEvent, InitChat, PlayerAction, PlayerInfoFlags,
         },
-        server::play::SChatMessage,
     },
 };
 use pumpkin_protocol::{
@@ -108,7 +110,7 @@ use pumpkin_util::resource_location::ResourceLocation;
 use pumpkin_util::text::{TextComponent, color::NamedColor};
 use pumpkin_util::version::MinecraftVersion;
 use pumpkin_util::{
-    Difficulty,
+    Difficulty, GameMode, LocalDifficulty,
     math::{boundingbox::BoundingBox, position::BlockPos, vector3::Vector3},
 };
 use pumpkin_util::{
@@ -116,16 +118,17 @@ use pumpkin_util::{
     random::{RandomImpl, get_seed, xoroshiro128::Xoroshiro},
 };
 use pumpkin_world::inventory::Clearable;
-use pumpkin_world::world::{GetBlockError, WorldFuture};
+use pumpkin_world::world::{GetBlockError, NearbyPlayer, WorldFuture};
 use pumpkin_world::{
     BlockStateId, CURRENT_BEDROCK_MC_VERSION, biome, block::entities::BlockEntity,
     chunk::io::Dirtiable, inventory::Inventory, item::ItemStack, world::SimpleWorld,
 };
 use pumpkin_world::{chunk::ChunkData, world::BlockAccessor};
-use pumpkin_world::{level::Level, tick::TickPriority};
+use pumpkin_world::{level::Level, poi::POI_TYPE_LIGHTNING_ROD, tick::TickPriority};
 use pumpkin_world::{world::BlockFlags, world_info::LevelData};
 use rand::seq::SliceRandom;
 use rand::{RngExt, rng};
+use redstone_debug::{RedstoneDebugger, UpdateKind};
 use scoreboard::Scoreboard;
 use time::LevelTime;
 use tokio::sync::Mutex;
@@ -134,6 +137,7 @@ pub mod border;
 pub mod bossbar;
 pub mod custom_bossbar;
 pub mod natural_spawner;
+pub mod redstone_debug;
 pub mod scoreboard;
 pub mod weather;
 
@@ -146,6 +150,19 @@ use weather::Weather;
 
 type FlowingFluidProperties = pumpkin_data::fluid::FlowingWaterLikeFluidProperties;
 
+/// Returns the squared distance within which a sound played at `volume` can be heard.
+///
+/// Sounds carry 16 blocks, or `16 * volume` blocks for volumes above 1.0.
+#[must_use]
+pub fn sound_range_squared(volume: f32) -> f64 {
+    let range = if volume > 1.0 {
+        f64::from(volume * 16.0)
+    } else {
+        16.0
+    };
+    range * range
+}
+
 impl PumpkinError for GetBlockError {
     fn is_kick(&self) -> bool {
         false
@@ -160,6 +177,9 @@ impl PumpkinError for GetBlockError {
     }
 }
 
+/// How many ticks of inhabited time a chunk accumulates before it is marked for saving again.
+const INHABITED_TIME_SAVE_INTERVAL: i64 = 1200;
+
 /// Represents a Minecraft world, containing entities, players, and the underlying level data.
 ///
 /// Each dimension (Overworld, Nether, End) typically has its own `World`.
@@ -200,6 +220,8 @@ pub struct World {
     unsent_block_changes: Mutex<HashMap<BlockPos, u16>>,
     /// POI storage for fast portal lookups
     pub portal_poi: Mutex<portal::PortalPoiStorage>,
+    /// Sessions of the `/debugredstone` command
+    pub redstone_debugger: RedstoneDebugger,
 }
 
 impl PartialEq for World {
@@ -242,6 +264,7 @@ impl World {
             synced_block_event_queue: Mutex::new(Vec::new()),
             unsent_block_changes: Mutex::new(HashMap::new()),
             portal_poi: Mutex::new(portal_poi),
+            redstone_debugger: RedstoneDebugger::default(),
             server,
         }
     }
@@ -264,6 +287,18 @@ impl World {
             .unwrap_or("world")
     }
 
+    /// Saves the entities into their chunks and writes every dirty chunk to disk.
+    pub async fn flush(&self) {
+        for entity in self.entities.load().iter() {
+            self.save_entity(entity).await;
+        }
+        let save_result = self.portal_poi.lock().await.save_all();
+        if let Err(e) = save_result {
+            error!("Failed to save portal POI: {e}");
+        }
+        self.level.flush().await;
+    }
+
     pub async fn shutdown(&self) {
         for entity in self.entities.load().iter() {
             self.save_entity(entity).await;
@@ -356,6 +391,21 @@ impl World {
         self.level_info.store(Arc::new(new_info));
     }
 
+    /// Returns the regional difficulty at `pos`, based on world time, how long players have
+    /// spent in its chunk and the moon phase.
+    pub async fn get_local_difficulty(&self, pos: &BlockPos) -> LocalDifficulty {
+        let difficulty = self.level_info.load().difficulty;
+        let (time_of_day, moon_brightness) = {
+            let level_time = self.level_time.lock().await;
+            (level_time.time_of_day, level_time.moon_brightness())
+        };
+        let inhabited_time = self
+            .level
+            .try_get_chunk(&pos.chunk_position())
+            .map_or(0, |chunk| chunk.inhabited_time.load(Ordering::Relaxed));
+        LocalDifficulty::new(difficulty, time_of_day, inhabited_time, moon_brightness)
+    }
+
     pub async fn add_synced_block_event(&self, pos: BlockPos, r#type: u8, data: u8) {
         let mut queue = self.synced_block_event_queue.lock().await;
         queue.push(BlockEvent { pos, r#type, data });
@@ -479,55 +529,13 @@ impl World {
         }
     }
 
-    pub async fn broadcast_secure_player_chat(
-        &self,
-        sender: &Arc<Player>,
-        chat_message: &SChatMessage,
-        decorated_message: &TextComponent,
+    /// Broadcasts a packet to the given players only, serializing it once per protocol version.
+    pub async fn broadcast_packet_to<'a, P: ClientPacket>(
+        players: impl Iterator<Item = &'a Arc<Player>>,
+        packet: &P,
     ) {
-        let messages_sent: i32 = sender.chat_session.lock().await.messages_sent;
-        let sender_last_seen = {
-            let cache = sender.signature_cache.lock().await;
-            cache.last_seen.clone()
-        };
-
-        for recipient in self.players.load().iter() {
-            let messages_received: i32 = recipient.chat_session.lock().await.messages_received;
-            let packet = &CPlayerChatMessage::new(
-                VarInt(messages_received),
-                sender.gameprofile.id,
-                VarInt(messages_sent),
-                chat_message.signature.clone(),
-                chat_message.message.clone(),
-                chat_message.timestamp,
-                chat_message.salt,
-                sender_last_seen.indexed_for(recipient).await,
-                Some(decorated_message.clone()),
-                FilterType::PassThrough,
-                (RAW + 1).into(), // Custom registry chat_type with no sender name
-                TextComponent::text(""), // Not needed since we're injecting the name in the message for custom formatting
-                None,
-            );
-            recipient.client.enqueue_packet(packet).await;
-
-            recipient
-                .signature_cache
-                .lock()
-                .await
-                .add_seen_signature(&chat_message.signature.clone().unwrap()); // Unwrap is safe because we check for None in validate_chat_message
-
-            if recipient.gameprofile.id != sender.gameprofile.id {
-                // Sender may update recipient on signatures recipient hasn't seen
-                recipient
-                    .signature_cache
-                    .lock()
-                    .await
-                    .cache_signatures(sender_last_seen.as_ref());
-            }
-            recipient.chat_session.lock().await.messages_received += 1;
-        }
-
-        sender.chat_session.lock().await.messages_sent += 1;
+        let recipients_by_version = Self::collect_java_recipients_by_version(players);
+        Self::broadcast_java_grouped(packet, recipients_by_version).await;
     }
 
     /// Broadcasts a packet to all connected players within the world, excluding the specified players.
@@ -600,9 +608,9 @@ impl World {
         volume: f32,
         pitch: f32,
     ) {
-        let seed = rng().random::<f64>();
-        let packet = CSoundEffect::new(IdOr::Id(sound_id), category, position, volume, pitch, seed);
-        self.broadcast_packet_all(&packet).await;
+        let seed = rng().random();
+        self.play_sound_seeded(None, sound_id, category, position, volume, pitch, seed)
+            .await;
     }
 
     pub async fn play_sound_raw_expect(
@@ -614,10 +622,44 @@ impl World {
         volume: f32,
         pitch: f32,
     ) {
-        let seed = rng().random::<f64>();
+        let seed = rng().random();
+        self.play_sound_seeded(
+            Some(player),
+            sound_id,
+            category,
+            position,
+            volume,
+            pitch,
+            seed,
+        )
+        .await;
+    }
+
+    /// Plays a sound to every player within hearing range of `position`, optionally excluding
+    /// the player who caused it (they already predicted the sound client-side).
+    ///
+    /// The `seed` is forwarded to clients, which use it to pick the sound variant and
+    /// any pitch variation, so every recipient hears the same sound.
+    #[expect(clippy::too_many_arguments)]
+    pub async fn play_sound_seeded(
+        &self,
+        except: Option<&Player>,
+        sound_id: u16,
+        category: SoundCategory,
+        position: &Vector3<f64>,
+        volume: f32,
+        pitch: f32,
+        seed: i64,
+    ) {
         let packet = CSoundEffect::new(IdOr::Id(sound_id), category, position, volume, pitch, seed);
-        self.broadcast_packet_except(&[player.gameprofile.id], &packet)
-            .await;
+        let range_squared = sound_range_squared(volume);
+        let players = self.players.load();
+        let recipients_by_version =
+            Self::collect_java_recipients_by_version(players.iter().filter(|candidate| {
+                except.is_none_or(|except| except.gameprofile.id != candidate.gameprofile.id)
+                    && candidate.position().squared_distance_to_vec(position) <= range_squared
+            }));
+        Self::broadcast_java_grouped(&packet, recipients_by_version).await;
     }
 
     pub async fn play_block_sound(
@@ -634,20 +676,36 @@ impl World {
         self.play_sound(sound, category, &new_vec).await;
     }
 
+    pub async fn play_block_sound_fine(
+        &self,
+        sound: Sound,
+        category: SoundCategory,
+        position: BlockPos,
+        volume: f32,
+        pitch: f32,
+    ) {
+        self.play_sound_fine(sound, category, &position.to_centered_f64(), volume, pitch)
+            .await;
+    }
+
     pub async fn play_block_sound_expect(
         &self,
         player: &Player,
         sound: Sound,
         category: SoundCategory,
         position: BlockPos,
+        volume: f32,
+        pitch: f32,
     ) {
-        let new_vec = Vector3::new(
-            f64::from(position.0.x) + 0.5,
-            f64::from(position.0.y) + 0.5,
-            f64::from(position.0.z) + 0.5,
-        );
-        self.play_sound_expect(player, sound, category, &new_vec)
-            .await;
+        self.play_sound_raw_expect(
+            player,
+            sound as u16,
+            category,
+            &position.to_centered_f64(),
+            volume,
+            pitch,
+        )
+        .await;
     }
 
     pub async fn tick(self: &Arc<Self>, server: &Server) {
@@ -656,12 +714,22 @@ impl World {
         // IMPORTANT: send flush_block_updates first to prevent issues with CAcknowledgeBlockChange
         self.flush_block_updates().await;
         self.flush_synced_block_events().await;
-        self.tick_environment().await;
+
+        // Time, weather, blocks and spawning stand still while the game is frozen
+        let tick_rate_manager = &server.tick_rate_manager;
+        let runs_normally = tick_rate_manager.runs_normally();
+        if runs_normally {
+            self.tick_environment().await;
+        }
 
         let chunk_start = tokio::time::Instant::now();
-        self.tick_chunks().await;
+        if runs_normally {
+            self.tick_chunks().await;
+        }
         let chunk_elapsed = chunk_start.elapsed();
 
+        self.redstone_debugger.tick(self).await;
+
         let player_start = tokio::time::Instant::now();
         let players = self.players.load();
         let player_count = players.len();
@@ -675,8 +743,15 @@ impl World {
         let entity_count = entities_to_tick.len();
 
         for entity in entities_to_tick.iter() {
+            // Passengers are ticked by their vehicle
+            if entity.get_entity().has_vehicle().await
+                || tick_rate_manager.should_skip_tick(entity.as_ref()).await
+            {
+                continue;
+            }
             entity.get_entity().age.fetch_add(1, Relaxed);
             entity.tick(entity.clone(), server).await;
+            Entity::tick_passengers(entity.as_ref(), server).await;
 
             for player in players.iter() {
                 if player
@@ -692,12 +767,26 @@ impl World {
                 }
             }
         }
+
+        // Entities are tracked after everything moved, including passengers and frozen entities
+        for entity in self.entities.load().iter() {
+            let leash_holder = entity.get_leash().and_then(Leash::holder_id);
+            entity.get_entity().send_tracking_update(leash_holder).await;
+        }
+
+        // Metadata changed during this tick goes out in one packet per entity
+        for player in self.players.load().iter() {
+            player.living_entity.entity.flush_metadata().await;
+        }
+        for entity in self.entities.load().iter() {
+            entity.get_entity().flush_metadata().await;
+        }
         let entity_elapsed = entity_start.elapsed();
 
         //self.level.chunk_loading.lock().unwrap().send_change();
 
         let total_elapsed = start.elapsed();
-        if total_elapsed.as_millis() > 50 {
+        if total_elapsed.as_nanos() > tick_rate_manager.nanoseconds_per_tick() as u128 {
             debug!(
                 "Slow Tick [{}ms]: Chunks: {:?} | Players({}): {:?} | Entities({}): {:?}",
                 total_elapsed.as_millis(),
@@ -798,6 +887,9 @@ impl World {
     pub async fn tick_chunks(self: &Arc<Self>) {
         let tick_data = self.level.get_tick_data();
         for scheduled_tick in tick_data.block_ticks {
+            self.redstone_debugger
+                .record_update(scheduled_tick.position, UpdateKind::Tick)
+                .await;
             let block = self.get_block(&scheduled_tick.position).await;
             if let Some(pumpkin_block) = self.block_registry.get_pumpkin_block(block.id) {
                 pumpkin_block
@@ -851,6 +943,14 @@ impl World {
             }
         }
 
+        for chunk in spawning_chunks_map.values() {
+            let inhabited_time = chunk.inhabited_time.fetch_add(1, Ordering::Relaxed) + 1;
+            // Persist the inhabited time once in a while without rewriting every chunk every tick
+            if inhabited_time % INHABITED_TIME_SAVE_INTERVAL == 0 {
+                chunk.dirty.store(true, Ordering::Relaxed);
+            }
+        }
+
         let mut spawn_state =
             SpawnState::new(spawning_chunks_map.len() as i32, &self.entities, self).await; // TODO store it
 
@@ -1221,31 +1321,27 @@ impl World {
         // this.level.tickThunder(chunk);
         //TODO check in simulation distance
         let weather = self.weather.lock().await;
-        if weather.raining && weather.thundering && rng().random_range(0..100_000) == 0 {
+        let thundering = weather.raining && weather.thundering;
+        drop(weather);
+        if thundering && rng().random_range(0..100_000) == 0 {
             let rand_value = rng().random::<i32>() >> 2;
-            let delta = Vector3::new(rand_value & 15, rand_value >> 16 & 15, rand_value >> 8 & 15);
-            let random_pos = Vector3::new(
-                chunk_pos.x << 4,
-                chunk.heightmap.lock().unwrap().get(
-                    MotionBlocking,
-                    chunk_pos.x << 4,
-                    chunk_pos.y << 4,
-                    self.min_y,
-                ),
-                chunk_pos.y << 4,
-            )
-            .add(&delta);
+            let x = (chunk_pos.x << 4) + (rand_value & 15);
+            let z = (chunk_pos.y << 4) + (rand_value >> 8 & 15);
+            let y = chunk
+                .heightmap
+                .lock()
+                .unwrap()
+                .get(MotionBlocking, x, z, self.min_y);
             // TODO this.getBrightness(LightLayer.SKY, blockPos) >= 15;
-            // TODO heightmap
 
-            // TODO findLightningRod(blockPos)
+            let lightning_rod = self.find_lightning_rod(BlockPos::new(x, y, z)).await;
+            let random_pos = lightning_rod.unwrap_or_else(|| BlockPos::new(x, y, z));
             // TODO encapsulatingFullBlocks
             if true {
                 // TODO biome.getPrecipitationAt(pos, this.getSeaLevel()) == Biome.Precipitation.RAIN
-                // TODO this.getCurrentDifficultyAt(blockPos);
-                if rng().random::<f32>() < 0.0675
-                    && self.get_block(&random_pos.to_block_pos().down()).await
-                        != &Block::LIGHTNING_ROD
+                let local_difficulty = self.get_local_difficulty(&random_pos).await;
+                if rng().random::<f32>() < local_difficulty.local_difficulty * 0.01
+                    && lightning_rod.is_none()
                 {
                     let entity = Entity::new(
                         self.clone(),
@@ -1254,15 +1350,9 @@ impl World {
                     );
                     self.spawn_entity(Arc::new(entity)).await;
                 }
-                let entity = Entity::new(
-                    self.clone(),
-                    random_pos.to_f64().add_raw(0.5, 0., 0.5),
-                    &EntityType::LIGHTNING_BOLT,
-                );
-                self.spawn_entity(Arc::new(entity)).await;
+                LightningEntity::strike(self, random_pos.to_f64().add_raw(0.5, 0., 0.5)).await;
             }
         }
-        drop(weather);
 
         if spawn_list.is_empty() {
             return;
@@ -1271,6 +1361,32 @@ impl World {
         spawn_for_chunk(self, chunk_pos, chunk, spawn_state, spawn_list).await;
     }
 
+    /// Finds the closest lightning rod within 128 blocks of `pos` that is the top block of its
+    /// column, returning the position above it that lightning strikes instead of `pos`.
+    pub async fn find_lightning_rod(&self, pos: BlockPos) -> Option<BlockPos> {
+        let rods =
+            self.portal_poi
+                .lock()
+                .await
+                .get_in_square(pos, 128, Some(POI_TYPE_LIGHTNING_ROD));
+        let mut closest: Option<(BlockPos, i32)> = None;
+        for rod in rods {
+            // The POI can outlive its rod, e.g. when the chunk was edited outside the game
+            if !LightningRodBlock::is_lightning_rod(self.get_block(&rod).await) {
+                self.portal_poi.lock().await.remove(&rod);
+                continue;
+            }
+            if self.get_top_block(Vector2::new(rod.0.x, rod.0.z)).await != rod.0.y {
+                continue;
+            }
+            let distance = rod.squared_distance(&pos);
+            if closest.is_none_or(|(_, closest_distance)| distance < closest_distance) {
+                closest = Some((rod, distance));
+            }
+        }
+        closest.map(|(rod, _)| rod.up())
+    }
+
     /// Gets the y position of the first non air block from the top down
     pub async fn get_top_block(&self, position: Vector2<i32>) -> i32 {
         for y in (self.dimension.min_y..self.dimension.height).rev() {
@@ -1284,6 +1400,60 @@ impl World {
         self.dimension.min_y
     }
 
+    /// Finds where a player without a respawn point spawns. Like vanilla, a random spot within the
+    /// `respawn_radius` gamerule around the world spawn is picked, except in adventure mode and in
+    /// dimensions without skylight.
+    pub async fn get_world_spawn_position(&self, gamemode: GameMode) -> Vector3<f64> {
+        let (spawn_x, spawn_z, radius) = {
+            let info = self.level_info.load();
+            (
+                info.spawn_x,
+                info.spawn_z,
+                info.game_rules.respawn_radius.clamp(0, 29_999_984),
+            )
+        };
+        // Keep respawns inside a world border smaller than the radius
+        let inside_border = self
+            .worldborder
+            .lock()
+            .await
+            .distance_inside(f64::from(spawn_x), f64::from(spawn_z))
+            .floor() as i64;
+        let radius = radius.min(inside_border.max(1));
+
+        if self.dimension.has_skylight && gamemode != GameMode::Adventure && radius > 0 {
+            let side = radius * 2 + 1;
+            let area = (side * side).min(i64::from(i32::MAX));
+            let step = coprime_step(area);
+            let start = rng().random_range(0..area);
+            for i in 0..area {
+                let index = (start + step * i) % area;
+                let x = spawn_x + (index % side - radius) as i32;
+                let z = spawn_z + (index / side - radius) as i32;
+                if let Some(y) = self.get_respawn_surface(x, z).await {
+                    return Vector3::new(f64::from(x) + 0.5, f64::from(y), f64::from(z) + 0.5);
+                }
+            }
+        }
+
+        let top = self.get_top_block(Vector2::new(spawn_x, spawn_z)).await;
+        Vector3::new(
+            f64::from(spawn_x) + 0.5,
+            f64::from(top + 1),
+            f64::from(spawn_z) + 0.5,
+        )
+    }
+
+    /// The height a player can spawn at in the given column, `None` if its surface is a fluid.
+    async fn get_respawn_surface(&self, x: i32, z: i32) -> Option<i32> {
+        let top = self.get_top_block(Vector2::new(x, z)).await;
+        let state = self.get_block_state(&BlockPos::new(x, top, z)).await;
+        if state.is_air() || state.is_liquid() {
+            return None;
+        }
+        Some(top + 1)
+    }
+
     /// Gets the `MOTION_BLOCKING` heightmap value for a given XZ position.
     pub async fn get_motion_blocking_height(&self, x: i32, z: i32) -> i32 {
         let chunk_pos = Vector2::new(x >> 4, z >> 4);
@@ -1312,14 +1482,7 @@ impl World {
 
             (position, yaw, pitch)
         } else {
-            let spawn_position = Vector2::new(level_info.spawn_x, level_info.spawn_z);
-            let pos_y = self.get_top_block(spawn_position).await + 1; // +1 to spawn on top of the block
-
-            let position = Vector3::new(
-                f64::from(level_info.spawn_x) + 0.5,
-                f64::from(pos_y),
-                f64::from(level_info.spawn_z) + 0.5,
-            );
+            let position = self.get_world_spawn_position(player.gamemode.load()).await;
             (position, level_info.spawn_yaw, level_info.spawn_pitch)
         };
         // Todo make the data less spread
@@ -1629,15 +1792,8 @@ impl World {
 
             (position, yaw, pitch)
         } else {
-            let info = &self.level_info.load();
-            let spawn_position = Vector2::new(info.spawn_x, info.spawn_z);
-            let pos_y = self.get_top_block(spawn_position).await + 1; // +1 to spawn on top of the block
-
-            let position = Vector3::new(
-                f64::from(info.spawn_x) + 0.5,
-                f64::from(pos_y),
-                f64::from(info.spawn_z) + 0.5,
-            );
+            let position = self.get_world_spawn_position(player.gamemode.load()).await;
+            let info = self.level_info.load();
             (position, info.spawn_yaw, info.spawn_pitch)
         };
 
@@ -1900,6 +2056,12 @@ impl World {
 
         player.send_active_effects().await;
         self.send_player_equipment(player).await;
+        if let Some(server) = self.server.upgrade() {
+            player
+                .recipe_book
+                .send_initial(player, &server.recipe_manager)
+                .await;
+        }
     }
 
     async fn send_player_equipment(&self, from: &Player) {
@@ -1972,14 +2134,47 @@ impl World {
     }
 
     pub async fn explode(self: &Arc<Self>, position: Vector3<f64>, power: f32) {
-        let explosion = Explosion::new(power, position);
-        let block_count = explosion.explode(self).await;
         let particle = if power < 2.0 {
             Particle::Explosion
         } else {
             Particle::ExplosionEmitter
         };
-        let sound = IdOr::<SoundEvent>::Id(Sound::EntityGenericExplode as u16);
+        self.create_explosion(
+            Explosion::new(power, position),
+            particle,
+            Sound::EntityGenericExplode,
+        )
+        .await;
+    }
+
+    /// Knocks back nearby entities and triggers blocks like buttons and doors, without
+    /// damaging either. This is what wind charges do on impact.
+    pub async fn wind_burst(
+        self: &Arc<Self>,
+        position: Vector3<f64>,
+        power: f32,
+        knockback_multiplier: f64,
+        particle: Particle,
+        sound: Sound,
+    ) {
+        self.create_explosion(
+            Explosion::wind_burst(power, position, knockback_multiplier),
+            particle,
+            sound,
+        )
+        .await;
+    }
+
+    async fn create_explosion(
+        self: &Arc<Self>,
+        explosion: Explosion,
+        particle: Particle,
+        sound: Sound,
+    ) {
+        let block_count = explosion.explode(self).await;
+        let position = explosion.position();
+        let power = explosion.power();
+        let sound = IdOr::<SoundEvent>::Id(sound as u16);
         for player in self.players.load().iter() {
             if player.position().squared_distance_to_vec(&position) > 4096.0 {
                 continue;
@@ -2011,11 +2206,9 @@ impl World {
         let data_kept = u8::from(alive);
 
         // Copy spawn info from level_info to avoid holding lock across await
-        let (spawn_x, spawn_z, spawn_yaw, spawn_pitch, keep_inventory) = {
+        let (spawn_yaw, spawn_pitch, keep_inventory) = {
             let info = self.level_info.load();
             (
-                info.spawn_x,
-                info.spawn_z,
                 info.spawn_yaw,
                 info.spawn_pitch,
                 info.game_rules.keep_inventory,
@@ -2038,17 +2231,8 @@ impl World {
                     .send_packet_now(&CGameEvent::new(GameEvent::NoRespawnBlockAvailable, 0.0))
                     .await;
 
-                // FIXME: This spawn position calculation is incorrect. Should use vanilla's
-                // proper spawn position calculation (see #1381). The y-level calculation
-                // needs to account for spawn radius and find a safe spawn position.
-                let top = self.get_top_block(Vector2::new(spawn_x, spawn_z)).await;
-
                 (
-                    Vector3::new(
-                        f64::from(spawn_x) + 0.5,
-                        (top + 1).into(),
-                        f64::from(spawn_z) + 0.5,
-                    ),
+                    self.get_world_spawn_position(player.gamemode.load()).await,
                     spawn_yaw,
                     spawn_pitch,
                     self.dimension,
@@ -2107,14 +2291,7 @@ impl World {
                 "Target world {:?} not found, using world spawn in {:?}",
                 respawn_dimension, self.dimension
             );
-            // FIXME: This spawn position calculation is incorrect. Should use vanilla's
-            // proper spawn position calculation (see #1381).
-            let top = self.get_top_block(Vector2::new(spawn_x, spawn_z)).await;
-            let fallback_pos = Vector3::new(
-                f64::from(spawn_x) + 0.5,
-                (top + 1).into(),
-                f64::from(spawn_z) + 0.5,
-            );
+            let fallback_pos = self.get_world_spawn_position(player.gamemode.load()).await;
             (self.as_ref(), fallback_pos)
         } else {
             (self.as_ref(), position)
@@ -2137,6 +2314,10 @@ impl World {
                 data_kept,
             ))
             .await;
+        // The client dropped every entity, so they have to be spawned for it again
+        target_world
+            .forget_tracking_player(player.entity_id())
+            .await;
 
         // Inform the client of the default spawn position so the client doesn't
         // fall back to (0, 2, 0) while the world reloads (fixes rubberbanding).
@@ -2199,9 +2380,11 @@ impl World {
     pub fn should_skip_night(&self) -> bool {
         let players = self.players.load();
 
-        let player_count = players.len();
+        // Idle (AFK) players neither count towards nor block skipping the night.
+        let player_count = players.iter().filter(|player| !player.is_idle()).count();
         let sleeping_player_count = players
             .iter()
+            .filter(|player| !player.is_idle())
             .filter(|player| {
                 player
                     .sleeping_since
@@ -2356,11 +2539,6 @@ impl World {
                     let entity =
                         from_type(entity_type, Vector3::new(0.0, 0.0, 0.0), &world, *uuid).await;
                     entity.read_nbt_non_mut(entity_nbt).await;
-                    let base_entity = entity.get_entity();
-                    player
-                        .client
-                        .enqueue_packet(&base_entity.create_spawn_packet())
-                        .await;
                     entity.init_data_tracker().await;
 
                     entities_to_add.push(entity);
@@ -2404,6 +2582,21 @@ impl World {
         None
     }
 
+    /// Gets an entity or player by its UUID
+    pub fn get_entity_by_uuid(&self, uuid: Uuid) -> Option<Arc<dyn EntityBase>> {
+        for entity in self.entities.load().iter() {
+            if entity.get_entity().entity_uuid == uuid {
+                return Some(entity.clone());
+            }
+        }
+        for player in self.players.load().iter() {
+            if player.get_entity().entity_uuid == uuid {
+                return Some(player.clone() as Arc<dyn EntityBase>);
+            }
+        }
+        None
+    }
+
     /// Gets a `Player` by a username
     pub fn get_player_by_name(&self, name: &str) -> Option<Arc<Player>> {
         for player in self.players.load().iter() {
@@ -2713,10 +2906,9 @@ impl World {
         removed_player
     }
 
+    /// Adds the entity to the world. Its tracker spawns it for nearby players on the next tick.
     pub async fn spawn_entity(&self, entity: Arc<dyn EntityBase>) {
         let base_entity = entity.get_entity();
-        self.broadcast_packet_all(&base_entity.create_spawn_packet())
-            .await;
         entity.init_data_tracker().await;
 
         let chunk_coordinate = base_entity.block_pos.load().chunk_position();
@@ -2735,7 +2927,22 @@ impl World {
         });
     }
 
+    /// Makes every entity forget that the player was tracking it, so it is spawned for them again
+    /// on the next tick.
+    pub async fn forget_tracking_player(&self, player_id: i32) {
+        for entity in self.entities.load().iter() {
+            entity
+                .get_entity()
+                .tracker
+                .lock()
+                .await
+                .forget_watcher(player_id);
+        }
+    }
+
     pub async fn remove_entity(&self, entity: &Entity) {
+        entity.remove_all_passengers().await;
+        entity.stop_riding().await;
         self.entities.rcu(|current_entities| {
             let mut new_entities = (**current_entities).clone();
             new_entities.retain(|e| e.get_entity().entity_uuid != entity.entity_uuid);
@@ -3256,6 +3463,9 @@ impl World {
             }
 
             let neighbor_pos = block_pos.offset(direction.to_offset());
+            self.redstone_debugger
+                .record_update(neighbor_pos, UpdateKind::Neighbor)
+                .await;
             let (neighbor_block, neighbor_fluid) = self.get_block_and_fluid(&neighbor_pos).await;
 
             if let Some(neighbor_pumpkin_block) =
@@ -3287,6 +3497,9 @@ impl World {
         neighbor_block_pos: &BlockPos,
         source_block: &Block,
     ) {
+        self.redstone_debugger
+            .record_update(*neighbor_block_pos, UpdateKind::Neighbor)
+            .await;
         let neighbor_block = self.get_block(neighbor_block_pos).await;
 
         if let Some(neighbor_pumpkin_block) =
@@ -3317,6 +3530,9 @@ impl World {
         {
             return;
         }
+        self.redstone_debugger
+            .record_update(*block_pos, UpdateKind::Shape)
+            .await;
 
         let neighbor_pos = block_pos.offset(direction.to_offset());
         let neighbor_state_id = self.get_block_state_id(&neighbor_pos).await;
@@ -3656,10 +3872,75 @@ impl pumpkin_world::world::SimpleWorld for World {
         self: Arc<Self>,
         entity_type: &'static EntityType,
         position: Vector3<f64>,
-    ) -> WorldFuture<'static, ()> {
+    ) -> WorldFuture<'static, Uuid> {
         Box::pin(async move {
-            let mob = from_type(entity_type, position, &self, Uuid::new_v4()).await;
+            let uuid = Uuid::new_v4();
+            let mob = from_type(entity_type, position, &self, uuid).await;
             self.spawn_entity(mob).await;
+            uuid
+        })
+    }
+
+    fn is_entity_alive(&self, uuid: Uuid) -> WorldFuture<'_, bool> {
+        Box::pin(async move {
+            self.entities.load().iter().any(|entity| {
+                let entity = entity.get_entity();
+                entity.entity_uuid == uuid && entity.is_alive()
+            })
+        })
+    }
+
+    fn discard_entity(&self, uuid: Uuid) -> WorldFuture<'_, Option<BlockPos>> {
+        Box::pin(async move {
+            let entity = self
+                .entities
+                .load()
+                .iter()
+                .find(|entity| entity.get_entity().entity_uuid == uuid)
+                .cloned()?;
+            let entity = entity.get_entity();
+            let pos = entity.block_pos.load();
+            entity.remove().await;
+            Some(pos)
+        })
+    }
+
+    fn get_nearby_players(
+        &self,
+        center: Vector3<f64>,
+        range: f64,
+    ) -> WorldFuture<'_, Vec<NearbyPlayer>> {
+        Box::pin(async move {
+            let mut nearby = Vec::new();
+            for player in self.players.load().iter() {
+                if player.is_spectator()
+                    || player.position().squared_distance_to_vec(&center) > range * range
+                {
+                    continue;
+                }
+                nearby.push(NearbyPlayer {
+                    uuid: player.gameprofile.id,
+                    has_trial_omen: player
+                        .living_entity
+                        .has_effect(&StatusEffect::TRIAL_OMEN)
+                        .await,
+                });
+            }
+            nearby
+        })
+    }
+
+    fn spawn_item(
+        self: Arc<Self>,
+        position: Vector3<f64>,
+        stack: ItemStack,
+        velocity: Vector3<f64>,
+    ) -> WorldFuture<'static, ()> {
+        Box::pin(async move {
+            let entity = Entity::new(self.clone(), position, &EntityType::ITEM);
+            let item_entity =
+                Arc::new(ItemEntity::new_with_velocity(entity, stack, velocity, 10).await);
+            self.spawn_entity(item_entity).await;
         })
     }
 
@@ -3801,3 +4082,35 @@ impl BlockAccessor for World {
         Box::pin(async move { self.get_block_and_state(position).await })
     }
 }
+
+/// A step coprime to `area`, so that stepping through the area with it visits every index
+/// exactly once.
+fn coprime_step(area: i64) -> i64 {
+    const fn gcd(a: i64, b: i64) -> i64 {
+        if b == 0 { a } else { gcd(b, a % b) }
+    }
+    if area <= 16 {
+        return (area - 1).max(1);
+    }
+    // `area - 1` is always coprime, so a step is always found
+    (17..area).find(|step| gcd(area, *step) == 1).unwrap_or(1)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::coprime_step;
+
+    #[test]
+    fn coprime_step_visits_every_index() {
+        // Sides of 17 and 51 share a factor with the usual step of 17
+        for radius in [0, 1, 2, 8, 10, 25] {
+            let side: i64 = radius * 2 + 1;
+            let area = side * side;
+            let step = coprime_step(area);
+            let visited: HashSet<i64> = (0..area).map(|i| (5 + step * i) % area).collect();
+            assert_eq!(visited.len() as i64, area, "radius {radius}");
+        }
+    }
+}
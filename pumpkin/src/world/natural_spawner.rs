@@ -77,7 +77,8 @@ impl LocalMobCapCalculator {
             Entry::Vacant(entry) => {
                 let mut players = Vec::new();
                 for player in world.players.load().iter() {
-                    if player.gamemode.load() == GameMode::Spectator {
+                    // Idle (AFK) players do not keep the mob spawning range around them active.
+                    if player.gamemode.load() == GameMode::Spectator || player.is_idle() {
                         continue;
                     }
                     if Self::calc_distance(chunk_pos, &player.position()) < 16384. {
@@ -427,18 +428,17 @@ pub async fn spawn_category_for_position(
         for entity in &batch_buffer {
             entity.init_data_tracker().await;
             let base_entity = entity.get_entity();
-            let packet = base_entity.create_spawn_packet();
             let mut nbt = NbtCompound::new();
             entity.write_nbt(&mut nbt).await;
             // Keep the entity reference here so we don't have to "find" it later
-            prepared_data.push((base_entity.entity_uuid, nbt, packet, entity.clone()));
+            prepared_data.push((base_entity.entity_uuid, nbt, entity.clone()));
         }
 
         {
             let chunk_handle = world.level.get_entity_chunk(*chunk_pos).await;
             let mut data = chunk_handle.data.lock().await;
 
-            for (uuid, nbt, _, _) in &prepared_data {
+            for (uuid, nbt, _) in &prepared_data {
                 data.insert(*uuid, nbt.clone());
             }
             drop(data);
@@ -447,7 +447,7 @@ pub async fn spawn_category_for_position(
             world.entities.rcu(|current_entities| {
                 let mut new_entities = (**current_entities).clone();
 
-                for (uuid, _, _, entity_ref) in &prepared_data {
+                for (uuid, _, entity_ref) in &prepared_data {
                     if !new_entities
                         .iter()
                         .any(|e| e.get_entity().entity_uuid == *uuid)
@@ -458,10 +458,7 @@ pub async fn spawn_category_for_position(
                 new_entities
             });
         };
-
-        for (_, _, packet, _) in prepared_data {
-            world.broadcast_packet_all(&packet).await;
-        }
+        // The entity trackers spawn the new entities for nearby players on the next tick
     }
 }
 
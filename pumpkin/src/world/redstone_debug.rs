@@ -0,0 +1,275 @@
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use pumpkin_data::BlockDirection;
+use pumpkin_data::particle::Particle;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector2::Vector2;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::text::TextComponent;
+use pumpkin_util::text::color::NamedColor;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use super::World;
+use crate::entity::player::{Player, TitleMode};
+
+/// The most blocks a single session may watch, as every one of them is inspected on each render.
+pub const MAX_VOLUME: i64 = 4096;
+/// How many ticks pass between two renders of a session.
+const RENDER_INTERVAL: u32 = 4;
+/// How many entries of the update order and tick queue fit in the action bar.
+const MAX_LISTED: usize = 4;
+
+/// What caused a block in a watched region to update.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UpdateKind {
+    /// A scheduled block tick ran.
+    Tick,
+    /// A neighboring block changed.
+    Neighbor,
+    /// The block's state was recomputed after a neighbor changed shape.
+    Shape,
+}
+
+impl UpdateKind {
+    const fn symbol(self) -> char {
+        match self {
+            Self::Tick => 'T',
+            Self::Neighbor => 'N',
+            Self::Shape => 'S',
+        }
+    }
+}
+
+/// A player watching the redstone in a region through `/debugredstone`.
+struct Session {
+    player: Uuid,
+    min: BlockPos,
+    max: BlockPos,
+    remaining_ticks: u32,
+    /// The block updates that happened in the region since the last render, in order.
+    updates: Vec<(BlockPos, UpdateKind)>,
+}
+
+impl Session {
+    const fn contains(&self, pos: &BlockPos) -> bool {
+        pos.0.x >= self.min.0.x
+            && pos.0.x <= self.max.0.x
+            && pos.0.y >= self.min.0.y
+            && pos.0.y <= self.max.0.y
+            && pos.0.z >= self.min.0.z
+            && pos.0.z <= self.max.0.z
+    }
+}
+
+/// Streams redstone power levels, scheduled tick queues and update order of a region to the
+/// players that asked for them.
+///
+/// Powered blocks show electric sparks, one per power level, blocks with a pending scheduled tick
+/// show a flame and blocks that were just updated show green sparkles. The action bar lists the
+/// update order and the next ticks in the queue.
+#[derive(Default)]
+pub struct RedstoneDebugger {
+    sessions: Mutex<Vec<Session>>,
+    /// The number of sessions, so updates can be skipped without locking when nobody watches.
+    active: AtomicUsize,
+}
+
+impl RedstoneDebugger {
+    /// Starts watching the region between `from` and `to` for `ticks` ticks, replacing any region
+    /// the player was already watching.
+    pub async fn start(&self, player: Uuid, from: BlockPos, to: BlockPos, ticks: u32) {
+        let session = Session {
+            player,
+            min: BlockPos::new(
+                from.0.x.min(to.0.x),
+                from.0.y.min(to.0.y),
+                from.0.z.min(to.0.z),
+            ),
+            max: BlockPos::new(
+                from.0.x.max(to.0.x),
+                from.0.y.max(to.0.y),
+                from.0.z.max(to.0.z),
+            ),
+            remaining_ticks: ticks,
+            updates: Vec::new(),
+        };
+        let mut sessions = self.sessions.lock().await;
+        sessions.retain(|session| session.player != player);
+        sessions.push(session);
+        self.active.store(sessions.len(), Ordering::Relaxed);
+    }
+
+    /// Stops the player's session, returning whether there was one.
+    pub async fn stop(&self, player: Uuid) -> bool {
+        let mut sessions = self.sessions.lock().await;
+        let len = sessions.len();
+        sessions.retain(|session| session.player != player);
+        self.active.store(sessions.len(), Ordering::Relaxed);
+        sessions.len() != len
+    }
+
+    /// Records that the block at `pos` was updated.
+    pub async fn record_update(&self, pos: BlockPos, kind: UpdateKind) {
+        if self.active.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+        let mut sessions = self.sessions.lock().await;
+        for session in sessions.iter_mut().filter(|session| session.contains(&pos)) {
+            session.updates.push((pos, kind));
+        }
+    }
+
+    pub async fn tick(&self, world: &World) {
+        let mut sessions = self.sessions.lock().await;
+        if sessions.is_empty() {
+            return;
+        }
+        let mut finished = Vec::new();
+        for session in sessions.iter_mut() {
+            let Some(player) = world.get_player_by_uuid(session.player) else {
+                finished.push(session.player);
+                continue;
+            };
+            session.remaining_ticks = session.remaining_ticks.saturating_sub(1);
+            if session.remaining_ticks == 0 {
+                player
+                    .show_title(
+                        &TextComponent::text("Redstone debugging finished"),
+                        &TitleMode::ActionBar,
+                    )
+                    .await;
+                finished.push(session.player);
+            } else if session.remaining_ticks.is_multiple_of(RENDER_INTERVAL) {
+                Self::render(world, &player, session).await;
+                session.updates.clear();
+            }
+        }
+        sessions.retain(|session| !finished.contains(&session.player));
+        self.active.store(sessions.len(), Ordering::Relaxed);
+    }
+
+    async fn render(world: &World, player: &Player, session: &Session) {
+        let mut powered = 0;
+        let mut max_power = 0;
+        for x in session.min.0.x..=session.max.0.x {
+            for y in session.min.0.y..=session.max.0.y {
+                for z in session.min.0.z..=session.max.0.z {
+                    let pos = BlockPos::new(x, y, z);
+                    let power = Self::get_power(world, &pos).await;
+                    if power == 0 {
+                        continue;
+                    }
+                    powered += 1;
+                    max_power = max_power.max(power);
+                    Self::spawn_marker(player, &pos, Particle::ElectricSpark, i32::from(power))
+                        .await;
+                }
+            }
+        }
+
+        let queue = Self::get_scheduled_ticks(world, session);
+        for (pos, _) in &queue {
+            Self::spawn_marker(player, pos, Particle::Flame, 1).await;
+        }
+        for (pos, _) in &session.updates {
+            Self::spawn_marker(player, pos, Particle::HappyVillager, 1).await;
+        }
+
+        let mut status = format!(
+            "{}s | {powered} powered (max {max_power}) | {} scheduled",
+            session.remaining_ticks.div_ceil(20),
+            queue.len()
+        );
+        for (pos, delay) in queue.iter().take(MAX_LISTED) {
+            let _ = write!(status, " [{pos} in {delay}t]");
+        }
+        if !session.updates.is_empty() {
+            status.push_str(" | updates:");
+            for (i, (pos, kind)) in session.updates.iter().take(MAX_LISTED).enumerate() {
+                let _ = write!(status, " {}. {}[{pos}]", i + 1, kind.symbol());
+            }
+            if session.updates.len() > MAX_LISTED {
+                let _ = write!(status, " +{}", session.updates.len() - MAX_LISTED);
+            }
+        }
+        player
+            .show_title(
+                &TextComponent::text(status).color_named(NamedColor::Red),
+                &TitleMode::ActionBar,
+            )
+            .await;
+    }
+
+    /// The strongest power the block emits in any direction.
+    async fn get_power(world: &World, pos: &BlockPos) -> u8 {
+        let (block, state) = world.get_block_and_state(pos).await;
+        if state.is_air() {
+            return 0;
+        }
+        let mut power = 0;
+        for direction in BlockDirection::all() {
+            power = power.max(
+                world
+                    .block_registry
+                    .get_weak_redstone_power(block, world, pos, state, direction)
+                    .await,
+            );
+        }
+        power
+    }
+
+    /// The pending block ticks in the region in the order they will run, with their delay. Only
+    /// loaded chunks are looked at.
+    fn get_scheduled_ticks(world: &World, session: &Session) -> Vec<(BlockPos, u8)> {
+        let min_chunk = session.min.chunk_position();
+        let max_chunk = session.max.chunk_position();
+        let mut ticks = Vec::new();
+        for chunk_x in min_chunk.x..=max_chunk.x {
+            for chunk_z in min_chunk.y..=max_chunk.y {
+                let Some(chunk) = world
+                    .level
+                    .loaded_chunks
+                    .get(&Vector2::new(chunk_x, chunk_z))
+                    .map(|chunk| chunk.clone())
+                else {
+                    continue;
+                };
+                ticks.extend(
+                    chunk
+                        .block_ticks
+                        .to_vec()
+                        .into_iter()
+                        .filter(|tick| session.contains(&tick.position))
+                        .map(|tick| (tick.delay, tick.priority, tick.position)),
+                );
+            }
+        }
+        // Ticks due on the same game tick run by priority, then in the order they were scheduled
+        ticks.sort_by_key(|(delay, priority, _)| (*delay, *priority));
+        ticks
+            .into_iter()
+            .map(|(delay, _, pos)| (pos, delay))
+            .collect()
+    }
+
+    async fn spawn_marker(player: &Player, pos: &BlockPos, particle: Particle, count: i32) {
+        player
+            .spawn_particle(
+                pos.to_centered_f64(),
+                Vector3::new(0.2, 0.2, 0.2),
+                0.0,
+                count,
+                particle,
+            )
+            .await;
+    }
+}
+
+/// The number of blocks in the region between `from` and `to`.
+#[must_use]
+pub fn volume(from: &BlockPos, to: &BlockPos) -> i64 {
+    let size = |a: i32, b: i32| i64::from((a - b).abs()) + 1;
+    size(from.0.x, to.0.x) * size(from.0.y, to.0.y) * size(from.0.z, to.0.z)
+}
@@ -2,6 +2,9 @@ use pumpkin_protocol::{bedrock::client::set_time::CSetTime, java::client::play::
 
 use super::World;
 
+/// Brightness of the moon for each of the eight moon phases, starting at full moon.
+const MOON_BRIGHTNESS_PER_PHASE: [f32; 8] = [1.0, 0.75, 0.5, 0.25, 0.0, 0.25, 0.5, 0.75];
+
 pub struct LevelTime {
     pub world_age: i64,
     pub time_of_day: i64,
@@ -66,6 +69,16 @@ impl LevelTime {
         self.time_of_day / 24000
     }
 
+    #[must_use]
+    pub const fn moon_phase(&self) -> usize {
+        self.query_day().rem_euclid(8) as usize
+    }
+
+    #[must_use]
+    pub const fn moon_brightness(&self) -> f32 {
+        MOON_BRIGHTNESS_PER_PHASE[self.moon_phase()]
+    }
+
     #[must_use]
     pub const fn is_night(&self) -> bool {
         (self.time_of_day % 24000) >= 12000 && (self.time_of_day % 24000) <= 23999